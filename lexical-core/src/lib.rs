@@ -58,6 +58,30 @@
 //! # }
 //! ```
 //!
+//! For columnar data, such as a row of CSV, [`parse_delimited`] parses
+//! every delimiter-separated field into a slice, reporting which field
+//! failed to parse:
+//!
+//! ```rust
+//! # #[cfg(feature = "parse-floats")] {
+//! let mut values = [0f64; 3];
+//! let r = lexical_core::parse_delimited(b"1.5,2,-3e1", b',', &mut values); // Ok((3, 10))
+//! # }
+//! ```
+//!
+//! Strings stored as UTF-16 or UTF-32 code units, such as from Windows or
+//! JavaScript APIs, can be parsed with [`parse_units`] and the other
+//! `_units` parsers. The code units of the number are narrowed to bytes
+//! before parsing, which allocates for long numbers, and any error indexes
+//! are in code units:
+//!
+//! ```rust
+//! # #[cfg(all(feature = "parse-integers", feature = "std"))] {
+//! let units: Vec<u16> = "1a5".encode_utf16().collect();
+//! let r = lexical_core::parse_units::<i32, _>(&units); // Err(ErrorCode::InvalidDigit.into())
+//! # }
+//! ```
+//!
 //! #### Write API
 //!
 //! The main parsing API is [`write`]. For example, to write a number to an
@@ -89,6 +113,21 @@
 //! are at least [`T::FORMATTED_SIZE_DECIMAL`] bytes, which requires the
 //! [`FormattedSize`] trait to be in scope.
 //!
+//! To write many numbers, such as a JSON array or a row of CSV, into a
+//! single buffer, use [`write_slice`] with a separator, and size the buffer
+//! with [`slice_buffer_size`]:
+//!
+//! ```rust
+//! # #[cfg(feature = "write-integers")] {
+//! use lexical_core::FormattedSize;
+//!
+//! const SIZE: usize = lexical_core::slice_buffer_size(3, i32::FORMATTED_SIZE_DECIMAL, 1);
+//! let mut buf = [b'0'; SIZE];
+//! let slc = lexical_core::write_slice::<i32>(&[1, -2, 3], b",", &mut buf);
+//! assert_eq!(slc, b"1,-2,3");
+//! # }
+//! ```
+//!
 //! <!-- References -->
 #![cfg_attr(
     any(feature = "write-floats", feature = "write-integers"),
@@ -245,10 +284,17 @@
 //! * `format` - Add support for custom number formatting.
 //! * `f16` - Enable support for half-precision [`f16`][`ieee-f16`] and
 //!   [`bf16`][`brain-float`] floats.
+//! * `f128` - Enable support for quadruple-precision [`f128`][`ieee-f128`]
+//!   floats.
+//! * `unicode` - Enable support for parsing and writing Unicode decimal
+//!   digits, such as fullwidth or Arabic-Indic digits.
+//! * `diagnostic` - Enable diagnostic parse errors with the span, component,
+//!   and format rule of the error.
 //! * `std` (Default) - Disable to allow use in a [`no_std`] environment.
 //!
 //! [`ieee-f16`]: https://en.wikipedia.org/wiki/Half-precision_floating-point_format
 //! [`brain-float`]: https://en.wikipedia.org/wiki/Bfloat16_floating-point_format
+//! [`ieee-f128`]: https://en.wikipedia.org/wiki/Quadruple-precision_floating-point_format
 //!
 //! A complete description of supported features includes:
 //!
@@ -463,6 +509,13 @@
 //! For a list of all supported fields, see
 //! [Fields][NumberFormatBuilder#fields-1].
 //!
+//! The number format is a const generic, so it must be known at compile
+//! time. To select one of a few prebuilt formats at runtime, such as the
+//! dialect of a query, use [`PrebuiltFormat`], which supports the
+//! data-interchange and database formats, such as [`JSON`], and the
+//! standard format for each radix. Other formats, including custom formats
+//! from a [`NumberFormatBuilder`], must be known at compile time.
+//!
 //! <!-- Spacer for rustfmt -->
 #![cfg_attr(
     feature = "format",
@@ -509,6 +562,7 @@
 //! - The exponent [`break-point`][`write-float-positive_exponent_break`] for scientific notation.
 //! - The [`maximum`][`write-float-max_significant_digits`] and [`minimum`][`write-float-min_significant_digits`] number of significant digits to write.
 //! - The rounding [`mode`][`write-float-round_mode`] when truncating significant digits while writing.
+//! - The [`separator`][`write-float-group_separator`] to group the integer digits while writing, such as `1,234,567.89`.
 //!
 //! <!-- Spacer for Rustfmt -->
 #![cfg_attr(
@@ -522,6 +576,7 @@
 [`write-float-max_significant_digits`]: WriteFloatOptionsBuilder::max_significant_digits
 [`write-float-min_significant_digits`]: WriteFloatOptionsBuilder::min_significant_digits
 [`write-float-round_mode`]: WriteFloatOptionsBuilder::round_mode
+[`write-float-group_separator`]: WriteFloatOptionsBuilder::group_separator
 "
 )]
 #![cfg_attr(
@@ -535,6 +590,7 @@
 [`write-float-max_significant_digits`]: https://docs.rs/lexical-core/latest/lexical_core/struct.WriteFloatOptionsBuilder.html#method.max_significant_digits
 [`write-float-min_significant_digits`]: https://docs.rs/lexical-core/latest/lexical_core/struct.WriteFloatOptionsBuilder.html#method.min_significant_digits
 [`write-float-round_mode`]: https://docs.rs/lexical-core/latest/lexical_core/struct.WriteFloatOptionsBuilder.html#method.round_mode
+[`write-float-group_separator`]: https://docs.rs/lexical-core/latest/lexical_core/struct.WriteFloatOptionsBuilder.html#method.group_separator
 "
 )]
 //!
@@ -640,6 +696,8 @@
     doc = "
 [`write`]: crate::write
 [`write_with_options`]: crate::write_with_options
+[`write_slice`]: crate::write_slice
+[`slice_buffer_size`]: crate::slice_buffer_size
 "
 )]
 #![cfg_attr(
@@ -647,6 +705,8 @@
     doc = "
 [`write`]: https://docs.rs/lexical-core/latest/lexical_core/fn.write.html
 [`write_with_options`]: https://docs.rs/lexical-core/latest/lexical_core/fn.write_with_options.html
+[`write_slice`]: https://docs.rs/lexical-core/latest/lexical_core/fn.write_slice.html
+[`slice_buffer_size`]: https://docs.rs/lexical-core/latest/lexical_core/fn.slice_buffer_size.html
 "
 )]
 #![cfg_attr(
@@ -656,8 +716,35 @@
 [`parse_partial`]: crate::parse_partial
 [`parse_with_options`]: crate::parse_with_options
 [`parse_partial_with_options`]: crate::parse_partial_with_options
+[`parse_delimited`]: crate::parse_delimited
 "
 )]
+#![cfg_attr(
+    all(feature = "std", any(feature = "parse-floats", feature = "parse-integers")),
+    doc = "[`parse_units`]: crate::parse_units"
+)]
+#![cfg_attr(
+    not(all(feature = "std", any(feature = "parse-floats", feature = "parse-integers"))),
+    doc = "[`parse_units`]: https://docs.rs/lexical-core/latest/lexical_core/fn.parse_units.html"
+)]
+#![cfg_attr(
+    any(
+        feature = "parse-floats",
+        feature = "parse-integers",
+        feature = "write-floats",
+        feature = "write-integers"
+    ),
+    doc = "[`PrebuiltFormat`]: crate::PrebuiltFormat"
+)]
+#![cfg_attr(
+    not(any(
+        feature = "parse-floats",
+        feature = "parse-integers",
+        feature = "write-floats",
+        feature = "write-integers"
+    )),
+    doc = "[`PrebuiltFormat`]: https://docs.rs/lexical-core/latest/lexical_core/struct.PrebuiltFormat.html"
+)]
 #![cfg_attr(
     not(any(feature = "parse-floats", feature = "parse-integers")),
     doc = "
@@ -665,6 +752,7 @@
 [`parse_partial`]: https://docs.rs/lexical-core/latest/lexical_core/fn.parse_partial.html
 [`parse_with_options`]: https://docs.rs/lexical-core/latest/lexical_core/fn.parse_with_options.html
 [`parse_partial_with_options`]: https://docs.rs/lexical-core/latest/lexical_core/fn.parse_partial_with_options.html
+[`parse_delimited`]: https://docs.rs/lexical-core/latest/lexical_core/fn.parse_delimited.html
 "
 )]
 //!
@@ -710,6 +798,9 @@
 )]
 #![cfg_attr(rustfmt, rustfmt_skip)]  // reason = "this simplifies our imports"
 
+#[cfg(any(feature = "parse-floats", feature = "parse-integers"))]
+use core::{fmt, result};
+
 // Re-exports
 pub use lexical_util::Error;
 pub use lexical_util::result::Result;
@@ -730,11 +821,23 @@ pub use lexical_util::bf16::bf16;
 #[cfg(feature = "f16")]
 pub use lexical_util::f16::f16;
 
+#[cfg(feature = "f128")]
+pub use lexical_util::f128::f128;
+
+#[cfg(feature = "unicode")]
+pub use lexical_util::unicode::DigitBlock;
+
+#[cfg(feature = "diagnostic")]
+pub use lexical_util::diagnostic::{Component, Diagnostic, Expected, Rule};
+
 // PARSE
 
 #[cfg(any(feature = "parse-floats", feature = "parse-integers"))]
 pub use lexical_util::options::ParseOptions;
 
+#[cfg(all(feature = "std", any(feature = "parse-floats", feature = "parse-integers")))]
+pub use lexical_util::code_unit::CodeUnit;
+
 #[cfg(any(feature = "parse-floats", feature = "parse-integers"))]
 use lexical_util::{from_lexical, from_lexical_with_options};
 
@@ -898,6 +1001,9 @@ macro_rules! float_from_lexical {
 #[cfg(feature = "parse-floats")]
 float_from_lexical! { f32 f64 }
 
+#[cfg(all(feature = "parse-floats", feature = "f128"))]
+float_from_lexical! { f128 }
+
 /// Implement `ToLexical` and `ToLexicalWithOptions` for numeric types.
 ///
 /// * `t`                           - The numerical type.
@@ -952,6 +1058,9 @@ macro_rules! float_to_lexical {
 #[cfg(feature = "write-floats")]
 float_to_lexical! { f32 f64 }
 
+#[cfg(all(feature = "write-floats", feature = "f128"))]
+float_to_lexical! { f128 }
+
 /// Write number to string.
 ///
 /// Returns a subslice of the input buffer containing the written bytes,
@@ -1070,6 +1179,343 @@ pub fn write_with_options<'a, N: ToLexicalWithOptions, const FORMAT: u128>(
     n.to_lexical_with_options::<FORMAT>(bytes, options)
 }
 
+/// Write number to string with Unicode decimal digits.
+///
+/// This is identical to [`write`][fn@write], except the digits `0-9` are
+/// written as the digits of the block. The buffer must be at least
+/// [`DigitBlock::buffer_size`] of [`FORMATTED_SIZE_DECIMAL`] bytes long.
+///
+/// * `value`   - Number to serialize.
+/// * `block`   - The block of digits to write.
+/// * `bytes`   - Buffer to write number to.
+///
+/// # Panics
+///
+/// Panics if the buffer is not large enough to hold the serialized number.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "write-integers")] {
+/// use lexical_core::{DigitBlock, FormattedSize};
+///
+/// const SIZE: usize = DigitBlock::FULLWIDTH.buffer_size(i32::FORMATTED_SIZE_DECIMAL);
+/// let mut buffer = [0u8; SIZE];
+/// let digits = lexical_core::write_unicode(-123i32, DigitBlock::FULLWIDTH, &mut buffer);
+/// assert_eq!(digits, "-１２３".as_bytes());
+/// # }
+/// ```
+///
+/// [`FORMATTED_SIZE_DECIMAL`]: FormattedSize::FORMATTED_SIZE_DECIMAL
+#[inline]
+#[cfg(all(feature = "unicode", any(feature = "write-floats", feature = "write-integers")))]
+pub fn write_unicode<N: ToLexical>(n: N, block: DigitBlock, bytes: &mut [u8]) -> &mut [u8] {
+    let count = n.to_lexical(bytes).len();
+    let count = block.encode_digits(bytes, count);
+    &mut bytes[..count]
+}
+
+/// Write number to string with Unicode decimal digits and custom options.
+///
+/// This is identical to [`write_with_options`], except the digits `0-9`
+/// are written as the digits of the block. The buffer must be at least
+/// [`DigitBlock::buffer_size`] of the required buffer size of the options.
+///
+/// * `FORMAT`  - Packed struct containing the number format.
+/// * `value`   - Number to serialize.
+/// * `block`   - The block of digits to write.
+/// * `bytes`   - Buffer to write number to.
+/// * `options` - Options to customize number writing.
+///
+/// # Panics
+///
+/// Panics if the buffer is not large enough to hold the serialized number.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "write-floats")] {
+/// use lexical_core::{DigitBlock, FormattedSize, WriteFloatOptions};
+///
+/// const FORMAT: u128 = lexical_core::format::STANDARD;
+/// const OPTIONS: WriteFloatOptions = WriteFloatOptions::new();
+/// const SIZE: usize = DigitBlock::ARABIC_INDIC.buffer_size(f64::FORMATTED_SIZE_DECIMAL);
+/// let mut buffer = [0u8; SIZE];
+/// let block = DigitBlock::ARABIC_INDIC;
+/// let digits = lexical_core::write_unicode_with_options::<_, FORMAT>(1.5f64, block, &mut buffer, &OPTIONS);
+/// assert_eq!(digits, "١.٥".as_bytes());
+/// # }
+/// ```
+#[inline]
+#[cfg(all(feature = "unicode", any(feature = "write-floats", feature = "write-integers")))]
+pub fn write_unicode_with_options<'a, N: ToLexicalWithOptions, const FORMAT: u128>(
+    n: N,
+    block: DigitBlock,
+    bytes: &'a mut [u8],
+    options: &N::Options,
+) -> &'a mut [u8] {
+    let count = n.to_lexical_with_options::<FORMAT>(bytes, options).len();
+    let count = block.encode_digits(bytes, count);
+    &mut bytes[..count]
+}
+
+/// Get the buffer size required to write a slice of numbers.
+///
+/// This is the size of each number, such as [`FORMATTED_SIZE_DECIMAL`] or
+/// the `buffer_size_const` of the write options, for every number, plus
+/// a separator between each number. Since it is a `const fn`, it may be
+/// used to size arrays at compile time. If the size overflows, this
+/// returns [`usize::MAX`], which no buffer can hold.
+///
+/// * `count`           - The number of values to write.
+/// * `value_size`      - The maximum number of bytes for each value.
+/// * `separator_size`  - The number of bytes in the separator.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "write-integers")] {
+/// use lexical_core::FormattedSize;
+///
+/// const SIZE: usize = lexical_core::slice_buffer_size(3, u8::FORMATTED_SIZE_DECIMAL, 2);
+/// assert_eq!(SIZE, 13);
+/// assert_eq!(lexical_core::slice_buffer_size(usize::MAX, 2, 1), usize::MAX);
+/// # }
+/// ```
+///
+/// [`FORMATTED_SIZE_DECIMAL`]: FormattedSize::FORMATTED_SIZE_DECIMAL
+#[inline(always)]
+#[cfg(any(feature = "write-floats", feature = "write-integers"))]
+pub const fn slice_buffer_size(count: usize, value_size: usize, separator_size: usize) -> usize {
+    let values = count.checked_mul(value_size);
+    let separators = count.saturating_sub(1).checked_mul(separator_size);
+    match (values, separators) {
+        (Some(values), Some(separators)) => match values.checked_add(separators) {
+            Some(size) => size,
+            None => usize::MAX,
+        },
+        _ => usize::MAX,
+    }
+}
+
+/// Write a slice of numbers to string, with a separator between each number.
+///
+/// Returns a subslice of the input buffer containing the written bytes,
+/// starting from the same address in memory as the input slice.
+///
+/// * `values`      - Numbers to serialize.
+/// * `separator`   - Bytes to write between each number.
+/// * `bytes`       - Buffer to write numbers to.
+///
+/// # Panics
+///
+/// Panics if the buffer may not be large enough to hold the serialized
+/// numbers. In order to ensure the function will not panic, provide a
+/// buffer with at least [`slice_buffer_size`] elements, using
+/// `{integer}::FORMATTED_SIZE` for each number.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "write-floats")] {
+/// use lexical_core::FormattedSize;
+///
+/// const SIZE: usize = lexical_core::slice_buffer_size(3, f64::FORMATTED_SIZE_DECIMAL, 1);
+/// let mut buffer = [0u8; SIZE];
+/// let values = [1.5, -2.0, 1e300];
+/// let digits = lexical_core::write_slice(&values, b",", &mut buffer);
+/// assert_eq!(digits, b"1.5,-2.0,1.0e300");
+/// # }
+/// ```
+#[inline]
+#[cfg(any(feature = "write-floats", feature = "write-integers"))]
+pub fn write_slice<'a, N: ToLexical>(
+    values: &[N],
+    separator: &[u8],
+    bytes: &'a mut [u8],
+) -> &'a mut [u8] {
+    write_iter(values.iter().copied(), separator, bytes)
+}
+
+/// Write a slice of numbers to string with custom options, with a separator
+/// between each number.
+///
+/// Returns a subslice of the input buffer containing the written bytes,
+/// starting from the same address in memory as the input slice.
+///
+/// * `FORMAT`      - Packed struct containing the number format.
+/// * `values`      - Numbers to serialize.
+/// * `separator`   - Bytes to write between each number.
+/// * `bytes`       - Buffer to write numbers to.
+/// * `options`     - Options to customize number writing.
+///
+/// # Panics
+///
+/// Panics if the buffer may not be large enough to hold the serialized
+/// numbers. In order to ensure the function will not panic, provide a
+/// buffer with at least [`slice_buffer_size`] elements, using the
+/// `buffer_size_const` of the options for each number.
+///
+/// If the provided `FORMAT` is not valid, the function may panic. Please
+/// ensure `is_valid()` is called prior to using the format, or checking
+/// its validity using a static assertion.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "write-integers")] {
+/// const FORMAT: u128 = lexical_core::format::STANDARD;
+/// const OPTIONS: lexical_core::WriteIntegerOptions = lexical_core::WriteIntegerOptions::new();
+/// const SIZE: usize = lexical_core::slice_buffer_size(
+///     4,
+///     OPTIONS.buffer_size_const::<i32, FORMAT>(),
+///     2,
+/// );
+/// let mut buffer = [0u8; SIZE];
+/// let values = [1, -20, 300, -4000];
+/// let digits = lexical_core::write_slice_with_options::<_, FORMAT>(
+///     &values,
+///     b", ",
+///     &mut buffer,
+///     &OPTIONS,
+/// );
+/// assert_eq!(digits, b"1, -20, 300, -4000");
+/// # }
+/// ```
+#[inline]
+#[cfg(any(feature = "write-floats", feature = "write-integers"))]
+pub fn write_slice_with_options<'a, N: ToLexicalWithOptions, const FORMAT: u128>(
+    values: &[N],
+    separator: &[u8],
+    bytes: &'a mut [u8],
+    options: &N::Options,
+) -> &'a mut [u8] {
+    write_iter_with_options::<_, _, FORMAT>(values.iter().copied(), separator, bytes, options)
+}
+
+/// Write numbers from an iterator to string, with a separator between
+/// each number.
+///
+/// Returns a subslice of the input buffer containing the written bytes,
+/// starting from the same address in memory as the input slice.
+///
+/// * `values`      - Numbers to serialize.
+/// * `separator`   - Bytes to write between each number.
+/// * `bytes`       - Buffer to write numbers to.
+///
+/// # Panics
+///
+/// Panics if the buffer may not be large enough to hold the serialized
+/// numbers. In order to ensure the function will not panic, provide a
+/// buffer with at least [`slice_buffer_size`] elements, using
+/// `{integer}::FORMATTED_SIZE` for each number.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "write-integers")] {
+/// let mut buffer = [0u8; lexical_core::BUFFER_SIZE];
+/// let digits = lexical_core::write_iter((1..=5u32).map(|x| x * x), b"\t", &mut buffer);
+/// assert_eq!(digits, b"1\t4\t9\t16\t25");
+/// # }
+/// ```
+#[inline]
+#[cfg(any(feature = "write-floats", feature = "write-integers"))]
+pub fn write_iter<'a, N: ToLexical, I: IntoIterator<Item = N>>(
+    values: I,
+    separator: &[u8],
+    bytes: &'a mut [u8],
+) -> &'a mut [u8] {
+    write_iter_impl(values, separator, bytes, |value, buffer| value.to_lexical(buffer).len())
+}
+
+/// Write numbers from an iterator to string with custom options, with a
+/// separator between each number.
+///
+/// Returns a subslice of the input buffer containing the written bytes,
+/// starting from the same address in memory as the input slice.
+///
+/// * `FORMAT`      - Packed struct containing the number format.
+/// * `values`      - Numbers to serialize.
+/// * `separator`   - Bytes to write between each number.
+/// * `bytes`       - Buffer to write numbers to.
+/// * `options`     - Options to customize number writing.
+///
+/// # Panics
+///
+/// Panics if the buffer may not be large enough to hold the serialized
+/// numbers. In order to ensure the function will not panic, provide a
+/// buffer with at least [`slice_buffer_size`] elements, using the
+/// `buffer_size_const` of the options for each number.
+///
+/// If the provided `FORMAT` is not valid, the function may panic. Please
+/// ensure `is_valid()` is called prior to using the format, or checking
+/// its validity using a static assertion.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "write-floats")] {
+/// const FORMAT: u128 = lexical_core::format::STANDARD;
+/// const OPTIONS: lexical_core::WriteFloatOptions = lexical_core::WriteFloatOptions::new();
+/// const SIZE: usize = lexical_core::slice_buffer_size(
+///     2,
+///     OPTIONS.buffer_size_const::<f32, FORMAT>(),
+///     1,
+/// );
+/// let mut buffer = [0u8; SIZE];
+/// let values = [0.5f32, 0.25].into_iter();
+/// let digits = lexical_core::write_iter_with_options::<_, _, FORMAT>(
+///     values,
+///     b";",
+///     &mut buffer,
+///     &OPTIONS,
+/// );
+/// assert_eq!(digits, b"0.5;0.25");
+/// # }
+/// ```
+#[inline]
+#[cfg(any(feature = "write-floats", feature = "write-integers"))]
+pub fn write_iter_with_options<'a, N, I, const FORMAT: u128>(
+    values: I,
+    separator: &[u8],
+    bytes: &'a mut [u8],
+    options: &N::Options,
+) -> &'a mut [u8]
+where
+    N: ToLexicalWithOptions,
+    I: IntoIterator<Item = N>,
+{
+    let write = |value: N, buffer: &mut [u8]| {
+        value.to_lexical_with_options::<FORMAT>(buffer, options).len()
+    };
+    write_iter_impl(values, separator, bytes, write)
+}
+
+/// Write numbers from an iterator to string, using a number writer.
+#[inline(always)]
+#[cfg(any(feature = "write-floats", feature = "write-integers"))]
+fn write_iter_impl<'a, N, I, Write>(
+    values: I,
+    separator: &[u8],
+    bytes: &'a mut [u8],
+    write: Write,
+) -> &'a mut [u8]
+where
+    I: IntoIterator<Item = N>,
+    Write: Fn(N, &mut [u8]) -> usize,
+{
+    let mut index = 0;
+    for (count, value) in values.into_iter().enumerate() {
+        if count != 0 {
+            bytes[index..index + separator.len()].copy_from_slice(separator);
+            index += separator.len();
+        }
+        index += write(value, &mut bytes[index..]);
+    }
+    &mut bytes[..index]
+}
+
 /// Parse complete number from string.
 ///
 /// This method parses the entire string, returning an error if
@@ -1173,3 +1619,775 @@ pub fn parse_partial_with_options<N: FromLexicalWithOptions, const FORMAT: u128>
 ) -> Result<(N, usize)> {
     N::from_lexical_partial_with_options::<FORMAT>(bytes, options)
 }
+
+/// Parse complete number from UTF-16 or UTF-32 code units.
+///
+/// This is identical to [`parse`], except any error indexes are in
+/// code units.
+///
+/// * `units`   - Slice of code units containing a numeric string.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "parse-floats")] {
+/// let units: Vec<u16> = "3.14159265359".encode_utf16().collect();
+/// let result = lexical_core::parse_units::<f32, _>(&units);
+/// assert_eq!(result, Ok(3.14159265359_f32));
+/// # }
+/// ```
+#[inline]
+#[cfg(all(feature = "std", any(feature = "parse-floats", feature = "parse-integers")))]
+pub fn parse_units<N: FromLexical, C: CodeUnit>(units: &[C]) -> Result<N> {
+    N::from_lexical_units(units)
+}
+
+/// Parse partial number from UTF-16 or UTF-32 code units.
+///
+/// This is identical to [`parse_partial`], except the number of
+/// processed elements and any error indexes are in code units.
+///
+/// * `units`   - Slice of code units containing a numeric string.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "parse-floats")] {
+/// let units: Vec<u16> = "3.14159265359 \u{3c0}".encode_utf16().collect();
+/// let result = lexical_core::parse_partial_units::<f32, _>(&units);
+/// assert_eq!(result, Ok((3.14159265359_f32, 13)));
+/// # }
+/// ```
+#[inline]
+#[cfg(all(feature = "std", any(feature = "parse-floats", feature = "parse-integers")))]
+pub fn parse_partial_units<N: FromLexical, C: CodeUnit>(units: &[C]) -> Result<(N, usize)> {
+    N::from_lexical_partial_units(units)
+}
+
+/// Parse complete number from UTF-16 or UTF-32 code units with custom
+/// parsing options.
+///
+/// This is identical to [`parse_with_options`], except any error indexes
+/// are in code units.
+///
+/// * `FORMAT`  - Packed struct containing the number format.
+/// * `units`   - Slice of code units containing a numeric string.
+/// * `options` - Options to customize number parsing.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(all(feature = "parse-floats", feature = "format"))] {
+/// const JSON: u128 = lexical_core::format::JSON;
+/// const OPTIONS: lexical_core::ParseFloatOptions = lexical_core::ParseFloatOptions::new();
+/// let units: Vec<u32> = "3.14159265359".chars().map(u32::from).collect();
+/// let result = lexical_core::parse_units_with_options::<f32, _, JSON>(&units, &OPTIONS);
+/// assert_eq!(result, Ok(3.14159265359_f32));
+/// # }
+/// ```
+#[inline]
+#[cfg(all(feature = "std", any(feature = "parse-floats", feature = "parse-integers")))]
+pub fn parse_units_with_options<N: FromLexicalWithOptions, C: CodeUnit, const FORMAT: u128>(
+    units: &[C],
+    options: &N::Options,
+) -> Result<N> {
+    N::from_lexical_units_with_options::<FORMAT, C>(units, options)
+}
+
+/// Parse partial number from UTF-16 or UTF-32 code units with custom
+/// parsing options.
+///
+/// This is identical to [`parse_partial_with_options`], except the number
+/// of processed elements and any error indexes are in code units.
+///
+/// * `FORMAT`  - Packed struct containing the number format.
+/// * `units`   - Slice of code units containing a numeric string.
+/// * `options` - Options to customize number parsing.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(all(feature = "parse-floats", feature = "format"))] {
+/// const JSON: u128 = lexical_core::format::JSON;
+/// const OPTIONS: lexical_core::ParseFloatOptions = lexical_core::ParseFloatOptions::new();
+/// let units: Vec<u16> = "3.14159265359 hello".encode_utf16().collect();
+/// let result = lexical_core::parse_partial_units_with_options::<f32, _, JSON>(&units, &OPTIONS);
+/// assert_eq!(result, Ok((3.14159265359_f32, 13)));
+/// # }
+/// ```
+#[inline]
+#[cfg(all(feature = "std", any(feature = "parse-floats", feature = "parse-integers")))]
+pub fn parse_partial_units_with_options<
+    N: FromLexicalWithOptions,
+    C: CodeUnit,
+    const FORMAT: u128,
+>(
+    units: &[C],
+    options: &N::Options,
+) -> Result<(N, usize)> {
+    N::from_lexical_partial_units_with_options::<FORMAT, C>(units, options)
+}
+
+/// Parse complete number from string with Unicode decimal digits.
+///
+/// This is identical to [`parse`], except the digits may be UTF-8 encoded
+/// digits of any single block of Unicode decimal digits, such as fullwidth
+/// or Arabic-Indic digits. All other characters must be ASCII, and any
+/// error indexes are in bytes of the input.
+///
+/// * `bytes`   - Byte slice containing a numeric string.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "parse-integers")] {
+/// let result = lexical_core::parse_unicode::<i32>("-１２３".as_bytes());
+/// assert_eq!(result, Ok(-123));
+///
+/// // Digits from different blocks are invalid.
+/// let result = lexical_core::parse_unicode::<i32>("١2".as_bytes());
+/// assert_eq!(result, Err(lexical_core::Error::InvalidDigit(2)));
+/// # }
+/// ```
+#[inline]
+#[cfg(all(feature = "unicode", any(feature = "parse-floats", feature = "parse-integers")))]
+pub fn parse_unicode<N: FromLexical>(bytes: &[u8]) -> Result<N> {
+    // The standard format only uses `.` as punctuation.
+    lexical_util::unicode::parse_complete(bytes, b".", N::from_lexical)
+}
+
+/// Parse partial number from string with Unicode decimal digits.
+///
+/// This is identical to [`parse_partial`], except the digits may be UTF-8
+/// encoded digits of any single block of Unicode decimal digits. The
+/// number of processed bytes and any error indexes are in bytes of the
+/// input.
+///
+/// * `bytes`   - Byte slice containing a numeric string.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "parse-floats")] {
+/// let result = lexical_core::parse_partial_unicode::<f64>("٣.٥ kg".as_bytes());
+/// assert_eq!(result, Ok((3.5, 5)));
+/// # }
+/// ```
+#[inline]
+#[cfg(all(feature = "unicode", any(feature = "parse-floats", feature = "parse-integers")))]
+pub fn parse_partial_unicode<N: FromLexical>(bytes: &[u8]) -> Result<(N, usize)> {
+    // The standard format only uses `.` as punctuation.
+    lexical_util::unicode::parse_partial(bytes, b".", N::from_lexical_partial)
+}
+
+/// Parse complete number from string with Unicode decimal digits and
+/// custom parsing options.
+///
+/// This is identical to [`parse_with_options`], except the digits may be
+/// UTF-8 encoded digits of any single block of Unicode decimal digits. The
+/// digit separators, signs, and other punctuation must be ASCII.
+///
+/// * `FORMAT`  - Packed struct containing the number format.
+/// * `bytes`   - Byte slice containing a numeric string.
+/// * `options` - Options to customize number parsing.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(all(feature = "parse-integers", feature = "format"))] {
+/// use core::num::NonZeroU8;
+///
+/// const FORMAT: u128 = lexical_core::NumberFormatBuilder::new()
+///     .digit_separator(NonZeroU8::new(b','))
+///     .integer_internal_digit_separator(true)
+///     .build_strict();
+/// const OPTIONS: lexical_core::ParseIntegerOptions = lexical_core::ParseIntegerOptions::new();
+/// let result = lexical_core::parse_unicode_with_options::<u32, FORMAT>("१,२३४".as_bytes(), &OPTIONS);
+/// assert_eq!(result, Ok(1234));
+/// # }
+/// ```
+#[inline]
+#[cfg(all(feature = "unicode", any(feature = "parse-floats", feature = "parse-integers")))]
+pub fn parse_unicode_with_options<N: FromLexicalWithOptions, const FORMAT: u128>(
+    bytes: &[u8],
+    options: &N::Options,
+) -> Result<N> {
+    let punctuation = options.punctuation::<FORMAT>();
+    lexical_util::unicode::parse_complete(bytes, &punctuation, |bytes| {
+        N::from_lexical_with_options::<FORMAT>(bytes, options)
+    })
+}
+
+/// Parse partial number from string with Unicode decimal digits and
+/// custom parsing options.
+///
+/// This is identical to [`parse_partial_with_options`], except the digits
+/// may be UTF-8 encoded digits of any single block of Unicode decimal
+/// digits. The number of processed bytes and any error indexes are in
+/// bytes of the input.
+///
+/// * `FORMAT`  - Packed struct containing the number format.
+/// * `bytes`   - Byte slice containing a numeric string.
+/// * `options` - Options to customize number parsing.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "parse-floats")] {
+/// const FORMAT: u128 = lexical_core::format::STANDARD;
+/// const OPTIONS: lexical_core::ParseFloatOptions = lexical_core::ParseFloatOptions::new();
+/// let bytes = "１.２５e１ m".as_bytes();
+/// let result = lexical_core::parse_partial_unicode_with_options::<f64, FORMAT>(bytes, &OPTIONS);
+/// assert_eq!(result, Ok((12.5, 14)));
+/// # }
+/// ```
+#[inline]
+#[cfg(all(feature = "unicode", any(feature = "parse-floats", feature = "parse-integers")))]
+pub fn parse_partial_unicode_with_options<N: FromLexicalWithOptions, const FORMAT: u128>(
+    bytes: &[u8],
+    options: &N::Options,
+) -> Result<(N, usize)> {
+    let punctuation = options.punctuation::<FORMAT>();
+    lexical_util::unicode::parse_partial(bytes, &punctuation, |bytes| {
+        N::from_lexical_partial_with_options::<FORMAT>(bytes, options)
+    })
+}
+
+/// Parse complete number from string, with a diagnostic on error.
+///
+/// This is identical to [`parse`], except errors are converted to a
+/// [`Diagnostic`] with the span, component, and expected characters of
+/// the error. This has no cost unless parsing fails.
+///
+/// * `bytes`   - Byte slice containing a numeric string.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "parse-floats")] {
+/// use lexical_core::{Component, Expected};
+///
+/// let error = lexical_core::parse_diagnostic::<f64>(b"1.5e").unwrap_err();
+/// assert_eq!(error.span(), Some(4..4));
+/// assert_eq!(error.component(), Some(Component::Exponent));
+/// assert_eq!(error.expected(), Some(Expected::Digit(10)));
+/// # }
+/// ```
+#[inline]
+#[cfg(all(feature = "diagnostic", any(feature = "parse-floats", feature = "parse-integers")))]
+pub fn parse_diagnostic<N: FromLexical>(bytes: &[u8]) -> core::result::Result<N, Diagnostic<'_>> {
+    const FORMAT: u128 = lexical_util::format::STANDARD;
+    N::from_lexical(bytes).map_err(|error| {
+        if N::IS_INTEGER {
+            Diagnostic::integer::<FORMAT>(error, bytes, N::IS_SIGNED)
+        } else {
+            Diagnostic::new::<FORMAT>(error, bytes)
+        }
+    })
+}
+
+/// Parse complete number from string with custom parsing options, with a
+/// diagnostic on error.
+///
+/// This is identical to [`parse_with_options`], except errors are
+/// converted to a [`Diagnostic`] with the span, component, expected
+/// characters, and number format rule of the error.
+///
+/// * `FORMAT`  - Packed struct containing the number format.
+/// * `bytes`   - Byte slice containing a numeric string.
+/// * `options` - Options to customize number parsing.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(all(feature = "parse-floats", feature = "format"))] {
+/// use lexical_core::{Component, Rule};
+///
+/// const FORMAT: u128 = lexical_core::NumberFormatBuilder::new()
+///     .no_positive_exponent_sign(true)
+///     .build_strict();
+/// const OPTIONS: lexical_core::ParseFloatOptions = lexical_core::ParseFloatOptions::new();
+/// let result = lexical_core::parse_diagnostic_with_options::<f64, FORMAT>(b"1.1e+15", &OPTIONS);
+/// let error = result.unwrap_err();
+/// assert_eq!(error.span(), Some(4..5));
+/// assert_eq!(error.component(), Some(Component::Exponent));
+/// assert_eq!(error.rule(), Some(Rule::NoPositiveExponentSign));
+/// # }
+/// ```
+#[inline]
+#[cfg(all(feature = "diagnostic", any(feature = "parse-floats", feature = "parse-integers")))]
+pub fn parse_diagnostic_with_options<'a, N: FromLexicalWithOptions, const FORMAT: u128>(
+    bytes: &'a [u8],
+    options: &N::Options,
+) -> core::result::Result<N, Diagnostic<'a>> {
+    N::from_lexical_with_options::<FORMAT>(bytes, options).map_err(|error| {
+        if N::IS_INTEGER {
+            Diagnostic::integer::<FORMAT>(error, bytes, N::IS_SIGNED)
+        } else {
+            let decimal_point = options.decimal_point();
+            let exponent = options.exponent();
+            Diagnostic::with_punctuation::<FORMAT>(error, bytes, decimal_point, exponent)
+        }
+    })
+}
+
+/// Error from parsing a field of delimiter-separated numbers.
+///
+/// The index of the error is relative to the start of the field, as if
+/// the field was parsed on its own with [`parse`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg(any(feature = "parse-floats", feature = "parse-integers"))]
+pub struct FieldError {
+    /// The number of the field that failed to parse, starting from 0.
+    pub field: usize,
+    /// The byte offset of the start of the field in the input.
+    pub offset: usize,
+    /// The error from parsing the field.
+    pub error: Error,
+}
+
+#[cfg(any(feature = "parse-floats", feature = "parse-integers"))]
+impl fmt::Display for FieldError {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(formatter, "field {} at offset {}: {}", self.field, self.offset, self.error)
+    }
+}
+
+#[cfg(all(feature = "std", any(feature = "parse-floats", feature = "parse-integers")))]
+impl std::error::Error for FieldError {
+}
+
+/// Parse delimiter-separated numbers into a slice.
+///
+/// This parses each field between delimiters as a complete number, until
+/// either the input or `values` is exhausted, and returns the number of
+/// values written and the number of bytes processed, including the
+/// delimiter after the last field. To parse the remaining fields, call
+/// this again with the unprocessed bytes. Empty input has no fields,
+/// while a trailing delimiter is followed by an empty field.
+///
+/// * `bytes`       - Byte slice containing delimiter-separated numbers.
+/// * `delimiter`   - The byte separating each field.
+/// * `values`      - Slice to write the parsed numbers to.
+///
+/// # Errors
+///
+/// Returns a [`FieldError`] for the first field which is not a valid
+/// number. The fields before it are written to `values`.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "parse-integers")] {
+/// let mut values = [0u32; 4];
+/// let result = lexical_core::parse_delimited(b"1,22,333", b',', &mut values);
+/// assert_eq!(result, Ok((3, 8)));
+/// assert_eq!(values, [1, 22, 333, 0]);
+///
+/// let error = lexical_core::parse_delimited(b"1,2x,3", b',', &mut values).unwrap_err();
+/// assert_eq!(error.field, 1);
+/// assert_eq!(error.offset, 2);
+/// assert_eq!(error.error, lexical_core::Error::InvalidDigit(1));
+/// # }
+/// ```
+#[inline]
+#[cfg(any(feature = "parse-floats", feature = "parse-integers"))]
+pub fn parse_delimited<N: FromLexical>(
+    bytes: &[u8],
+    delimiter: u8,
+    values: &mut [N],
+) -> result::Result<(usize, usize), FieldError> {
+    parse_delimited_impl(bytes, delimiter, values, N::from_lexical)
+}
+
+/// Parse delimiter-separated numbers into a slice with custom parsing options.
+///
+/// This parses each field between delimiters as a complete number, until
+/// either the input or `values` is exhausted, and returns the number of
+/// values written and the number of bytes processed, including the
+/// delimiter after the last field. To parse the remaining fields, call
+/// this again with the unprocessed bytes. Empty input has no fields,
+/// while a trailing delimiter is followed by an empty field.
+///
+/// The delimiter must not be a valid character in the number format,
+/// such as a digit separator or the decimal point.
+///
+/// * `FORMAT`      - Packed struct containing the number format.
+/// * `bytes`       - Byte slice containing delimiter-separated numbers.
+/// * `delimiter`   - The byte separating each field.
+/// * `values`      - Slice to write the parsed numbers to.
+/// * `options`     - Options to customize number parsing.
+///
+/// # Errors
+///
+/// Returns a [`FieldError`] for the first field which is not a valid
+/// number. The fields before it are written to `values`.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(all(feature = "parse-floats", feature = "format"))] {
+/// const JSON: u128 = lexical_core::format::JSON;
+/// const OPTIONS: lexical_core::ParseFloatOptions = lexical_core::ParseFloatOptions::new();
+/// let mut values = [0f64; 2];
+/// let result = lexical_core::parse_delimited_with_options::<_, JSON>(
+///     b"1.5;-2e3;4",
+///     b';',
+///     &mut values,
+///     &OPTIONS,
+/// );
+/// assert_eq!(result, Ok((2, 9)));
+/// assert_eq!(values, [1.5, -2000.0]);
+/// # }
+/// ```
+#[inline]
+#[cfg(any(feature = "parse-floats", feature = "parse-integers"))]
+pub fn parse_delimited_with_options<N: FromLexicalWithOptions, const FORMAT: u128>(
+    bytes: &[u8],
+    delimiter: u8,
+    values: &mut [N],
+    options: &N::Options,
+) -> result::Result<(usize, usize), FieldError> {
+    let parse = |field: &[u8]| N::from_lexical_with_options::<FORMAT>(field, options);
+    parse_delimited_impl(bytes, delimiter, values, parse)
+}
+
+/// Parse delimiter-separated numbers into a slice, using a field parser.
+#[inline(always)]
+#[cfg(any(feature = "parse-floats", feature = "parse-integers"))]
+fn parse_delimited_impl<N, Parse>(
+    bytes: &[u8],
+    delimiter: u8,
+    values: &mut [N],
+    parse: Parse,
+) -> result::Result<(usize, usize), FieldError>
+where
+    Parse: Fn(&[u8]) -> Result<N>,
+{
+    let mut offset = 0;
+    let mut count = 0;
+    let mut is_done = bytes.is_empty();
+    while !is_done && count < values.len() {
+        let rest = &bytes[offset..];
+        let (field, length) = match find_delimiter(rest, delimiter) {
+            Some(index) => (&rest[..index], index + 1),
+            None => {
+                is_done = true;
+                (rest, rest.len())
+            },
+        };
+        values[count] = parse(field).map_err(|error| FieldError {
+            field: count,
+            offset,
+            error,
+        })?;
+        count += 1;
+        offset += length;
+    }
+
+    Ok((count, offset))
+}
+
+/// Find the index of the first delimiter in the bytes.
+///
+/// This checks 8 bytes at a time, using the SWAR zero-byte detection from
+/// "Bit Twiddling Hacks", since fields are typically short and the search
+/// otherwise dominates the cost of parsing small numbers.
+#[inline(always)]
+#[cfg(any(feature = "parse-floats", feature = "parse-integers"))]
+fn find_delimiter(bytes: &[u8], delimiter: u8) -> Option<usize> {
+    const LO: u64 = 0x0101_0101_0101_0101;
+    const HI: u64 = 0x8080_8080_8080_8080;
+
+    let mask = LO * delimiter as u64;
+    let mut index = 0;
+    while index + 8 <= bytes.len() {
+        let mut chunk = [0u8; 8];
+        chunk.copy_from_slice(&bytes[index..index + 8]);
+        let value = u64::from_le_bytes(chunk) ^ mask;
+        let found = value.wrapping_sub(LO) & !value & HI;
+        if found != 0 {
+            return Some(index + found.trailing_zeros() as usize / 8);
+        }
+        index += 8;
+    }
+    bytes[index..].iter().position(|&c| c == delimiter).map(|i| index + i)
+}
+
+/// Normalize the radixes of a packed number format.
+///
+/// The exponent base and radix default to the mantissa radix if they are
+/// not set, so identical formats may have different packed values, unless
+/// both are always set.
+#[inline(always)]
+#[cfg(any(
+    feature = "parse-floats",
+    feature = "parse-integers",
+    feature = "write-floats",
+    feature = "write-integers"
+))]
+const fn normalize_format(format: u128) -> u128 {
+    let mask = format::EXPONENT_BASE | format::EXPONENT_RADIX;
+    let base = format::exponent_base(format) as u128;
+    let radix = format::exponent_radix(format) as u128;
+    (format & !mask) | (base << format::EXPONENT_BASE_SHIFT) | (radix << format::EXPONENT_RADIX_SHIFT)
+}
+
+/// Call a function with a number format only known at runtime.
+///
+/// This matches the format, which must be normalized, against each
+/// supported pre-defined format and each standard format from a radix,
+/// binding the matching format to a constant named `$name`, so `$call` is
+/// monomorphized for each format. Any other format evaluates `$fallback`.
+///
+/// Every format added here is another copy of each parser and writer, so
+/// only the data-interchange and database formats are supported.
+#[cfg(any(
+    feature = "parse-floats",
+    feature = "parse-integers",
+    feature = "write-floats",
+    feature = "write-integers"
+))]
+macro_rules! dispatch_format {
+    ($format:expr, $name:ident => $call:expr, _ => $fallback:expr $(,)?) => {{
+        let value: u128 = $format;
+        dispatch_format!(
+            @prebuilt value, $name => $call,
+            dispatch_format!(
+                @radix value, $name => $call, $fallback ;
+                pow2: [2, 4, 8, 16, 32] ;
+                radix: [
+                    3, 5, 6, 7, 9, 10, 11, 12, 13, 14, 15, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27,
+                    28, 29, 30, 31, 33, 34, 35, 36,
+                ] ;
+            ) ;
+            format: [JSON, TOML, XML, SQLITE, POSTGRESQL, MYSQL, MONGODB, PERMISSIVE, IGNORE] ;
+        )
+    }};
+
+    (
+        @prebuilt $value:ident, $name:ident => $call:expr, $fallback:expr ;
+        format: [$($format:ident),* $(,)?] ;
+    ) => {{
+        const STANDARD: u128 = normalize_format(format::STANDARD);
+        $(
+            #[cfg(feature = "format")]
+            const $format: u128 = normalize_format(format::$format);
+        )*
+
+        // Some pre-defined formats are identical, which is harmless.
+        #[allow(unreachable_patterns)]
+        let result = match $value {
+            STANDARD => {
+                const $name: u128 = format::STANDARD;
+                $call
+            },
+            $(
+                #[cfg(feature = "format")]
+                $format => {
+                    const $name: u128 = format::$format;
+                    $call
+                },
+            )*
+            _ => $fallback,
+        };
+        result
+    }};
+
+    (
+        @radix $value:ident, $name:ident => $call:expr, $fallback:expr ;
+        pow2: [$($pow2:literal),* $(,)?] ;
+        radix: [$($radix:literal),* $(,)?] ;
+    ) => {
+        match () {
+            $(
+                #[cfg(feature = "power-of-two")]
+                _ if $value == normalize_format(NumberFormatBuilder::from_radix($pow2)) => {
+                    const $name: u128 = NumberFormatBuilder::from_radix($pow2);
+                    $call
+                },
+            )*
+            $(
+                #[cfg(feature = "radix")]
+                _ if $value == normalize_format(NumberFormatBuilder::from_radix($radix)) => {
+                    const $name: u128 = NumberFormatBuilder::from_radix($radix);
+                    $call
+                },
+            )*
+            _ => $fallback,
+        }
+    };
+}
+
+/// Prebuilt number format selected at runtime.
+///
+/// The parsers and writers take the number format as a const generic, so
+/// it must be known at compile time. This selects one of the prebuilt
+/// formats from a packed number format only known at runtime, such as the
+/// dialect of a query, and dispatches to the parser or writer monomorphized
+/// for that format, so the only overhead is a single lookup of the format
+/// for each call.
+///
+/// This is not an interpreter for arbitrary formats: since each format must
+/// be compiled ahead of time, only the following formats may be selected:
+/// - [`STANDARD`][format::STANDARD].
+/// - The data-interchange and database formats: `JSON`, `TOML`, `XML`,
+///   `SQLITE`, `POSTGRESQL`, `MYSQL`, `MONGODB`, `PERMISSIVE`, and `IGNORE`,
+///   with the `format` feature.
+/// - The standard format for each radix, from
+///   `NumberFormatBuilder::from_radix`, with the `power-of-two` or `radix`
+///   features.
+///
+/// Any other valid format, such as the language literal formats or a
+/// custom format, is rejected with [`Error::UnsupportedFormat`]. Formats
+/// with the same packed value as a supported format, like many language
+/// string formats, are accepted.
+///
+/// Each method is compiled for every supported format, so this increases
+/// the binary size: if only a few formats are required, match on them and
+/// call the functions taking a const `FORMAT` instead.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(all(feature = "parse-floats", feature = "format"))] {
+/// use lexical_core::{format, ParseFloatOptions, PrebuiltFormat};
+///
+/// let dialect = match "postgresql" {
+///     "mysql" => format::MYSQL,
+///     "postgresql" => format::POSTGRESQL,
+///     _ => format::STANDARD,
+/// };
+/// let prebuilt = PrebuiltFormat::new(dialect).unwrap();
+/// let options = ParseFloatOptions::new();
+/// assert_eq!(prebuilt.parse::<f64>(b"1.5e3", &options), Ok(1500.0));
+///
+/// let custom = lexical_core::NumberFormatBuilder::new().required_digits(true).build_strict();
+/// assert!(PrebuiltFormat::new(custom).unwrap_err().is_unsupported_format());
+/// assert!(PrebuiltFormat::new(format::RUST_LITERAL).unwrap_err().is_unsupported_format());
+/// # }
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg(any(
+    feature = "parse-floats",
+    feature = "parse-integers",
+    feature = "write-floats",
+    feature = "write-integers"
+))]
+pub struct PrebuiltFormat {
+    format: u128,
+}
+
+#[cfg(any(
+    feature = "parse-floats",
+    feature = "parse-integers",
+    feature = "write-floats",
+    feature = "write-integers"
+))]
+impl PrebuiltFormat {
+    /// Select the prebuilt format from a packed number format.
+    ///
+    /// Returns the format error if the format is invalid, or
+    /// [`Error::UnsupportedFormat`] if the format is not a prebuilt
+    /// format.
+    #[inline]
+    pub fn new(format: u128) -> Result<Self> {
+        match format::runtime_format_error(format) {
+            Error::Success => (),
+            error => return Err(error),
+        }
+        dispatch_format!(
+            normalize_format(format),
+            FORMAT => Ok(Self { format: normalize_format(FORMAT) }),
+            _ => Err(Error::UnsupportedFormat),
+        )
+    }
+
+    /// Get the packed number format.
+    ///
+    /// The exponent base and exponent radix are always set, so this may
+    /// differ from the packed format used to select the prebuilt format.
+    #[inline(always)]
+    pub const fn format(&self) -> u128 {
+        self.format
+    }
+
+    /// Parse complete number from string with custom parsing options.
+    ///
+    /// This is identical to [`parse_with_options`], using this format.
+    ///
+    /// * `bytes`   - Byte slice containing a numeric string.
+    /// * `options` - Options to customize number parsing.
+    #[inline]
+    #[cfg(any(feature = "parse-floats", feature = "parse-integers"))]
+    pub fn parse<N: FromLexicalWithOptions>(&self, bytes: &[u8], options: &N::Options) -> Result<N> {
+        dispatch_format!(
+            self.format,
+            FORMAT => N::from_lexical_with_options::<FORMAT>(bytes, options),
+            _ => unreachable!(),
+        )
+    }
+
+    /// Parse partial number from string with custom parsing options.
+    ///
+    /// This is identical to [`parse_partial_with_options`], using this
+    /// format.
+    ///
+    /// * `bytes`   - Byte slice containing a numeric string.
+    /// * `options` - Options to customize number parsing.
+    #[inline]
+    #[cfg(any(feature = "parse-floats", feature = "parse-integers"))]
+    pub fn parse_partial<N: FromLexicalWithOptions>(
+        &self,
+        bytes: &[u8],
+        options: &N::Options,
+    ) -> Result<(N, usize)> {
+        dispatch_format!(
+            self.format,
+            FORMAT => N::from_lexical_partial_with_options::<FORMAT>(bytes, options),
+            _ => unreachable!(),
+        )
+    }
+
+    /// Write number to string with custom options.
+    ///
+    /// This is identical to [`write_with_options`], using this format.
+    ///
+    /// * `n`       - Number to convert to string.
+    /// * `bytes`   - Buffer to write number to.
+    /// * `options` - Options to customize number writing.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the buffer is not of sufficient size. The size
+    /// required can be determined with [`buffer_size`][Self::buffer_size].
+    #[inline]
+    #[cfg(any(feature = "write-floats", feature = "write-integers"))]
+    pub fn write<'a, N: ToLexicalWithOptions>(
+        &self,
+        n: N,
+        bytes: &'a mut [u8],
+        options: &N::Options,
+    ) -> &'a mut [u8] {
+        dispatch_format!(
+            self.format,
+            FORMAT => n.to_lexical_with_options::<FORMAT>(bytes, options),
+            _ => unreachable!(),
+        )
+    }
+
+    /// Get an upper bound on the buffer size required to write a number.
+    ///
+    /// This is the `buffer_size_const` of the write options for this
+    /// format, which cannot be called with a format only known at runtime.
+    #[inline]
+    #[allow(deprecated)] // reason = "buffer_size is only deprecated for compile-time formats"
+    #[cfg(any(feature = "write-floats", feature = "write-integers"))]
+    pub fn buffer_size<N: ToLexicalWithOptions>(&self, options: &N::Options) -> usize {
+        dispatch_format!(
+            self.format,
+            FORMAT => options.buffer_size::<N, FORMAT>(),
+            _ => unreachable!(),
+        )
+    }
+}
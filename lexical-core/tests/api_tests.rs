@@ -54,6 +54,63 @@ fn string_to_float_test() {
     );
 }
 
+#[test]
+#[cfg(all(feature = "f128", feature = "parse-floats", feature = "write-floats"))]
+fn f128_roundtrip_test() {
+    use lexical_core::{f128, FormattedSize};
+
+    let roundtrip = |bits: u128| {
+        let mut buffer = [b'0'; f128::FORMATTED_SIZE_DECIMAL];
+        let digits = lexical_core::write(f128::from_bits(bits), &mut buffer);
+        lexical_core::parse::<f128>(digits).unwrap().to_bits()
+    };
+    let values = [
+        1,
+        0x00010000000000000000000000000000,
+        0x3FFB999999999999999999999999999A,
+        0x4000921FB54442D18469898CC51701B8,
+        0x7FFEFFFFFFFFFFFFFFFFFFFFFFFFFFFF,
+        0xBFFE5555555555555555555555555555,
+    ];
+    for bits in values {
+        assert_eq!(roundtrip(bits), bits);
+    }
+}
+
+#[test]
+#[cfg(all(feature = "radix", feature = "parse-floats", feature = "write-floats"))]
+fn radix_float_roundtrip_test() {
+    use lexical_core::{NumberFormatBuilder, ParseFloatOptions, PrebuiltFormat, WriteFloatOptions};
+
+    let values = [
+        0.1f64,
+        1.0 / 3.0,
+        1e100,
+        -1234.5678,
+        5e-324,
+        2.2250738585072014e-308,
+        f64::MAX,
+        f64::EPSILON,
+        123456789012345680.0,
+    ];
+    let mut buffer = [0u8; 2048];
+    for radix in 2..=36 {
+        let prebuilt = PrebuiltFormat::new(NumberFormatBuilder::from_radix(radix)).unwrap();
+        let write_options = WriteFloatOptions::from_radix(radix);
+        let parse_options = ParseFloatOptions::from_radix(radix);
+        for value in values {
+            let digits = prebuilt.write(value, &mut buffer, &write_options);
+            assert_eq!(prebuilt.parse::<f64>(digits, &parse_options), Ok(value));
+            // Special values are ambiguous with the digits in large radixes.
+            let value = value as f32;
+            if value.is_finite() {
+                let digits = prebuilt.write(value, &mut buffer, &write_options);
+                assert_eq!(prebuilt.parse::<f32>(digits, &parse_options), Ok(value));
+            }
+        }
+    }
+}
+
 /// Test that converting the specified value into a buffer of FORMATTED_SIZE
 /// yields the expected string
 #[cfg(feature = "write-integers")]
@@ -92,3 +149,475 @@ fn numeric_limit_string_tests() {
     test_format!(i64, i64::MIN, "-9223372036854775808");
     test_format!(i64, i64::MAX, "9223372036854775807");
 }
+
+#[test]
+#[cfg(feature = "parse-integers")]
+fn parse_delimited_test() {
+    use lexical_core::{Error, FieldError};
+
+    let mut values = [0i32; 8];
+    assert_eq!(lexical_core::parse_delimited(b"", b',', &mut values), Ok((0, 0)));
+    assert_eq!(lexical_core::parse_delimited(b"-12", b',', &mut values), Ok((1, 3)));
+    assert_eq!(values[0], -12);
+
+    // Long fields and inputs, so the delimiter is found in every byte of a word.
+    let input = b"1,22,333,4444,55555,666666,7777777,88888888";
+    assert_eq!(lexical_core::parse_delimited(input, b',', &mut values), Ok((8, input.len())));
+    assert_eq!(values, [1, 22, 333, 4444, 55555, 666666, 7777777, 88888888]);
+
+    // Stop once the output is full, after the delimiter.
+    let mut values = [0i32; 3];
+    assert_eq!(lexical_core::parse_delimited(input, b',', &mut values), Ok((3, 9)));
+    assert_eq!(lexical_core::parse_delimited(&input[9..], b',', &mut values), Ok((3, 18)));
+    assert_eq!(values, [4444, 55555, 666666]);
+
+    // Errors are relative to the start of the field.
+    assert_eq!(
+        lexical_core::parse_delimited(b"1,2,", b',', &mut values),
+        Err(FieldError {
+            field: 2,
+            offset: 4,
+            error: Error::Empty(0),
+        })
+    );
+    assert_eq!(
+        lexical_core::parse_delimited(b"1\t99999999999\t3", b'\t', &mut values),
+        Err(FieldError {
+            field: 1,
+            offset: 2,
+            error: Error::Overflow(9),
+        })
+    );
+    assert_eq!(values[0], 1);
+}
+
+#[test]
+#[cfg(all(feature = "std", feature = "parse-integers", feature = "parse-floats"))]
+fn parse_units_test() {
+    use lexical_core::Error;
+
+    let units: Vec<u16> = "-1.5e3".encode_utf16().collect();
+    assert_eq!(lexical_core::parse_units::<f64, _>(&units), Ok(-1500.0));
+    assert_eq!(lexical_core::parse_units::<i32, _>(&units), Err(Error::InvalidDigit(2)));
+    assert_eq!(lexical_core::parse_partial_units::<i32, _>(&units), Ok((-1, 2)));
+
+    let units: Vec<u32> = "12\u{1f600}".chars().map(u32::from).collect();
+    assert_eq!(lexical_core::parse_units::<u8, _>(&units), Err(Error::InvalidDigit(2)));
+    assert_eq!(lexical_core::parse_partial_units::<f32, _>(&units), Ok((12.0, 2)));
+
+    const FORMAT: u128 = lexical_core::format::STANDARD;
+    const OPTIONS: lexical_core::ParseIntegerOptions = lexical_core::ParseIntegerOptions::new();
+    let units: Vec<u16> = "255 ".encode_utf16().collect();
+    let result = lexical_core::parse_units_with_options::<u8, _, FORMAT>(&units, &OPTIONS);
+    assert_eq!(result, Err(Error::InvalidDigit(3)));
+    let result = lexical_core::parse_partial_units_with_options::<u8, _, FORMAT>(&units, &OPTIONS);
+    assert_eq!(result, Ok((255, 3)));
+
+    // Only the number is narrowed, so the rest of the input is ignored.
+    let units: Vec<u16> = "1.5,2.5,1e".encode_utf16().collect();
+    assert_eq!(lexical_core::parse_partial_units::<f64, _>(&units), Ok((1.5, 3)));
+    assert_eq!(lexical_core::parse_units::<f64, _>(&units), Err(Error::InvalidDigit(3)));
+}
+
+#[test]
+#[cfg(all(feature = "unicode", feature = "parse-integers", feature = "parse-floats"))]
+fn parse_unicode_test() {
+    use lexical_core::Error;
+
+    assert_eq!(lexical_core::parse_unicode::<i32>("-１２３".as_bytes()), Ok(-123));
+    assert_eq!(lexical_core::parse_unicode::<u64>("०९८७".as_bytes()), Ok(987));
+    assert_eq!(lexical_core::parse_unicode::<f64>("۱.۲۵e۲".as_bytes()), Ok(125.0));
+    assert_eq!(lexical_core::parse_unicode::<f64>(b"1.25e2"), Ok(125.0));
+
+    // Errors are in bytes of the input.
+    assert_eq!(lexical_core::parse_unicode::<u8>("２５６".as_bytes()), Err(Error::Overflow(6)));
+    assert_eq!(lexical_core::parse_unicode::<u8>("-١".as_bytes()), Err(Error::InvalidDigit(0)));
+    assert_eq!(lexical_core::parse_unicode::<i32>("١2".as_bytes()), Err(Error::InvalidDigit(2)));
+    assert_eq!(lexical_core::parse_unicode::<i32>("1١".as_bytes()), Err(Error::InvalidDigit(1)));
+    assert_eq!(lexical_core::parse_unicode::<f64>("١.".as_bytes()), Ok(1.0));
+    assert_eq!(lexical_core::parse_unicode::<f64>("١e".as_bytes()), Err(Error::EmptyExponent(3)));
+    assert_eq!(lexical_core::parse_unicode::<i32>("".as_bytes()), Err(Error::Empty(0)));
+
+    assert_eq!(lexical_core::parse_partial_unicode::<i32>("１２abc".as_bytes()), Ok((12, 6)));
+    assert_eq!(lexical_core::parse_partial_unicode::<i32>("١2".as_bytes()), Ok((1, 2)));
+    assert_eq!(lexical_core::parse_partial_unicode::<f32>("٣.٥ kg".as_bytes()), Ok((3.5, 5)));
+    assert_eq!(lexical_core::parse_partial_unicode::<i32>("١٢,٣٤".as_bytes()), Ok((12, 4)));
+    assert_eq!(lexical_core::parse_partial_unicode::<i32>(b"12,34"), Ok((12, 2)));
+}
+
+#[test]
+#[cfg(all(feature = "unicode", feature = "format", feature = "parse-integers"))]
+fn parse_unicode_format_test() {
+    use core::num::NonZeroU8;
+
+    use lexical_core::{Error, NumberFormatBuilder, ParseIntegerOptions};
+
+    const FORMAT: u128 = NumberFormatBuilder::new()
+        .digit_separator(NonZeroU8::new(b'_'))
+        .integer_internal_digit_separator(true)
+        .required_mantissa_sign(true)
+        .build_strict();
+    const OPTIONS: ParseIntegerOptions = ParseIntegerOptions::new();
+
+    let parse =
+        |s: &str| lexical_core::parse_unicode_with_options::<i32, FORMAT>(s.as_bytes(), &OPTIONS);
+    assert_eq!(parse("+１_２３４"), Ok(1234));
+    assert_eq!(parse("-٩_٩"), Ok(-99));
+    assert_eq!(parse("１_２３４"), Err(Error::MissingSign(0)));
+    assert_eq!(parse("+１２３_"), Err(Error::InvalidDigit(10)));
+    assert_eq!(parse("+_１"), Err(Error::InvalidDigit(1)));
+
+    let bytes = "+१_२ rest".as_bytes();
+    let result = lexical_core::parse_partial_unicode_with_options::<i32, FORMAT>(bytes, &OPTIONS);
+    assert_eq!(result, Ok((12, 8)));
+}
+
+#[test]
+#[cfg(all(feature = "unicode", feature = "write-integers", feature = "write-floats"))]
+fn write_unicode_test() {
+    use lexical_core::{DigitBlock, FormattedSize, WriteFloatOptions};
+
+    let mut buffer = [0u8; 256];
+    let digits = lexical_core::write_unicode(-1234i64, DigitBlock::FULLWIDTH, &mut buffer);
+    assert_eq!(digits, "-１２３４".as_bytes());
+    let digits = lexical_core::write_unicode(u128::MAX, DigitBlock::ASCII, &mut buffer);
+    assert_eq!(digits, b"340282366920938463463374607431768211455");
+    let digits = lexical_core::write_unicode(1.5e-7f64, DigitBlock::ARABIC_INDIC, &mut buffer);
+    assert_eq!(digits, "١.٥e-٧".as_bytes());
+    let digits = lexical_core::write_unicode(f64::NAN, DigitBlock::THAI, &mut buffer);
+    assert_eq!(digits, b"NaN");
+
+    // The buffer size is enough for every value.
+    let block = DigitBlock::new('\u{1D7F6}').unwrap();
+    let mut buffer = vec![0u8; block.buffer_size(f64::FORMATTED_SIZE_DECIMAL)];
+    let digits = lexical_core::write_unicode(-1.7976931348623157e308f64, block, &mut buffer);
+    assert_eq!(digits.len(), 3 + 4 * 20);
+
+    const FORMAT: u128 = lexical_core::format::STANDARD;
+    const OPTIONS: WriteFloatOptions =
+        WriteFloatOptions::builder().trim_floats(true).build_strict();
+    let mut buffer = [0u8; 256];
+    let block = DigitBlock::DEVANAGARI;
+    let digits = lexical_core::write_unicode_with_options::<_, FORMAT>(
+        20.0f32,
+        block,
+        &mut buffer,
+        &OPTIONS,
+    );
+    assert_eq!(digits, "२०".as_bytes());
+}
+
+#[test]
+#[cfg(all(feature = "diagnostic", feature = "parse-integers", feature = "parse-floats"))]
+fn parse_diagnostic_test() {
+    use lexical_core::{Component, Error, Expected};
+
+    assert_eq!(lexical_core::parse_diagnostic::<i32>(b"1234"), Ok(1234));
+
+    let error = lexical_core::parse_diagnostic::<i32>(b"12a4").unwrap_err();
+    assert_eq!(error.error(), Error::InvalidDigit(2));
+    assert_eq!(error.span(), Some(2..3));
+    assert_eq!(error.component(), Some(Component::Integer));
+    assert_eq!(error.expected(), Some(Expected::Digit(10)));
+
+    let error = lexical_core::parse_diagnostic::<u8>(b"1e").unwrap_err();
+    assert_eq!(error.component(), Some(Component::Integer));
+    assert_eq!(error.expected(), Some(Expected::Digit(10)));
+    let error = lexical_core::parse_diagnostic::<u8>(b"-").unwrap_err();
+    assert_eq!(error.component(), Some(Component::Sign));
+    assert_eq!(error.expected(), Some(Expected::PositiveSignOrDigit));
+
+    let error = lexical_core::parse_diagnostic::<f64>(b"1.5x").unwrap_err();
+    assert_eq!(error.component(), Some(Component::Fraction));
+    assert_eq!(error.found(), Some(&b"x"[..]));
+
+    let error = lexical_core::parse_diagnostic::<f64>(b"1.5e").unwrap_err();
+    assert_eq!(error.error(), Error::EmptyExponent(4));
+    assert_eq!(error.span(), Some(4..4));
+    assert_eq!(error.component(), Some(Component::Exponent));
+}
+
+#[test]
+#[cfg(all(
+    feature = "diagnostic",
+    feature = "format",
+    feature = "parse-integers",
+    feature = "parse-floats"
+))]
+fn parse_diagnostic_format_test() {
+    use core::num::NonZeroU8;
+
+    use lexical_core::{
+        Component,
+        Diagnostic,
+        NumberFormatBuilder,
+        ParseFloatOptions,
+        ParseIntegerOptions,
+        Rule,
+    };
+
+    const FLOAT_FORMAT: u128 =
+        NumberFormatBuilder::new().no_positive_exponent_sign(true).build_strict();
+    const FLOAT_OPTIONS: ParseFloatOptions =
+        ParseFloatOptions::builder().decimal_point(b',').build_strict();
+
+    fn parse_float(s: &[u8]) -> Result<f64, Diagnostic<'_>> {
+        lexical_core::parse_diagnostic_with_options::<f64, FLOAT_FORMAT>(s, &FLOAT_OPTIONS)
+    }
+    assert_eq!(parse_float(b"1,5e-3"), Ok(0.0015));
+    let error = parse_float(b"1,1e+15").unwrap_err();
+    assert_eq!(error.span(), Some(4..5));
+    assert_eq!(error.component(), Some(Component::Exponent));
+    assert_eq!(error.rule(), Some(Rule::NoPositiveExponentSign));
+    let error = parse_float(b"1,x").unwrap_err();
+    assert_eq!(error.component(), Some(Component::Fraction));
+
+    const INTEGER_FORMAT: u128 = NumberFormatBuilder::new()
+        .digit_separator(NonZeroU8::new(b'_'))
+        .integer_internal_digit_separator(true)
+        .build_strict();
+    const INTEGER_OPTIONS: ParseIntegerOptions = ParseIntegerOptions::new();
+
+    fn parse_integer(s: &[u8]) -> Result<i32, Diagnostic<'_>> {
+        lexical_core::parse_diagnostic_with_options::<i32, INTEGER_FORMAT>(s, &INTEGER_OPTIONS)
+    }
+    assert_eq!(parse_integer(b"1_000"), Ok(1000));
+    let error = parse_integer(b"1_000_").unwrap_err();
+    assert_eq!(error.span(), Some(5..6));
+    assert_eq!(error.rule(), Some(Rule::TrailingDigitSeparator(Component::Integer)));
+}
+
+#[test]
+#[cfg(feature = "parse-floats")]
+fn parse_delimited_float_test() {
+    let mut values = [0f64; 4];
+    let input = b"1.5|-2.25e2|NaN|inf";
+    assert_eq!(lexical_core::parse_delimited(input, b'|', &mut values), Ok((4, input.len())));
+    assert_eq!(values[..2], [1.5, -225.0]);
+    assert!(values[2].is_nan());
+    assert_eq!(values[3], f64::INFINITY);
+
+    const OPTIONS: lexical_core::ParseFloatOptions = lexical_core::ParseFloatOptions::new();
+    const FORMAT: u128 = lexical_core::format::STANDARD;
+    let result = lexical_core::parse_delimited_with_options::<_, FORMAT>(
+        b"0.1 0.2 0..3",
+        b' ',
+        &mut values,
+        &OPTIONS,
+    );
+    let error = result.unwrap_err();
+    assert_eq!((error.field, error.offset), (2, 8));
+    assert!(error.error.is_invalid_digit());
+}
+
+#[test]
+#[cfg(feature = "write-integers")]
+fn write_slice_test() {
+    use lexical_core::FormattedSize;
+
+    const SIZE: usize = lexical_core::slice_buffer_size(4, i64::FORMATTED_SIZE_DECIMAL, 2);
+    let mut buffer = [b'0'; SIZE];
+    assert_eq!(lexical_core::write_slice::<i64>(&[], b", ", &mut buffer), b"");
+    assert_eq!(lexical_core::write_slice(&[i64::MIN], b", ", &mut buffer), b"-9223372036854775808");
+    let values = [i64::MIN, -1, 0, i64::MAX];
+    let digits = lexical_core::write_slice(&values, b", ", &mut buffer);
+    assert_eq!(digits, b"-9223372036854775808, -1, 0, 9223372036854775807" as &[u8]);
+    assert_eq!(lexical_core::write_slice(&values[1..3], b"", &mut buffer), b"-10");
+
+    let digits = lexical_core::write_iter(values.iter().rev().copied(), b"\n", &mut buffer);
+    assert_eq!(digits, b"9223372036854775807\n0\n-1\n-9223372036854775808" as &[u8]);
+}
+
+#[test]
+#[cfg(feature = "write-integers")]
+fn slice_buffer_size_test() {
+    assert_eq!(lexical_core::slice_buffer_size(0, 20, 2), 0);
+    assert_eq!(lexical_core::slice_buffer_size(1, 20, 2), 20);
+    assert_eq!(lexical_core::slice_buffer_size(4, 20, 2), 86);
+    assert_eq!(lexical_core::slice_buffer_size(usize::MAX, 2, 0), usize::MAX);
+    assert_eq!(lexical_core::slice_buffer_size(usize::MAX / 2, 1, 2), usize::MAX);
+    assert_eq!(lexical_core::slice_buffer_size(2, usize::MAX / 2, 2), usize::MAX);
+}
+
+#[test]
+#[cfg(feature = "write-floats")]
+fn write_slice_float_test() {
+    const FORMAT: u128 = lexical_core::format::STANDARD;
+    const OPTIONS: lexical_core::WriteFloatOptions =
+        lexical_core::WriteFloatOptions::builder().trim_floats(true).build_strict();
+    const SIZE: usize =
+        lexical_core::slice_buffer_size(3, OPTIONS.buffer_size_const::<f64, FORMAT>(), 1);
+    let mut buffer = [b'0'; SIZE];
+    let values = [1.0, f64::NAN, -0.5];
+    let digits =
+        lexical_core::write_slice_with_options::<_, FORMAT>(&values, b",", &mut buffer, &OPTIONS);
+    assert_eq!(digits, b"1,NaN,-0.5");
+    let digits = lexical_core::write_iter_with_options::<_, _, FORMAT>(
+        values.iter().map(|x| x * 2.0),
+        b",",
+        &mut buffer,
+        &OPTIONS,
+    );
+    assert_eq!(digits, b"2,NaN,-1");
+}
+
+#[test]
+#[cfg(all(feature = "format", feature = "parse-floats", feature = "write-floats"))]
+fn group_separator_roundtrip_test() {
+    use core::num::NonZeroU8;
+
+    const FORMAT: u128 = lexical_core::NumberFormatBuilder::new()
+        .digit_separator(NonZeroU8::new(b','))
+        .integer_internal_digit_separator(true)
+        .build_strict();
+    const WRITE_OPTIONS: lexical_core::WriteFloatOptions =
+        lexical_core::WriteFloatOptions::builder()
+            .group_separator(NonZeroU8::new(b','))
+            .build_strict();
+    const INDIAN_OPTIONS: lexical_core::WriteFloatOptions =
+        WRITE_OPTIONS.rebuild().secondary_group_size(NonZeroU8::new(2)).build_strict();
+    const PARSE_OPTIONS: lexical_core::ParseFloatOptions = lexical_core::ParseFloatOptions::new();
+    const SIZE: usize = INDIAN_OPTIONS.buffer_size_const::<f64, FORMAT>();
+
+    let mut buffer = [b'0'; SIZE];
+    for (value, options, expected) in [
+        (1234567.89, &WRITE_OPTIONS, "1,234,567.89"),
+        (1234567.89, &INDIAN_OPTIONS, "12,34,567.89"),
+        (-987654321.0, &INDIAN_OPTIONS, "-98,76,54,321.0"),
+    ] {
+        let digits = lexical_core::write_with_options::<f64, FORMAT>(value, &mut buffer, options);
+        assert_eq!(digits, expected.as_bytes());
+        let parsed = lexical_core::parse_with_options::<f64, FORMAT>(digits, &PARSE_OPTIONS);
+        assert_eq!(parsed, Ok(value));
+    }
+}
+
+#[test]
+#[cfg(feature = "parse-floats")]
+fn prebuilt_format_test() {
+    use lexical_core::{format, Error, ParseFloatOptions, PrebuiltFormat};
+
+    let prebuilt = PrebuiltFormat::new(format::STANDARD).unwrap();
+    let options = ParseFloatOptions::new();
+    assert_eq!(prebuilt.parse::<f64>(b"1.5e3", &options), Ok(1500.0));
+    assert_eq!(prebuilt.parse_partial::<f64>(b"1.5e3,", &options), Ok((1500.0, 5)));
+    assert_eq!(prebuilt.parse::<f64>(b"1.5e", &options), Err(Error::EmptyExponent(4)));
+
+    assert_eq!(PrebuiltFormat::new(0), Err(Error::InvalidMantissaRadix));
+}
+
+#[test]
+#[cfg(all(feature = "format", feature = "parse-floats", feature = "parse-integers"))]
+fn prebuilt_format_prebuilt_test() {
+    use core::num::NonZeroU8;
+
+    use lexical_core::{format, Error, NumberFormatBuilder, ParseFloatOptions, PrebuiltFormat};
+
+    macro_rules! check_prebuilt {
+        ($($format:ident),*) => {$(
+            let prebuilt = PrebuiltFormat::new(format::$format).unwrap();
+            let options = ParseFloatOptions::new();
+            for input in ["1", "+1", "1.", ".5", "01.5", "1_000.5", "1e5", "1E+5", "inf"] {
+                let expected = lexical_core::parse_with_options::<f64, { format::$format }>(
+                    input.as_bytes(),
+                    &options,
+                );
+                let actual = prebuilt.parse::<f64>(input.as_bytes(), &options);
+                assert_eq!(actual, expected);
+            }
+            let expected = lexical_core::parse_partial_with_options::<i64, { format::$format }>(
+                b"1_000;",
+                &Default::default(),
+            );
+            assert_eq!(prebuilt.parse_partial::<i64>(b"1_000;", &Default::default()), expected);
+        )*};
+    }
+
+    check_prebuilt!(JSON, TOML, XML, SQLITE, POSTGRESQL, MYSQL, MONGODB, PERMISSIVE, IGNORE);
+
+    // The language literal formats are not supported.
+    for format in [format::RUST_LITERAL, format::PYTHON_LITERAL, format::CXX_LITERAL] {
+        assert_eq!(PrebuiltFormat::new(format), Err(Error::UnsupportedFormat));
+    }
+
+    let custom = NumberFormatBuilder::new()
+        .digit_separator(NonZeroU8::new(b'#'))
+        .internal_digit_separator(true);
+    assert!(PrebuiltFormat::new(custom.build_unchecked()).unwrap_err().is_unsupported_format());
+    let json = NumberFormatBuilder::rebuild(format::JSON).build_strict();
+    let prebuilt = PrebuiltFormat::new(json).unwrap();
+    assert_eq!(prebuilt, PrebuiltFormat::new(format::JSON).unwrap());
+    assert_eq!(prebuilt.parse::<f64>(b"1.", &Default::default()), Err(Error::EmptyFraction(2)));
+}
+
+#[test]
+#[cfg(all(feature = "radix", feature = "parse-integers", feature = "write-integers"))]
+fn prebuilt_format_radix_test() {
+    use lexical_core::{NumberFormatBuilder, PrebuiltFormat, WriteIntegerOptions};
+
+    let options = WriteIntegerOptions::new();
+    let mut buffer = [0u8; 128];
+    for radix in 2..=36 {
+        let prebuilt = PrebuiltFormat::new(NumberFormatBuilder::from_radix(radix)).unwrap();
+        let size = prebuilt.buffer_size::<i64>(&options);
+        assert!(size <= buffer.len());
+        let digits = prebuilt.write(-12345i64, &mut buffer[..size], &options);
+        assert_eq!(prebuilt.parse::<i64>(digits, &Default::default()), Ok(-12345));
+    }
+    let hex = PrebuiltFormat::new(NumberFormatBuilder::hexadecimal()).unwrap();
+    assert_eq!(hex.write(255u32, &mut buffer, &options), b"FF");
+}
+
+#[test]
+#[cfg(all(
+    feature = "format",
+    feature = "power-of-two",
+    feature = "parse-floats",
+    feature = "parse-integers",
+    feature = "write-floats",
+    feature = "write-integers"
+))]
+fn base_prefix_roundtrip_test() {
+    use core::num::NonZeroU8;
+
+    use lexical_core::{
+        NumberFormatBuilder,
+        ParseFloatOptions,
+        ParseIntegerOptions,
+        WriteFloatOptions,
+        WriteIntegerOptions,
+    };
+
+    // Hexadecimal floats with a `0x` prefix.
+    const HEX: u128 = NumberFormatBuilder::rebuild(NumberFormatBuilder::hexadecimal())
+        .base_prefix(NonZeroU8::new(b'x'))
+        .build_strict();
+    const WRITE_FLOAT: WriteFloatOptions = WriteFloatOptions::from_radix(16);
+    const PARSE_FLOAT: ParseFloatOptions = ParseFloatOptions::from_radix(16);
+
+    let mut buffer = [b'0'; 512];
+    for (value, expected) in
+        [(12345.0f64, "0x3.039^3"), (-0.5, "-0x0.8"), (1e300, "0x1.7E43C8800759C^F9")]
+    {
+        let digits = lexical_core::write_with_options::<f64, HEX>(value, &mut buffer, &WRITE_FLOAT);
+        assert_eq!(digits, expected.as_bytes());
+        let parsed = lexical_core::parse_with_options::<f64, HEX>(digits, &PARSE_FLOAT);
+        assert_eq!(parsed, Ok(value));
+    }
+
+    // Binary integers with a sign, prefix, and suffix.
+    const BINARY: u128 = NumberFormatBuilder::new()
+        .mantissa_radix(2)
+        .base_prefix(NonZeroU8::new(b'b'))
+        .base_suffix(NonZeroU8::new(b'y'))
+        .build_strict();
+    const WRITE_INTEGER: WriteIntegerOptions = WriteIntegerOptions::new();
+    const PARSE_INTEGER: ParseIntegerOptions = ParseIntegerOptions::new();
+    for (value, expected) in [(0i32, "0b0y"), (5, "0b101y"), (-6, "-0b110y")] {
+        let digits =
+            lexical_core::write_with_options::<i32, BINARY>(value, &mut buffer, &WRITE_INTEGER);
+        assert_eq!(digits, expected.as_bytes());
+        let parsed = lexical_core::parse_with_options::<i32, BINARY>(digits, &PARSE_INTEGER);
+        assert_eq!(parsed, Ok(value));
+    }
+}
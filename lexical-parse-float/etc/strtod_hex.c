@@ -0,0 +1,124 @@
+// Generate the `strtod` hex float corpus for `tests/c_hex_tests.rs`.
+//
+// Build and run with a C99 libc, such as glibc:
+//      cc -std=c99 -O2 etc/strtod_hex.c -o strtod_hex
+//      ./strtod_hex > tests/corpus/strtod_hex.txt
+//
+// Each line is `<f64|f32> <input> <bits>`, where the bits are the hex
+// representation of the parsed float. Only inputs that `strtod` parses
+// completely are written, and every input has the `0x` prefix, since
+// `strtod` parses strings without it as decimal floats.
+
+#include <inttypes.h>
+#include <stdio.h>
+#include <stdlib.h>
+#include <string.h>
+
+static uint64_t state = 0x9e3779b97f4a7c15ULL;
+
+// Deterministic xorshift64* generator, so the corpus is reproducible.
+static uint64_t next(void) {
+    state ^= state >> 12;
+    state ^= state << 25;
+    state ^= state >> 27;
+    return state * 0x2545f4914f6cdd1dULL;
+}
+
+static void parse(const char* input) {
+    char* end;
+    double f64 = strtod(input, &end);
+    if (*end == '\0') {
+        uint64_t bits;
+        memcpy(&bits, &f64, sizeof(bits));
+        printf("f64 %s %016" PRIx64 "\n", input, bits);
+    }
+    float f32 = strtof(input, &end);
+    if (*end == '\0') {
+        uint32_t bits;
+        memcpy(&bits, &f32, sizeof(bits));
+        printf("f32 %s %08" PRIx32 "\n", input, bits);
+    }
+}
+
+// Write a random hex float with up to `max_digits` digits.
+static void random_float(char* buffer, int max_digits, int min_exp, int max_exp) {
+    static const char DIGITS[] = "0123456789abcdefABCDEF";
+    int integer = (int)(next() % 3);
+    int fraction = (int)(next() % (uint64_t)max_digits);
+    int exp = min_exp + (int)(next() % (uint64_t)(max_exp - min_exp + 1));
+    char* cursor = buffer;
+    if (next() % 4 == 0) {
+        *cursor++ = '-';
+    }
+    *cursor++ = '0';
+    *cursor++ = next() % 2 ? 'x' : 'X';
+    for (int i = 0; i < integer; i++) {
+        *cursor++ = DIGITS[next() % 22];
+    }
+    if (fraction != 0 || integer == 0) {
+        *cursor++ = '.';
+        for (int i = 0; i <= fraction; i++) {
+            *cursor++ = DIGITS[next() % 22];
+        }
+    }
+    sprintf(cursor, "%s%d", next() % 2 ? "p" : "P", exp);
+}
+
+int main(void) {
+    static const char* INPUTS[] = {
+        "0x0", "0x0p0", "-0x0p+0", "0x1", "0x1p0", "0X1P0", "0x1.8p1",
+        "0x1.8P+1", "0x.8p1", "0x1.p1", "0xAbC",
+        "0x00001.000p+0", "0x0.00000000000000000001p+80",
+        "0x1.fffffffffffffp+1023", "0x1.fffffffffffff8p+1023",
+        "0x1.fffffffffffff7ffffffp+1023", "0x2p+1023", "0x1p+1024",
+        "0x1p-1022", "0x1p-1074", "0x1p-1075", "0x1.0000000000001p-1075",
+        "0x1.8p-1075", "0x1p-1076", "0x0.0000000000001p-1022",
+        "0x0.00000000000008p-1022", "0x0.00000000000018p-1022",
+        "0x0.000000000000080000000001p-1022",
+        "0x1.00000000000008p+0", "0x1.00000000000018p+0",
+        "0x1.000000000000080000000000000000001p+0",
+        "0x1.0000000000000800000000000000000000000000000000000p+0",
+        "0x1.000002p+0", "0x1.000001p+0", "0x1.000003p+0",
+        "0x1.0000010000000000001p+0", "0x1.fffffep+127", "0x1.ffffffp+127",
+        "0x1p+128", "0x1p-126", "0x1p-149", "0x1p-150", "0x1.8p-150",
+        "0x123456789abcdef0123456789abcdefp-100",
+        "0x.000000000000000000000000000000000000000001p+200",
+        "0x1p+99999", "0x1p-99999", "inf", "-inf", "INF", "infinity",
+        "Infinity", "nan", "NaN", "-nan",
+    };
+    char buffer[256];
+
+    for (size_t i = 0; i < sizeof(INPUTS) / sizeof(INPUTS[0]); i++) {
+        parse(INPUTS[i]);
+    }
+
+    // Random floats near the normal, denormal and overflow ranges.
+    for (int i = 0; i < 300; i++) {
+        random_float(buffer, 40, -60, 60);
+        parse(buffer);
+    }
+    for (int i = 0; i < 100; i++) {
+        random_float(buffer, 20, -1100, -1000);
+        parse(buffer);
+    }
+    for (int i = 0; i < 50; i++) {
+        random_float(buffer, 20, 1000, 1030);
+        parse(buffer);
+    }
+    for (int i = 0; i < 100; i++) {
+        random_float(buffer, 10, -160, -120);
+        parse(buffer);
+    }
+
+    // Exactly halfway between two floats, with and without a sticky bit.
+    for (int i = 0; i < 100; i++) {
+        uint64_t mantissa = (next() >> 11) | (1ULL << 52);
+        int exp = (int)(next() % 200) - 100;
+        sprintf(buffer, "0x%" PRIx64 "8p%d", mantissa, exp);
+        parse(buffer);
+        sprintf(buffer, "0x%" PRIx64 "80000000000000000001p%d", mantissa, exp);
+        parse(buffer);
+    }
+
+    return 0;
+}
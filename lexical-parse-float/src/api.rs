@@ -5,6 +5,8 @@
 #[cfg(feature = "f16")]
 use lexical_util::bf16::bf16;
 use lexical_util::error::Error;
+#[cfg(feature = "f128")]
+use lexical_util::f128::f128;
 #[cfg(feature = "f16")]
 use lexical_util::f16::f16;
 use lexical_util::format::{is_valid_options_punctuation, NumberFormat, STANDARD};
@@ -77,3 +79,5 @@ float_from_lexical! { f32 f64 }
 
 #[cfg(feature = "f16")]
 float_from_lexical! { bf16 f16 }
+#[cfg(feature = "f128")]
+float_from_lexical! { f128 }
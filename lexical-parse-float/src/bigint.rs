@@ -1040,27 +1040,7 @@ pub fn pow<const SIZE: usize>(x: &mut StackVec<SIZE>, base: u32, mut exp: u32) -
 // SCALAR
 // ------
 
-/// Add two small integers and return the resulting value and if overflow
-/// happens.
-#[must_use]
-#[inline(always)]
-pub const fn scalar_add(x: Limb, y: Limb) -> (Limb, bool) {
-    x.overflowing_add(y)
-}
-
-/// Multiply two small integers (with carry) (and return the overflow
-/// contribution).
-///
-/// Returns the (low, high) components.
-#[must_use]
-#[inline(always)]
-pub const fn scalar_mul(x: Limb, y: Limb, carry: Limb) -> (Limb, Limb) {
-    // Cannot overflow, as long as wide is 2x as wide. This is because
-    // the following is always true:
-    // `Wide::MAX - (Narrow::MAX * Narrow::MAX) >= Narrow::MAX`
-    let z: Wide = (x as Wide) * (y as Wide) + (carry as Wide);
-    (z as Limb, (z >> Limb::BITS) as Limb)
-}
+pub use lexical_util::limb::{scalar_add, scalar_mul};
 
 // SMALL
 // -----
@@ -1162,6 +1142,25 @@ pub fn large_add<const SIZE: usize>(x: &mut StackVec<SIZE>, y: &[Limb]) -> Optio
     large_add_from(x, y, 0)
 }
 
+/// Subtract bigint from bigint, where `x >= y`.
+#[inline(always)]
+#[cfg(feature = "f128")]
+pub fn large_sub<const SIZE: usize>(x: &mut StackVec<SIZE>, y: &[Limb]) {
+    debug_assert!(compare(x, y) != cmp::Ordering::Less, "cannot subtract a larger value");
+    let mut borrow = false;
+    for (index, xi) in x.iter_mut().enumerate() {
+        let yi = y.get(index).copied().unwrap_or(0);
+        if index >= y.len() && !borrow {
+            break;
+        }
+        let (value, b1) = xi.overflowing_sub(yi);
+        let (value, b2) = value.overflowing_sub(borrow as Limb);
+        *xi = value;
+        borrow = b1 || b2;
+    }
+    x.normalize();
+}
+
 /// Grade-school multiplication algorithm.
 ///
 /// Slow, naive algorithm, using limb-bit bases and just shifting left for
@@ -1337,25 +1336,7 @@ pub fn large_quorem<const SIZE: usize>(x: &mut StackVec<SIZE>, y: &[Limb]) -> Li
 // COMPARE
 // -------
 
-/// Compare `x` to `y`, in little-endian order.
-#[must_use]
-#[inline(always)]
-pub fn compare(x: &[Limb], y: &[Limb]) -> cmp::Ordering {
-    match x.len().cmp(&y.len()) {
-        cmp::Ordering::Equal => {
-            let iter = x.iter().rev().zip(y.iter().rev());
-            for (&xi, yi) in iter {
-                match xi.cmp(yi) {
-                    cmp::Ordering::Equal => (),
-                    ord => return ord,
-                }
-            }
-            // Equal case.
-            cmp::Ordering::Equal
-        },
-        ord => ord,
-    }
-}
+pub use lexical_util::limb::compare;
 
 // SHIFT
 // -----
@@ -1475,7 +1456,7 @@ pub const fn split_radix(radix: u32) -> (u32, u32) {
         9 => (9, 0),
         10 => (5, 1),
         11 => (11, 0),
-        12 => (6, 1),
+        12 => (3, 2),
         13 => (13, 0),
         14 => (7, 1),
         15 => (15, 0),
@@ -1544,86 +1525,4 @@ pub const fn split_radix(radix: u32) -> (u32, u32) {
 // LIMB
 // ----
 
-//  Type for a single limb of the big integer.
-//
-//  A limb is analogous to a digit in base10, except, it stores 32-bit
-//  or 64-bit numbers instead. We want types where 64-bit multiplication
-//  is well-supported by the architecture, rather than emulated in 3
-//  instructions. The quickest way to check this support is using a
-//  cross-compiler for numerous architectures, along with the following
-//  source file and command:
-//
-//  Compile with `gcc main.c -c -S -O3 -masm=intel`
-//
-//  And the source code is:
-//  ```text
-//  #include <stdint.h>
-//
-//  struct i128 {
-//      uint64_t hi;
-//      uint64_t lo;
-//  };
-//
-//  // Type your code here, or load an example.
-//  struct i128 square(uint64_t x, uint64_t y) {
-//      __int128 prod = (__int128)x * (__int128)y;
-//      struct i128 z;
-//      z.hi = (uint64_t)(prod >> 64);
-//      z.lo = (uint64_t)prod;
-//      return z;
-//  }
-//  ```
-//
-//  If the result contains `call __multi3`, then the multiplication
-//  is emulated by the compiler. Otherwise, it's natively supported.
-//
-//  This should be all-known 64-bit platforms supported by Rust.
-//      https://forge.rust-lang.org/platform-support.html
-//
-//  # Supported
-//
-//  Platforms where native 128-bit multiplication is explicitly supported:
-//      - x86_64 (Supported via `MUL`).
-//      - mips64 (Supported via `DMULTU`, which `HI` and `LO` can be read-from).
-//      - s390x (Supported via `MLGR`).
-//
-//  # Efficient
-//
-//  Platforms where native 64-bit multiplication is supported and
-//  you can extract hi-lo for 64-bit multiplications.
-//      - aarch64 (Requires `UMULH` and `MUL` to capture high and low bits).
-//      - powerpc64 (Requires `MULHDU` and `MULLD` to capture high and low
-//        bits).
-//      - riscv64 (Requires `MUL` and `MULH` to capture high and low bits).
-//
-//  # Unsupported
-//
-//  Platforms where native 128-bit multiplication is not supported,
-//  requiring software emulation.
-//      sparc64 (`UMUL` only supports double-word arguments).
-//      sparcv9 (Same as sparc64).
-//
-//  These tests are run via `xcross`, my own library for C cross-compiling,
-//  which supports numerous targets (far in excess of Rust's tier 1 support,
-//  or rust-embedded/cross's list). xcross may be found here:
-//      https://github.com/Alexhuszagh/xcross
-//
-//  To compile for the given target, run:
-//      `xcross gcc main.c -c -S -O3 --target $target`
-//
-//  All 32-bit architectures inherently do not have support. That means
-//  we can essentially look for 64-bit architectures that are not SPARC.
-
-#[cfg(all(target_pointer_width = "64", not(target_arch = "sparc")))]
-pub type Limb = u64;
-#[cfg(all(target_pointer_width = "64", not(target_arch = "sparc")))]
-pub type Wide = u128;
-#[cfg(all(target_pointer_width = "64", not(target_arch = "sparc")))]
-pub type SignedWide = i128;
-
-#[cfg(not(all(target_pointer_width = "64", not(target_arch = "sparc"))))]
-pub type Limb = u32;
-#[cfg(not(all(target_pointer_width = "64", not(target_arch = "sparc"))))]
-pub type Wide = u64;
-#[cfg(not(all(target_pointer_width = "64", not(target_arch = "sparc"))))]
-pub type SignedWide = i64;
+pub use lexical_util::limb::{Limb, SignedWide, Wide};
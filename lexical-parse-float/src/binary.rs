@@ -6,6 +6,8 @@
 #![cfg(feature = "power-of-two")]
 #![doc(hidden)]
 
+use core::cmp;
+
 #[cfg(not(feature = "compact"))]
 use lexical_parse_integer::algorithm;
 use lexical_util::digit::char_to_valid_digit_const;
@@ -13,8 +15,9 @@ use lexical_util::format::NumberFormat;
 use lexical_util::iterator::{AsBytes, DigitsIter};
 use lexical_util::step::u64_step;
 
+use crate::bigint::Bigint;
 use crate::float::{ExtendedFloat80, RawFloat};
-use crate::mask::lower_n_halfway;
+use crate::mask::{lower_n_halfway, lower_n_mask};
 use crate::number::Number;
 use crate::shared;
 
@@ -35,6 +38,11 @@ pub fn binary<F: RawFloat, const FORMAT: u128>(num: &Number, lossy: bool) -> Ext
         exp: 0,
     };
 
+    // Short-circuit if the value can only be a literal 0.
+    if num.mantissa == 0 {
+        return fp_zero;
+    }
+
     // Normalize our mantissa for simpler results.
     let ctlz = num.mantissa.leading_zeros();
     let mantissa = num.mantissa << ctlz;
@@ -51,10 +59,11 @@ pub fn binary<F: RawFloat, const FORMAT: u128>(num: &Number, lossy: bool) -> Ext
     // disambiguate the float. If it's even, and exactly halfway, this
     // step fails.
     let power2 = shared::calculate_power2::<F, FORMAT>(num.exponent, ctlz);
-    if -power2 + 1 >= 64 {
-        // Have more than 63 bits below the minimum exponent, must be 0.
+    if -power2 + 1 > 64 {
+        // Have more than 64 bits below the minimum exponent, must be 0.
         // Since we can't have partial digit rounding, this is true always
-        // if the power-of-two >= 64.
+        // if the power-of-two > 64. With exactly 64 bits, the value is
+        // at least halfway to the smallest denormal, and may round up.
         return fp_zero;
     }
 
@@ -63,11 +72,11 @@ pub fn binary<F: RawFloat, const FORMAT: u128>(num: &Number, lossy: bool) -> Ext
     // relative to the current leading zeros of the float.
     let shift = shared::calculate_shift::<F>(power2);
 
-    // Determine if we can see if we're at a halfway point.
-    let last_bit = 1u64 << shift;
-    let truncated = last_bit - 1;
+    // Determine if we can see if we're at a halfway point. If every bit
+    // is truncated, the last bit is an implicit 0, and therefore even.
+    let truncated = lower_n_mask(shift as u64);
     let halfway = lower_n_halfway(shift as u64);
-    let is_even = mantissa & last_bit == 0;
+    let is_even = shift == 64 || mantissa & (1u64 << shift) == 0;
     let is_halfway = mantissa & truncated == halfway;
     if !lossy && is_even && is_halfway && num.many_digits {
         // Exactly halfway and even, cannot safely determine our representation.
@@ -212,3 +221,74 @@ pub fn slow_binary<F: RawFloat, const FORMAT: u128>(num: Number) -> ExtendedFloa
     });
     fp
 }
+
+/// Compare the real digits to the exact value of an extended float.
+///
+/// The parsed mantissa has at least 56 significant bits, so any truncated
+/// digits are below the last bit of the float or halfway point, and only
+/// need to be checked if the rest of the digits compare equal.
+#[allow(clippy::unwrap_used)] // reason = "the exponents are bounded by the float range"
+pub fn compare_binary<const FORMAT: u128>(num: &Number, theor: ExtendedFloat80) -> cmp::Ordering {
+    let format = NumberFormat::<{ FORMAT }> {};
+    debug_assert!(
+        matches!(format.radix(), 2 | 4 | 8 | 16 | 32),
+        "algorithm requires a power-of-two"
+    );
+
+    // Scale both values by the difference in their binary exponents.
+    let power2 = num.exponent as i32 * shared::log2(format.exponent_base());
+    let mut real_digits = Bigint::from_u64(num.mantissa);
+    let mut theor_digits = Bigint::from_u64(theor.mant);
+    let shift = power2 - theor.exp;
+    if shift > 0 {
+        real_digits.pow(2, shift as u32).unwrap();
+    } else if shift < 0 {
+        theor_digits.pow(2, shift.unsigned_abs()).unwrap();
+    }
+
+    match real_digits.data.cmp(&theor_digits.data) {
+        cmp::Ordering::Equal if is_truncated::<FORMAT>(num) => cmp::Ordering::Greater,
+        ord => ord,
+    }
+}
+
+/// Determine if any non-zero digits were truncated from the mantissa.
+#[cfg_attr(not(feature = "compact"), inline(always))]
+fn is_truncated<const FORMAT: u128>(num: &Number) -> bool {
+    if !num.many_digits {
+        return false;
+    }
+
+    // Skip the significant digits that were parsed into the mantissa.
+    let format = NumberFormat::<{ FORMAT }> {};
+    let mut step = u64_step(format.radix());
+    let mut is_nonzero = |c: u8| {
+        if step == 0 {
+            c != b'0'
+        } else {
+            step -= 1;
+            false
+        }
+    };
+
+    let mut integer = num.integer.bytes::<FORMAT>();
+    let mut integer_iter = integer.integer_iter();
+    integer_iter.skip_zeros();
+    let mut has_integer = false;
+    for &c in integer_iter {
+        has_integer = true;
+        if is_nonzero(c) {
+            return true;
+        }
+    }
+    if let Some(fraction) = num.fraction {
+        let mut fraction = fraction.bytes::<FORMAT>();
+        let mut fraction_iter = fraction.fraction_iter();
+        if !has_integer {
+            fraction_iter.skip_zeros();
+        }
+        fraction_iter.any(|&c| is_nonzero(c))
+    } else {
+        false
+    }
+}
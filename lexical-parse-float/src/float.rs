@@ -8,6 +8,8 @@
 #[cfg(feature = "f16")]
 use lexical_util::bf16::bf16;
 use lexical_util::extended_float::ExtendedFloat;
+#[cfg(feature = "f128")]
+use lexical_util::f128::f128;
 #[cfg(feature = "f16")]
 use lexical_util::f16::f16;
 use lexical_util::num::{AsCast, Float};
@@ -110,6 +112,14 @@ impl RawFloat for bf16 {
     }
 }
 
+#[cfg(feature = "f128")]
+impl RawFloat for f128 {
+    #[inline(always)]
+    fn pow_fast_path(_: usize, _: u32) -> Self {
+        unimplemented!()
+    }
+}
+
 /// Helper trait to add more float characteristics for the Eisel-Lemire
 /// algorithm.
 pub trait LemireFloat: RawFloat {
@@ -178,6 +188,15 @@ impl LemireFloat for bf16 {
     const LARGEST_POWER_OF_TEN: i32 = 0;
 }
 
+#[cfg(feature = "f128")]
+impl LemireFloat for f128 {
+    const MIN_EXPONENT_ROUND_TO_EVEN: i32 = 0;
+    const MAX_EXPONENT_ROUND_TO_EVEN: i32 = 0;
+    const MINIMUM_EXPONENT: i32 = 0;
+    const SMALLEST_POWER_OF_TEN: i32 = 0;
+    const LARGEST_POWER_OF_TEN: i32 = 0;
+}
+
 #[inline(always)]
 #[cfg(all(feature = "std", feature = "compact"))]
 pub fn powf(x: f32, y: f32) -> f32 {
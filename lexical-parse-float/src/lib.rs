@@ -82,6 +82,39 @@
 //! # }
 //! ```
 //!
+//! # Decimal Parts
+//!
+//! To use the format-aware tokenizer without rounding to a native float,
+//! such as for arbitrary-precision decimal types, [`parse_decimal_parts`]
+//! returns the sign, the significant digits, and the exponent relative to
+//! those digits.
+//!
+//! ```rust
+//! use lexical_parse_float::{format, parse_decimal_parts, Options};
+//!
+//! const OPTIONS: Options = Options::new();
+//! let parts = parse_decimal_parts::<{ format::STANDARD }>(b"3.14159265358979323846264338327950288", &OPTIONS);
+//! let parts = parts.unwrap();
+//! assert_eq!(parts.digit_count(), 36);
+//! assert_eq!(parts.exponent(), -35);
+//! ```
+//!
+//! # Status
+//!
+//! To detect if any precision was lost when parsing a float, such as when
+//! validating that decimal values are exactly representable, use
+//! [`parse_with_status`], which returns the float and whether it is
+//! [`Exact`][Status::Exact], [`Inexact`][Status::Inexact], or was rounded
+//! due to [`Underflow`][Status::Underflow] or [`Overflow`][Status::Overflow].
+//!
+//! ```rust
+//! use lexical_parse_float::{format, parse_with_status, Options, Status};
+//!
+//! const OPTIONS: Options = Options::new();
+//! let result = parse_with_status::<f64, { format::STANDARD }>(b"0.1", &OPTIONS);
+//! assert_eq!(result, Ok((0.1, Status::Inexact)));
+//! ```
+//!
 //! # Features
 //!
 //! * `format` - Add support for parsing custom integer formats.
@@ -90,11 +123,14 @@
 //! * `compact` - Reduce code size at the cost of performance.
 //! * `f16` - Enable support for half-precision [`f16`][`ieee-f16`] and
 //!   [`bf16`][`brain-float`] floats.
+//! * `f128` - Enable support for quadruple-precision [`f128`][`ieee-f128`]
+//!   floats.
 //! * `std` (Default) - Disable to allow use in a [`no_std`] environment.
 //!
 //! [`no_std`]: https://docs.rust-embedded.org/book/intro/no-std.html
 //! [`ieee-f16`]: https://en.wikipedia.org/wiki/Half-precision_floating-point_format
 //! [`brain-float`]: https://en.wikipedia.org/wiki/Bfloat16_floating-point_format
+//! [`ieee-f128`]: https://en.wikipedia.org/wiki/Quadruple-precision_floating-point_format
 //!
 //! A complete description of supported features includes:
 //!
@@ -278,6 +314,24 @@
 //! # }
 //! ```
 //!
+//! #### f128
+//!
+//! This enables the use of the quadruple-precision float
+//! [`f128`][`ieee-f128`], using a software implementation. Since the
+//! extended-float algorithms only have 64 bits of precision, every
+//! number is parsed with an arbitrary-precision algorithm, so parsing is
+//! much slower than for native floats, but is still correctly rounded.
+//!
+//! ```rust
+//! # #[cfg(feature = "f128")] {
+//! use lexical_parse_float::{f128, FromLexical};
+//!
+//! let value = "0.1";
+//! let result = f128::from_lexical(value.as_bytes()).unwrap();
+//! assert_eq!(result.to_bits(), 0x3FFB999999999999999999999999999A);
+//! # }
+//! ```
+//!
 //! #### std
 //!
 //! Enable use of the standard library. Currently, the standard library
@@ -551,7 +605,11 @@ pub mod mask;
 pub mod number;
 pub mod options;
 pub mod parse;
+pub mod parts;
+pub mod quad;
 pub mod slow;
+pub mod status;
+pub mod stream;
 pub mod table;
 
 mod api;
@@ -571,6 +629,8 @@ extern crate lexical_parse_integer;
 #[cfg(feature = "f16")]
 pub use lexical_util::bf16::bf16;
 pub use lexical_util::error::Error;
+#[cfg(feature = "f128")]
+pub use lexical_util::f128::f128;
 #[cfg(feature = "f16")]
 pub use lexical_util::f16::f16;
 pub use lexical_util::format::{self, NumberFormat, NumberFormatBuilder};
@@ -579,4 +639,8 @@ pub use lexical_util::result::Result;
 
 pub use self::api::{FromLexical, FromLexicalWithOptions};
 #[doc(inline)]
-pub use self::options::{Options, OptionsBuilder};
+pub use self::options::{Options, OptionsBuilder, Rounding};
+#[doc(inline)]
+pub use self::parts::{parse_decimal_parts, parse_partial_decimal_parts, DecimalParts};
+#[doc(inline)]
+pub use self::status::{parse_partial_with_status, parse_with_status, Status};
@@ -5,6 +5,8 @@
 use lexical_util::assert::debug_assert_radix;
 #[cfg(feature = "f16")]
 use lexical_util::bf16::bf16;
+#[cfg(feature = "f128")]
+use lexical_util::f128::f128;
 #[cfg(feature = "f16")]
 use lexical_util::f16::f16;
 
@@ -96,21 +98,20 @@ impl ExactFloat for bf16 {
     }
 }
 
-//#[cfg(feature = "f128")]
-//impl ExactFloat for f128 {
-//    #[inline(always)]
-//    fn exponent_limit(radix: u32) -> (i64, i64) {
-//        debug_assert_radix(radix);
-//        f128_exponent_limit(radix)
-//        }
-//    }
-//
-//    #[inline(always)]
-//    fn mantissa_limit(radix: u32) -> i64 {
-//        debug_assert_radix(radix);
-//        f128_mantissa_limit(radix)
-//    }
-//}
+#[cfg(feature = "f128")]
+impl ExactFloat for f128 {
+    #[inline(always)]
+    fn exponent_limit(radix: u32) -> (i64, i64) {
+        debug_assert_radix(radix);
+        f128_exponent_limit(radix)
+    }
+
+    #[inline(always)]
+    fn mantissa_limit(radix: u32) -> i64 {
+        debug_assert_radix(radix);
+        f128_mantissa_limit(radix)
+    }
+}
 
 // CONST FN
 // --------
@@ -900,32 +901,16 @@ impl MaxDigits for bf16 {
     }
 }
 
-///// `emin = -16382`
-///// `p2 = 113`
-//#[cfg(feature = "f128")]
-//impl MaxDigits for f128 {
-//    #[inline(always)]
-//    fn max_digits(radix: u32) -> Option<usize> {
-//        match radix {
-//            6 => Some(10159),
-//            10 => Some(11565),
-//            12 => Some(11927),
-//            14 => Some(12194),
-//            18 => Some(12568),
-//            20 => Some(12706),
-//            22 => Some(12823),
-//            24 => Some(12924),
-//            26 => Some(13012),
-//            28 => Some(13089),
-//            30 => Some(13158),
-//            34 => Some(13277),
-//            36 => Some(13328),
-//            // Powers of two should be unreachable.
-//            // Odd numbers will have infinite digits.
-//            _ => None,
-//        }
-//    }
-//}
+/// `emin = -16382`
+/// `p2 = 113`
+#[cfg(feature = "f128")]
+impl MaxDigits for f128 {
+    #[inline(always)]
+    fn max_digits(radix: u32) -> Option<usize> {
+        debug_assert_radix(radix);
+        f128_max_digits(radix)
+    }
+}
 
 // CONST FN
 // --------
@@ -977,3 +962,28 @@ pub const fn f64_max_digits(radix: u32) -> Option<usize> {
         _ => None,
     }
 }
+
+/// Get the maximum number of significant digits as a const fn.
+#[must_use]
+#[inline(always)]
+#[cfg(feature = "f128")]
+pub const fn f128_max_digits(radix: u32) -> Option<usize> {
+    match radix {
+        6 => Some(10159),
+        10 => Some(11565),
+        12 => Some(11927),
+        14 => Some(12194),
+        18 => Some(12568),
+        20 => Some(12706),
+        22 => Some(12823),
+        24 => Some(12924),
+        26 => Some(13012),
+        28 => Some(13089),
+        30 => Some(13158),
+        34 => Some(13277),
+        36 => Some(13328),
+        // Powers of two should be unreachable.
+        // Odd numbers will have infinite digits.
+        _ => None,
+    }
+}
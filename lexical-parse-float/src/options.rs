@@ -195,6 +195,35 @@ use lexical_util::result::Result;
 /// Maximum length for a special string.
 pub const MAX_SPECIAL_STRING_LENGTH: usize = 50;
 
+/// Enumeration for how to round parsed floats that cannot be represented
+/// exactly.
+///
+/// For example, `0.1` lies between two binary floats: using
+/// [`NearestTieEven`][Rounding::NearestTieEven] or
+/// [`TowardPositive`][Rounding::TowardPositive] gives the float above it,
+/// while [`TowardZero`][Rounding::TowardZero] or
+/// [`TowardNegative`][Rounding::TowardNegative] gives the float below it.
+/// Digits that are exactly representable are never rounded.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Rounding {
+    /// Round to the nearest float, with halfway cases rounded to the float
+    /// with an even significand.
+    NearestTieEven,
+
+    /// Round to the nearest float, with halfway cases rounded away from
+    /// zero.
+    NearestTieAway,
+
+    /// Round toward zero, truncating the significant digits.
+    TowardZero,
+
+    /// Round toward positive infinity.
+    TowardPositive,
+
+    /// Round toward negative infinity.
+    TowardNegative,
+}
+
 /// Builder for [`Options`].
 ///
 /// This enables extensive control over how the float is parsed, from
@@ -219,6 +248,10 @@ pub struct OptionsBuilder {
     /// Disable the use of arbitrary-precision arithmetic, and always
     /// return the results from the fast or intermediate path algorithms.
     lossy: bool,
+    /// How to round values that cannot be exactly represented.
+    rounding: Rounding,
+    /// Only accept integer syntax, without a fraction or exponent.
+    integer_syntax: bool,
     /// Character to designate the exponent component of a float.
     exponent: u8,
     /// Character to separate the integer from the fraction components.
@@ -237,6 +270,8 @@ impl OptionsBuilder {
     pub const fn new() -> Self {
         Self {
             lossy: false,
+            rounding: Rounding::NearestTieEven,
+            integer_syntax: false,
             exponent: b'e',
             decimal_point: b'.',
             nan_string: Some(b"NaN"),
@@ -264,6 +299,38 @@ impl OptionsBuilder {
         self.lossy
     }
 
+    /// Get how to round values that cannot be exactly represented.
+    ///
+    /// Defaults to [`Rounding::NearestTieEven`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lexical_parse_float::options::{Options, Rounding};
+    ///
+    /// assert_eq!(Options::builder().get_rounding(), Rounding::NearestTieEven);
+    /// ```
+    #[inline(always)]
+    pub const fn get_rounding(&self) -> Rounding {
+        self.rounding
+    }
+
+    /// Get if we only accept integer syntax.
+    ///
+    /// Defaults to [`false`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lexical_parse_float::options::Options;
+    ///
+    /// assert_eq!(Options::builder().get_integer_syntax(), false);
+    /// ```
+    #[inline(always)]
+    pub const fn get_integer_syntax(&self) -> bool {
+        self.integer_syntax
+    }
+
     /// Get the character to designate the exponent component of a float.
     ///
     /// Any non-control character is valid, but `\t` to `\r` are also valid.
@@ -378,6 +445,74 @@ impl OptionsBuilder {
         self
     }
 
+    /// Set how to round values that cannot be exactly represented.
+    ///
+    /// Any mode other than [`Rounding::NearestTieEven`] always compares
+    /// the significant digits exactly, and so ignores [`lossy`]. Defaults
+    /// to [`Rounding::NearestTieEven`].
+    ///
+    /// [`lossy`]: Self::lossy
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lexical_parse_float::format::STANDARD;
+    /// use lexical_parse_float::options::{Options, Rounding};
+    /// use lexical_parse_float::FromLexicalWithOptions;
+    ///
+    /// const DOWN: Options = Options::builder()
+    ///     .rounding(Rounding::TowardNegative)
+    ///     .build_strict();
+    /// const UP: Options = Options::builder()
+    ///     .rounding(Rounding::TowardPositive)
+    ///     .build_strict();
+    ///
+    /// let down = f64::from_lexical_with_options::<STANDARD>(b"0.1", &DOWN).unwrap();
+    /// let up = f64::from_lexical_with_options::<STANDARD>(b"0.1", &UP).unwrap();
+    /// assert_eq!(down, 0.09999999999999999);
+    /// assert_eq!(up, 0.1);
+    /// ```
+    #[must_use]
+    #[inline(always)]
+    pub const fn rounding(mut self, rounding: Rounding) -> Self {
+        self.rounding = rounding;
+        self
+    }
+
+    /// Set if we only accept integer syntax.
+    ///
+    /// If enabled, a decimal point or exponent is an invalid digit, and
+    /// special values such as `NaN` and `inf` are not parsed, so the
+    /// complete parser returns [`Error::InvalidDigit`] at the decimal point
+    /// or exponent, and the partial parser stops before them. The value is
+    /// still rounded to the nearest float if it cannot be exactly
+    /// represented. Defaults to [`false`].
+    ///
+    /// [`Error::InvalidDigit`]: lexical_util::error::Error::InvalidDigit
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lexical_parse_float::format::STANDARD;
+    /// use lexical_parse_float::options::Options;
+    /// use lexical_parse_float::{Error, FromLexicalWithOptions};
+    ///
+    /// const OPTIONS: Options = Options::builder()
+    ///     .integer_syntax(true)
+    ///     .build_strict();
+    ///
+    /// let parse = |x: &str| f64::from_lexical_with_options::<STANDARD>(x.as_bytes(), &OPTIONS);
+    /// assert_eq!(parse("1234"), Ok(1234.0));
+    /// assert_eq!(parse("12.0"), Err(Error::InvalidDigit(2)));
+    /// assert_eq!(parse("1e3"), Err(Error::InvalidDigit(1)));
+    /// ```
+    #[must_use]
+    #[inline(always)]
+    pub const fn integer_syntax(mut self, integer_syntax: bool) -> Self {
+        self.integer_syntax = integer_syntax;
+        self
+    }
+
     /// Set the character to designate the exponent component of a float.
     ///
     /// Any non-control character is valid, but `\t` to `\r` are also valid.
@@ -641,6 +776,8 @@ impl OptionsBuilder {
     pub const fn build_unchecked(&self) -> Options {
         Options {
             lossy: self.lossy,
+            rounding: self.rounding,
+            integer_syntax: self.integer_syntax,
             exponent: self.exponent,
             decimal_point: self.decimal_point,
             nan_string: self.nan_string,
@@ -761,6 +898,10 @@ pub struct Options {
     /// Disable the use of arbitrary-precision arithmetic, and always
     /// return the results from the fast or intermediate path algorithms.
     lossy: bool,
+    /// How to round values that cannot be exactly represented.
+    rounding: Rounding,
+    /// Only accept integer syntax, without a fraction or exponent.
+    integer_syntax: bool,
     /// Character to designate the exponent component of a float.
     exponent: u8,
     /// Character to separate the integer from the fraction components.
@@ -824,6 +965,38 @@ impl Options {
         self.lossy
     }
 
+    /// Get how to round values that cannot be exactly represented.
+    ///
+    /// Defaults to [`Rounding::NearestTieEven`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lexical_parse_float::options::{Options, Rounding};
+    ///
+    /// assert_eq!(Options::new().rounding(), Rounding::NearestTieEven);
+    /// ```
+    #[inline(always)]
+    pub const fn rounding(&self) -> Rounding {
+        self.rounding
+    }
+
+    /// Get if we only accept integer syntax.
+    ///
+    /// Defaults to [`false`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lexical_parse_float::options::Options;
+    ///
+    /// assert_eq!(Options::new().integer_syntax(), false);
+    /// ```
+    #[inline(always)]
+    pub const fn integer_syntax(&self) -> bool {
+        self.integer_syntax
+    }
+
     /// Get the character to designate the exponent component of a float.
     ///
     /// Any non-control character is valid, but `\t` to `\r` are also valid.
@@ -971,6 +1144,8 @@ impl Options {
     pub const fn rebuild(&self) -> OptionsBuilder {
         OptionsBuilder {
             lossy: self.lossy,
+            rounding: self.rounding,
+            integer_syntax: self.integer_syntax,
             exponent: self.exponent,
             decimal_point: self.decimal_point,
             nan_string: self.nan_string,
@@ -992,6 +1167,16 @@ impl ParseOptions for Options {
     fn is_valid(&self) -> bool {
         Self::is_valid(self)
     }
+
+    #[inline(always)]
+    fn decimal_point(&self) -> u8 {
+        Self::decimal_point(self)
+    }
+
+    #[inline(always)]
+    fn exponent(&self) -> u8 {
+        Self::exponent(self)
+    }
 }
 
 /// Unwrap `Option` as a const fn.
@@ -9,30 +9,40 @@
 
 #![doc(hidden)]
 
+#[cfg(feature = "f16")]
+use core::cmp;
+
 #[cfg(not(feature = "compact"))]
 use lexical_parse_integer::algorithm;
 #[cfg(feature = "f16")]
 use lexical_util::bf16::bf16;
 use lexical_util::digit::{char_to_digit_const, char_to_valid_digit_const};
 use lexical_util::error::Error;
+#[cfg(feature = "f128")]
+use lexical_util::f128::f128;
 #[cfg(feature = "f16")]
 use lexical_util::f16::f16;
 use lexical_util::format::NumberFormat;
 use lexical_util::iterator::{AsBytes, Bytes, DigitsIter, Iter};
+#[cfg(feature = "f16")]
+use lexical_util::num::Float;
 use lexical_util::result::Result;
 use lexical_util::step::u64_step;
 
 #[cfg(any(feature = "compact", feature = "radix"))]
 use crate::bellerophon::bellerophon;
 #[cfg(feature = "power-of-two")]
-use crate::binary::{binary, slow_binary};
+use crate::binary::{binary, compare_binary, slow_binary};
 use crate::float::{extended_to_float, ExtendedFloat80, LemireFloat};
 #[cfg(not(feature = "compact"))]
 use crate::lemire::lemire;
 use crate::number::Number;
-use crate::options::Options;
+use crate::options::{Options, Rounding};
+#[cfg(feature = "f128")]
+use crate::quad;
 use crate::shared;
-use crate::slow::slow_radix;
+use crate::slow::{b, bh, compare_float, slow_radix};
+use crate::status::Status;
 
 // API
 // ---
@@ -91,6 +101,28 @@ pub trait ParseFloat: LemireFloat {
         parse_partial::<Self, FORMAT>(bytes, options)
     }
 
+    /// Forward complete parser parameters to the backend, and get the
+    /// status of the parsed float.
+    #[cfg_attr(not(feature = "compact"), inline(always))]
+    fn parse_complete_status<const FORMAT: u128>(
+        bytes: &[u8],
+        options: &Options,
+    ) -> Result<(Self, Status)> {
+        check_radix!(FORMAT);
+        parse_complete_status::<Self, FORMAT>(bytes, options)
+    }
+
+    /// Forward partial parser parameters to the backend, and get the
+    /// status of the parsed float.
+    #[cfg_attr(not(feature = "compact"), inline(always))]
+    fn parse_partial_status<const FORMAT: u128>(
+        bytes: &[u8],
+        options: &Options,
+    ) -> Result<(Self, Status, usize)> {
+        check_radix!(FORMAT);
+        parse_partial_status::<Self, FORMAT>(bytes, options)
+    }
+
     /// Forward complete parser parameters to the backend, using only the fast
     /// path.
     #[cfg_attr(not(feature = "compact"), inline(always))]
@@ -127,6 +159,12 @@ macro_rules! parse_float_as_f32 {
             fn parse_complete<const FORMAT: u128>(bytes: &[u8], options: &Options)
                 -> Result<Self>
             {
+                if options.rounding() != Rounding::NearestTieEven {
+                    let (lower, upper) = directed_options(options);
+                    let lower = parse_complete::<f32, FORMAT>(bytes, &lower)?;
+                    let upper = parse_complete::<f32, FORMAT>(bytes, &upper)?;
+                    return Ok(directed_from_f32(lower, upper, options.rounding()));
+                }
                 Ok(Self::from_f32(parse_complete::<f32, FORMAT>(bytes, options)?))
             }
 
@@ -134,10 +172,37 @@ macro_rules! parse_float_as_f32 {
             fn parse_partial<const FORMAT: u128>(bytes: &[u8], options: &Options)
                 -> Result<(Self, usize)>
             {
+                if options.rounding() != Rounding::NearestTieEven {
+                    let (lower, upper) = directed_options(options);
+                    let (lower, count) = parse_partial::<f32, FORMAT>(bytes, &lower)?;
+                    let (upper, _) = parse_partial::<f32, FORMAT>(bytes, &upper)?;
+                    return Ok((directed_from_f32(lower, upper, options.rounding()), count));
+                }
                 let (float, count) = parse_partial::<f32, FORMAT>(bytes, options)?;
                 Ok((Self::from_f32(float), count))
             }
 
+            #[cfg_attr(not(feature = "compact"), inline(always))]
+            fn parse_complete_status<const FORMAT: u128>(bytes: &[u8], options: &Options)
+                -> Result<(Self, Status)>
+            {
+                let (lower, upper) = directed_options(options);
+                let lower = parse_complete::<f32, FORMAT>(bytes, &lower)?;
+                let upper = parse_complete::<f32, FORMAT>(bytes, &upper)?;
+                Ok(status_from_f32(lower, upper, options.rounding()))
+            }
+
+            #[cfg_attr(not(feature = "compact"), inline(always))]
+            fn parse_partial_status<const FORMAT: u128>(bytes: &[u8], options: &Options)
+                -> Result<(Self, Status, usize)>
+            {
+                let (lower, upper) = directed_options(options);
+                let (lower, count) = parse_partial::<f32, FORMAT>(bytes, &lower)?;
+                let (upper, _) = parse_partial::<f32, FORMAT>(bytes, &upper)?;
+                let (float, status) = status_from_f32(lower, upper, options.rounding());
+                Ok((float, status, count))
+            }
+
             #[cfg_attr(not(feature = "compact"), inline(always))]
             fn fast_path_complete<const FORMAT: u128>(bytes: &[u8], options: &Options)
                 -> Result<Self>
@@ -159,6 +224,157 @@ macro_rules! parse_float_as_f32 {
 #[cfg(feature = "f16")]
 parse_float_as_f32! { bf16 f16 }
 
+/// Get the options to parse the floats below and above a value.
+#[cfg(feature = "f16")]
+#[inline(always)]
+fn directed_options(options: &Options) -> (Options, Options) {
+    let lower = options.rebuild().rounding(Rounding::TowardNegative).build_unchecked();
+    let upper = options.rebuild().rounding(Rounding::TowardPositive).build_unchecked();
+    (lower, upper)
+}
+
+/// Round a value to a half-precision float using any rounding mode.
+///
+/// The `lower` and `upper` values are the value rounded toward negative
+/// and positive infinity as an `f32`. These are used to round the value
+/// to odd, which has enough extra bits that rounding it again to a
+/// half-precision float gives the correctly rounded result.
+#[cfg(feature = "f16")]
+fn directed_from_f32<F: Float>(lower: f32, upper: f32, rounding: Rounding) -> F {
+    // Round-to-odd the magnitude, using the float toward zero.
+    let is_negative = lower.is_sign_negative();
+    let truncated = if is_negative {
+        upper
+    } else {
+        lower
+    };
+    let mut bits = truncated.to_bits() & !f32::SIGN_MASK;
+    if lower.to_bits() != upper.to_bits() {
+        bits |= 1;
+    }
+    let odd = f32::from_bits(bits);
+    if odd == 0.0 || odd.is_special() {
+        // Zero and special values are always exact.
+        return F::as_cast(truncated);
+    }
+
+    let float: F =
+        shared::round_directed(F::as_cast(odd), rounding, is_negative, |value, is_halfway| {
+            let lo = value.as_f32();
+            let theor = if !is_halfway {
+                lo
+            } else if value.next_positive().is_inf() {
+                lo + (lo - value.prev_positive().as_f32()) / 2.0
+            } else {
+                lo + (value.next_positive().as_f32() - lo) / 2.0
+            };
+            // Both values are finite, so they're always ordered.
+            odd.partial_cmp(&theor).unwrap_or(cmp::Ordering::Equal)
+        });
+    if is_negative {
+        -float
+    } else {
+        float
+    }
+}
+
+/// Round a value to a half-precision float, and get its status.
+///
+/// The `lower` and `upper` values are the value rounded toward negative
+/// and positive infinity as an `f32`. Every half-precision float, and the
+/// float 1 ULP above it, is exactly representable as an `f32`, so we can
+/// compare the value to them using the nearest `f32` values.
+#[cfg(feature = "f16")]
+fn status_from_f32<F: Float>(lower: f32, upper: f32, rounding: Rounding) -> (F, Status) {
+    let float: F = directed_from_f32(lower, upper, rounding);
+    let is_exact = lower.to_bits() == upper.to_bits();
+    if lower.is_nan() || (is_exact && (lower.is_special() || lower == 0.0)) {
+        // Zero and special values are always exact.
+        return (float, Status::Exact);
+    }
+
+    // Get the magnitudes of the floats below and above the value.
+    let is_negative = lower.is_sign_negative();
+    let (below, above, magnitude) = if is_negative {
+        (-upper, -lower, -float)
+    } else {
+        (lower, upper, float)
+    };
+    let status = shared::float_status(magnitude, |value, is_above| {
+        let theor = if !is_above {
+            value.as_f32()
+        } else if value.next_positive().is_inf() {
+            let lo = value.as_f32();
+            lo + (lo - value.prev_positive().as_f32())
+        } else {
+            value.next_positive().as_f32()
+        };
+        if is_exact {
+            // Both values are finite, so they're always ordered.
+            below.partial_cmp(&theor).unwrap_or(cmp::Ordering::Equal)
+        } else if above.is_inf() {
+            // The value is above the largest `f32`, so treat it as above
+            // any half-precision float, even with an unbounded exponent.
+            cmp::Ordering::Greater
+        } else if below < theor {
+            // The value is between two `f32` values, so the next float
+            // is always at or above the upper one.
+            cmp::Ordering::Less
+        } else {
+            cmp::Ordering::Greater
+        }
+    });
+    (float, status)
+}
+
+#[cfg(feature = "f128")]
+impl ParseFloat for f128 {
+    #[cfg_attr(not(feature = "compact"), inline(always))]
+    fn parse_complete<const FORMAT: u128>(bytes: &[u8], options: &Options) -> Result<Self> {
+        check_radix!(FORMAT);
+        quad::parse_complete::<FORMAT>(bytes, options)
+    }
+
+    #[cfg_attr(not(feature = "compact"), inline(always))]
+    fn parse_partial<const FORMAT: u128>(bytes: &[u8], options: &Options) -> Result<(Self, usize)> {
+        check_radix!(FORMAT);
+        quad::parse_partial::<FORMAT>(bytes, options)
+    }
+
+    #[cfg_attr(not(feature = "compact"), inline(always))]
+    fn parse_complete_status<const FORMAT: u128>(
+        bytes: &[u8],
+        options: &Options,
+    ) -> Result<(Self, Status)> {
+        check_radix!(FORMAT);
+        quad::parse_complete_status::<FORMAT>(bytes, options)
+    }
+
+    #[cfg_attr(not(feature = "compact"), inline(always))]
+    fn parse_partial_status<const FORMAT: u128>(
+        bytes: &[u8],
+        options: &Options,
+    ) -> Result<(Self, Status, usize)> {
+        check_radix!(FORMAT);
+        quad::parse_partial_status::<FORMAT>(bytes, options)
+    }
+
+    /// The quadruple-precision parser has no fast path, so always use the
+    /// correctly-rounded algorithm.
+    #[cfg_attr(not(feature = "compact"), inline(always))]
+    fn fast_path_complete<const FORMAT: u128>(bytes: &[u8], options: &Options) -> Result<Self> {
+        Self::parse_complete::<FORMAT>(bytes, options)
+    }
+
+    #[cfg_attr(not(feature = "compact"), inline(always))]
+    fn fast_path_partial<const FORMAT: u128>(
+        bytes: &[u8],
+        options: &Options,
+    ) -> Result<(Self, usize)> {
+        Self::parse_partial::<FORMAT>(bytes, options)
+    }
+}
+
 // PARSE
 // -----
 
@@ -263,25 +479,7 @@ pub fn parse_complete<F: LemireFloat, const FORMAT: u128>(
     // Parse our a small representation of our number.
     let num: Number<'_> =
         parse_number!(FORMAT, byte, is_negative, options, parse_complete_number, parse_special);
-    // Try the fast-path algorithm.
-    if let Some(value) = num.try_fast_path::<_, FORMAT>() {
-        return Ok(value);
-    }
-    // Now try the moderate path algorithm.
-    let mut fp = moderate_path::<F, FORMAT>(&num, options.lossy());
-
-    // Unable to correctly round the float using the fast or moderate algorithms.
-    // Fallback to a slower, but always correct algorithm. If we have
-    // lossy, we can't be here.
-    if fp.exp < 0 {
-        debug_assert!(!options.lossy(), "lossy algorithms never use slow algorithms");
-        // Undo the invalid extended float biasing.
-        fp.exp -= shared::INVALID_FP;
-        fp = slow_path::<F, FORMAT>(num, fp);
-    }
-
-    // Convert to native float and return result.
-    Ok(to_native!(F, fp, is_negative))
+    Ok(number_to_float::<F, FORMAT>(num, options))
 }
 
 /// Parse a float using only the fast path as a complete parser.
@@ -337,25 +535,70 @@ pub fn parse_partial<F: LemireFloat, const FORMAT: u128>(
         parse_partial_number,
         parse_partial_special
     );
-    // Try the fast-path algorithm.
-    if let Some(value) = num.try_fast_path::<_, FORMAT>() {
-        return Ok((value, count));
+    Ok((number_to_float::<F, FORMAT>(num, options), count))
+}
+
+/// Parse a float from bytes using a complete parser, and get its status.
+#[inline(always)]
+#[allow(clippy::missing_inline_in_public_items)] // reason = "only public for testing"
+pub fn parse_complete_status<F: LemireFloat, const FORMAT: u128>(
+    bytes: &[u8],
+    options: &Options,
+) -> Result<(F, Status)> {
+    let mut byte = bytes.bytes::<{ FORMAT }>();
+    let is_negative = parse_mantissa_sign(&mut byte)?;
+    if byte.integer_iter().is_consumed() {
+        if NumberFormat::<FORMAT>::REQUIRED_INTEGER_DIGITS
+            || NumberFormat::<FORMAT>::REQUIRED_MANTISSA_DIGITS
+        {
+            return Err(Error::Empty(byte.cursor()));
+        } else {
+            return Ok((F::ZERO, Status::Exact));
+        }
     }
-    // Now try the moderate path algorithm.
-    let mut fp = moderate_path::<F, FORMAT>(&num, options.lossy());
 
-    // Unable to correctly round the float using the fast or moderate algorithms.
-    // Fallback to a slower, but always correct algorithm. If we have
-    // lossy, we can't be here.
-    if fp.exp < 0 {
-        debug_assert!(!options.lossy(), "lossy algorithms never use slow algorithms");
-        // Undo the invalid extended float biasing.
-        fp.exp -= shared::INVALID_FP;
-        fp = slow_path::<F, FORMAT>(num, fp);
+    // Special values are always exact.
+    let num = match parse_complete_number::<FORMAT>(byte.clone(), is_negative, options) {
+        Ok(num) => num,
+        Err(error) => {
+            let float = parse_special::<F, FORMAT>(byte, is_negative, options).ok_or(error)?;
+            return Ok((float, Status::Exact));
+        },
+    };
+    let float = number_to_float::<F, FORMAT>(num, options);
+    Ok((float, number_status::<F, FORMAT>(num, float)))
+}
+
+/// Parse a float from bytes using a partial parser, and get its status.
+#[inline(always)]
+#[allow(clippy::missing_inline_in_public_items)] // reason = "only public for testing"
+pub fn parse_partial_status<F: LemireFloat, const FORMAT: u128>(
+    bytes: &[u8],
+    options: &Options,
+) -> Result<(F, Status, usize)> {
+    let mut byte = bytes.bytes::<{ FORMAT }>();
+    let is_negative = parse_mantissa_sign(&mut byte)?;
+    if byte.integer_iter().is_consumed() {
+        if NumberFormat::<FORMAT>::REQUIRED_INTEGER_DIGITS
+            || NumberFormat::<FORMAT>::REQUIRED_MANTISSA_DIGITS
+        {
+            return Err(Error::Empty(byte.cursor()));
+        } else {
+            return Ok((F::ZERO, Status::Exact, byte.cursor()));
+        }
     }
 
-    // Convert to native float and return result.
-    Ok((to_native!(F, fp, is_negative), count))
+    // Special values are always exact.
+    let (num, count) = match parse_partial_number::<FORMAT>(byte.clone(), is_negative, options) {
+        Ok(result) => result,
+        Err(error) => {
+            let (float, count) =
+                parse_partial_special::<F, FORMAT>(byte, is_negative, options).ok_or(error)?;
+            return Ok((float, Status::Exact, count));
+        },
+    };
+    let float = number_to_float::<F, FORMAT>(num, options);
+    Ok((float, number_status::<F, FORMAT>(num, float), count))
 }
 
 /// Parse a float using only the fast path as a partial parser.
@@ -479,6 +722,163 @@ pub fn slow_path<F: LemireFloat, const FORMAT: u128>(
     }
 }
 
+/// Round the parsed number to a native float.
+///
+/// This uses the rounding mode from the options, and the fast, moderate,
+/// and slow algorithms as required.
+#[must_use]
+#[cfg_attr(not(feature = "compact"), inline(always))]
+pub fn number_to_float<F: LemireFloat, const FORMAT: u128>(num: Number, options: &Options) -> F {
+    // Other rounding modes need to know the exact digits.
+    if options.rounding() != Rounding::NearestTieEven {
+        return directed_path::<F, FORMAT>(num, options.rounding());
+    }
+    // Try the fast-path algorithm.
+    if let Some(value) = num.try_fast_path::<_, FORMAT>() {
+        return value;
+    }
+    // Now try the moderate path algorithm.
+    let mut fp = moderate_path::<F, FORMAT>(&num, options.lossy());
+
+    // Unable to correctly round the float using the fast or moderate algorithms.
+    // Fallback to a slower, but always correct algorithm. If we have
+    // lossy, we can't be here.
+    if fp.exp < 0 {
+        debug_assert!(!options.lossy(), "lossy algorithms never use slow algorithms");
+        // Undo the invalid extended float biasing.
+        fp.exp -= shared::INVALID_FP;
+        fp = slow_path::<F, FORMAT>(num, fp);
+    }
+
+    // Convert to native float and return result.
+    let is_negative = num.is_negative;
+    to_native!(F, fp, is_negative)
+}
+
+/// Round the float using any rounding mode other than nearest, tie-even.
+///
+/// This first rounds to nearest, tie-even using the fast, moderate, and
+/// slow algorithms, and then compares the real digits to that float to
+/// find the correct result.
+#[must_use]
+#[inline(always)]
+pub fn directed_path<F: LemireFloat, const FORMAT: u128>(num: Number, rounding: Rounding) -> F {
+    // A zero value is always exact.
+    let mut float = if num.mantissa == 0 {
+        F::ZERO
+    } else {
+        let nearest = if let Some(value) = num.try_fast_path::<F, FORMAT>() {
+            if num.is_negative {
+                -value
+            } else {
+                value
+            }
+        } else {
+            let mut fp = moderate_path::<F, FORMAT>(&num, false);
+            if fp.exp < 0 {
+                // Undo the invalid extended float biasing.
+                fp.exp -= shared::INVALID_FP;
+                fp = slow_path::<F, FORMAT>(num, fp);
+            }
+            extended_to_float::<F>(fp)
+        };
+        shared::round_directed(nearest, rounding, num.is_negative, |value, is_halfway| {
+            let theor = if is_halfway {
+                bh(value)
+            } else {
+                b(value)
+            };
+            compare_path::<F, FORMAT>(num, theor)
+        })
+    };
+    if num.is_negative {
+        float = -float;
+    }
+    float
+}
+
+/// Compare the real digits to the exact value of an extended float.
+#[must_use]
+#[inline(always)]
+pub fn compare_path<F: LemireFloat, const FORMAT: u128>(
+    num: Number,
+    theor: ExtendedFloat80,
+) -> core::cmp::Ordering {
+    // Numbers far outside the float range cannot be scaled to big integers,
+    // so check the bounds of the binary exponents first.
+    if let Some(order) = compare_bounds::<FORMAT>(&num, &theor) {
+        return order;
+    }
+
+    #[cfg(not(feature = "power-of-two"))]
+    {
+        compare_float::<F, FORMAT>(num, theor)
+    }
+
+    #[cfg(feature = "power-of-two")]
+    {
+        let format = NumberFormat::<{ FORMAT }> {};
+        if is_power_two!(format.mantissa_radix()) {
+            compare_binary::<FORMAT>(&num, theor)
+        } else {
+            compare_float::<F, FORMAT>(num, theor)
+        }
+    }
+}
+
+/// Compare the real digits to an extended float from their binary exponents.
+///
+/// The real value is in `[mantissa, mantissa + 1) * base^exponent`, and the
+/// binary exponents are scaled by 12 to get precise bounds for `log2(base)`.
+/// Returns `None` if the exponents are too close to order the values.
+#[must_use]
+#[inline(always)]
+fn compare_bounds<const FORMAT: u128>(
+    num: &Number,
+    theor: &ExtendedFloat80,
+) -> Option<core::cmp::Ordering> {
+    let format = NumberFormat::<{ FORMAT }> {};
+    // `36^12 < 2^64`, so this cannot overflow.
+    let base_log2 = 63 - (format.exponent_base() as u64).pow(12).leading_zeros() as i64;
+    let exponent = num.exponent;
+    let (base_lower, base_upper) = if exponent >= 0 {
+        (base_log2.saturating_mul(exponent), (base_log2 + 1).saturating_mul(exponent))
+    } else {
+        ((base_log2 + 1).saturating_mul(exponent), base_log2.saturating_mul(exponent))
+    };
+    let bit_length = 64 - num.mantissa.leading_zeros() as i64;
+    let theor_length = 64 - theor.mant.leading_zeros() as i64 + theor.exp as i64;
+    if base_lower.saturating_add(12 * (bit_length - 1)) >= 12 * theor_length {
+        Some(core::cmp::Ordering::Greater)
+    } else if base_upper.saturating_add(12 * bit_length) <= 12 * (theor_length - 1) {
+        Some(core::cmp::Ordering::Less)
+    } else {
+        None
+    }
+}
+
+/// Get the status of a float rounded from the parsed number.
+#[must_use]
+#[inline(always)]
+pub fn number_status<F: LemireFloat, const FORMAT: u128>(num: Number, float: F) -> Status {
+    // A zero value is always exact.
+    if num.mantissa == 0 {
+        return Status::Exact;
+    }
+    let magnitude = if float.is_sign_negative() {
+        -float
+    } else {
+        float
+    };
+    shared::float_status(magnitude, |value, is_above| {
+        let mut theor = b(value);
+        if is_above {
+            theor.mant += 1;
+        }
+        compare_path::<F, FORMAT>(num, theor)
+    })
+}
+
 // NUMBER
 // ------
 
@@ -612,7 +1012,7 @@ pub fn parse_number<'a, const FORMAT: u128, const IS_PARTIAL: bool>(
     let mut implicit_exponent: i64;
     let int_end = n_digits as i64;
     let mut fraction_digits = None;
-    let has_decimal = byte.first_is_cased(decimal_point);
+    let has_decimal = !options.integer_syntax() && byte.first_is_cased(decimal_point);
     if has_decimal {
         // SAFETY: byte cannot be empty due to `first_is`
         unsafe { byte.step_unchecked() };
@@ -657,8 +1057,11 @@ pub fn parse_number<'a, const FORMAT: u128, const IS_PARTIAL: bool>(
 
     // NOTE: Check if we have our exponent **BEFORE** checking if the
     // mantissa is empty, so we can ensure
-    let has_exponent = byte
-        .first_is(exponent_character, format.case_sensitive_exponent() && cfg!(feature = "format"));
+    let has_exponent = !options.integer_syntax()
+        && byte.first_is(
+            exponent_character,
+            format.case_sensitive_exponent() && cfg!(feature = "format"),
+        );
 
     // check to see if we have any invalid leading zeros
     n_digits += n_after_dot;
@@ -987,7 +1390,7 @@ where
     F: LemireFloat,
 {
     let format = NumberFormat::<{ FORMAT }> {};
-    if cfg!(feature = "format") && format.no_special() {
+    if (cfg!(feature = "format") && format.no_special()) || options.integer_syntax() {
         return None;
     }
 
@@ -0,0 +1,253 @@
+//! Decompose a number into its sign, significant digits and exponent.
+//!
+//! This uses the same format-aware tokenizer as the float parsers, but
+//! never rounds the result to a native float, so the significant digits
+//! can be consumed by arbitrary-precision decimal types.
+
+use lexical_util::digit::{char_is_digit_const, char_to_valid_digit_const};
+use lexical_util::error::Error;
+use lexical_util::format::{is_valid_options_punctuation, NumberFormat};
+use lexical_util::iterator::{AsBytes, DigitsIter, Iter};
+use lexical_util::result::Result;
+
+use crate::number::Number;
+use crate::options::Options;
+use crate::parse::{parse_complete_number, parse_mantissa_sign, parse_partial_number};
+use crate::slow::scientific_exponent;
+
+/// The sign, significant digits and exponent of a parsed number.
+///
+/// The value is exactly `digits * radix^exponent`, where `radix` is the
+/// mantissa radix of the number format (`10` for decimal strings). The
+/// significant digits have leading zeros removed, but any trailing zeros
+/// are kept so the scale of the input is preserved: `1.50` has the digits
+/// `150` and an exponent of `-2`.
+///
+/// The digits are borrowed from the input, so any digit separators are
+/// skipped when iterating over them.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct DecimalParts<'a> {
+    /// If the number is negative.
+    is_negative: bool,
+    /// The raw integer digits, which may contain digit separators.
+    integer: &'a [u8],
+    /// The raw fraction digits, which may contain digit separators.
+    fraction: &'a [u8],
+    /// The number of significant digits.
+    digit_count: usize,
+    /// The exponent relative to the significant digits.
+    exponent: i64,
+    /// The radix of the significant digits.
+    radix: u32,
+}
+
+impl<'a> DecimalParts<'a> {
+    /// Get if the number is negative.
+    #[must_use]
+    #[inline(always)]
+    pub const fn is_negative(&self) -> bool {
+        self.is_negative
+    }
+
+    /// Get if the number is zero.
+    #[must_use]
+    #[inline(always)]
+    pub const fn is_zero(&self) -> bool {
+        self.digit_count == 0
+    }
+
+    /// Get the number of significant digits.
+    #[must_use]
+    #[inline(always)]
+    pub const fn digit_count(&self) -> usize {
+        self.digit_count
+    }
+
+    /// Get the exponent relative to the significant digits.
+    ///
+    /// Like when parsing a float, very large explicit exponents are clamped,
+    /// so this is only exact for exponents that could be meaningful.
+    #[must_use]
+    #[inline(always)]
+    pub const fn exponent(&self) -> i64 {
+        self.exponent
+    }
+
+    /// Get the exponent when the number is normalized to scientific
+    /// notation, that is, with a single integer digit.
+    ///
+    /// For zero, this is the same as [`exponent`][Self::exponent].
+    #[must_use]
+    #[inline(always)]
+    pub const fn scientific_exponent(&self) -> i64 {
+        if self.digit_count == 0 {
+            self.exponent
+        } else {
+            self.exponent + self.digit_count as i64 - 1
+        }
+    }
+
+    /// Iterate over the significant digits, as characters.
+    ///
+    /// This does not yield any leading zeros or digit separators.
+    #[inline(always)]
+    pub fn digits(&self) -> impl Iterator<Item = u8> + 'a {
+        let radix = self.radix;
+        let count = self.digit_count;
+        self.integer
+            .iter()
+            .chain(self.fraction.iter())
+            .copied()
+            .filter(move |&c| char_is_digit_const(c, radix))
+            .skip_while(|&c| c == b'0')
+            .take(count)
+    }
+
+    /// Get the significant digits as an integer, if they fit in a `u128`.
+    #[must_use]
+    #[inline]
+    pub fn mantissa(&self) -> Option<u128> {
+        let radix = self.radix as u128;
+        self.digits().try_fold(0u128, |value, c| {
+            let digit = char_to_valid_digit_const(c, self.radix) as u128;
+            value.checked_mul(radix)?.checked_add(digit)
+        })
+    }
+}
+
+/// Validate the number format and options for decomposing a number.
+#[inline(always)]
+fn check_format<const FORMAT: u128>(options: &Options) -> Result<()> {
+    let format = NumberFormat::<{ FORMAT }> {};
+    if !format.is_valid() {
+        Err(format.error())
+    } else if !is_valid_options_punctuation(FORMAT, options.exponent(), options.decimal_point()) {
+        Err(Error::InvalidPunctuation)
+    } else if format.mantissa_radix() != format.exponent_base() {
+        // The exponent must be able to scale the significant digits.
+        Err(Error::InvalidRadix)
+    } else {
+        Ok(())
+    }
+}
+
+/// Create the parts from a validated number.
+#[inline(always)]
+fn to_parts<'a, const FORMAT: u128>(num: Number<'a>) -> DecimalParts<'a> {
+    let format = NumberFormat::<{ FORMAT }> {};
+    let radix = format.mantissa_radix();
+    let fraction = num.fraction.unwrap_or(&[]);
+    let digit_count = num
+        .integer
+        .iter()
+        .chain(fraction.iter())
+        .filter(|&&c| char_is_digit_const(c, radix))
+        .skip_while(|&&c| c == b'0')
+        .count();
+
+    // If we didn't truncate our digits, the exponent is already relative
+    // to every significant digit. Otherwise, the mantissa only has the
+    // leading digits, but the scientific exponent is the same.
+    let exponent = if num.many_digits {
+        scientific_exponent::<FORMAT>(&num) as i64 + 1 - digit_count as i64
+    } else {
+        num.exponent
+    };
+
+    DecimalParts {
+        is_negative: num.is_negative,
+        integer: num.integer,
+        fraction,
+        digit_count,
+        exponent,
+        radix,
+    }
+}
+
+/// Decompose a complete number into its sign, significant digits and
+/// exponent, without rounding to a native float.
+///
+/// The input is validated against the number format exactly like when
+/// parsing a float, so this returns the same errors, except that special
+/// values such as `NaN` or `inf` are rejected since they have no digits.
+///
+/// # Examples
+///
+/// ```rust
+/// use lexical_parse_float::{format, parse_decimal_parts, Options};
+///
+/// const OPTIONS: Options = Options::new();
+/// let parts = parse_decimal_parts::<{ format::STANDARD }>(b"-001.50e3", &OPTIONS).unwrap();
+/// assert!(parts.is_negative());
+/// assert_eq!(parts.digits().collect::<Vec<u8>>(), b"150");
+/// assert_eq!(parts.exponent(), 1);
+/// assert_eq!(parts.mantissa(), Some(150));
+/// ```
+#[inline]
+pub fn parse_decimal_parts<'a, const FORMAT: u128>(
+    bytes: &'a [u8],
+    options: &Options,
+) -> Result<DecimalParts<'a>> {
+    check_format::<FORMAT>(options)?;
+    let mut byte = bytes.bytes::<{ FORMAT }>();
+    let is_negative = parse_mantissa_sign(&mut byte)?;
+    if byte.integer_iter().is_consumed() {
+        if NumberFormat::<FORMAT>::REQUIRED_INTEGER_DIGITS
+            || NumberFormat::<FORMAT>::REQUIRED_MANTISSA_DIGITS
+        {
+            return Err(Error::Empty(byte.cursor()));
+        } else {
+            return Ok(to_parts::<FORMAT>(Number {
+                is_negative,
+                ..Number::default()
+            }));
+        }
+    }
+
+    let num = parse_complete_number(byte, is_negative, options)?;
+    Ok(to_parts::<FORMAT>(num))
+}
+
+/// Decompose a number into its sign, significant digits and exponent,
+/// stopping at the first invalid character.
+///
+/// Returns the parts and the number of processed bytes. See
+/// [`parse_decimal_parts`] for more details.
+///
+/// # Examples
+///
+/// ```rust
+/// use lexical_parse_float::{format, parse_partial_decimal_parts, Options};
+///
+/// const OPTIONS: Options = Options::new();
+/// let (parts, count) =
+///     parse_partial_decimal_parts::<{ format::STANDARD }>(b"12.5,", &OPTIONS).unwrap();
+/// assert_eq!(parts.digits().collect::<Vec<u8>>(), b"125");
+/// assert_eq!(parts.exponent(), -1);
+/// assert_eq!(count, 4);
+/// ```
+#[inline]
+pub fn parse_partial_decimal_parts<'a, const FORMAT: u128>(
+    bytes: &'a [u8],
+    options: &Options,
+) -> Result<(DecimalParts<'a>, usize)> {
+    check_format::<FORMAT>(options)?;
+    let mut byte = bytes.bytes::<{ FORMAT }>();
+    let is_negative = parse_mantissa_sign(&mut byte)?;
+    if byte.integer_iter().is_consumed() {
+        if NumberFormat::<FORMAT>::REQUIRED_INTEGER_DIGITS
+            || NumberFormat::<FORMAT>::REQUIRED_MANTISSA_DIGITS
+        {
+            return Err(Error::Empty(byte.cursor()));
+        } else {
+            let num = Number {
+                is_negative,
+                ..Number::default()
+            };
+            return Ok((to_parts::<FORMAT>(num), byte.cursor()));
+        }
+    }
+
+    let (num, count) = parse_partial_number(byte, is_negative, options)?;
+    Ok((to_parts::<FORMAT>(num), count))
+}
@@ -0,0 +1,754 @@
+//! Correctly-rounded parsing for quadruple-precision floats.
+//!
+//! An [`f128`] has too much precision for the extended-float algorithms,
+//! which use a 64-bit mantissa, so every number is parsed with an
+//! arbitrary-precision algorithm instead. The significant digits are
+//! parsed into a large big integer, which is then scaled exactly by the
+//! exponent and rounded to the nearest float.
+//!
+//! For radixes that can exactly represent every binary float, we only
+//! need to keep as many digits as the longest halfway point between two
+//! floats, and mark any truncated, non-zero digits below it. For odd
+//! radixes, we keep a fixed number of digits and, if the truncated digits
+//! could change the rounding, compare the remaining digits to the halfway
+//! point between the two candidate floats.
+
+#![cfg(feature = "f128")]
+#![doc(hidden)]
+
+use core::cmp;
+
+use lexical_util::digit::char_to_valid_digit_const;
+use lexical_util::error::Error;
+use lexical_util::f128::f128;
+use lexical_util::format::NumberFormat;
+use lexical_util::iterator::{AsBytes, DigitsIter, Iter};
+use lexical_util::num::Float;
+use lexical_util::result::Result;
+use lexical_util::step::u64_step;
+
+#[cfg(feature = "radix")]
+use crate::bigint::large_add;
+use crate::bigint::{
+    bit_length,
+    compare,
+    large_sub,
+    pow,
+    shl,
+    small_add,
+    small_mul,
+    split_radix,
+    Limb,
+    StackVec,
+};
+use crate::limits::{u32_power_limit, u64_power_limit, MaxDigits};
+use crate::number::Number;
+use crate::options::{Options, Rounding};
+use crate::parse::{
+    parse_complete_number,
+    parse_mantissa_sign,
+    parse_partial_number,
+    parse_partial_special,
+    parse_special,
+};
+use crate::shared;
+use crate::status::Status;
+
+/// ≅ 69000 for base-36, rounded-up.
+///
+/// This is the largest of the significant digits, which can have up to
+/// 13328 digits, and the power of the odd radix required to scale the
+/// smallest denormal float.
+#[cfg(feature = "radix")]
+const QUAD_BITS: usize = 72_000;
+
+/// ≅ 38500 for base-10, rounded-up.
+#[cfg(not(feature = "radix"))]
+const QUAD_BITS: usize = 44_000;
+
+/// The number of limbs for the bigint.
+const QUAD_LIMBS: usize = QUAD_BITS / Limb::BITS as usize;
+
+/// Storage for the big integers used to round quadruple-precision floats.
+type Bigint = StackVec<QUAD_LIMBS>;
+
+/// Number of bits kept when rounding the scaled significant digits.
+///
+/// This is 3 bits more than the precision of the float, so any truncated
+/// bits are always below the halfway point.
+const QUAD_PRECISION: u32 = 116;
+
+/// Maximum number of significant digits to parse for an odd radix.
+///
+/// These radixes have infinite representations for halfway points, so
+/// any larger inputs are disambiguated by a digit comparison.
+#[cfg(feature = "radix")]
+const ODD_MAX_DIGITS: usize = 1024;
+
+/// The largest binary exponent, in bits, which always rounds to infinity.
+const INFINITE_POWER: i64 = 16384;
+
+/// The smallest binary exponent, in bits, which always rounds to zero.
+const ZERO_POWER: i64 = -16495;
+
+// ENTRY
+// -----
+
+/// Parse a quadruple-precision float from bytes using a complete parser.
+#[inline]
+#[allow(clippy::missing_inline_in_public_items)] // reason = "only public for testing"
+pub fn parse_complete<const FORMAT: u128>(bytes: &[u8], options: &Options) -> Result<f128> {
+    let mut byte = bytes.bytes::<{ FORMAT }>();
+    let is_negative = parse_mantissa_sign(&mut byte)?;
+    if byte.integer_iter().is_consumed() {
+        if NumberFormat::<FORMAT>::REQUIRED_INTEGER_DIGITS
+            || NumberFormat::<FORMAT>::REQUIRED_MANTISSA_DIGITS
+        {
+            return Err(Error::Empty(byte.cursor()));
+        } else {
+            return Ok(f128::ZERO);
+        }
+    }
+
+    match parse_complete_number::<FORMAT>(byte.clone(), is_negative, options) {
+        Ok(num) => Ok(slow_quad::<FORMAT>(&num, options.rounding())),
+        Err(error) => parse_special::<_, FORMAT>(byte, is_negative, options).ok_or(error),
+    }
+}
+
+/// Parse a quadruple-precision float from bytes using a partial parser.
+#[inline]
+#[allow(clippy::missing_inline_in_public_items)] // reason = "only public for testing"
+pub fn parse_partial<const FORMAT: u128>(bytes: &[u8], options: &Options) -> Result<(f128, usize)> {
+    let mut byte = bytes.bytes::<{ FORMAT }>();
+    let is_negative = parse_mantissa_sign(&mut byte)?;
+    if byte.integer_iter().is_consumed() {
+        if NumberFormat::<FORMAT>::REQUIRED_INTEGER_DIGITS
+            || NumberFormat::<FORMAT>::REQUIRED_MANTISSA_DIGITS
+        {
+            return Err(Error::Empty(byte.cursor()));
+        } else {
+            return Ok((f128::ZERO, byte.cursor()));
+        }
+    }
+
+    match parse_partial_number::<FORMAT>(byte.clone(), is_negative, options) {
+        Ok((num, count)) => Ok((slow_quad::<FORMAT>(&num, options.rounding()), count)),
+        Err(error) => parse_partial_special::<_, FORMAT>(byte, is_negative, options).ok_or(error),
+    }
+}
+
+/// Parse a quadruple-precision float from bytes using a complete parser,
+/// and get its status.
+#[inline]
+#[allow(clippy::missing_inline_in_public_items)] // reason = "only public for testing"
+pub fn parse_complete_status<const FORMAT: u128>(
+    bytes: &[u8],
+    options: &Options,
+) -> Result<(f128, Status)> {
+    let mut byte = bytes.bytes::<{ FORMAT }>();
+    let is_negative = parse_mantissa_sign(&mut byte)?;
+    if byte.integer_iter().is_consumed() {
+        if NumberFormat::<FORMAT>::REQUIRED_INTEGER_DIGITS
+            || NumberFormat::<FORMAT>::REQUIRED_MANTISSA_DIGITS
+        {
+            return Err(Error::Empty(byte.cursor()));
+        } else {
+            return Ok((f128::ZERO, Status::Exact));
+        }
+    }
+
+    match parse_complete_number::<FORMAT>(byte.clone(), is_negative, options) {
+        Ok(num) => Ok(slow_quad_status::<FORMAT>(&num, options.rounding())),
+        Err(error) => parse_special::<_, FORMAT>(byte, is_negative, options)
+            .map(|float| (float, Status::Exact))
+            .ok_or(error),
+    }
+}
+
+/// Parse a quadruple-precision float from bytes using a partial parser,
+/// and get its status.
+#[inline]
+#[allow(clippy::missing_inline_in_public_items)] // reason = "only public for testing"
+pub fn parse_partial_status<const FORMAT: u128>(
+    bytes: &[u8],
+    options: &Options,
+) -> Result<(f128, Status, usize)> {
+    let mut byte = bytes.bytes::<{ FORMAT }>();
+    let is_negative = parse_mantissa_sign(&mut byte)?;
+    if byte.integer_iter().is_consumed() {
+        if NumberFormat::<FORMAT>::REQUIRED_INTEGER_DIGITS
+            || NumberFormat::<FORMAT>::REQUIRED_MANTISSA_DIGITS
+        {
+            return Err(Error::Empty(byte.cursor()));
+        } else {
+            return Ok((f128::ZERO, Status::Exact, byte.cursor()));
+        }
+    }
+
+    match parse_partial_number::<FORMAT>(byte.clone(), is_negative, options) {
+        Ok((num, count)) => {
+            let (float, status) = slow_quad_status::<FORMAT>(&num, options.rounding());
+            Ok((float, status, count))
+        },
+        Err(error) => parse_partial_special::<_, FORMAT>(byte, is_negative, options)
+            .map(|(float, count)| (float, Status::Exact, count))
+            .ok_or(error),
+    }
+}
+
+// ALGORITHM
+// ---------
+
+/// Create a correctly-rounded float from the parsed number.
+#[must_use]
+#[inline]
+#[allow(clippy::missing_inline_in_public_items)] // reason = "only public for testing"
+pub fn slow_quad<const FORMAT: u128>(num: &Number, rounding: Rounding) -> f128 {
+    let digits = parse_digits::<FORMAT>(num);
+    let float = round_digits::<FORMAT>(num, &digits, rounding);
+    if num.is_negative {
+        -float
+    } else {
+        float
+    }
+}
+
+/// Create a correctly-rounded float from the parsed number, and get its
+/// status.
+#[must_use]
+#[inline]
+#[allow(clippy::missing_inline_in_public_items)] // reason = "only public for testing"
+pub fn slow_quad_status<const FORMAT: u128>(num: &Number, rounding: Rounding) -> (f128, Status) {
+    let digits = parse_digits::<FORMAT>(num);
+    let float = round_digits::<FORMAT>(num, &digits, rounding);
+    let status = if digits.mantissa.is_empty() {
+        // A zero value is always exact.
+        Status::Exact
+    } else {
+        shared::float_status(float, |value, is_above| {
+            let (mut tm, te) = float_parts(value, false);
+            if is_above {
+                tm += 1;
+            }
+            compare_float::<FORMAT>(num, &digits, (tm, te))
+        })
+    };
+    if num.is_negative {
+        (-float, status)
+    } else {
+        (float, status)
+    }
+}
+
+/// The leading significant digits of a number, parsed into a big integer.
+struct Digits {
+    /// The first `count` significant digits.
+    mantissa: Bigint,
+    /// The number of parsed significant digits.
+    #[cfg_attr(not(feature = "radix"), allow(dead_code))] // reason = "used for odd radixes"
+    count: usize,
+    /// If any truncated digits were non-zero.
+    is_truncated: bool,
+    /// The digits are scaled by `odd^exponent * 2^(bits * exponent)`.
+    odd: u32,
+    /// The binary bits of the radix, see `odd`.
+    bits: u32,
+    /// The exponent of the last parsed digit, see `odd`.
+    exponent: i64,
+}
+
+/// Parse the leading significant digits and the exponent of the number.
+#[inline]
+fn parse_digits<const FORMAT: u128>(num: &Number) -> Digits {
+    let format = NumberFormat::<FORMAT> {};
+    let radix = format.mantissa_radix();
+    let max_digits = max_digits(radix);
+    let (mantissa, count, is_truncated) = parse_mantissa::<FORMAT>(num, max_digits);
+
+    // Get the exponent of the last parsed digit: if we have many digits,
+    // the exponent is relative to the first `u64_step` significant digits.
+    let shift = if num.many_digits {
+        u64_step(radix) as i64 - count as i64
+    } else {
+        0
+    };
+    let (odd, bits, exponent) = if radix == format.exponent_base() {
+        let (odd, bits) = split_radix(radix);
+        (odd, bits, num.exponent + shift)
+    } else {
+        // Both radixes are powers-of-two, so use a binary exponent.
+        let bits_per_digit = radix.trailing_zeros() as i64;
+        let bits_per_base = format.exponent_base().trailing_zeros() as i64;
+        (0, 1, num.exponent * bits_per_base + shift * bits_per_digit)
+    };
+
+    Digits {
+        mantissa,
+        count,
+        is_truncated,
+        odd,
+        bits,
+        exponent,
+    }
+}
+
+/// Round the parsed digits to the magnitude of a float.
+#[inline]
+fn round_digits<const FORMAT: u128>(num: &Number, digits: &Digits, rounding: Rounding) -> f128 {
+    let mantissa = &digits.mantissa;
+    let float = to_float(mantissa, digits.odd, digits.bits, digits.exponent, digits.is_truncated);
+
+    // With an odd radix, the truncated digits might be above a halfway point.
+    #[cfg(feature = "radix")]
+    let float = if digits.is_truncated && NumberFormat::<FORMAT>::MANTISSA_RADIX % 2 == 1 {
+        round_odd::<FORMAT>(num, float, mantissa, digits.count, digits.exponent)
+    } else {
+        float
+    };
+
+    // Other rounding modes need to compare the exact digits to the float.
+    if rounding != Rounding::NearestTieEven && !mantissa.is_empty() {
+        shared::round_directed(float, rounding, num.is_negative, |value, is_halfway| {
+            compare_float::<FORMAT>(num, digits, float_parts(value, is_halfway))
+        })
+    } else {
+        float
+    }
+}
+
+/// Get the maximum number of significant digits to parse.
+#[must_use]
+#[inline(always)]
+fn max_digits(radix: u32) -> usize {
+    if radix.is_power_of_two() {
+        // Keep enough bits to be above the halfway point.
+        2 + f128::BITS / radix.trailing_zeros() as usize
+    } else if let Some(digits) = f128::max_digits(radix) {
+        digits
+    } else {
+        #[cfg(feature = "radix")]
+        return ODD_MAX_DIGITS;
+
+        #[cfg(not(feature = "radix"))]
+        unreachable!()
+    }
+}
+
+/// Multiply the big integer by a small value and add a small value.
+#[inline(always)]
+#[allow(clippy::unwrap_used)] // reason="the bigint is sized for the maximum number of digits"
+fn small_mul_add(x: &mut Bigint, y: Limb, z: Limb) {
+    small_mul(x, y).unwrap();
+    small_add(x, z).unwrap();
+}
+
+/// Iterate over the significant digits, until the callback returns `false`.
+#[inline]
+fn for_each_digit<const FORMAT: u128>(num: &Number, mut cb: impl FnMut(u32) -> bool) {
+    let radix = NumberFormat::<FORMAT>::MANTISSA_RADIX;
+    let mut is_significant = false;
+    let mut integer = num.integer.bytes::<FORMAT>();
+    let mut integer_iter = integer.integer_iter();
+    integer_iter.skip_zeros();
+    for &c in integer_iter {
+        is_significant = true;
+        if !cb(char_to_valid_digit_const(c, radix)) {
+            return;
+        }
+    }
+
+    if let Some(fraction) = num.fraction {
+        let mut fraction = fraction.bytes::<FORMAT>();
+        let mut fraction_iter = fraction.fraction_iter();
+        if !is_significant {
+            fraction_iter.skip_zeros();
+        }
+        for &c in fraction_iter {
+            if !cb(char_to_valid_digit_const(c, radix)) {
+                return;
+            }
+        }
+    }
+}
+
+/// Parse up to `max_digits` significant digits into a big integer.
+///
+/// Returns the big integer, the number of parsed digits, and if any
+/// truncated digits were non-zero.
+#[inline]
+fn parse_mantissa<const FORMAT: u128>(num: &Number, max_digits: usize) -> (Bigint, usize, bool) {
+    let radix = NumberFormat::<FORMAT>::MANTISSA_RADIX;
+    let step = if Limb::BITS == 32 {
+        u32_power_limit(radix)
+    } else {
+        u64_power_limit(radix)
+    } as usize;
+    let max_native = (radix as Limb).pow(step as u32);
+
+    let mut result = Bigint::new();
+    let mut value: Limb = 0;
+    let mut counter: usize = 0;
+    let mut count: usize = 0;
+    let mut is_truncated = false;
+    for_each_digit::<FORMAT>(num, |digit| {
+        if count == max_digits {
+            is_truncated = digit != 0;
+            return !is_truncated;
+        }
+        value = value * radix as Limb + digit as Limb;
+        counter += 1;
+        count += 1;
+        if counter == step {
+            small_mul_add(&mut result, max_native, value);
+            value = 0;
+            counter = 0;
+        }
+        true
+    });
+    if counter != 0 {
+        small_mul_add(&mut result, (radix as Limb).pow(counter as u32), value);
+    }
+
+    (result, count, is_truncated)
+}
+
+/// Get the bounds of `log2(odd)`, scaled by 12.
+#[inline(always)]
+const fn log2_bounds(odd: u32) -> (i64, i64) {
+    if odd == 0 {
+        (0, 0)
+    } else {
+        // `35^12 < 2^64`, so this cannot overflow.
+        let log2 = 63 - (odd as u64).pow(12).leading_zeros() as i64;
+        (log2, log2 + 1)
+    }
+}
+
+/// Round `mantissa * odd^exponent * 2^(bits * exponent)` to the nearest float.
+///
+/// An `odd` of `0` means the radix is a power of two. `is_truncated` marks
+/// non-zero digits that were truncated after the mantissa.
+#[inline]
+#[allow(clippy::unwrap_used)] // reason="the bigint is sized for the maximum number of digits"
+fn to_float(mantissa: &Bigint, odd: u32, bits: u32, exponent: i64, is_truncated: bool) -> f128 {
+    if mantissa.is_empty() {
+        return f128::ZERO;
+    }
+
+    // Find the bounds of the binary exponent, scaled by 12 to get more
+    // precise bounds for the odd power, and check for overflow or underflow.
+    let binary_exponent = bits as i64 * exponent;
+    let (lower, upper) = log2_bounds(odd);
+    let (odd_lower, odd_upper) = if exponent >= 0 {
+        (lower * exponent, upper * exponent)
+    } else {
+        (upper * exponent, lower * exponent)
+    };
+    let bit_length = bit_length(mantissa) as i64;
+    let min_log2 = 12 * (bit_length - 1 + binary_exponent) + odd_lower;
+    let max_log2 = 12 * (bit_length + binary_exponent) + odd_upper;
+    if min_log2 >= 12 * INFINITE_POWER {
+        return f128::INFINITY;
+    } else if max_log2 <= 12 * ZERO_POWER {
+        return f128::ZERO;
+    }
+
+    // The exponent is now within `±2^16`, so it cannot overflow an `i32`.
+    if odd == 0 || exponent >= 0 {
+        let mut scaled = mantissa.clone();
+        if odd != 0 {
+            pow(&mut scaled, odd, exponent as u32).unwrap();
+        }
+        let (hi, shift, is_inexact) = hi_bits(&scaled);
+        // Keep enough bits so any truncated digits are below the halfway point.
+        let extra = hi.leading_zeros().saturating_sub(128 - QUAD_PRECISION);
+        let exponent = binary_exponent as i32 + shift - extra as i32;
+        f128::from_parts(false, hi << extra, exponent, is_inexact || is_truncated)
+    } else {
+        let mut den = Bigint::from_u64(1);
+        pow(&mut den, odd, exponent.unsigned_abs() as u32).unwrap();
+        let (hi, shift, is_inexact) = divide(mantissa.clone(), den);
+        f128::from_parts(false, hi, binary_exponent as i32 + shift, is_inexact || is_truncated)
+    }
+}
+
+/// Get the high bits of a big integer.
+///
+/// Returns the high bits, the number of truncated bits, and if any of
+/// the truncated bits were non-zero.
+#[inline]
+fn hi_bits(x: &[Limb]) -> (u128, i32, bool) {
+    let limb_bits = Limb::BITS as usize;
+    let shift = (bit_length(x) as usize).saturating_sub(QUAD_PRECISION as usize);
+    let start = shift / limb_bits;
+    let mut hi: u128 = 0;
+    for (index, &limb) in x.iter().enumerate().skip(start) {
+        let position = (index * limb_bits) as isize - shift as isize;
+        if position >= 0 {
+            hi |= (limb as u128) << position;
+        } else {
+            hi |= (limb as u128) >> -position;
+        }
+    }
+    let mask = ((1 as Limb) << (shift % limb_bits)) - 1;
+    let is_truncated = x[..start].iter().any(|&limb| limb != 0) || x[start] & mask != 0;
+    (hi, shift as i32, is_truncated)
+}
+
+/// Get the high bits of the quotient of two big integers.
+///
+/// This uses a simple, restoring division algorithm, since we only need
+/// the first [`QUAD_PRECISION`] bits of the quotient. Returns the high bits,
+/// the binary exponent of the quotient, and if the remainder is non-zero.
+#[inline]
+#[allow(clippy::unwrap_used)] // reason="the bigint is sized for the maximum number of digits"
+fn divide(mut num: Bigint, mut den: Bigint) -> (u128, i32, bool) {
+    // Normalize so `den <= num < 2 * den`.
+    let num_bits = bit_length(&num) as i32;
+    let den_bits = bit_length(&den) as i32;
+    let mut exponent = num_bits - den_bits;
+    if exponent >= 0 {
+        shl(&mut den, exponent as usize).unwrap();
+    } else {
+        shl(&mut num, -exponent as usize).unwrap();
+    }
+    if compare(&num, &den) == cmp::Ordering::Less {
+        shl(&mut num, 1).unwrap();
+        exponent -= 1;
+    }
+
+    let mut hi: u128 = 0;
+    for _ in 0..QUAD_PRECISION {
+        hi <<= 1;
+        if compare(&num, &den) != cmp::Ordering::Less {
+            large_sub(&mut num, &den);
+            hi |= 1;
+        }
+        shl(&mut num, 1).unwrap();
+    }
+
+    (hi, exponent - (QUAD_PRECISION as i32 - 1), !num.is_empty())
+}
+
+// ODD RADIX
+// ---------
+
+/// Create a big integer from a 128-bit value.
+#[inline]
+#[allow(clippy::unwrap_used)] // reason="the bigint is sized for the maximum number of digits"
+fn from_u128(mut value: u128) -> Bigint {
+    let mut result = Bigint::new();
+    while value != 0 {
+        result.try_push(value as Limb).unwrap();
+        value = value.checked_shr(Limb::BITS).unwrap_or(0);
+    }
+    result
+}
+
+/// Correctly round a float from an odd radix with truncated digits.
+///
+/// The `float` was rounded from the first `count` significant digits,
+/// which were parsed into the `mantissa`, with an `exponent` for the last
+/// digit, and marked as truncated. If adding an
+/// extra unit to the last digit rounds to a different float, the
+/// halfway point between the two floats is between the truncated and
+/// the real value, so we need to compare the remaining digits to it.
+#[inline]
+#[cfg(feature = "radix")]
+#[allow(clippy::unwrap_used)] // reason="the bigint is sized for the maximum number of digits"
+fn round_odd<const FORMAT: u128>(
+    num: &Number,
+    float: f128,
+    mantissa: &Bigint,
+    count: usize,
+    exponent: i64,
+) -> f128 {
+    let radix = NumberFormat::<FORMAT>::MANTISSA_RADIX;
+    let mut above = mantissa.clone();
+    small_add(&mut above, 1).unwrap();
+    let next = to_float(&above, radix, 0, exponent, false);
+    if next.to_bits() == float.to_bits() {
+        return float;
+    }
+
+    // Get the difference between the halfway point and the truncated
+    // value, and compare the remaining digits to it.
+    let order =
+        compare_remaining::<FORMAT>(num, mantissa, count, exponent, float_parts(float, true));
+    let is_above = match order {
+        cmp::Ordering::Greater => true,
+        cmp::Ordering::Less => false,
+        cmp::Ordering::Equal => float.is_odd(),
+    };
+    if is_above {
+        f128::from_bits(float.to_bits() + 1)
+    } else {
+        float
+    }
+}
+
+/// Compare the real value to `tm * 2^te`, which is above the truncated value.
+///
+/// The truncated value is the first `count` significant digits, which were
+/// parsed into the `mantissa`, with an `exponent` for the last digit.
+#[inline]
+#[cfg(feature = "radix")]
+#[allow(clippy::unwrap_used)] // reason="the bigint is sized for the maximum number of digits"
+fn compare_remaining<const FORMAT: u128>(
+    num: &Number,
+    mantissa: &Bigint,
+    count: usize,
+    exponent: i64,
+    theor: (u128, i32),
+) -> cmp::Ordering {
+    // Get the difference between the theoretical and the truncated
+    // value, in units of the last digit, as the fraction `P / Q`.
+    let radix = NumberFormat::<FORMAT>::MANTISSA_RADIX;
+    let (tm, te) = theor;
+    let mut diff = from_u128(tm);
+    let mut truncated = mantissa.clone();
+    let mut den = Bigint::from_u64(1);
+    if exponent >= 0 {
+        pow(&mut truncated, radix, exponent as u32).unwrap();
+        pow(&mut den, radix, exponent as u32).unwrap();
+    } else {
+        pow(&mut diff, radix, exponent.unsigned_abs() as u32).unwrap();
+    }
+    if te >= 0 {
+        shl(&mut diff, te as usize).unwrap();
+    } else {
+        shl(&mut truncated, -te as usize).unwrap();
+        shl(&mut den, -te as usize).unwrap();
+    }
+    large_sub(&mut diff, &truncated);
+
+    // Compare the remaining digits to the digits of `P / Q`.
+    compare_digits::<FORMAT>(num, count, diff, &den)
+}
+
+/// Compare the significant digits after the first `count` to the fraction
+/// `num / den`, where `num <= den`.
+#[inline]
+#[cfg(feature = "radix")]
+#[allow(clippy::unwrap_used)] // reason="the bigint is sized for the maximum number of digits"
+fn compare_digits<const FORMAT: u128>(
+    number: &Number,
+    count: usize,
+    mut num: Bigint,
+    den: &Bigint,
+) -> cmp::Ordering {
+    let radix = NumberFormat::<FORMAT>::MANTISSA_RADIX;
+    let mut index = 0;
+    let mut order = None;
+    for_each_digit::<FORMAT>(number, |digit| {
+        index += 1;
+        if index <= count {
+            return true;
+        }
+
+        // Compare the digit to `⌊num * radix / den⌋`.
+        small_mul(&mut num, radix as Limb).unwrap();
+        let mut lower = den.clone();
+        small_mul(&mut lower, digit as Limb).unwrap();
+        lower.normalize();
+        if compare(&num, &lower) == cmp::Ordering::Less {
+            order = Some(cmp::Ordering::Greater);
+            return false;
+        }
+        let mut upper = lower.clone();
+        large_add(&mut upper, den).unwrap();
+        if compare(&num, &upper) != cmp::Ordering::Less {
+            order = Some(cmp::Ordering::Less);
+            return false;
+        }
+        large_sub(&mut num, &lower);
+        true
+    });
+
+    // If we have no more digits, the fraction might be longer.
+    order.unwrap_or_else(|| {
+        if num.is_empty() {
+            cmp::Ordering::Equal
+        } else {
+            cmp::Ordering::Less
+        }
+    })
+}
+
+// DIRECTED ROUNDING
+// -----------------
+
+/// Get the exact value of a float, or the halfway point above it, as `m * 2^e`.
+#[inline(always)]
+fn float_parts(float: f128, is_halfway: bool) -> (u128, i32) {
+    let bits = float.to_bits();
+    let biased = (bits >> f128::MANTISSA_SIZE) as i32;
+    let (mantissa, exponent) = if biased == 0 {
+        (bits, f128::DENORMAL_EXPONENT)
+    } else {
+        let mantissa = (bits & f128::MANTISSA_MASK) | f128::HIDDEN_BIT_MASK;
+        (mantissa, biased + f128::DENORMAL_EXPONENT - 1)
+    };
+    if is_halfway {
+        (2 * mantissa + 1, exponent - 1)
+    } else {
+        (mantissa, exponent)
+    }
+}
+
+/// Compare the real value to `tm * 2^te`.
+#[inline]
+#[allow(clippy::unwrap_used)] // reason="the bigint is sized for the maximum number of digits"
+#[cfg_attr(not(feature = "radix"), allow(unused_variables))] // reason = "used for odd radixes"
+fn compare_float<const FORMAT: u128>(
+    num: &Number,
+    digits: &Digits,
+    theor: (u128, i32),
+) -> cmp::Ordering {
+    // Check the bounds of the binary exponents first, scaled by 12 like
+    // in `to_float`, so we never scale values far outside the float range.
+    let mantissa = &digits.mantissa;
+    let (odd, bits, exponent) = (digits.odd, digits.bits, digits.exponent);
+    let (tm, te) = theor;
+    let binary_exponent = bits as i64 * exponent;
+    let (lower, upper) = log2_bounds(odd);
+    let (odd_lower, odd_upper) = if exponent >= 0 {
+        (lower * exponent, upper * exponent)
+    } else {
+        (upper * exponent, lower * exponent)
+    };
+    let bit_length = bit_length(mantissa) as i64;
+    let theor_length = 128 - tm.leading_zeros() as i64 + te as i64;
+    if 12 * (bit_length - 1 + binary_exponent) + odd_lower >= 12 * theor_length {
+        return cmp::Ordering::Greater;
+    } else if 12 * (bit_length + binary_exponent) + odd_upper <= 12 * (theor_length - 1) {
+        return cmp::Ordering::Less;
+    }
+
+    // Scale both values to integers with the same binary exponent.
+    let mut real = mantissa.clone();
+    let mut theor = from_u128(tm);
+    if odd != 0 && exponent >= 0 {
+        pow(&mut real, odd, exponent as u32).unwrap();
+    } else if odd != 0 {
+        pow(&mut theor, odd, exponent.unsigned_abs() as u32).unwrap();
+    }
+    let shift = binary_exponent - te as i64;
+    if shift >= 0 {
+        shl(&mut real, shift as usize).unwrap();
+    } else {
+        shl(&mut theor, -shift as usize).unwrap();
+    }
+
+    let is_truncated = digits.is_truncated;
+    match compare(&real, &theor) {
+        cmp::Ordering::Equal if is_truncated => cmp::Ordering::Greater,
+        // With an odd radix, the truncated digits might be above the value.
+        #[cfg(feature = "radix")]
+        cmp::Ordering::Less if is_truncated && NumberFormat::<FORMAT>::MANTISSA_RADIX % 2 == 1 => {
+            compare_remaining::<FORMAT>(num, mantissa, digits.count, exponent, (tm, te))
+        },
+        order => order,
+    }
+}
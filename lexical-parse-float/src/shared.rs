@@ -2,12 +2,16 @@
 
 #![doc(hidden)]
 
+use core::cmp;
+
 #[cfg(feature = "power-of-two")]
 use lexical_util::format::NumberFormat;
-use lexical_util::num::AsPrimitive;
+use lexical_util::num::{AsPrimitive, Float};
 
 use crate::float::{ExtendedFloat80, RawFloat};
 use crate::mask::{lower_n_halfway, lower_n_mask};
+use crate::options::Rounding;
+use crate::status::Status;
 
 // 8 DIGIT
 // -------
@@ -284,3 +288,94 @@ pub fn round_down(fp: &mut ExtendedFloat80, shift: i32) {
     };
     fp.exp += shift;
 }
+
+// DIRECTED ROUNDING
+// -----------------
+
+impl Rounding {
+    /// Determine if an inexact value is rounded to the float with the
+    /// larger magnitude, for the directed rounding modes.
+    #[inline(always)]
+    pub const fn is_away(self, is_negative: bool) -> bool {
+        match self {
+            Self::TowardPositive => !is_negative,
+            Self::TowardNegative => is_negative,
+            _ => false,
+        }
+    }
+}
+
+/// Round a non-zero value to a float using any rounding mode.
+///
+/// The `nearest` float is the magnitude of the value rounded to nearest,
+/// tie-even, which is always within 1 ULP of the result. The callback
+/// should compare the magnitude of the real value to a float, or the
+/// halfway point above the float if the second parameter is `true`.
+/// This returns the magnitude of the rounded float.
+#[cfg_attr(not(feature = "compact"), inline(always))]
+pub fn round_directed<F, Cb>(nearest: F, rounding: Rounding, is_negative: bool, mut cb: Cb) -> F
+where
+    F: Float,
+    // `value`, `is_halfway`
+    Cb: FnMut(F, bool) -> cmp::Ordering,
+{
+    debug_assert!(nearest.is_sign_positive(), "must be the float magnitude");
+    let is_away = rounding.is_away(is_negative);
+    match rounding {
+        Rounding::NearestTieEven => nearest,
+        // Only the halfway point above the float can round differently:
+        // if the value is halfway below it, it was already rounded away.
+        Rounding::NearestTieAway if nearest.is_inf() => nearest,
+        Rounding::NearestTieAway => match cb(nearest, true) {
+            cmp::Ordering::Equal => nearest.next_positive(),
+            _ => nearest,
+        },
+        // The value is always above the largest finite float.
+        _ if nearest.is_inf() && is_away => nearest,
+        _ if nearest.is_inf() => F::MAX,
+        // The value is non-zero, so it is always above zero.
+        _ if nearest == F::ZERO && is_away => nearest.next_positive(),
+        _ if nearest == F::ZERO => nearest,
+        _ => match cb(nearest, false) {
+            cmp::Ordering::Greater if is_away => nearest.next_positive(),
+            cmp::Ordering::Less if !is_away => nearest.prev_positive(),
+            _ => nearest,
+        },
+    }
+}
+
+// STATUS
+// ------
+
+/// Get the status of a non-zero value rounded to a float.
+///
+/// The `float` is the magnitude of the rounded value. The callback should
+/// compare the magnitude of the real value to a float, or the float 1 ULP
+/// above it with an unbounded exponent if the second parameter is `true`.
+/// Like IEEE-754, the value underflows if it is inexact and below the
+/// smallest normal float, before rounding.
+#[cfg_attr(not(feature = "compact"), inline(always))]
+pub fn float_status<F, Cb>(float: F, mut cb: Cb) -> Status
+where
+    F: Float,
+    // `value`, `is_above`
+    Cb: FnMut(F, bool) -> cmp::Ordering,
+{
+    debug_assert!(float.is_sign_positive(), "must be the float magnitude");
+    if float.is_inf() {
+        return Status::Overflow;
+    } else if float == F::ZERO {
+        return Status::Underflow;
+    }
+    match cb(float, false) {
+        cmp::Ordering::Equal => Status::Exact,
+        // The value is above the largest float, even if it rounded down to it.
+        cmp::Ordering::Greater if float == F::MAX && cb(float, true) != cmp::Ordering::Less => {
+            Status::Overflow
+        },
+        // The value is below the smallest normal float, even if it rounded up to it.
+        cmp::Ordering::Less if float.to_bits() == F::HIDDEN_BIT_MASK => Status::Underflow,
+        _ if float.is_denormal() => Status::Underflow,
+        _ => Status::Inexact,
+    }
+}
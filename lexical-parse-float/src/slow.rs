@@ -115,6 +115,58 @@ pub fn digit_comp<F: RawFloat, const FORMAT: u128>(
     }
 }
 
+/// Compare the real digits to the exact value of an extended float.
+///
+/// This is used for rounding modes other than nearest, tie-even, where
+/// we need to know if the real digits are above, below, or equal to a
+/// float or a halfway point. The extended float must be the exact
+/// significant digits and binary exponent, such as from [`b`] or [`bh`],
+/// and must be within a few ULP of the real value.
+#[must_use]
+#[allow(clippy::unwrap_used)] // reason = "none is a developer error"
+pub fn compare_float<F: RawFloat, const FORMAT: u128>(
+    num: Number,
+    theor: ExtendedFloat80,
+) -> cmp::Ordering {
+    let format = NumberFormat::<{ FORMAT }> {};
+    let sci_exp = scientific_exponent::<FORMAT>(&num);
+
+    #[cfg(feature = "radix")]
+    {
+        if let Some(max_digits) = F::max_digits(format.radix()) {
+            digit_compare::<FORMAT>(num, theor, sci_exp, max_digits)
+        } else {
+            byte_compare::<FORMAT>(num, theor, sci_exp)
+        }
+    }
+
+    #[cfg(not(feature = "radix"))]
+    {
+        let max_digits = F::max_digits(format.radix()).unwrap();
+        digit_compare::<FORMAT>(num, theor, sci_exp, max_digits)
+    }
+}
+
+/// Compare the real digits to an extended float for a finite representation.
+///
+/// Any float or halfway point can be exactly represented in `max_digits`,
+/// and truncated digits are rounded-up past the last digit, so they
+/// never compare equal to a float.
+#[must_use]
+#[inline(always)]
+#[allow(clippy::cast_possible_wrap)] // reason = "the value range is [-324, 308]"
+pub fn digit_compare<const FORMAT: u128>(
+    num: Number,
+    theor: ExtendedFloat80,
+    sci_exp: i32,
+    max_digits: usize,
+) -> cmp::Ordering {
+    let (bigmant, digits) = parse_mantissa::<FORMAT>(num, max_digits);
+    // This can't underflow, since `digits` is at most `max_digits`.
+    let exponent = sci_exp + 1 - digits as i32;
+    compare_scaled::<FORMAT>(bigmant, exponent, theor)
+}
+
 /// Generate the significant digits with a positive exponent relative to
 /// mantissa.
 #[must_use]
@@ -189,11 +241,8 @@ pub fn negative_digit_comp<F: RawFloat, const FORMAT: u128>(
     //  1. The significant digits are not shifted into place.
     debug_assert!(fp.mant & (1 << 63) != 0, "the significant digits must be normalized");
 
-    let format = NumberFormat::<FORMAT> {};
-    let radix = format.radix();
-
     // Get the significant digits and radix exponent for the real digits.
-    let mut real_digits = bigmant;
+    let real_digits = bigmant;
     let real_exp = exponent;
     debug_assert!(real_exp < 0, "algorithm only works with negative numbers");
 
@@ -202,19 +251,60 @@ pub fn negative_digit_comp<F: RawFloat, const FORMAT: u128>(
     shared::round::<F, _>(&mut b, shared::round_down);
     let b = extended_to_float::<F>(b);
 
-    // Get the significant digits and the binary exponent for `b+h`.
-    let theor = bh(b);
+    // Compare the real digits to the significant digits of `b+h`.
+    let ord = compare_scaled::<FORMAT>(real_digits, real_exp, bh(b));
+
+    // Round nearest, tie even.
+    shared::round::<F, _>(&mut fp, |f, s| {
+        shared::round_nearest_tie_even(f, s, |is_odd, _, _| {
+            // Can ignore `is_halfway` and `is_above`, since those were
+            // calculates using less significant digits.
+            match ord {
+                cmp::Ordering::Greater => true,
+                cmp::Ordering::Less => false,
+                cmp::Ordering::Equal if is_odd => true,
+                cmp::Ordering::Equal => false,
+            }
+        });
+    });
+    fp
+}
+
+/// Compare the real digits, `m1 * b^N1`, to an extended float, `m2 * 2^N2`.
+///
+/// This scales both to big integers without any loss of precision,
+/// removing as many powers of two as possible, as described in
+/// [`negative_digit_comp`].
+#[must_use]
+#[inline(always)]
+#[allow(clippy::match_bool)] // reason = "simplifies documentation"
+#[allow(clippy::unwrap_used)] // reason = "unwrap panics if a developer error"
+#[allow(clippy::missing_inline_in_public_items)] // reason = "only exposed for unittesting"
+pub fn compare_scaled<const FORMAT: u128>(
+    mut real_digits: Bigint,
+    real_exp: i32,
+    theor: ExtendedFloat80,
+) -> cmp::Ordering {
+    let format = NumberFormat::<FORMAT> {};
+    let radix = format.radix();
     let mut theor_digits = Bigint::from_u64(theor.mant);
     let theor_exp = theor.exp;
 
-    // We need to scale the real digits and `b+h` digits to be the same
+    // We need to scale the real and theoretical digits to be the same
     // order. We currently have `real_exp`, in `radix`, that needs to be
-    // shifted to `theor_digits` (since it is negative), and `theor_exp`
+    // shifted to `theor_digits` (if it is negative), and `theor_exp`
     // to either `theor_digits` or `real_digits` as a power of 2 (since it
     // may be positive or negative). Try to remove as many powers of 2
     // as possible. All values are relative to `theor_digits`, that is,
     // reflect the power you need to multiply `theor_digits` by.
     let (binary_exp, halfradix_exp, radix_exp) = match radix.is_even() {
+        // A positive exponent just scales the real digits.
+        _ if real_exp >= 0 => {
+            if real_exp != 0 {
+                real_digits.pow(radix, real_exp as u32).unwrap();
+            }
+            (theor_exp, 0, 0)
+        },
         // Can remove a power-of-two.
         // Both are on opposite-sides of equation, can factor out a
         // power of two.
@@ -240,21 +330,7 @@ pub fn negative_digit_comp<F: RawFloat, const FORMAT: u128>(
         real_digits.pow(2, (-binary_exp) as u32).unwrap();
     }
 
-    // Compare our theoretical and real digits and round nearest, tie even.
-    let ord = real_digits.data.cmp(&theor_digits.data);
-    shared::round::<F, _>(&mut fp, |f, s| {
-        shared::round_nearest_tie_even(f, s, |is_odd, _, _| {
-            // Can ignore `is_halfway` and `is_above`, since those were
-            // calculates using less significant digits.
-            match ord {
-                cmp::Ordering::Greater => true,
-                cmp::Ordering::Less => false,
-                cmp::Ordering::Equal if is_odd => true,
-                cmp::Ordering::Equal => false,
-            }
-        });
-    });
-    fp
+    real_digits.data.cmp(&theor_digits.data)
 }
 
 /// Try to parse 8 digits at a time.
@@ -518,6 +594,10 @@ macro_rules! integer_compare {
                 _ => break,
             };
             let rem = $num.data.quorem(&$den.data) as u32;
+            if rem >= $radix {
+                // The theoretical digits start at a higher power.
+                return cmp::Ordering::Less;
+            }
             let expected = digit_to_char_const(rem, $radix);
             $num.data.mul_small($radix as Limb).unwrap();
             if actual < expected {
@@ -557,6 +637,10 @@ macro_rules! fraction_compare {
                 _ => return cmp::Ordering::Less,
             };
             let rem = $num.data.quorem(&$den.data) as u32;
+            if rem >= $radix {
+                // The theoretical digits start at a higher power.
+                return cmp::Ordering::Less;
+            }
             let expected = digit_to_char_const(rem, $radix);
             $num.data.mul_small($radix as Limb).unwrap();
             if actual < expected {
@@ -600,15 +684,44 @@ pub fn byte_comp<F: RawFloat, const FORMAT: u128>(
     //  1. The significant digits are not shifted into place.
     debug_assert!(fp.mant & (1 << 63) != 0);
 
-    let format = NumberFormat::<FORMAT> {};
-
     // Round down our extended-precision float and calculate `b`.
     let mut b = fp;
     shared::round::<F, _>(&mut b, shared::round_down);
     let b = extended_to_float::<F>(b);
 
-    // Calculate `b+h` to create a ratio for our theoretical digits.
-    let theor = Bigfloat::from_float(bh::<F>(b));
+    // Compare our theoretical digits for `b+h` and round nearest, tie even.
+    let ord = byte_compare::<FORMAT>(number, bh::<F>(b), sci_exp);
+    shared::round::<F, _>(&mut fp, |f, s| {
+        shared::round_nearest_tie_even(f, s, |is_odd, _, _| {
+            // Can ignore `is_halfway` and `is_above`, since those were
+            // calculates using less significant digits.
+            match ord {
+                cmp::Ordering::Greater => true,
+                cmp::Ordering::Less => false,
+                cmp::Ordering::Equal if is_odd => true,
+                cmp::Ordering::Equal => false,
+            }
+        });
+    });
+    fp
+}
+
+/// Compare the real digits to the theoretical digits of an extended float.
+///
+/// This generates the theoretical digits as bytes on the fly, using the
+/// ratio of the float to the scientific exponent of the real digits.
+#[cfg(feature = "radix")]
+#[allow(clippy::unwrap_used)] // reason = "none is a developer error due to shl overflow"
+#[allow(clippy::comparison_chain)] // reason = "logically different conditions for algorithm"
+pub fn byte_compare<const FORMAT: u128>(
+    number: Number,
+    theor: ExtendedFloat80,
+    sci_exp: i32,
+) -> cmp::Ordering {
+    let format = NumberFormat::<FORMAT> {};
+
+    // Create a ratio for our theoretical digits.
+    let theor = Bigfloat::from_float(theor);
 
     // Now, create a scaling factor for the digit count.
     let mut factor = Bigfloat::from_u32(1);
@@ -663,21 +776,7 @@ pub fn byte_comp<F: RawFloat, const FORMAT: u128>(
         }
     }
 
-    // Compare our theoretical and real digits and round nearest, tie even.
-    let ord = compare_bytes::<FORMAT>(number, num, den);
-    shared::round::<F, _>(&mut fp, |f, s| {
-        shared::round_nearest_tie_even(f, s, |is_odd, _, _| {
-            // Can ignore `is_halfway` and `is_above`, since those were
-            // calculates using less significant digits.
-            match ord {
-                cmp::Ordering::Greater => true,
-                cmp::Ordering::Less => false,
-                cmp::Ordering::Equal if is_odd => true,
-                cmp::Ordering::Equal => false,
-            }
-        });
-    });
-    fp
+    compare_bytes::<FORMAT>(number, num, den)
 }
 
 /// Compare digits between the generated values the ratio and the actual view.
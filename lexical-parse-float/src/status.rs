@@ -0,0 +1,123 @@
+//! Parse floats and report if the value was rounded.
+//!
+//! This uses the same algorithms as the float parsers, but also compares
+//! the significant digits to the rounded float, to detect if any precision
+//! was lost when converting the digits to a native float.
+
+use lexical_util::error::Error;
+use lexical_util::format::{is_valid_options_punctuation, NumberFormat};
+use lexical_util::result::Result;
+
+use crate::options::Options;
+use crate::parse::ParseFloat;
+
+/// The status of a parsed float, similar to the IEEE-754 exceptions.
+///
+/// # Examples
+///
+/// ```rust
+/// use lexical_parse_float::{format, parse_with_status, Options, Status};
+///
+/// const FORMAT: u128 = format::STANDARD;
+/// const OPTIONS: Options = Options::new();
+/// assert_eq!(parse_with_status::<f64, FORMAT>(b"0.5", &OPTIONS), Ok((0.5, Status::Exact)));
+/// assert_eq!(parse_with_status::<f64, FORMAT>(b"0.1", &OPTIONS), Ok((0.1, Status::Inexact)));
+/// assert_eq!(parse_with_status::<f64, FORMAT>(b"1e-320", &OPTIONS), Ok((1e-320, Status::Underflow)));
+/// assert_eq!(
+///     parse_with_status::<f64, FORMAT>(b"1e400", &OPTIONS),
+///     Ok((f64::INFINITY, Status::Overflow))
+/// );
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum Status {
+    /// The float is exactly equal to the parsed value.
+    Exact,
+
+    /// The parsed value was rounded to a normal float.
+    Inexact,
+
+    /// The parsed value was rounded, and is below the smallest normal
+    /// float, so it was rounded to a denormal float or to zero.
+    Underflow,
+
+    /// The parsed value is too large for a finite float, so it was rounded
+    /// to infinity, or to the largest finite float when rounding toward
+    /// zero.
+    Overflow,
+}
+
+impl Status {
+    /// Get if the float is exactly equal to the parsed value.
+    #[must_use]
+    #[inline(always)]
+    pub const fn is_exact(self) -> bool {
+        matches!(self, Self::Exact)
+    }
+}
+
+/// Validate the number format and options for parsing a float.
+#[inline(always)]
+fn check_format<const FORMAT: u128>(options: &Options) -> Result<()> {
+    let format = NumberFormat::<{ FORMAT }> {};
+    if !format.is_valid() {
+        Err(format.error())
+    } else if !is_valid_options_punctuation(FORMAT, options.exponent(), options.decimal_point()) {
+        Err(Error::InvalidPunctuation)
+    } else {
+        Ok(())
+    }
+}
+
+/// Parse a complete float, and get if the value was rounded.
+///
+/// This parses the float exactly like
+/// [`from_lexical_with_options`][crate::FromLexicalWithOptions::from_lexical_with_options],
+/// including the [`rounding`][Options::rounding] mode, but always
+/// compares the parsed digits to the float. Special values such as
+/// `NaN` or `inf` are always exact.
+///
+/// # Examples
+///
+/// ```rust
+/// use lexical_parse_float::{format, parse_with_status, Options, Status};
+///
+/// const FORMAT: u128 = format::STANDARD;
+/// const OPTIONS: Options = Options::new();
+/// let digits = b"1.000000000000000000000000000001";
+/// let (float, status) = parse_with_status::<f64, FORMAT>(digits, &OPTIONS).unwrap();
+/// assert_eq!(float, 1.0);
+/// assert_eq!(status, Status::Inexact);
+/// ```
+#[inline]
+pub fn parse_with_status<F: ParseFloat, const FORMAT: u128>(
+    bytes: &[u8],
+    options: &Options,
+) -> Result<(F, Status)> {
+    check_format::<FORMAT>(options)?;
+    F::parse_complete_status::<FORMAT>(bytes, options)
+}
+
+/// Parse a float, stopping at the first invalid character, and get if
+/// the value was rounded.
+///
+/// Returns the float, the status, and the number of processed bytes. See
+/// [`parse_with_status`] for more details.
+///
+/// # Examples
+///
+/// ```rust
+/// use lexical_parse_float::{format, parse_partial_with_status, Options, Status};
+///
+/// const FORMAT: u128 = format::STANDARD;
+/// const OPTIONS: Options = Options::new();
+/// let result = parse_partial_with_status::<f32, FORMAT>(b"0.25,0.1", &OPTIONS);
+/// assert_eq!(result, Ok((0.25, Status::Exact, 4)));
+/// ```
+#[inline]
+pub fn parse_partial_with_status<F: ParseFloat, const FORMAT: u128>(
+    bytes: &[u8],
+    options: &Options,
+) -> Result<(F, Status, usize)> {
+    check_format::<FORMAT>(options)?;
+    F::parse_partial_status::<FORMAT>(bytes, options)
+}
@@ -0,0 +1,134 @@
+//! Parse floats incrementally from chunked input.
+//!
+//! This buffers the bytes of a float split across multiple chunks,
+//! such as network reads, in a caller-provided buffer until the float
+//! is complete, using the same number format validation and error
+//! indexes as parsing the float from a single slice.
+//!
+//! ```rust
+//! use lexical_parse_float::stream::{Status, StreamParser};
+//!
+//! let mut buffer = [0u8; 64];
+//! let mut parser = StreamParser::<f64>::new(&mut buffer);
+//! assert_eq!(parser.push(b"1.2"), Ok(Status::Incomplete));
+//! assert_eq!(parser.push(b"5e"), Ok(Status::Incomplete));
+//! assert_eq!(parser.push(b"1,"), Ok(Status::Complete(12.5, 6)));
+//! assert_eq!(parser.push(b"in"), Ok(Status::Incomplete));
+//! assert_eq!(parser.push(b"f"), Ok(Status::Incomplete));
+//! assert_eq!(parser.push(b" "), Ok(Status::Complete(f64::INFINITY, 3)));
+//! ```
+
+use core::marker::PhantomData;
+
+use lexical_util::format::STANDARD;
+use lexical_util::result::Result;
+pub use lexical_util::stream::Status;
+use lexical_util::stream::StreamBuffer;
+
+use crate::options::Options;
+use crate::FromLexicalWithOptions;
+
+/// Default options for the stream parser.
+const DEFAULT_OPTIONS: Options = Options::new();
+
+/// Resumable parser for floats split across chunks of input.
+///
+/// The float is complete once a byte that cannot be part of the float
+/// is found, or once the caller signals the end of the input with
+/// [`finish`]. After a complete float or an error, the parser is reset
+/// to parse the next float.
+///
+/// The buffer must be at least 1 byte longer than the longest float to
+/// parse, otherwise [`push`] returns [`Error::BufferFull`].
+///
+/// [`finish`]: Self::finish
+/// [`push`]: Self::push
+/// [`Error::BufferFull`]: lexical_util::error::Error::BufferFull
+#[derive(Debug)]
+pub struct StreamParser<'a, T, const FORMAT: u128 = STANDARD> {
+    /// The bytes of the float buffered so far.
+    buffer: StreamBuffer<'a>,
+    /// The options to parse the float.
+    options: &'a Options,
+    /// Marker for the float type.
+    marker: PhantomData<T>,
+}
+
+impl<'a, T, const FORMAT: u128> StreamParser<'a, T, FORMAT>
+where
+    T: FromLexicalWithOptions<Options = Options>,
+{
+    /// Create a stream parser with the default options.
+    #[inline(always)]
+    pub fn new(buffer: &'a mut [u8]) -> Self {
+        Self::with_options(buffer, &DEFAULT_OPTIONS)
+    }
+
+    /// Create a stream parser with custom options.
+    #[inline(always)]
+    pub fn with_options(buffer: &'a mut [u8], options: &'a Options) -> Self {
+        Self {
+            buffer: StreamBuffer::new(buffer),
+            options,
+            marker: PhantomData,
+        }
+    }
+
+    /// Get the bytes of the current float buffered so far.
+    #[inline(always)]
+    pub fn buffered(&self) -> &[u8] {
+        self.buffer.as_slice()
+    }
+
+    /// Discard any buffered bytes, to start parsing a new float.
+    #[inline(always)]
+    pub fn reset(&mut self) {
+        self.buffer.clear();
+    }
+
+    /// Parse the next chunk of input.
+    ///
+    /// Returns [`Status::Incomplete`] if the float may continue in the
+    /// next chunk, otherwise the float and the number of bytes of the
+    /// float. The count and error indexes are relative to the start of
+    /// the float, across all chunks, so the float may end before the
+    /// last chunk.
+    #[inline]
+    pub fn push(&mut self, chunk: &[u8]) -> Result<Status<T>> {
+        let options = self.options;
+        let parse = |bytes: &[u8]| T::from_lexical_partial_with_options::<FORMAT>(bytes, options);
+        self.buffer.push(chunk, parse, |bytes| is_special_prefix(bytes, options))
+    }
+
+    /// Parse the buffered bytes at the end of the input.
+    ///
+    /// Returns the float and the number of buffered bytes that were
+    /// part of the float.
+    #[inline]
+    pub fn finish(&mut self) -> Result<(T, usize)> {
+        let options = self.options;
+        self.buffer.finish(|bytes| T::from_lexical_partial_with_options::<FORMAT>(bytes, options))
+    }
+}
+
+/// Determine if the bytes, after an optional sign, could be the start of
+/// a special float string.
+///
+/// An incomplete special string such as `in` is an error, and `infi` is
+/// parsed as `inf`, but both may continue as `infinity` with more input.
+#[inline]
+fn is_special_prefix(bytes: &[u8], options: &Options) -> bool {
+    let bytes = match bytes.first() {
+        Some(b'+' | b'-') => &bytes[1..],
+        _ => bytes,
+    };
+    let is_prefix = |special: Option<&[u8]>| match special {
+        Some(special) if bytes.len() < special.len() => {
+            bytes.eq_ignore_ascii_case(&special[..bytes.len()])
+        },
+        _ => false,
+    };
+    is_prefix(options.nan_string())
+        || is_prefix(options.inf_string())
+        || is_prefix(options.infinity_string())
+}
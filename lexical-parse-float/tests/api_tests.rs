@@ -1,7 +1,7 @@
 #[cfg(feature = "format")]
 use core::num;
 
-use lexical_parse_float::{FromLexical, FromLexicalWithOptions, Options};
+use lexical_parse_float::{FromLexical, FromLexicalWithOptions, Options, Rounding};
 use lexical_util::error::Error;
 #[cfg(feature = "format")]
 use lexical_util::format;
@@ -668,6 +668,176 @@ fn parse_f64_lossy_test() {
     assert_eq!(Ok((1.2345e10, 9)), parse(b"1.2345e10"));
 }
 
+#[test]
+fn parse_f32_rounding_test() {
+    const FORMAT: u128 = STANDARD;
+    let parse = |x: &[u8], rounding| {
+        let options = Options::builder().rounding(rounding).build_strict();
+        f32::from_lexical_with_options::<FORMAT>(x, &options).unwrap().to_bits()
+    };
+
+    assert_eq!(parse(b"0.1", Rounding::NearestTieEven), 0x3DCCCCCD);
+    assert_eq!(parse(b"0.1", Rounding::TowardZero), 0x3DCCCCCC);
+    assert_eq!(parse(b"-0.1", Rounding::TowardZero), 0xBDCCCCCC);
+    assert_eq!(parse(b"-0.1", Rounding::TowardNegative), 0xBDCCCCCD);
+    assert_eq!(parse(b"16777217", Rounding::NearestTieAway), 0x4B800001);
+    assert_eq!(parse(b"1e39", Rounding::TowardZero), f32::MAX.to_bits());
+    assert_eq!(parse(b"1e-50", Rounding::TowardPositive), 1);
+}
+
+#[test]
+fn parse_f64_rounding_test() {
+    const FORMAT: u128 = STANDARD;
+    let parse = |x: &[u8], rounding| {
+        let options = Options::builder().rounding(rounding).build_strict();
+        f64::from_lexical_with_options::<FORMAT>(x, &options).unwrap()
+    };
+
+    // Inexact values.
+    assert_eq!(parse(b"0.1", Rounding::NearestTieEven), 0.1);
+    assert_eq!(parse(b"0.1", Rounding::NearestTieAway), 0.1);
+    assert_eq!(parse(b"0.1", Rounding::TowardZero), 0.09999999999999999);
+    assert_eq!(parse(b"0.1", Rounding::TowardPositive), 0.1);
+    assert_eq!(parse(b"0.1", Rounding::TowardNegative), 0.09999999999999999);
+    assert_eq!(parse(b"-0.1", Rounding::TowardZero), -0.09999999999999999);
+    assert_eq!(parse(b"-0.1", Rounding::TowardPositive), -0.09999999999999999);
+    assert_eq!(parse(b"-0.1", Rounding::TowardNegative), -0.1);
+    assert_eq!(parse(b"1e23", Rounding::TowardZero), 1e23);
+    assert_eq!(parse(b"1e23", Rounding::TowardPositive), 1.0000000000000001e23);
+
+    // Exact values.
+    assert_eq!(parse(b"1.5", Rounding::TowardZero), 1.5);
+    assert_eq!(parse(b"-1.5", Rounding::TowardNegative), -1.5);
+    assert_eq!(parse(b"0", Rounding::TowardPositive), 0.0);
+
+    // Halfway values, and many digits above them.
+    assert_eq!(parse(b"9007199254740993", Rounding::NearestTieEven), 9007199254740992.0);
+    assert_eq!(parse(b"9007199254740993", Rounding::NearestTieAway), 9007199254740994.0);
+    assert_eq!(parse(b"-9007199254740995", Rounding::NearestTieEven), -9007199254740996.0);
+    assert_eq!(parse(b"-9007199254740995", Rounding::NearestTieAway), -9007199254740996.0);
+    assert_eq!(parse(b"-9007199254740995", Rounding::TowardZero), -9007199254740994.0);
+    let above = b"9007199254740993.000000000000000000000000001";
+    assert_eq!(parse(above, Rounding::TowardZero), 9007199254740992.0);
+    assert_eq!(parse(above, Rounding::NearestTieEven), 9007199254740994.0);
+
+    // Overflow and underflow.
+    assert_eq!(parse(b"1e400", Rounding::NearestTieAway), f64::INFINITY);
+    assert_eq!(parse(b"1e400", Rounding::TowardZero), f64::MAX);
+    assert_eq!(parse(b"1e400", Rounding::TowardPositive), f64::INFINITY);
+    assert_eq!(parse(b"-1e400", Rounding::TowardPositive), f64::MIN);
+    assert_eq!(parse(b"-1e400", Rounding::TowardNegative), f64::NEG_INFINITY);
+    assert_eq!(parse(b"1e-400", Rounding::TowardZero), 0.0);
+    assert_eq!(parse(b"1e-400", Rounding::TowardPositive), 5e-324);
+    assert_eq!(parse(b"-2.4703282292062328e-324", Rounding::NearestTieAway), -5e-324);
+    assert_eq!(parse(b"-2.4703282292062328e-324", Rounding::TowardZero), -0.0);
+
+    // Exponents far outside the float range.
+    assert_eq!(parse(b"1e20000", Rounding::NearestTieEven), f64::INFINITY);
+    assert_eq!(parse(b"1e20000", Rounding::NearestTieAway), f64::INFINITY);
+    assert_eq!(parse(b"1e20000", Rounding::TowardZero), f64::MAX);
+    assert_eq!(parse(b"1e20000", Rounding::TowardPositive), f64::INFINITY);
+    assert_eq!(parse(b"1e20000", Rounding::TowardNegative), f64::MAX);
+    assert_eq!(parse(b"-1e20000", Rounding::TowardPositive), f64::MIN);
+    assert_eq!(parse(b"1e-5000", Rounding::NearestTieEven), 0.0);
+    assert_eq!(parse(b"1e-5000", Rounding::NearestTieAway), 0.0);
+    assert_eq!(parse(b"1e-5000", Rounding::TowardZero), 0.0);
+    assert_eq!(parse(b"1e-5000", Rounding::TowardPositive), 5e-324);
+    assert_eq!(parse(b"1e-5000", Rounding::TowardNegative), 0.0);
+    assert_eq!(parse(b"-1e-5000", Rounding::TowardNegative), -5e-324);
+    let digits = b"1.00000000000000000000000000000000000000000000000000000000001e-5000";
+    assert_eq!(parse(digits, Rounding::NearestTieAway), 0.0);
+    assert_eq!(parse(digits, Rounding::TowardPositive), 5e-324);
+}
+
+#[test]
+#[cfg(feature = "power-of-two")]
+fn parse_f64_rounding_binary_test() {
+    const FORMAT: u128 = NumberFormatBuilder::from_radix(16);
+    let parse = |x: &[u8], rounding| {
+        let options = Options::builder().exponent(b'^').rounding(rounding).build_strict();
+        f64::from_lexical_with_options::<FORMAT>(x, &options).unwrap()
+    };
+
+    // `1 + 2^-56`, and `2^53 + 1` with many trailing digits.
+    assert_eq!(parse(b"1.00000000000001", Rounding::NearestTieEven), 1.0);
+    assert_eq!(parse(b"1.00000000000001", Rounding::TowardPositive), 1.0000000000000002);
+    assert_eq!(parse(b"-1.00000000000001", Rounding::TowardNegative), -1.0000000000000002);
+    let above = b"20000000000001.000000000000000000000000000000000000001";
+    assert_eq!(parse(above, Rounding::TowardZero), 9007199254740992.0);
+    assert_eq!(parse(above, Rounding::NearestTieEven), 9007199254740994.0);
+}
+
+#[test]
+#[cfg(feature = "radix")]
+fn parse_f64_rounding_radix_test() {
+    const FORMAT: u128 = NumberFormatBuilder::from_radix(3);
+    let parse = |x: &[u8], rounding| {
+        let options = Options::builder().exponent(b'^').rounding(rounding).build_strict();
+        f64::from_lexical_with_options::<FORMAT>(x, &options).unwrap()
+    };
+
+    assert_eq!(parse(b"0.1", Rounding::NearestTieEven), 0.3333333333333333);
+    assert_eq!(parse(b"0.1", Rounding::TowardPositive), 0.33333333333333337);
+    assert_eq!(parse(b"-0.1", Rounding::TowardZero), -0.3333333333333333);
+    assert_eq!(parse(b"1^10", Rounding::TowardZero), 27.0);
+}
+
+#[test]
+fn parse_f64_integer_syntax_test() {
+    const FORMAT: u128 = STANDARD;
+    const OPTIONS: Options = Options::builder().integer_syntax(true).build_strict();
+    let parse = |x: &[u8]| f64::from_lexical_with_options::<FORMAT>(x, &OPTIONS);
+    let parse_partial = |x: &[u8]| f64::from_lexical_partial_with_options::<FORMAT>(x, &OPTIONS);
+
+    assert_eq!(parse(b"0"), Ok(0.0));
+    assert_eq!(parse(b"-1234"), Ok(-1234.0));
+    assert_eq!(parse(b"+1234"), Ok(1234.0));
+    assert_eq!(parse(b"9007199254740993"), Ok(9007199254740992.0));
+    assert_eq!(parse(b"12.0"), Err(Error::InvalidDigit(2)));
+    assert_eq!(parse(b"-12."), Err(Error::InvalidDigit(3)));
+    assert_eq!(parse(b"1e3"), Err(Error::InvalidDigit(1)));
+    assert_eq!(parse(b"1E3"), Err(Error::InvalidDigit(1)));
+    assert_eq!(parse(b".5"), Err(Error::InvalidDigit(0)));
+    assert_eq!(parse(b"NaN"), Err(Error::InvalidDigit(0)));
+    assert_eq!(parse(b"-inf"), Err(Error::InvalidDigit(1)));
+    assert_eq!(parse_partial(b"12.5"), Ok((12.0, 2)));
+    assert_eq!(parse_partial(b"12e5"), Ok((12.0, 2)));
+
+    // Float syntax is still accepted without the option.
+    assert_eq!(f64::from_lexical(b"12.0"), Ok(12.0));
+    assert_eq!(f64::from_lexical(b"1e3"), Ok(1000.0));
+}
+
+#[test]
+#[cfg(feature = "f16")]
+fn parse_f16_rounding_test() {
+    use lexical_parse_float::{bf16, f16};
+
+    const FORMAT: u128 = STANDARD;
+    let parse_f16 = |x: &[u8], rounding| {
+        let options = Options::builder().rounding(rounding).build_strict();
+        f16::from_lexical_with_options::<FORMAT>(x, &options).unwrap().to_bits()
+    };
+    let parse_bf16 = |x: &[u8], rounding| {
+        let options = Options::builder().rounding(rounding).build_strict();
+        bf16::from_lexical_with_options::<FORMAT>(x, &options).unwrap().to_bits()
+    };
+
+    assert_eq!(parse_f16(b"0.1", Rounding::TowardZero), 0x2E66);
+    assert_eq!(parse_f16(b"0.1", Rounding::TowardPositive), 0x2E67);
+    assert_eq!(parse_f16(b"-0.1", Rounding::TowardNegative), 0xAE67);
+    assert_eq!(parse_f16(b"1e5", Rounding::TowardZero), 0x7BFF);
+    assert_eq!(parse_f16(b"1e5", Rounding::NearestTieAway), 0x7C00);
+    assert_eq!(parse_f16(b"1e-9", Rounding::TowardPositive), 1);
+
+    // Halfway values that are exact as an `f32`.
+    assert_eq!(parse_f16(b"296.125", Rounding::NearestTieEven), 0x5CA0);
+    assert_eq!(parse_f16(b"296.125", Rounding::NearestTieAway), 0x5CA1);
+    assert_eq!(parse_bf16(b"8435315769344", Rounding::TowardZero), 0x54F5);
+    assert_eq!(parse_bf16(b"8435315769344", Rounding::TowardPositive), 0x54F6);
+    assert_eq!(parse_bf16(b"0.1", Rounding::TowardNegative), 0x3DCC);
+}
+
 #[test]
 fn f32_lossy_decimal_test() {
     const FORMAT: u128 = STANDARD;
@@ -1235,3 +1405,55 @@ fn issue68_test() {
     assert_eq!(f32::INFINITY, f32::from_lexical_with_options::<FORMAT>(hex, &OPTIONS).unwrap());
     assert_eq!(f64::INFINITY, f64::from_lexical_with_options::<FORMAT>(hex, &OPTIONS).unwrap());
 }
+
+#[cfg(feature = "std")]
+fn utf16(string: &str) -> Vec<u16> {
+    string.encode_utf16().collect()
+}
+
+#[cfg(feature = "std")]
+fn utf32(string: &str) -> Vec<u32> {
+    string.chars().map(u32::from).collect()
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn from_lexical_units_test() {
+    assert_eq!(f64::from_lexical_units(&utf16("1.5e3")), Ok(1500.0));
+    assert_eq!(f32::from_lexical_units(&utf32("-0.1")), Ok(-0.1));
+    assert!(f64::from_lexical_units(&utf16("NaN")).unwrap().is_nan());
+    assert_eq!(f64::from_lexical_units(&utf16("inf")), Ok(f64::INFINITY));
+    assert_eq!(f64::from_lexical_units(&utf16("1e")), Err(Error::EmptyExponent(2)));
+
+    // Non-ASCII characters are invalid digits, and indexes are in code units.
+    assert_eq!(f64::from_lexical_units(&utf16("1.5\u{3c0}")), Err(Error::InvalidDigit(3)));
+    assert_eq!(f64::from_lexical_units(&utf16("1.5e\u{1f600}")), Err(Error::EmptyExponent(4)));
+    assert_eq!(f64::from_lexical_partial_units(&utf16("2.5\u{1f600}")), Ok((2.5, 3)));
+    assert_eq!(f64::from_lexical_partial_units(&utf32("2.5\u{1f600}")), Ok((2.5, 3)));
+
+    // Code units that would be ASCII if truncated.
+    assert_eq!(f64::from_lexical_units(&[0x31u16, 0x12E, 0x35]), Err(Error::InvalidDigit(1)));
+    assert_eq!(f64::from_lexical_units(&[0x31u32, 0x10065, 0x35]), Err(Error::InvalidDigit(1)));
+
+    // Inputs longer than the stack buffer are narrowed on the heap.
+    let mut string = "1.".to_string();
+    string.push_str(&"3".repeat(500));
+    let expected = f64::from_lexical(string.as_bytes());
+    assert_eq!(expected, Ok(1.3333333333333333));
+    assert_eq!(f64::from_lexical_units(&utf16(&string)), expected);
+    string.push('\u{3c0}');
+    let index = string.len() - '\u{3c0}'.len_utf8();
+    assert_eq!(f64::from_lexical_units(&utf16(&string)), Err(Error::InvalidDigit(index)));
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn from_lexical_units_with_options_test() {
+    const OPTIONS: Options = Options::builder().decimal_point(b',').build_strict();
+
+    let units = utf16("1,25");
+    assert_eq!(f64::from_lexical_units_with_options::<STANDARD, _>(&units, &OPTIONS), Ok(1.25));
+    let units = utf32("1,25 \u{20ac}");
+    let result = f64::from_lexical_partial_units_with_options::<STANDARD, _>(&units, &OPTIONS);
+    assert_eq!(result, Ok((1.25, 4)));
+}
@@ -0,0 +1,66 @@
+#![cfg(all(feature = "power-of-two", feature = "format"))]
+
+use lexical_parse_float::format::{C_HEX_PRINTF, C_HEX_STRTOD};
+use lexical_parse_float::{options, Error, FromLexicalWithOptions};
+
+/// Reference `strtod` and `strtof` results generated by `etc/strtod_hex.c`.
+const STRTOD_HEX: &str = include_str!("corpus/strtod_hex.txt");
+
+fn parse<T: FromLexicalWithOptions<Options = lexical_parse_float::Options>, const FORMAT: u128>(
+    bytes: &[u8],
+) -> Result<T, Error> {
+    T::from_lexical_with_options::<FORMAT>(bytes, &options::HEX_FLOAT)
+}
+
+#[test]
+fn strtod_hex_test() {
+    assert_eq!(parse::<f64, C_HEX_STRTOD>(b"0x1.8p1"), Ok(3.0));
+    assert_eq!(parse::<f64, C_HEX_STRTOD>(b"0X1.8P+1"), Ok(3.0));
+    assert_eq!(parse::<f64, C_HEX_STRTOD>(b"0x1.8"), Ok(1.5));
+    assert_eq!(parse::<f64, C_HEX_STRTOD>(b"1.8p1"), Ok(3.0));
+    assert_eq!(parse::<f64, C_HEX_STRTOD>(b"-0x8.0p-4"), Ok(-0.5));
+    assert_eq!(parse::<f64, C_HEX_STRTOD>(b"0x.8p1"), Ok(1.0));
+    assert_eq!(parse::<f64, C_HEX_STRTOD>(b"0x1.p1"), Ok(2.0));
+    assert_eq!(parse::<f32, C_HEX_STRTOD>(b"0x1.99999ap-4"), Ok(0.1));
+    assert!(parse::<f64, C_HEX_STRTOD>(b"0x1.8q1").is_err());
+}
+
+#[test]
+fn strtod_hex_corpus_test() {
+    for line in STRTOD_HEX.lines() {
+        let fields: Vec<&str> = line.split(' ').collect();
+        let (kind, input, bits) = (fields[0], fields[1], fields[2]);
+        let (actual, expected) = match kind {
+            "f64" => {
+                let actual = parse::<f64, C_HEX_STRTOD>(input.as_bytes()).unwrap();
+                let expected = f64::from_bits(u64::from_str_radix(bits, 16).unwrap());
+                (actual, expected)
+            },
+            _ => {
+                let actual = parse::<f32, C_HEX_STRTOD>(input.as_bytes()).unwrap();
+                let expected = f32::from_bits(u32::from_str_radix(bits, 16).unwrap());
+                (actual as f64, expected as f64)
+            },
+        };
+        if expected.is_nan() {
+            assert!(actual.is_nan(), "{}", line);
+            assert_eq!(actual.is_sign_negative(), expected.is_sign_negative(), "{}", line);
+        } else {
+            assert_eq!(actual.to_bits(), expected.to_bits(), "{}", line);
+        }
+    }
+}
+
+#[test]
+fn printf_hex_test() {
+    // These are the outputs of `printf("%a")` and `printf("%A")`.
+    assert_eq!(parse::<f64, C_HEX_PRINTF>(b"0x0p+0"), Ok(0.0));
+    assert_eq!(parse::<f64, C_HEX_PRINTF>(b"0x1.8p+1"), Ok(3.0));
+    assert_eq!(parse::<f64, C_HEX_PRINTF>(b"0X1.8P+1"), Ok(3.0));
+    assert_eq!(parse::<f64, C_HEX_PRINTF>(b"0x1.999999999999ap-4"), Ok(0.1));
+    assert_eq!(parse::<f64, C_HEX_PRINTF>(b"0x0.0000000000001p-1022"), Ok(5e-324));
+    assert_eq!(parse::<f64, C_HEX_PRINTF>(b"-0x1.fffffffffffffp+1023"), Ok(f64::MIN));
+    assert_eq!(parse::<f64, C_HEX_PRINTF>(b"0x2p+0"), Ok(2.0));
+    assert_eq!(parse::<f64, C_HEX_PRINTF>(b"0x1.8"), Err(Error::MissingExponent(5)));
+    assert_eq!(parse::<f64, C_HEX_PRINTF>(b"0x1.8p1"), Err(Error::MissingExponentSign(6)));
+}
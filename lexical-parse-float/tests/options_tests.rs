@@ -1,4 +1,4 @@
-use lexical_parse_float::options::{Options, OptionsBuilder};
+use lexical_parse_float::options::{Options, OptionsBuilder, Rounding};
 
 #[test]
 fn invalid_exponent_test() {
@@ -98,6 +98,7 @@ fn builder_test() {
     builder = builder.nan_string(Some(b"nan"));
     builder = builder.inf_string(Some(b"Infinity"));
     builder = builder.infinity_string(Some(b"Infiniiiiiity"));
+    builder = builder.rounding(Rounding::TowardZero);
 
     assert_eq!(builder.get_lossy(), true);
     assert_eq!(builder.get_exponent(), b'^');
@@ -105,6 +106,7 @@ fn builder_test() {
     assert_eq!(builder.get_nan_string(), Some("nan".as_bytes()));
     assert_eq!(builder.get_inf_string(), Some("Infinity".as_bytes()));
     assert_eq!(builder.get_infinity_string(), Some("Infiniiiiiity".as_bytes()));
+    assert_eq!(builder.get_rounding(), Rounding::TowardZero);
 
     assert!(builder.is_valid());
     assert_eq!(builder.build(), Ok(builder.build_unchecked()));
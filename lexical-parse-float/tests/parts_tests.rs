@@ -0,0 +1,133 @@
+#[cfg(any(feature = "format", feature = "radix"))]
+use core::num;
+
+#[cfg(feature = "format")]
+use lexical_parse_float::format;
+use lexical_parse_float::options::Options;
+use lexical_parse_float::{parse_decimal_parts, parse_partial_decimal_parts, Error};
+#[cfg(any(feature = "format", feature = "radix"))]
+use lexical_util::format::NumberFormatBuilder;
+use lexical_util::format::STANDARD;
+
+const OPTIONS: Options = Options::new();
+
+fn parts<const FORMAT: u128>(bytes: &[u8]) -> (bool, String, i64) {
+    let parts = parse_decimal_parts::<FORMAT>(bytes, &OPTIONS).unwrap();
+    let digits = String::from_utf8(parts.digits().collect()).unwrap();
+    assert_eq!(digits.len(), parts.digit_count());
+    (parts.is_negative(), digits, parts.exponent())
+}
+
+#[test]
+fn parse_decimal_parts_test() {
+    assert_eq!(parts::<STANDARD>(b"0"), (false, "".into(), 0));
+    assert_eq!(parts::<STANDARD>(b"-0.00"), (true, "".into(), -2));
+    assert_eq!(parts::<STANDARD>(b"1"), (false, "1".into(), 0));
+    assert_eq!(parts::<STANDARD>(b"+1.5"), (false, "15".into(), -1));
+    assert_eq!(parts::<STANDARD>(b"-1.50"), (true, "150".into(), -2));
+    assert_eq!(parts::<STANDARD>(b"001.25e3"), (false, "125".into(), 1));
+    assert_eq!(parts::<STANDARD>(b"0.000123"), (false, "123".into(), -6));
+    assert_eq!(parts::<STANDARD>(b"1e-400"), (false, "1".into(), -400));
+    assert_eq!(parts::<STANDARD>(b".5"), (false, "5".into(), -1));
+    assert_eq!(parts::<STANDARD>(b"5."), (false, "5".into(), 0));
+
+    // Many digits, which overflow the native mantissa.
+    let (_, digits, exponent) = parts::<STANDARD>(b"3.14159265358979323846264338327950288");
+    assert_eq!(digits, "314159265358979323846264338327950288");
+    assert_eq!(exponent, -35);
+    let (_, digits, exponent) = parts::<STANDARD>(b"0.0000123456789012345678901234567890e10");
+    assert_eq!(digits, "123456789012345678901234567890");
+    assert_eq!(exponent, -24);
+    let (_, digits, exponent) = parts::<STANDARD>(b"12345678901234567890123456789000");
+    assert_eq!(digits, "12345678901234567890123456789000");
+    assert_eq!(exponent, 0);
+
+    // Errors are the same as when parsing a float.
+    assert_eq!(parse_decimal_parts::<STANDARD>(b"", &OPTIONS), Err(Error::Empty(0)));
+    assert_eq!(parse_decimal_parts::<STANDARD>(b"1.2e", &OPTIONS), Err(Error::EmptyExponent(4)));
+    assert_eq!(parse_decimal_parts::<STANDARD>(b"1.2 ", &OPTIONS), Err(Error::InvalidDigit(3)));
+    assert!(parse_decimal_parts::<STANDARD>(b"NaN", &OPTIONS).is_err());
+    assert!(parse_decimal_parts::<STANDARD>(b"-inf", &OPTIONS).is_err());
+}
+
+#[test]
+fn parse_partial_decimal_parts_test() {
+    let (parts, count) = parse_partial_decimal_parts::<STANDARD>(b"12.5,3", &OPTIONS).unwrap();
+    assert_eq!(parts.digits().collect::<Vec<u8>>(), b"125");
+    assert_eq!(parts.exponent(), -1);
+    assert_eq!(count, 4);
+
+    let (parts, count) = parse_partial_decimal_parts::<STANDARD>(b"-1e5 ", &OPTIONS).unwrap();
+    assert!(parts.is_negative());
+    assert_eq!(parts.digits().collect::<Vec<u8>>(), b"1");
+    assert_eq!(parts.exponent(), 5);
+    assert_eq!(count, 4);
+
+    let result = parse_partial_decimal_parts::<STANDARD>(b"x", &OPTIONS);
+    assert_eq!(result, Err(Error::EmptyMantissa(0)));
+}
+
+#[test]
+fn decimal_parts_mantissa_test() {
+    let result = parse_decimal_parts::<STANDARD>(b"1.50e-3", &OPTIONS).unwrap();
+    assert_eq!(result.mantissa(), Some(150));
+    assert_eq!(result.scientific_exponent(), -3);
+    assert!(!result.is_zero());
+
+    let result = parse_decimal_parts::<STANDARD>(b"0.0", &OPTIONS).unwrap();
+    assert_eq!(result.mantissa(), Some(0));
+    assert!(result.is_zero());
+
+    let result =
+        parse_decimal_parts::<STANDARD>(b"340282366920938463463374607431768211455", &OPTIONS);
+    assert_eq!(result.unwrap().mantissa(), Some(u128::MAX));
+    let result =
+        parse_decimal_parts::<STANDARD>(b"340282366920938463463374607431768211456", &OPTIONS);
+    assert_eq!(result.unwrap().mantissa(), None);
+}
+
+#[test]
+#[cfg(feature = "format")]
+fn parse_decimal_parts_format_test() {
+    const FORMAT: u128 = NumberFormatBuilder::new()
+        .digit_separator(num::NonZeroU8::new(b'_'))
+        .integer_internal_digit_separator(true)
+        .fraction_internal_digit_separator(true)
+        .build_strict();
+    let (_, digits, exponent) = parts::<FORMAT>(b"1_000.000_5");
+    assert_eq!(digits, "10000005");
+    assert_eq!(exponent, -4);
+    let (_, digits, exponent) = parts::<FORMAT>(b"0_0.1_2345_6789_0123_4567_8901_2345");
+    assert_eq!(digits, "1234567890123456789012345");
+    assert_eq!(exponent, -25);
+
+    assert_eq!(
+        parse_decimal_parts::<{ format::JSON }>(b"01.5", &OPTIONS),
+        Err(Error::InvalidLeadingZeros(0))
+    );
+    assert_eq!(
+        parse_decimal_parts::<{ format::JSON }>(b"1.", &OPTIONS),
+        Err(Error::EmptyFraction(2))
+    );
+}
+
+#[test]
+#[cfg(feature = "radix")]
+fn parse_decimal_parts_radix_test() {
+    const BASE36: u128 = NumberFormatBuilder::from_radix(36);
+    let options = Options::builder().exponent(b'^').build_strict();
+    let parts = parse_decimal_parts::<BASE36>(b"-Z.Z^1", &options).unwrap();
+    assert!(parts.is_negative());
+    assert_eq!(parts.digits().collect::<Vec<u8>>(), b"ZZ");
+    assert_eq!(parts.exponent(), 0);
+    assert_eq!(parts.mantissa(), Some(36 * 35 + 35));
+
+    // The exponent must scale the significant digits.
+    const HEX: u128 = NumberFormatBuilder::new()
+        .mantissa_radix(16)
+        .exponent_base(num::NonZeroU8::new(2))
+        .exponent_radix(num::NonZeroU8::new(10))
+        .build_strict();
+    let options = Options::builder().exponent(b'p').build_strict();
+    assert_eq!(parse_decimal_parts::<HEX>(b"A.8p1", &options), Err(Error::InvalidRadix));
+}
@@ -0,0 +1,129 @@
+#![cfg(feature = "f128")]
+
+use lexical_parse_float::{f128, FromLexical, FromLexicalWithOptions, Options, Rounding};
+use lexical_util::format::STANDARD;
+use lexical_util::num::Float;
+
+fn parse(string: &str) -> u128 {
+    f128::from_lexical(string.as_bytes()).unwrap().to_bits()
+}
+
+#[test]
+fn parse_simple_test() {
+    assert_eq!(parse("0"), 0);
+    assert_eq!(parse("-0"), 1 << 127);
+    assert_eq!(parse("1"), 0x3FFF0000000000000000000000000000);
+    assert_eq!(parse("0.1"), 0x3FFB999999999999999999999999999A);
+    assert_eq!(parse("-1e-300"), 0xBC1A56E1FC2F8F358D94DB7AC6149156);
+    assert_eq!(parse("1e300"), 0x43E37E43C8800759BA59C08E14C7CD7B);
+    assert_eq!(parse("3.14159265358979323846264338327950288"), 0x4000921FB54442D18469898CC51701B8);
+    assert_eq!(
+        parse("2.718281828459045235360287471352662497757"),
+        0x40005BF0A8B1457695355FB8AC404E7A
+    );
+    assert_eq!(
+        parse("123456789012345678901234567890123456789"),
+        0x407D7383A6958057FB16AB7E8CA2B8E6
+    );
+}
+
+#[test]
+fn parse_limits_test() {
+    assert_eq!(parse("1e4932"), 0x7FFEAE596552B8FDED99D037E3D04B75);
+    assert_eq!(parse("1.18973149535723176508575932662800701e4932"), f128::MAX.to_bits());
+    assert_eq!(parse("1.2e4932"), f128::INFINITY.to_bits());
+    assert_eq!(parse("1e5000"), f128::INFINITY.to_bits());
+    assert_eq!(parse("6.47517511943802511092443895822764655e-4966"), 1);
+    assert_eq!(parse("3.3e-4966"), 1);
+    assert_eq!(parse("3.2e-4966"), 0);
+    assert_eq!(parse("1e-5000"), 0);
+    assert_eq!(parse("-1e-5000"), 1 << 127);
+}
+
+#[test]
+fn parse_halfway_test() {
+    // Exactly halfway between `1` and the next float, which rounds down to even.
+    let halfway = "1.00000000000000000000000000000000009629649721936179265279889712924636592690508241076940976199693977832794189453125";
+    assert_eq!(parse(halfway), 0x3FFF0000000000000000000000000000);
+    let above = halfway.to_string() + "1";
+    assert_eq!(parse(&above), 0x3FFF0000000000000000000000000001);
+    let above = halfway.to_string() + &"0".repeat(20000) + "1";
+    assert_eq!(parse(&above), 0x3FFF0000000000000000000000000001);
+    let below = halfway.replace("453125", "453124");
+    assert_eq!(parse(&below), 0x3FFF0000000000000000000000000000);
+
+    // Many digits that aren't significant.
+    let zeros = "0.".to_string() + &"0".repeat(20000) + "1e20001";
+    assert_eq!(parse(&zeros), 0x3FFF0000000000000000000000000000);
+}
+
+#[test]
+fn parse_rounding_test() {
+    let parse = |string: &str, rounding| {
+        let options = Options::builder().rounding(rounding).build().unwrap();
+        f128::from_lexical_with_options::<STANDARD>(string.as_bytes(), &options).unwrap().to_bits()
+    };
+
+    assert_eq!(parse("0.1", Rounding::NearestTieAway), 0x3FFB999999999999999999999999999A);
+    assert_eq!(parse("0.1", Rounding::TowardZero), 0x3FFB9999999999999999999999999999);
+    assert_eq!(parse("0.1", Rounding::TowardPositive), 0x3FFB999999999999999999999999999A);
+    assert_eq!(parse("-0.1", Rounding::TowardPositive), 0xBFFB9999999999999999999999999999);
+    assert_eq!(parse("1.5", Rounding::TowardZero), f128::from_f64(1.5).to_bits());
+
+    // Halfway between `1` and the next float, and just above it.
+    let halfway = "1.00000000000000000000000000000000009629649721936179265279889712924636592690508241076940976199693977832794189453125";
+    let above = "1.000000000000000000000000000000000096296497219361792652798897129246365926905082410769409761996939778327941894531250000000000000000000000000000000000000001";
+    assert_eq!(parse(halfway, Rounding::NearestTieEven), 0x3FFF0000000000000000000000000000);
+    assert_eq!(parse(halfway, Rounding::NearestTieAway), 0x3FFF0000000000000000000000000001);
+    assert_eq!(parse(above, Rounding::TowardZero), 0x3FFF0000000000000000000000000000);
+    assert_eq!(parse(above, Rounding::TowardPositive), 0x3FFF0000000000000000000000000001);
+
+    // Overflow and underflow.
+    assert_eq!(parse("1e5000", Rounding::TowardZero), f128::MAX.to_bits());
+    assert_eq!(parse("1e5000", Rounding::TowardPositive), f128::INFINITY.to_bits());
+    assert_eq!(parse("-1e5000", Rounding::TowardPositive), (-f128::MAX).to_bits());
+    assert_eq!(parse("1e-5000", Rounding::TowardPositive), 1);
+    assert_eq!(parse("-1e-5000", Rounding::TowardNegative), (1 << 127) | 1);
+    assert_eq!(parse("-1e-5000", Rounding::TowardPositive), 1 << 127);
+}
+
+#[test]
+fn parse_special_test() {
+    assert!(f128::from_lexical(b"NaN").unwrap().is_nan());
+    assert_eq!(f128::from_lexical(b"inf").unwrap(), f128::INFINITY);
+    assert_eq!(f128::from_lexical(b"-Infinity").unwrap(), f128::NEG_INFINITY);
+    assert!(f128::from_lexical(b"").is_err());
+    assert!(f128::from_lexical(b"1.0x").is_err());
+    assert_eq!(f128::from_lexical_partial(b"1.5x"), Ok((f128::from_f64(1.5), 3)));
+    assert_eq!(f128::from_lexical_partial(b"-infx").map(|x| x.1), Ok(4));
+}
+
+#[test]
+#[cfg(feature = "radix")]
+fn parse_radix_test() {
+    use lexical_parse_float::NumberFormatBuilder;
+
+    const BASE3: u128 = NumberFormatBuilder::from_radix(3);
+    const BASE36: u128 = NumberFormatBuilder::from_radix(36);
+    let options = Options::builder().exponent(b'^').build().unwrap();
+    let parse3 = |x: &[u8]| f128::from_lexical_with_options::<BASE3>(x, &options).unwrap();
+    let parse36 = |x: &[u8]| f128::from_lexical_with_options::<BASE36>(x, &options).unwrap();
+
+    assert_eq!(parse3(b"0.1").to_bits(), 0x3FFD5555555555555555555555555555);
+    assert_eq!(parse3(b"120.12").to_bits(), 0x4002F1C71C71C71C71C71C71C71C71C7);
+    assert_eq!(parse3(b"1^2").to_bits(), f128::from_f64(9.0).to_bits());
+    assert_eq!(parse36(b"z.i").to_bits(), f128::from_f64(35.5).to_bits());
+
+    // An input longer than the maximum digits for an odd radix, which is
+    // just below one half.
+    let half = "0.".to_string() + &"1".repeat(2000);
+    assert_eq!(parse3(half.as_bytes()).to_bits(), 0x3FFE0000000000000000000000000000);
+
+    // Directed rounding must compare the truncated digits.
+    let options = options.rebuild().rounding(Rounding::TowardZero).build().unwrap();
+    let parse3 = |x: &[u8]| f128::from_lexical_with_options::<BASE3>(x, &options).unwrap();
+    assert_eq!(parse3(half.as_bytes()).to_bits(), 0x3FFDFFFFFFFFFFFFFFFFFFFFFFFFFFFF);
+    let options = options.rebuild().rounding(Rounding::TowardPositive).build().unwrap();
+    let parse3 = |x: &[u8]| f128::from_lexical_with_options::<BASE3>(x, &options).unwrap();
+    assert_eq!(parse3(b"0.1").to_bits(), 0x3FFD5555555555555555555555555556);
+}
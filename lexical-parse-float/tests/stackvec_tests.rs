@@ -468,5 +468,6 @@ fn split_radix_test() {
         assert_eq!(bigint::split_radix(16), (0, 4));
         assert_eq!(bigint::split_radix(32), (0, 5));
         assert_eq!(bigint::split_radix(14), (7, 1));
+        assert_eq!(bigint::split_radix(12), (3, 2));
     }
 }
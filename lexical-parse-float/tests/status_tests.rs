@@ -0,0 +1,173 @@
+#[cfg(feature = "power-of-two")]
+use lexical_parse_float::NumberFormatBuilder;
+use lexical_parse_float::{
+    parse_partial_with_status,
+    parse_with_status,
+    Error,
+    Options,
+    Rounding,
+    Status,
+};
+use lexical_util::format::STANDARD;
+
+const OPTIONS: Options = Options::new();
+
+fn status<const FORMAT: u128>(bytes: &[u8], options: &Options) -> (f64, Status) {
+    parse_with_status::<f64, FORMAT>(bytes, options).unwrap()
+}
+
+#[test]
+fn parse_with_status_test() {
+    assert_eq!(status::<STANDARD>(b"0", &OPTIONS), (0.0, Status::Exact));
+    assert_eq!(status::<STANDARD>(b"-0.000", &OPTIONS), (-0.0, Status::Exact));
+    assert_eq!(status::<STANDARD>(b"1.5", &OPTIONS), (1.5, Status::Exact));
+    assert_eq!(status::<STANDARD>(b"-0.125e3", &OPTIONS), (-125.0, Status::Exact));
+    assert_eq!(
+        status::<STANDARD>(b"9007199254740992", &OPTIONS),
+        (9007199254740992.0, Status::Exact)
+    );
+    assert_eq!(status::<STANDARD>(b"1e22", &OPTIONS), (1e22, Status::Exact));
+    assert_eq!(status::<STANDARD>(b"0.1", &OPTIONS), (0.1, Status::Inexact));
+    assert_eq!(status::<STANDARD>(b"-0.1", &OPTIONS), (-0.1, Status::Inexact));
+    assert_eq!(status::<STANDARD>(b"1e23", &OPTIONS), (1e23, Status::Inexact));
+    assert_eq!(
+        status::<STANDARD>(b"9007199254740993", &OPTIONS),
+        (9007199254740992.0, Status::Inexact)
+    );
+
+    // Many digits, with only trailing zeros or a non-zero digit.
+    let exact = b"1.5000000000000000000000000000000000000000000000000000000000000000";
+    assert_eq!(status::<STANDARD>(exact, &OPTIONS), (1.5, Status::Exact));
+    let inexact = b"1.5000000000000000000000000000000000000000000000000000000000000001";
+    assert_eq!(status::<STANDARD>(inexact, &OPTIONS), (1.5, Status::Inexact));
+
+    // Special values are always exact.
+    assert!(status::<STANDARD>(b"NaN", &OPTIONS).0.is_nan());
+    assert_eq!(status::<STANDARD>(b"NaN", &OPTIONS).1, Status::Exact);
+    assert_eq!(status::<STANDARD>(b"-inf", &OPTIONS), (f64::NEG_INFINITY, Status::Exact));
+
+    // Errors are the same as when parsing a float.
+    assert_eq!(parse_with_status::<f64, STANDARD>(b"", &OPTIONS), Err(Error::Empty(0)));
+    assert_eq!(parse_with_status::<f64, STANDARD>(b"1.2 ", &OPTIONS), Err(Error::InvalidDigit(3)));
+}
+
+#[test]
+fn parse_with_status_limits_test() {
+    // Underflow.
+    assert_eq!(status::<STANDARD>(b"5e-324", &OPTIONS), (5e-324, Status::Underflow));
+    assert_eq!(status::<STANDARD>(b"1e-400", &OPTIONS), (0.0, Status::Underflow));
+    assert_eq!(status::<STANDARD>(b"-1e-400", &OPTIONS), (-0.0, Status::Underflow));
+    assert_eq!(status::<STANDARD>(b"2.2250738585072014e-308", &OPTIONS).1, Status::Inexact);
+    assert_eq!(status::<STANDARD>(b"2.2250738585072013e-308", &OPTIONS).1, Status::Underflow);
+    let denormal = b"4.940656458412465441765687928682213723651e-324";
+    assert_eq!(status::<STANDARD>(denormal, &OPTIONS), (5e-324, Status::Underflow));
+
+    // Overflow.
+    assert_eq!(status::<STANDARD>(b"1e400", &OPTIONS), (f64::INFINITY, Status::Overflow));
+    assert_eq!(status::<STANDARD>(b"-1e400", &OPTIONS), (f64::NEG_INFINITY, Status::Overflow));
+    assert_eq!(
+        status::<STANDARD>(b"1.7976931348623158e308", &OPTIONS),
+        (f64::MAX, Status::Inexact)
+    );
+
+    // Rounding toward zero only overflows above the largest float, with
+    // an unbounded exponent.
+    let options = Options::builder().rounding(Rounding::TowardZero).build_strict();
+    assert_eq!(status::<STANDARD>(b"1e400", &options), (f64::MAX, Status::Overflow));
+    assert_eq!(
+        status::<STANDARD>(b"1.7976931348623158e308", &options),
+        (f64::MAX, Status::Inexact)
+    );
+    assert_eq!(
+        status::<STANDARD>(b"1.797693134862315908e308", &options),
+        (f64::MAX, Status::Overflow)
+    );
+    assert_eq!(status::<STANDARD>(b"1e-400", &options), (0.0, Status::Underflow));
+    let options = Options::builder().rounding(Rounding::TowardPositive).build_strict();
+    assert_eq!(status::<STANDARD>(b"1e-400", &options), (5e-324, Status::Underflow));
+    assert_eq!(status::<STANDARD>(b"0.1", &options), (0.1, Status::Inexact));
+
+    // Exponents far outside the float range.
+    assert_eq!(status::<STANDARD>(b"1e-5000", &options), (5e-324, Status::Underflow));
+    assert_eq!(status::<STANDARD>(b"1e20000", &options), (f64::INFINITY, Status::Overflow));
+    let options = Options::builder().rounding(Rounding::TowardZero).build_strict();
+    assert_eq!(status::<STANDARD>(b"1e20000", &options), (f64::MAX, Status::Overflow));
+    assert_eq!(status::<STANDARD>(b"-1e-5000", &options), (-0.0, Status::Underflow));
+    assert_eq!(status::<STANDARD>(b"1e20000", &OPTIONS), (f64::INFINITY, Status::Overflow));
+    assert_eq!(status::<STANDARD>(b"1e-5000", &OPTIONS), (0.0, Status::Underflow));
+}
+
+#[test]
+fn parse_partial_with_status_test() {
+    let parse = |x| parse_partial_with_status::<f32, STANDARD>(x, &OPTIONS);
+    assert_eq!(parse(b"0.25,"), Ok((0.25, Status::Exact, 4)));
+    assert_eq!(parse(b"0.1,"), Ok((0.1, Status::Inexact, 3)));
+    assert_eq!(parse(b"1e39 "), Ok((f32::INFINITY, Status::Overflow, 4)));
+    assert_eq!(parse(b"1e-40 "), Ok((1e-40, Status::Underflow, 5)));
+    assert_eq!(parse(b"16777217"), Ok((16777216.0, Status::Inexact, 8)));
+    assert_eq!(parse(b"-infx"), Ok((f32::NEG_INFINITY, Status::Exact, 4)));
+    assert_eq!(parse(b"x"), Err(Error::EmptyMantissa(0)));
+}
+
+#[test]
+#[cfg(feature = "power-of-two")]
+fn parse_with_status_binary_test() {
+    const FORMAT: u128 = NumberFormatBuilder::from_radix(16);
+    let options = Options::builder().exponent(b'^').build_strict();
+    assert_eq!(status::<FORMAT>(b"1.8", &options), (1.5, Status::Exact));
+    assert_eq!(status::<FORMAT>(b"1.00000000000001", &options), (1.0, Status::Inexact));
+    let above = b"20000000000001.000000000000000000000000000000000000000";
+    assert_eq!(status::<FORMAT>(above, &options), (9007199254740992.0, Status::Inexact));
+}
+
+#[test]
+#[cfg(feature = "radix")]
+fn parse_with_status_radix_test() {
+    const FORMAT: u128 = NumberFormatBuilder::from_radix(3);
+    let options = Options::builder().exponent(b'^').build_strict();
+    assert_eq!(status::<FORMAT>(b"0.1", &options), (0.3333333333333333, Status::Inexact));
+    assert_eq!(status::<FORMAT>(b"-1^10", &options), (-27.0, Status::Exact));
+}
+
+#[test]
+#[cfg(feature = "f16")]
+fn parse_with_status_f16_test() {
+    use lexical_parse_float::{bf16, f16};
+
+    let parse = |x: &[u8], options| parse_with_status::<f16, STANDARD>(x, options).unwrap();
+    assert_eq!(parse(b"0.5", &OPTIONS), (f16::from_f32(0.5), Status::Exact));
+    assert_eq!(parse(b"0.1", &OPTIONS).1, Status::Inexact);
+    assert_eq!(parse(b"65504", &OPTIONS), (f16::MAX, Status::Exact));
+    assert_eq!(parse(b"65519", &OPTIONS), (f16::MAX, Status::Inexact));
+    assert_eq!(parse(b"65520", &OPTIONS), (f16::INFINITY, Status::Overflow));
+    assert_eq!(parse(b"1e-5", &OPTIONS).1, Status::Underflow);
+    assert_eq!(parse(b"6.103515625e-5", &OPTIONS).1, Status::Exact);
+
+    // Exact as an `f32`, but not as an `f16`.
+    assert_eq!(parse(b"296.125", &OPTIONS).1, Status::Inexact);
+
+    let options = Options::builder().rounding(Rounding::TowardZero).build_strict();
+    assert_eq!(parse(b"65535", &options), (f16::MAX, Status::Inexact));
+    assert_eq!(parse(b"65536", &options), (f16::MAX, Status::Overflow));
+
+    let parse = |x: &[u8]| parse_with_status::<bf16, STANDARD>(x, &OPTIONS).unwrap();
+    assert_eq!(parse(b"0.5"), (bf16::from_f32(0.5), Status::Exact));
+    assert_eq!(parse(b"0.1").1, Status::Inexact);
+    assert_eq!(parse(b"1e39"), (bf16::INFINITY, Status::Overflow));
+}
+
+#[test]
+#[cfg(feature = "f128")]
+fn parse_with_status_f128_test() {
+    use lexical_parse_float::f128;
+
+    let parse = |x: &[u8]| parse_with_status::<f128, STANDARD>(x, &OPTIONS).unwrap().1;
+    assert_eq!(parse(b"0.5"), Status::Exact);
+    assert_eq!(parse(b"0.1"), Status::Inexact);
+    assert_eq!(parse(b"9007199254740993"), Status::Exact);
+    assert_eq!(parse(b"1e-320"), Status::Inexact);
+    assert_eq!(parse(b"1e-4940"), Status::Underflow);
+    assert_eq!(parse(b"1e-5000"), Status::Underflow);
+    assert_eq!(parse(b"1e5000"), Status::Overflow);
+    assert_eq!(parse(b"-inf"), Status::Exact);
+}
@@ -0,0 +1,104 @@
+use lexical_parse_float::stream::{Status, StreamParser};
+use lexical_parse_float::{FromLexical, FromLexicalWithOptions, Options};
+use lexical_util::error::Error;
+use lexical_util::num::Float;
+
+/// Parse the input split at every index, and check the result matches
+/// parsing the input as a single slice.
+fn check_split<T>(input: &[u8])
+where
+    T: Float + FromLexical + FromLexicalWithOptions<Options = Options>,
+{
+    // Compare the bits, since NaN is not equal to itself.
+    let expected = T::from_lexical_partial(input).map(|(value, count)| (value.to_bits(), count));
+    for index in 0..=input.len() {
+        let mut buffer = [0u8; 128];
+        let mut parser = StreamParser::<T>::new(&mut buffer);
+        let (first, second) = input.split_at(index);
+        let result = match parser.push(first) {
+            Ok(Status::Complete(value, count)) => Ok((value, count)),
+            Ok(Status::Incomplete) => match parser.push(second) {
+                Ok(Status::Complete(value, count)) => Ok((value, count)),
+                Ok(Status::Incomplete) => parser.finish(),
+                Err(error) => Err(error),
+            },
+            Err(error) => Err(error),
+        };
+        let result = result.map(|(value, count)| (value.to_bits(), count));
+        assert_eq!(result, expected, "index={}", index);
+    }
+}
+
+#[test]
+fn split_test() {
+    check_split::<f64>(b"1.2345e-10,");
+    check_split::<f64>(b"-1.2345E+10");
+    check_split::<f64>(b"123456789012345678901234567890.5 ");
+    check_split::<f32>(b"0.1,0.2");
+    check_split::<f64>(b"1e");
+    check_split::<f64>(b"1e+,");
+    check_split::<f64>(b".e1,");
+    check_split::<f64>(b"-infinity,");
+    check_split::<f64>(b"inf,");
+    check_split::<f64>(b"infI,9");
+    check_split::<f64>(b"NaN ");
+    check_split::<f64>(b"na,");
+    check_split::<f64>(b"");
+}
+
+#[test]
+fn chunks_test() {
+    let mut buffer = [0u8; 32];
+    let mut parser = StreamParser::<f64>::new(&mut buffer);
+    assert_eq!(parser.push(b"-"), Ok(Status::Incomplete));
+    assert_eq!(parser.push(b"1."), Ok(Status::Incomplete));
+    assert_eq!(parser.push(b"5"), Ok(Status::Incomplete));
+    assert_eq!(parser.buffered(), b"-1.5");
+    assert_eq!(parser.push(b";2"), Ok(Status::Complete(-1.5, 4)));
+    assert!(parser.buffered().is_empty());
+
+    // Special values may continue, even if a shorter string is valid.
+    assert_eq!(parser.push(b"in"), Ok(Status::Incomplete));
+    assert_eq!(parser.push(b"fi"), Ok(Status::Incomplete));
+    assert_eq!(parser.push(b"nity"), Ok(Status::Incomplete));
+    assert_eq!(parser.finish(), Ok((f64::INFINITY, 8)));
+    assert_eq!(parser.push(b"inf"), Ok(Status::Incomplete));
+    assert_eq!(parser.push(b","), Ok(Status::Complete(f64::INFINITY, 3)));
+
+    // The number may end before the last chunk.
+    assert_eq!(parser.push(b"infI"), Ok(Status::Incomplete));
+    assert_eq!(parser.push(b",9"), Ok(Status::Complete(f64::INFINITY, 3)));
+    assert_eq!(parser.push(b"-infinit"), Ok(Status::Incomplete));
+    assert_eq!(parser.push(b"x"), Ok(Status::Complete(f64::NEG_INFINITY, 4)));
+
+    // Errors use the index from the start of the number.
+    assert_eq!(parser.push(b"12.5e"), Ok(Status::Incomplete));
+    assert_eq!(parser.push(b"+x"), Err(Error::EmptyExponent(6)));
+    assert_eq!(parser.push(b"inx"), Err(Error::EmptyMantissa(0)));
+}
+
+#[test]
+fn buffer_full_test() {
+    // One byte of the buffer is reserved.
+    let mut buffer = [0u8; 5];
+    let mut parser = StreamParser::<f64>::new(&mut buffer);
+    assert_eq!(parser.push(b"1.25"), Ok(Status::Incomplete));
+    assert_eq!(parser.push(b"5"), Err(Error::BufferFull(4)));
+    assert_eq!(parser.push(b"1.5,"), Ok(Status::Complete(1.5, 3)));
+}
+
+#[test]
+#[cfg(feature = "format")]
+fn format_test() {
+    use lexical_util::format::NumberFormatBuilder;
+
+    const FORMAT: u128 = NumberFormatBuilder::new()
+        .digit_separator(core::num::NonZeroU8::new(b'_'))
+        .integer_internal_digit_separator(true)
+        .build_strict();
+    const OPTIONS: Options = Options::new();
+    let mut buffer = [0u8; 32];
+    let mut parser = StreamParser::<f64, FORMAT>::with_options(&mut buffer, &OPTIONS);
+    assert_eq!(parser.push(b"1_"), Ok(Status::Incomplete));
+    assert_eq!(parser.push(b"000.5 "), Ok(Status::Complete(1000.5, 7)));
+}
@@ -39,6 +39,7 @@ use lexical_util::iterator::{AsBytes, Bytes, DigitsIter, Iter};
 use lexical_util::num::{as_cast, Integer};
 use lexical_util::result::Result;
 
+use crate::float;
 use crate::Options;
 
 // HELPERS
@@ -462,6 +463,51 @@ macro_rules! parse_1digit_checked {
     }};
 }
 
+/// Run a loop where the integer must be within caller-supplied bounds.
+///
+/// This is the same as [`parse_1digit_checked`], but stops at the first
+/// digit where the value is larger than `max` or smaller than `min`.
+///
+/// * `value` - The current parsed value.
+/// * `iter` - An iterator over all bytes in the input.
+/// * `start_index` - The offset where parsing started.
+/// * `invalid_digit` - Behavior when an invalid digit is found.
+/// * `is_negative` - If the value is negative.
+/// * `min` - The smallest valid value.
+/// * `max` - The largest valid value.
+macro_rules! parse_1digit_bounded {
+    (
+        $value:ident,
+        $iter:ident,
+        $start_index:ident,
+        $invalid_digit:ident,
+        $is_negative:ident,
+        $min:ident,
+        $max:ident
+    ) => {{
+        let radix = NumberFormat::<FORMAT>::MANTISSA_RADIX;
+        while let Some(&c) = $iter.next() {
+            let digit = match char_to_digit_const(c, radix) {
+                Some(v) => v,
+                None => fmt_invalid_digit!($value, $iter, c, $start_index, $invalid_digit, true),
+            };
+            let scaled = $value.checked_mul(as_cast(radix));
+            $value = match (scaled, $is_negative) {
+                (Some(x), false) => match x.checked_add(as_cast(digit)) {
+                    Some(value) if value <= $max => value,
+                    _ => into_error!(Overflow, $iter.cursor() - 1),
+                },
+                (Some(x), true) => match x.checked_sub(as_cast(digit)) {
+                    Some(value) if value >= $min => value,
+                    _ => into_error!(Underflow, $iter.cursor() - 1),
+                },
+                (None, false) => into_error!(Overflow, $iter.cursor() - 1),
+                (None, true) => into_error!(Underflow, $iter.cursor() - 1),
+            }
+        }
+    }};
+}
+
 // OVERALL DIGITS
 // --------------
 
@@ -567,56 +613,50 @@ macro_rules! parse_digits_checked {
     }};
 }
 
-// ALGORITHM
+// PREFIX
+// ------
 
-/// Generic algorithm for both partial and complete parsers.
+/// Parse the sign, base prefix, and leading zeros before the digits.
 ///
-/// * `invalid_digit` - Behavior on finding an invalid digit.
+/// This is shared by the fixed-width and arbitrary-precision parsers, so
+/// both accept the same syntax. This defines the `byte`, `iter`,
+/// `is_negative` and `start_index` variables, and returns early with
+/// `value` on empty input or invalid leading zeros.
+///
+/// * `bytes` - The bytes to parse.
+/// * `byte` - The name of the bytes iterator.
+/// * `iter` - The name of the integer digits iterator.
+/// * `is_negative` - The name for if the value is negative.
+/// * `start_index` - The name for the offset where parsing started.
+/// * `value` - The value to return on early exit.
 /// * `into_ok` - Behavior when returning a valid value.
 /// * `invalid_digit` - Behavior when an invalid digit is found.
-/// * `no_multi_digit` - If to disable multi-digit optimizations.
-/// * `is_partial` - If the parser is a partial parser.
 #[rustfmt::skip]
-macro_rules! algorithm {
-($bytes:ident, $into_ok:ident, $invalid_digit:ident, $no_multi_digit:expr) => {{
-    // WARNING:
-    // --------
-    // None of this code can be changed for optimization reasons.
-    // Do not change it without benchmarking every change.
-    //  1. You cannot use the `NoSkipIterator` in the loop,
-    //      you must either return a subslice (indexing)
-    //      or increment outside of the loop.
-    //      Failing to do so leads to numerous more, unnecessary
-    //      conditional move instructions, killing performance.
-    //  2. Return a 0 or 1 shift, and indexing unchecked outside
-    //      of the loop is slightly faster.
-    //  3. Partial and complete parsers cannot be efficiently done
-    //      together.
-    //
-    // If you try to refactor without carefully monitoring benchmarks or
-    // assembly generation, please log the number of wasted hours: so
-    //  16 hours so far.
-
-    // With `step_by_unchecked`, this is sufficiently optimized.
-    // Removes conditional paths, to, which simplifies maintenance.
-    // The skip version of the iterator automatically coalesces to
-    // the no-skip iterator.
-    let mut byte = $bytes.bytes::<FORMAT>();
-    let radix = NumberFormat::<FORMAT>::MANTISSA_RADIX;
-
-    let is_negative = parse_sign::<T, FORMAT>(&mut byte)?;
-    let mut iter = byte.integer_iter();
-    if iter.is_buffer_empty() {
+macro_rules! parse_prefix {
+(
+    $bytes:ident,
+    $byte:ident,
+    $iter:ident,
+    $is_negative:ident,
+    $start_index:ident,
+    $value:expr,
+    $into_ok:ident,
+    $invalid_digit:ident
+) => {
+    let mut $byte = $bytes.bytes::<FORMAT>();
+    let $is_negative = parse_sign::<T, FORMAT>(&mut $byte)?;
+    let mut $iter = $byte.integer_iter();
+    if $iter.is_buffer_empty() {
         // Our default format **ALWAYS** requires significant digits, however,
         // we can have cases where we don
         #[cfg(not(feature = "format"))]
-        into_error!(Empty, iter.cursor());
+        into_error!(Empty, $iter.cursor());
 
         #[cfg(feature = "format")]
         if required_digits!() {
-            into_error!(Empty, iter.cursor());
+            into_error!(Empty, $iter.cursor());
         } else {
-            $into_ok!(T::ZERO, iter.cursor(), 0)
+            $into_ok!($value, $iter.cursor(), 0)
         }
     }
 
@@ -624,7 +664,7 @@ macro_rules! algorithm {
     // We only want to skip the zeros if have either require a base prefix or we don't
     // allow integer leading zeros, since the skip is expensive
     #[allow(unused_variables, unused_mut)]
-    let mut start_index = iter.cursor();
+    let mut $start_index = $iter.cursor();
     #[cfg_attr(not(feature = "format"), allow(unused_variables))]
     let format = NumberFormat::<FORMAT> {};
     #[cfg(feature = "format")]
@@ -632,8 +672,8 @@ macro_rules! algorithm {
         // Skip any leading zeros. We want to do our check if it can't possibly overflow after.
         // For skipping digit-based formats, this approximation is a way over estimate.
         // NOTE: Skipping zeros is **EXPENSIVE* so we skip that without our format feature
-        let zeros = iter.skip_zeros();
-        start_index += zeros;
+        let zeros = $iter.skip_zeros();
+        $start_index += zeros;
 
         // Now, check to see if we have a valid base prefix.
         let mut is_prefix = false;
@@ -641,12 +681,12 @@ macro_rules! algorithm {
         if base_prefix != 0 && zeros == 1 {
             // Check to see if the next character is the base prefix.
             // We must have a format like `0x`, `0d`, `0o`. Note:
-            if iter.read_if_value(base_prefix, format.case_sensitive_base_prefix()).is_some() {
+            if $iter.read_if_value(base_prefix, format.case_sensitive_base_prefix()).is_some() {
                 is_prefix = true;
-                if iter.is_buffer_empty() {
-                    into_error!(Empty, iter.cursor());
+                if $iter.is_buffer_empty() {
+                    into_error!(Empty, $iter.cursor());
                 } else {
-                    start_index += 1;
+                    $start_index += 1;
                 }
             }
         }
@@ -656,21 +696,66 @@ macro_rules! algorithm {
         // first is 0, and the next is not a valid digit.
         if !is_prefix && format.no_integer_leading_zeros() && zeros != 0 {
             // Cannot have a base prefix and no leading zeros.
-            let index = iter.cursor() - zeros;
+            let index = $iter.cursor() - zeros;
             if zeros > 1 {
                 into_error!(InvalidLeadingZeros, index);
             }
             // NOTE: Zeros has to be 0 here, so our index == 1 or 2 (depending on sign)
-            match iter.peek().map(|&c| char_to_digit_const(c, format.radix())) {
+            match $iter.peek().map(|&c| char_to_digit_const(c, format.radix())) {
                 // Valid digit, we have an invalid value.
                 Some(Some(_)) => into_error!(InvalidLeadingZeros, index),
                 // Have a non-digit character that follows.
-                Some(None) => $invalid_digit!(<T>::ZERO, iter.cursor() + 1, iter.current_count()),
+                Some(None) => $invalid_digit!($value, $iter.cursor() + 1, $iter.current_count()),
                 // No digits following, has to be ok
-                None => $into_ok!(<T>::ZERO, index, iter.current_count()),
+                None => $into_ok!($value, index, $iter.current_count()),
             };
         }
     }
+};
+}
+
+#[cfg(all(feature = "bigint", feature = "format"))]
+pub(crate) use required_digits;
+#[cfg(feature = "bigint")]
+pub(crate) use {fmt_invalid_digit, into_error, parse_prefix};
+
+// ALGORITHM
+
+/// Generic algorithm for both partial and complete parsers.
+///
+/// * `invalid_digit` - Behavior on finding an invalid digit.
+/// * `into_ok` - Behavior when returning a valid value.
+/// * `invalid_digit` - Behavior when an invalid digit is found.
+/// * `no_multi_digit` - If to disable multi-digit optimizations.
+/// * `is_partial` - If the parser is a partial parser.
+/// * `bounds` - The optional inclusive bounds for the value.
+#[rustfmt::skip]
+macro_rules! algorithm {
+($bytes:ident, $into_ok:ident, $invalid_digit:ident, $no_multi_digit:expr, $bounds:expr) => {{
+    // WARNING:
+    // --------
+    // None of this code can be changed for optimization reasons.
+    // Do not change it without benchmarking every change.
+    //  1. You cannot use the `NoSkipIterator` in the loop,
+    //      you must either return a subslice (indexing)
+    //      or increment outside of the loop.
+    //      Failing to do so leads to numerous more, unnecessary
+    //      conditional move instructions, killing performance.
+    //  2. Return a 0 or 1 shift, and indexing unchecked outside
+    //      of the loop is slightly faster.
+    //  3. Partial and complete parsers cannot be efficiently done
+    //      together.
+    //
+    // If you try to refactor without carefully monitoring benchmarks or
+    // assembly generation, please log the number of wasted hours: so
+    //  16 hours so far.
+
+    // With `step_by_unchecked`, this is sufficiently optimized.
+    // Removes conditional paths, to, which simplifies maintenance.
+    // The skip version of the iterator automatically coalesces to
+    // the no-skip iterator.
+    let radix = NumberFormat::<FORMAT>::MANTISSA_RADIX;
+    parse_prefix!($bytes, byte, iter, is_negative, start_index, T::ZERO, $into_ok, $invalid_digit);
 
     // shorter strings cannot possibly overflow so a great optimization
     let overflow_digits = T::overflow_digits(radix);
@@ -684,9 +769,12 @@ macro_rules! algorithm {
     //      culminates in **way** slower performance overall for simple
     //      integers, and no improvement for large integers.
     let mut value = T::ZERO;
-    if cannot_overflow && is_negative {
+    let bounds: Option<(T, T)> = $bounds;
+    if let Some((min, max)) = bounds {
+        parse_1digit_bounded!(value, iter, start_index, $invalid_digit, is_negative, min, max);
+    } else if cannot_overflow && is_negative {
         parse_digits_unchecked!(value, iter, wrapping_sub, start_index, $invalid_digit, $no_multi_digit, true);
-    } if cannot_overflow {
+    } else if cannot_overflow {
         parse_digits_unchecked!(value, iter, wrapping_add, start_index, $invalid_digit, $no_multi_digit, true);
     } else if is_negative {
         parse_digits_checked!(value, iter, checked_sub, wrapping_sub, start_index, $invalid_digit, Underflow, $no_multi_digit, overflow_digits);
@@ -704,7 +792,10 @@ pub fn algorithm_complete<T, const FORMAT: u128>(bytes: &[u8], options: &Options
 where
     T: Integer,
 {
-    algorithm!(bytes, into_ok_complete, invalid_digit_complete, options.get_no_multi_digit())
+    if options.get_float_syntax() {
+        return float::parse_complete::<T, FORMAT>(bytes, options, None);
+    }
+    algorithm!(bytes, into_ok_complete, invalid_digit_complete, options.get_no_multi_digit(), None)
 }
 
 /// Algorithm for the partial parser.
@@ -716,5 +807,96 @@ pub fn algorithm_partial<T, const FORMAT: u128>(
 where
     T: Integer,
 {
-    algorithm!(bytes, into_ok_partial, invalid_digit_partial, options.get_no_multi_digit())
+    if options.get_float_syntax() {
+        return float::parse_partial::<T, FORMAT>(bytes, options, None);
+    }
+    algorithm!(bytes, into_ok_partial, invalid_digit_partial, options.get_no_multi_digit(), None)
+}
+
+/// Check a parsed value is within the inclusive bounds.
+///
+/// Values with too many digits are rejected while parsing the digits,
+/// so any remaining value out of bounds has too few digits, and we
+/// report the index of the first digit.
+#[inline(always)]
+fn check_bounds<T, const FORMAT: u128>(bytes: &[u8], value: T, min: T, max: T) -> Result<T>
+where
+    T: Integer,
+{
+    if value >= min && value <= max {
+        return Ok(value);
+    }
+    let mut byte = bytes.bytes::<FORMAT>();
+    parse_sign::<T, FORMAT>(&mut byte)?;
+    if value < min {
+        Err(Error::Underflow(byte.cursor()))
+    } else {
+        Err(Error::Overflow(byte.cursor()))
+    }
+}
+
+/// Algorithm for the complete parser with caller-supplied bounds.
+#[inline(always)]
+fn bounded_complete<T, const FORMAT: u128>(
+    bytes: &[u8],
+    options: &Options,
+    min: T,
+    max: T,
+) -> Result<T>
+where
+    T: Integer,
+{
+    if options.get_float_syntax() {
+        return float::parse_complete::<T, FORMAT>(bytes, options, Some((min, max)));
+    }
+    algorithm!(bytes, into_ok_complete, invalid_digit_complete, true, Some((min, max)))
+}
+
+/// Algorithm for the partial parser with caller-supplied bounds.
+#[inline(always)]
+fn bounded_partial<T, const FORMAT: u128>(
+    bytes: &[u8],
+    options: &Options,
+    min: T,
+    max: T,
+) -> Result<(T, usize)>
+where
+    T: Integer,
+{
+    if options.get_float_syntax() {
+        return float::parse_partial::<T, FORMAT>(bytes, options, Some((min, max)));
+    }
+    algorithm!(bytes, into_ok_partial, invalid_digit_partial, true, Some((min, max)))
+}
+
+/// Algorithm for the complete parser, checking the value is within
+/// the inclusive bounds.
+#[cfg_attr(not(feature = "compact"), inline(always))]
+pub fn algorithm_bounded_complete<T, const FORMAT: u128>(
+    bytes: &[u8],
+    options: &Options,
+    min: T,
+    max: T,
+) -> Result<T>
+where
+    T: Integer,
+{
+    let value = bounded_complete::<T, FORMAT>(bytes, options, min, max)?;
+    check_bounds::<T, FORMAT>(bytes, value, min, max)
+}
+
+/// Algorithm for the partial parser, checking the value is within
+/// the inclusive bounds.
+#[cfg_attr(not(feature = "compact"), inline(always))]
+pub fn algorithm_bounded_partial<T, const FORMAT: u128>(
+    bytes: &[u8],
+    options: &Options,
+    min: T,
+    max: T,
+) -> Result<(T, usize)>
+where
+    T: Integer,
+{
+    let (value, count) = bounded_partial::<T, FORMAT>(bytes, options, min, max)?;
+    Ok((check_bounds::<T, FORMAT>(bytes, value, min, max)?, count))
 }
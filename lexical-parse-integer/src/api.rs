@@ -2,12 +2,33 @@
 
 #![doc(hidden)]
 
-use lexical_util::format::{NumberFormat, STANDARD};
+use lexical_util::error::Error;
+use lexical_util::format::{is_valid_options_punctuation, NumberFormat, STANDARD};
+use lexical_util::result::Result;
 use lexical_util::{from_lexical, from_lexical_with_options};
 
 use crate::options::{Options, STANDARD as DEFAULT_OPTIONS};
 use crate::parse::ParseInteger;
 
+/// Validate the number format and options for parsing an integer.
+#[inline(always)]
+pub(crate) fn check_format<const FORMAT: u128>(options: &Options) -> Result<()> {
+    let format = NumberFormat::<{ FORMAT }> {};
+    if !format.is_valid() {
+        Err(format.error())
+    } else if options.get_float_syntax()
+        && !is_valid_options_punctuation(
+            FORMAT,
+            options.get_exponent(),
+            options.get_decimal_point(),
+        )
+    {
+        Err(Error::InvalidPunctuation)
+    } else {
+        Ok(())
+    }
+}
+
 /// Implement `FromLexical` for numeric type.
 ///
 /// Need to inline these, otherwise code generation is sub-optimal.
@@ -44,6 +65,10 @@ macro_rules! integer_from_lexical {
                 let format = NumberFormat::<{ FORMAT }> {};
                 if !format.is_valid() {
                     return Err(format.error());
+                } else if options.get_float_syntax()
+                    && !is_valid_options_punctuation(FORMAT, options.get_exponent(), options.get_decimal_point())
+                {
+                    return Err(Error::InvalidPunctuation);
                 }
                 Self::parse_complete::<FORMAT>(bytes, options)
             }
@@ -57,6 +82,10 @@ macro_rules! integer_from_lexical {
                 let format = NumberFormat::<{ FORMAT }> {};
                 if !format.is_valid() {
                     return Err(format.error());
+                } else if options.get_float_syntax()
+                    && !is_valid_options_punctuation(FORMAT, options.get_exponent(), options.get_decimal_point())
+                {
+                    return Err(Error::InvalidPunctuation);
                 }
                 Self::parse_partial::<FORMAT>(bytes, options)
             }
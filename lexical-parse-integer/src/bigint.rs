@@ -0,0 +1,203 @@
+//! Parse arbitrary-precision integers.
+//!
+//! This validates the integer with the same number format rules as the
+//! integer parsers, collecting the digits, and then converts the digits
+//! to limbs with a subquadratic algorithm, so integers of any length can
+//! be parsed without overflow.
+
+#![cfg(feature = "bigint")]
+
+use lexical_util::bigint::{BigInt, BigUint};
+use lexical_util::digit::char_to_digit_const;
+use lexical_util::error::Error;
+use lexical_util::format::NumberFormat;
+#[cfg(feature = "format")]
+use lexical_util::iterator::DigitsIter;
+use lexical_util::iterator::{AsBytes, Iter};
+use lexical_util::num::Integer;
+use lexical_util::result::Result;
+
+#[cfg(feature = "format")]
+use crate::algorithm::required_digits;
+use crate::algorithm::{fmt_invalid_digit, into_error, parse_prefix, parse_sign};
+use crate::api::check_format;
+use crate::options::Options;
+
+/// Return if the integer is negative and the number of processed bytes.
+macro_rules! into_ok_big {
+    ($is_negative:expr, $index:expr, $count:expr) => {{
+        #[cfg(not(feature = "format"))]
+        return Ok(($is_negative, $index));
+
+        #[cfg(feature = "format")]
+        if required_digits!() && $count == 0 {
+            into_error!(Empty, $index);
+        } else {
+            return Ok(($is_negative, $index));
+        }
+    }};
+}
+
+/// Return an error, or the processed bytes for a partial parser, upon an
+/// invalid digit.
+macro_rules! invalid_digit_big {
+    ($is_negative:expr, $index:expr, $count:expr) => {{
+        if IS_PARTIAL {
+            into_ok_big!($is_negative, $index - 1, $count)
+        } else {
+            into_error!(InvalidDigit, $index - 1)
+        }
+    }};
+}
+
+/// Collect the digit values of an integer.
+///
+/// This uses the same sign, base prefix and leading zero handling as the
+/// integer parsers. The `T` type is only used to determine if negative
+/// values are valid. Returns if the integer is negative and the number of
+/// processed bytes.
+fn parse_digits<T: Integer, const FORMAT: u128, const IS_PARTIAL: bool>(
+    bytes: &[u8],
+    digits: &mut Vec<u8>,
+) -> Result<(bool, usize)> {
+    parse_prefix!(
+        bytes,
+        byte,
+        iter,
+        is_negative,
+        start_index,
+        is_negative,
+        into_ok_big,
+        invalid_digit_big
+    );
+
+    let radix = NumberFormat::<FORMAT>::MANTISSA_RADIX;
+    while let Some(&c) = iter.next() {
+        let digit = match char_to_digit_const(c, radix) {
+            Some(v) => v,
+            None => fmt_invalid_digit!(is_negative, iter, c, start_index, invalid_digit_big, true),
+        };
+        digits.push(digit as u8);
+    }
+
+    into_ok_big!(is_negative, iter.buffer_length(), iter.current_count())
+}
+
+/// Parse an unsigned integer of any length.
+#[inline(always)]
+fn parse_unsigned<const FORMAT: u128, const IS_PARTIAL: bool>(
+    bytes: &[u8],
+    options: &Options,
+) -> Result<(BigUint, usize)> {
+    check_format::<FORMAT>(options)?;
+    let mut digits = Vec::new();
+    let (_, count) = parse_digits::<u64, FORMAT, IS_PARTIAL>(bytes, &mut digits)?;
+    let radix = NumberFormat::<{ FORMAT }>::MANTISSA_RADIX;
+    Ok((BigUint::from_digits(&digits, radix), count))
+}
+
+/// Parse a signed integer of any length.
+#[inline(always)]
+fn parse_signed<const FORMAT: u128, const IS_PARTIAL: bool>(
+    bytes: &[u8],
+    options: &Options,
+) -> Result<(BigInt, usize)> {
+    check_format::<FORMAT>(options)?;
+    let mut digits = Vec::new();
+    let (is_negative, count) = parse_digits::<i64, FORMAT, IS_PARTIAL>(bytes, &mut digits)?;
+    let radix = NumberFormat::<{ FORMAT }>::MANTISSA_RADIX;
+    Ok((BigInt::from_parts(is_negative, BigUint::from_digits(&digits, radix)), count))
+}
+
+/// Parse a complete unsigned integer of any length.
+///
+/// This validates the integer exactly like
+/// [`from_lexical_with_options`][crate::FromLexicalWithOptions::from_lexical_with_options],
+/// but never overflows. Only integer syntax is accepted, so the
+/// [`float_syntax`][crate::OptionsBuilder::float_syntax] option is ignored.
+///
+/// # Examples
+///
+/// ```rust
+/// use lexical_parse_integer::{format, parse_biguint, Options};
+///
+/// const FORMAT: u128 = format::STANDARD;
+/// const OPTIONS: Options = Options::new();
+/// let value = parse_biguint::<FORMAT>(b"340282366920938463463374607431768211456", &OPTIONS).unwrap();
+/// assert_eq!(value.bit_length(), 129);
+/// ```
+#[inline]
+pub fn parse_biguint<const FORMAT: u128>(bytes: &[u8], options: &Options) -> Result<BigUint> {
+    parse_unsigned::<FORMAT, false>(bytes, options).map(|(value, _)| value)
+}
+
+/// Parse an unsigned integer of any length, stopping at the first invalid
+/// character.
+///
+/// Returns the integer and the number of processed bytes. See
+/// [`parse_biguint`] for more details.
+///
+/// # Examples
+///
+/// ```rust
+/// use lexical_parse_integer::{format, parse_partial_biguint, Options};
+///
+/// const FORMAT: u128 = format::STANDARD;
+/// const OPTIONS: Options = Options::new();
+/// let (value, count) = parse_partial_biguint::<FORMAT>(b"18446744073709551616,1", &OPTIONS).unwrap();
+/// assert_eq!(value.to_u128(), Some(1 << 64));
+/// assert_eq!(count, 20);
+/// ```
+#[inline]
+pub fn parse_partial_biguint<const FORMAT: u128>(
+    bytes: &[u8],
+    options: &Options,
+) -> Result<(BigUint, usize)> {
+    parse_unsigned::<FORMAT, true>(bytes, options)
+}
+
+/// Parse a complete signed integer of any length.
+///
+/// See [`parse_biguint`] for more details.
+///
+/// # Examples
+///
+/// ```rust
+/// use lexical_parse_integer::{format, parse_bigint, Options};
+///
+/// const FORMAT: u128 = format::STANDARD;
+/// const OPTIONS: Options = Options::new();
+/// let value = parse_bigint::<FORMAT>(b"-18446744073709551616", &OPTIONS).unwrap();
+/// assert!(value.is_negative());
+/// assert_eq!(value.magnitude().to_u128(), Some(1 << 64));
+/// ```
+#[inline]
+pub fn parse_bigint<const FORMAT: u128>(bytes: &[u8], options: &Options) -> Result<BigInt> {
+    parse_signed::<FORMAT, false>(bytes, options).map(|(value, _)| value)
+}
+
+/// Parse a signed integer of any length, stopping at the first invalid
+/// character.
+///
+/// Returns the integer and the number of processed bytes. See
+/// [`parse_biguint`] for more details.
+///
+/// # Examples
+///
+/// ```rust
+/// use lexical_parse_integer::{format, parse_partial_bigint, Options};
+///
+/// const FORMAT: u128 = format::STANDARD;
+/// const OPTIONS: Options = Options::new();
+/// let (value, count) = parse_partial_bigint::<FORMAT>(b"-12 34", &OPTIONS).unwrap();
+/// assert!(value.is_negative());
+/// assert_eq!(value.magnitude().to_u64(), Some(12));
+/// assert_eq!(count, 3);
+/// ```
+#[inline]
+pub fn parse_partial_bigint<const FORMAT: u128>(
+    bytes: &[u8],
+    options: &Options,
+) -> Result<(BigInt, usize)> {
+    parse_signed::<FORMAT, true>(bytes, options)
+}
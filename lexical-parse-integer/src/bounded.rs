@@ -0,0 +1,148 @@
+//! Parse integers within a caller-supplied range or bit width.
+//!
+//! This uses the same number format validation as the integer parsers,
+//! but checks the value against the bounds while parsing each digit, so
+//! values out of range are rejected at the first offending digit. This
+//! is useful for protocol fields, such as a 12-bit identifier or a port
+//! number, that are narrower than the primitive integer types.
+
+use core::ops::RangeInclusive;
+
+use lexical_util::result::Result;
+
+use crate::api::check_format;
+use crate::options::Options;
+use crate::parse::ParseInteger;
+
+/// Get the inclusive bounds of an integer with `bits` bits.
+///
+/// Signed integers use two's complement, so an `i12` is within
+/// `-2048..=2047`, while a `u12` is within `0..=4095`.
+///
+/// # Panics
+///
+/// Panics if `bits` is 0 or larger than the number of bits in `T`.
+#[inline(always)]
+fn bit_range<T: ParseInteger>(bits: u32) -> RangeInclusive<T> {
+    assert!(bits != 0 && bits as usize <= T::BITS, "invalid number of bits");
+    let shift = (T::BITS - bits as usize) as i32;
+    (T::MIN >> shift)..=(T::MAX >> shift)
+}
+
+/// Parse a complete integer within an inclusive range.
+///
+/// This parses the integer exactly like
+/// [`from_lexical_with_options`][crate::FromLexicalWithOptions::from_lexical_with_options],
+/// but returns [`Error::Overflow`] if the value is larger than the end of
+/// the range, and [`Error::Underflow`] if it is smaller than the start of
+/// the range. The error index is the first digit where the value is
+/// known to be out of range: for values with too many digits, this is the
+/// first digit where the value exceeds the range, otherwise, this is the
+/// first digit of the integer.
+///
+/// # Examples
+///
+/// ```rust
+/// use lexical_parse_integer::{format, parse_in_range, Error, Options};
+///
+/// const FORMAT: u128 = format::STANDARD;
+/// const OPTIONS: Options = Options::new();
+/// assert_eq!(parse_in_range::<u16, FORMAT>(b"8080", 1..=65535, &OPTIONS), Ok(8080));
+/// assert_eq!(parse_in_range::<u32, FORMAT>(b"65536", 1..=65535, &OPTIONS), Err(Error::Overflow(4)));
+/// assert_eq!(parse_in_range::<u64, FORMAT>(b"65536123", 1..=65535, &OPTIONS), Err(Error::Overflow(4)));
+/// assert_eq!(parse_in_range::<u16, FORMAT>(b"0", 1..=65535, &OPTIONS), Err(Error::Underflow(0)));
+/// ```
+#[inline]
+pub fn parse_in_range<T: ParseInteger, const FORMAT: u128>(
+    bytes: &[u8],
+    range: RangeInclusive<T>,
+    options: &Options,
+) -> Result<T> {
+    check_format::<FORMAT>(options)?;
+    T::parse_complete_bounded::<FORMAT>(bytes, options, *range.start(), *range.end())
+}
+
+/// Parse an integer within an inclusive range, stopping at the first
+/// invalid character.
+///
+/// Returns the integer and the number of processed bytes. See
+/// [`parse_in_range`] for more details.
+///
+/// # Examples
+///
+/// ```rust
+/// use lexical_parse_integer::{format, parse_partial_in_range, Options};
+///
+/// const FORMAT: u128 = format::STANDARD;
+/// const OPTIONS: Options = Options::new();
+/// let result = parse_partial_in_range::<u8, FORMAT>(b"23:59", 0..=23, &OPTIONS);
+/// assert_eq!(result, Ok((23, 2)));
+/// ```
+#[inline]
+pub fn parse_partial_in_range<T: ParseInteger, const FORMAT: u128>(
+    bytes: &[u8],
+    range: RangeInclusive<T>,
+    options: &Options,
+) -> Result<(T, usize)> {
+    check_format::<FORMAT>(options)?;
+    T::parse_partial_bounded::<FORMAT>(bytes, options, *range.start(), *range.end())
+}
+
+/// Parse a complete integer that fits in `bits` bits.
+///
+/// Unsigned integers must be within `0..=2^bits - 1`, and signed integers
+/// within `-2^(bits - 1)..=2^(bits - 1) - 1`. See [`parse_in_range`] for
+/// more details.
+///
+/// # Panics
+///
+/// Panics if `bits` is 0 or larger than the number of bits in `T`.
+///
+/// # Examples
+///
+/// ```rust
+/// use lexical_parse_integer::{format, parse_with_bits, Error, Options};
+///
+/// const FORMAT: u128 = format::STANDARD;
+/// const OPTIONS: Options = Options::new();
+/// assert_eq!(parse_with_bits::<u16, FORMAT>(b"4095", 12, &OPTIONS), Ok(4095));
+/// assert_eq!(parse_with_bits::<u16, FORMAT>(b"4096", 12, &OPTIONS), Err(Error::Overflow(3)));
+/// assert_eq!(parse_with_bits::<i16, FORMAT>(b"-2049", 12, &OPTIONS), Err(Error::Underflow(4)));
+/// ```
+#[inline]
+pub fn parse_with_bits<T: ParseInteger, const FORMAT: u128>(
+    bytes: &[u8],
+    bits: u32,
+    options: &Options,
+) -> Result<T> {
+    parse_in_range::<T, FORMAT>(bytes, bit_range(bits), options)
+}
+
+/// Parse an integer that fits in `bits` bits, stopping at the first
+/// invalid character.
+///
+/// Returns the integer and the number of processed bytes. See
+/// [`parse_with_bits`] for more details.
+///
+/// # Panics
+///
+/// Panics if `bits` is 0 or larger than the number of bits in `T`.
+///
+/// # Examples
+///
+/// ```rust
+/// use lexical_parse_integer::{format, parse_partial_with_bits, Options};
+///
+/// const FORMAT: u128 = format::STANDARD;
+/// const OPTIONS: Options = Options::new();
+/// let result = parse_partial_with_bits::<i8, FORMAT>(b"-8,7", 4, &OPTIONS);
+/// assert_eq!(result, Ok((-8, 2)));
+/// ```
+#[inline]
+pub fn parse_partial_with_bits<T: ParseInteger, const FORMAT: u128>(
+    bytes: &[u8],
+    bits: u32,
+    options: &Options,
+) -> Result<(T, usize)> {
+    parse_partial_in_range::<T, FORMAT>(bytes, bit_range(bits), options)
+}
@@ -0,0 +1,372 @@
+//! Parse exact integers written with float syntax.
+//!
+//! This accepts a fraction and an exponent, like `12.0` or `1e3`, as long
+//! as the value is an exact integer, using the float syntax rules from the
+//! number format. This is much slower than the integer algorithms, since it
+//! validates the syntax before parsing any digits, so it is only used if
+//! [`float_syntax`] is enabled.
+//!
+//! [`float_syntax`]: crate::OptionsBuilder::float_syntax
+
+#![doc(hidden)]
+
+use lexical_util::digit::char_to_digit_const;
+use lexical_util::error::Error;
+use lexical_util::format::NumberFormat;
+use lexical_util::iterator::{AsBytes, Bytes, DigitsIter, Iter};
+use lexical_util::num::{as_cast, Integer};
+use lexical_util::result::Result;
+
+use crate::algorithm::parse_sign;
+use crate::Options;
+
+/// The largest exponent we track.
+///
+/// Any non-zero value overflows with a larger exponent, and any non-zero
+/// digit is fractional with a smaller one.
+const MAX_EXPONENT: i64 = 0x1000_0000;
+
+/// The components of a number with valid float syntax.
+struct Syntax<'a, const FORMAT: u128> {
+    /// The bytes, starting at the first integer digit.
+    digits: Bytes<'a, FORMAT>,
+    /// If the number has a decimal point.
+    has_decimal: bool,
+    /// The number of significant digits, including fraction digits.
+    digit_count: usize,
+    /// The exponent, in the mantissa radix, including fraction digits.
+    exponent: i64,
+    /// The index of the first exponent digit.
+    exponent_index: usize,
+    /// The index after the end of the number.
+    end: usize,
+}
+
+/// Consume and count the digits from the iterator.
+#[inline(always)]
+fn count_digits<'a, Iter: DigitsIter<'a>>(mut iter: Iter, radix: u32) -> usize {
+    let mut count = 0;
+    while let Some(&c) = iter.peek() {
+        if char_to_digit_const(c, radix).is_none() {
+            break;
+        }
+        // SAFETY: iter cannot be empty due to `iter.peek()`.
+        unsafe { iter.step_unchecked() };
+        iter.increment_count();
+        count += 1;
+    }
+    count
+}
+
+/// Validate the float syntax of the number, without parsing the digits.
+///
+/// This follows the syntax rules of the float parser, other than special
+/// values, which are never valid integers.
+#[allow(unused_mut)] // reason = "used when format is enabled"
+#[allow(clippy::collapsible_if)] // reason = "more readable uncollapsed"
+fn parse_syntax<'a, const FORMAT: u128, const IS_PARTIAL: bool>(
+    mut byte: Bytes<'a, FORMAT>,
+    options: &Options,
+) -> Result<Syntax<'a, FORMAT>> {
+    let format = NumberFormat::<{ FORMAT }> {};
+    let radix = format.mantissa_radix();
+
+    // INTEGER
+
+    // Check to see if we have a valid base prefix.
+    #[allow(unused_variables)]
+    let mut is_prefix = false;
+    #[cfg(feature = "format")]
+    {
+        let base_prefix = format.base_prefix();
+        let mut iter = byte.integer_iter();
+        if base_prefix != 0 && iter.read_if_value_cased(b'0').is_some() {
+            // Check to see if the next character is the base prefix.
+            is_prefix = true;
+            if iter.read_if_value(base_prefix, format.case_sensitive_base_prefix()).is_some()
+                && iter.is_buffer_empty()
+                && format.required_integer_digits()
+            {
+                return Err(Error::EmptyInteger(iter.cursor()));
+            }
+        }
+    }
+
+    let start = byte.clone();
+    let integer_count = count_digits(byte.integer_iter(), radix);
+    #[cfg(feature = "format")]
+    if format.required_integer_digits() && integer_count == 0 {
+        return Err(Error::EmptyInteger(byte.cursor()));
+    }
+
+    // Check if integer leading zeros are disabled.
+    #[cfg(feature = "format")]
+    if !is_prefix && (format.no_integer_leading_zeros() || format.no_float_leading_zeros()) {
+        if integer_count > 1 && start.as_slice().first() == Some(&b'0') {
+            return Err(Error::InvalidLeadingZeros(start.cursor()));
+        }
+    }
+
+    // FRACTION
+
+    let has_decimal = byte.first_is_cased(options.get_decimal_point());
+    let mut fraction_count = 0;
+    if has_decimal {
+        // SAFETY: byte cannot be empty due to `first_is`
+        unsafe { byte.step_unchecked() };
+        fraction_count = count_digits(byte.fraction_iter(), radix);
+        #[cfg(feature = "format")]
+        if format.required_fraction_digits() && fraction_count == 0 {
+            return Err(Error::EmptyFraction(byte.cursor()));
+        }
+    }
+
+    let has_exponent = byte.first_is(
+        options.get_exponent(),
+        format.case_sensitive_exponent() && cfg!(feature = "format"),
+    );
+    let digit_count = integer_count + fraction_count;
+    if format.required_mantissa_digits() && digit_count == 0 {
+        let any_digits = start.clone().integer_iter().peek().is_some();
+        if has_decimal || has_exponent || !any_digits || IS_PARTIAL {
+            return Err(Error::EmptyMantissa(byte.cursor()));
+        } else {
+            return Err(Error::InvalidDigit(start.cursor()));
+        }
+    }
+
+    // EXPONENT
+
+    let mut exponent = 0_i64;
+    let mut exponent_index = byte.cursor();
+    if has_exponent {
+        // SAFETY: byte cannot be empty due to `first_is` from `has_exponent`.
+        unsafe { byte.step_unchecked() };
+
+        // Check float format syntax checks.
+        #[cfg(feature = "format")]
+        {
+            if format.no_exponent_notation() {
+                return Err(Error::InvalidExponent(byte.cursor() - 1));
+            }
+            if format.no_exponent_without_fraction() && !has_decimal {
+                return Err(Error::ExponentWithoutFraction(byte.cursor() - 1));
+            }
+        }
+
+        let is_negative = crate::parse_sign!(
+            byte,
+            true,
+            format.no_positive_exponent_sign(),
+            format.required_exponent_sign(),
+            InvalidPositiveExponentSign,
+            MissingExponentSign
+        )?;
+        exponent_index = byte.cursor();
+        let before = byte.current_count();
+        let exponent_radix = format.exponent_radix();
+        let mut iter = byte.exponent_iter();
+        while let Some(&c) = iter.peek() {
+            let digit = match char_to_digit_const(c, exponent_radix) {
+                Some(digit) => digit,
+                None => break,
+            };
+            if exponent < MAX_EXPONENT {
+                exponent = exponent * exponent_radix as i64 + digit as i64;
+            }
+            // SAFETY: iter cannot be empty due to `iter.peek()`.
+            unsafe { iter.step_unchecked() };
+            iter.increment_count();
+        }
+        if format.required_exponent_digits() && byte.current_count() - before == 0 {
+            return Err(Error::EmptyExponent(byte.cursor()));
+        }
+        if is_negative {
+            exponent = -exponent;
+        }
+    } else if cfg!(feature = "format") && format.required_exponent_notation() {
+        return Err(Error::MissingExponent(byte.cursor()));
+    }
+
+    // Check to see if we have a valid base suffix.
+    #[cfg(feature = "format")]
+    {
+        let base_suffix = format.base_suffix();
+        if base_suffix != 0 && byte.first_is(base_suffix, format.case_sensitive_base_suffix()) {
+            // SAFETY: safe since `byte.len() >= 1`.
+            unsafe { byte.step_unchecked() };
+        }
+    }
+
+    let end = byte.cursor();
+    if !IS_PARTIAL && end != byte.buffer_length() {
+        return Err(Error::InvalidDigit(end));
+    }
+
+    Ok(Syntax {
+        digits: start,
+        has_decimal,
+        digit_count,
+        exponent: exponent - fraction_count as i64,
+        exponent_index,
+        end,
+    })
+}
+
+/// Parse the significant digits, checking the fractional digits are zero.
+///
+/// * `count` - The number of digits processed so far.
+/// * `kept` - The number of digits that are not fractional.
+/// * `bounds` - The smallest and largest valid values.
+#[inline(always)]
+fn parse_digits<'a, T, Iter>(
+    mut iter: Iter,
+    radix: u32,
+    is_negative: bool,
+    kept: usize,
+    bounds: (T, T),
+    count: &mut usize,
+    value: &mut T,
+) -> Result<()>
+where
+    T: Integer,
+    Iter: DigitsIter<'a>,
+{
+    while let Some(&c) = iter.peek() {
+        let digit = match char_to_digit_const(c, radix) {
+            Some(digit) => digit,
+            None => break,
+        };
+        let index = iter.cursor();
+        if *count < kept {
+            let scaled = value.checked_mul(as_cast(radix));
+            *value = match (scaled, is_negative) {
+                (Some(x), false) => x.checked_add(as_cast(digit)).filter(|&x| x <= bounds.1),
+                (Some(x), true) => x.checked_sub(as_cast(digit)).filter(|&x| x >= bounds.0),
+                (None, _) => None,
+            }
+            .ok_or(if is_negative {
+                Error::Underflow(index)
+            } else {
+                Error::Overflow(index)
+            })?;
+        } else if digit != 0 {
+            return Err(Error::NotAnInteger(index));
+        }
+        *count += 1;
+        // SAFETY: iter cannot be empty due to `iter.peek()`.
+        unsafe { iter.step_unchecked() };
+        iter.increment_count();
+    }
+    Ok(())
+}
+
+/// Parse the value of a number with valid float syntax.
+fn parse_value<T: Integer, const FORMAT: u128>(
+    syntax: Syntax<'_, FORMAT>,
+    is_negative: bool,
+    bounds: (T, T),
+) -> Result<T> {
+    let format = NumberFormat::<{ FORMAT }> {};
+    let radix = format.mantissa_radix();
+
+    // Get the number of digits before the decimal point, after applying
+    // the exponent: every digit after this must be zero.
+    let kept = if syntax.exponent >= 0 {
+        syntax.digit_count
+    } else {
+        syntax.digit_count.saturating_sub(syntax.exponent.unsigned_abs() as usize)
+    };
+
+    let mut value = T::ZERO;
+    let mut count = 0;
+    let mut byte = syntax.digits;
+    parse_digits(byte.integer_iter(), radix, is_negative, kept, bounds, &mut count, &mut value)?;
+    if syntax.has_decimal {
+        // SAFETY: safe, since we found the decimal point when validating.
+        unsafe { byte.step_unchecked() };
+        parse_digits(
+            byte.fraction_iter(),
+            radix,
+            is_negative,
+            kept,
+            bounds,
+            &mut count,
+            &mut value,
+        )?;
+    }
+
+    // Scale by any remaining exponent. This always overflows in at most
+    // `T::BITS` steps for non-zero values.
+    if value != T::ZERO {
+        for _ in 0..syntax.exponent.max(0) {
+            let scaled = value.checked_mul(as_cast(radix));
+            value = scaled.filter(|&x| x >= bounds.0 && x <= bounds.1).ok_or(if is_negative {
+                Error::Underflow(syntax.exponent_index)
+            } else {
+                Error::Overflow(syntax.exponent_index)
+            })?;
+        }
+    }
+
+    Ok(value)
+}
+
+/// Parse an exact integer with float syntax.
+///
+/// * `bounds` - The optional smallest and largest valid values.
+fn parse_float_syntax<T, const FORMAT: u128, const IS_PARTIAL: bool>(
+    bytes: &[u8],
+    options: &Options,
+    bounds: Option<(T, T)>,
+) -> Result<(T, usize)>
+where
+    T: Integer,
+{
+    let format = NumberFormat::<{ FORMAT }> {};
+    if format.mantissa_radix() != format.exponent_base() {
+        return Err(Error::InvalidExponentBase);
+    }
+
+    let mut byte = bytes.bytes::<FORMAT>();
+    let is_negative = parse_sign::<T, FORMAT>(&mut byte)?;
+    if byte.integer_iter().is_buffer_empty() {
+        if format.required_integer_digits() || format.required_mantissa_digits() {
+            return Err(Error::Empty(byte.cursor()));
+        } else {
+            return Ok((T::ZERO, byte.cursor()));
+        }
+    }
+
+    let syntax = parse_syntax::<FORMAT, IS_PARTIAL>(byte, options)?;
+    let end = syntax.end;
+    let bounds = bounds.unwrap_or((T::MIN, T::MAX));
+    let value = parse_value::<T, FORMAT>(syntax, is_negative, bounds)?;
+    Ok((value, end))
+}
+
+/// Parse an exact integer with float syntax using a complete parser.
+#[inline(always)]
+pub fn parse_complete<T, const FORMAT: u128>(
+    bytes: &[u8],
+    options: &Options,
+    bounds: Option<(T, T)>,
+) -> Result<T>
+where
+    T: Integer,
+{
+    parse_float_syntax::<T, FORMAT, false>(bytes, options, bounds).map(|(value, _)| value)
+}
+
+/// Parse an exact integer with float syntax using a partial parser.
+#[inline(always)]
+pub fn parse_partial<T, const FORMAT: u128>(
+    bytes: &[u8],
+    options: &Options,
+    bounds: Option<(T, T)>,
+) -> Result<(T, usize)>
+where
+    T: Integer,
+{
+    parse_float_syntax::<T, FORMAT, true>(bytes, options, bounds)
+}
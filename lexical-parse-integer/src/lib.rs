@@ -72,12 +72,33 @@
 //! assert_eq!(value, Ok(0xffffffffffffffff));
 //! ```
 //!
+//! To accept exact integers written with a fraction or exponent, such as
+//! `12.0` or `1e3`, you can use [`OptionsBuilder::float_syntax`]. Values
+//! with a non-zero fractional part return [`Error::NotAnInteger`].
+//!
+//! # Bounded Integers
+//!
+//! To parse integers narrower than the primitive types, such as a 12-bit
+//! field or a port number, use [`parse_in_range`] or [`parse_with_bits`].
+//! These return [`Error::Overflow`] or [`Error::Underflow`] at the first
+//! digit where the value is out of range.
+//!
+//! ```rust
+//! use lexical_parse_integer::{format, parse_in_range, Error, Options};
+//!
+//! const OPTIONS: Options = Options::new();
+//! let port = parse_in_range::<u16, { format::STANDARD }>(b"65536", 1..=65535, &OPTIONS);
+//! assert_eq!(port, Err(Error::Overflow(4)));
+//! ```
+//!
 //! # Features
 //!
 //! * `format` - Add support for parsing custom integer formats.
 //! * `power-of-two` - Add support for parsing power-of-two integer strings.
 //! * `radix` - Add support for strings of any radix.
 //! * `compact` - Reduce code size at the cost of performance.
+//! * `bigint` - Add support for parsing arbitrary-precision integers. This
+//!   requires the `std` feature.
 //! * `std` (Default) - Disable to allow use in a [`no_std`] environment.
 //!
 //! [`no_std`]: https://docs.rust-embedded.org/book/intro/no-std.html
@@ -118,6 +139,26 @@
 //! # }
 //! ```
 //!
+//! Many JSON producers also write integers with a fraction or exponent,
+//! such as `1.0e6` or `100.00`. The [`JSON`][options::JSON] options accept
+//! these if they are exact integers, using the float syntax rules of the
+//! format:
+//!
+//! ```rust
+//! # #[cfg(feature = "format")] {
+//! use lexical_parse_integer::{format, options, Error, FromLexicalWithOptions};
+//!
+//! let parse = |x: &str| {
+//!     u64::from_lexical_with_options::<{ format::JSON }>(x.as_bytes(), &options::JSON)
+//! };
+//! assert_eq!(parse("1.0e6"), Ok(1000000));
+//! assert_eq!(parse("100.00"), Ok(100));
+//! assert_eq!(parse("100.5"), Err(Error::NotAnInteger(4)));
+//! assert_eq!(parse("1.e6"), Err(Error::EmptyFraction(2)));
+//! assert_eq!(parse("1e20"), Err(Error::Overflow(2)));
+//! # }
+//! ```
+//!
 //! ##### Custom Format
 //!
 //! An example of building a custom format to with digit separator support is:
@@ -282,17 +323,33 @@
 )]
 
 pub mod algorithm;
+pub mod bigint;
+pub mod bounded;
+pub mod float;
 pub mod options;
 pub mod parse;
+pub mod stream;
 
 mod api;
 
 // Re-exports
+#[cfg(feature = "bigint")]
+pub use lexical_util::bigint::{BigInt, BigUint};
 pub use lexical_util::error::Error;
 pub use lexical_util::format::{self, NumberFormat, NumberFormatBuilder};
 pub use lexical_util::options::ParseOptions;
 pub use lexical_util::result::Result;
 
 pub use self::api::{FromLexical, FromLexicalWithOptions};
+#[cfg(feature = "bigint")]
+#[doc(inline)]
+pub use self::bigint::{parse_bigint, parse_biguint, parse_partial_bigint, parse_partial_biguint};
+#[doc(inline)]
+pub use self::bounded::{
+    parse_in_range,
+    parse_partial_in_range,
+    parse_partial_with_bits,
+    parse_with_bits,
+};
 #[doc(inline)]
 pub use self::options::{Options, OptionsBuilder};
@@ -8,6 +8,8 @@
 //!   integers, at a major performance cost to larger values.
 //! - [`LARGE_NUMBERS`][`LARGE_NUMBERS`]: Optimize the parsing of large
 //!   integers, at a slight performance cost to smaller values.
+//! - [`JSON`][`JSON`]: Accept exact integers written with a fraction or
+//!   exponent, such as `1.0e6` or `100.00`, as in `JSON` documents.
 //!
 //! # Examples
 //!
@@ -24,6 +26,8 @@
 //! assert_eq!(result, Ok(1234));
 //! ```
 
+use lexical_util::ascii::is_valid_ascii;
+use lexical_util::error::Error;
 use lexical_util::options::ParseOptions;
 use lexical_util::result::Result;
 
@@ -53,6 +57,12 @@ pub struct OptionsBuilder {
     /// increased branching can decrease performance for simple
     /// strings by 5-20%. Choose based on your inputs.
     no_multi_digit: bool,
+    /// Accept a fraction and exponent if the value is an exact integer.
+    float_syntax: bool,
+    /// Character to designate the exponent component of a float.
+    exponent: u8,
+    /// Character to separate the integer from the fraction components.
+    decimal_point: u8,
 }
 
 impl OptionsBuilder {
@@ -61,6 +71,9 @@ impl OptionsBuilder {
     pub const fn new() -> Self {
         Self {
             no_multi_digit: true,
+            float_syntax: false,
+            exponent: b'e',
+            decimal_point: b'.',
         }
     }
 
@@ -83,6 +96,59 @@ impl OptionsBuilder {
         self.no_multi_digit
     }
 
+    /// Get if we accept float syntax for exact integers.
+    ///
+    /// Defaults to [`false`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lexical_parse_integer::Options;
+    ///
+    /// let builder = Options::builder();
+    /// assert_eq!(builder.get_float_syntax(), false);
+    /// ```
+    #[inline(always)]
+    pub const fn get_float_syntax(&self) -> bool {
+        self.float_syntax
+    }
+
+    /// Get the character to designate the exponent component of a float.
+    ///
+    /// Any non-control character is valid, but `\t` to `\r` are also valid.
+    /// The full range is `[0x09, 0x0D]` and `[0x20, 0x7F]`. Defaults to `e`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lexical_parse_integer::Options;
+    ///
+    /// let builder = Options::builder();
+    /// assert_eq!(builder.get_exponent(), b'e');
+    /// ```
+    #[inline(always)]
+    pub const fn get_exponent(&self) -> u8 {
+        self.exponent
+    }
+
+    /// Get the character to separate the integer from the fraction components.
+    ///
+    /// Any non-control character is valid, but `\t` to `\r` are also valid.
+    /// The full range is `[0x09, 0x0D]` and `[0x20, 0x7F]`. Defaults to `.`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lexical_parse_integer::Options;
+    ///
+    /// let builder = Options::builder();
+    /// assert_eq!(builder.get_decimal_point(), b'.');
+    /// ```
+    #[inline(always)]
+    pub const fn get_decimal_point(&self) -> u8 {
+        self.decimal_point
+    }
+
     // SETTERS
 
     /// Set if we disable the use of multi-digit optimizations.
@@ -105,12 +171,98 @@ impl OptionsBuilder {
         self
     }
 
+    /// Set if we accept float syntax for exact integers.
+    ///
+    /// If enabled, the integer may have a fraction and an exponent, using
+    /// the [`decimal_point`] and [`exponent`] characters and the float
+    /// syntax rules from the number format, as long as the value is an
+    /// exact integer, such as `12.0` or `1e3`. The exponent must be in
+    /// the mantissa radix. A value with a fractional part returns
+    /// [`Error::NotAnInteger`] at the first non-zero fractional digit, and
+    /// values that do not fit in the type return [`Error::Overflow`] or
+    /// [`Error::Underflow`] at the first digit that does not fit, or at the
+    /// first exponent digit if only the exponent does not fit. Defaults to
+    /// [`false`].
+    ///
+    /// [`decimal_point`]: Self::decimal_point
+    /// [`exponent`]: Self::exponent
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lexical_parse_integer::format::STANDARD;
+    /// use lexical_parse_integer::{Error, FromLexicalWithOptions, Options};
+    ///
+    /// const OPTIONS: Options = Options::builder()
+    ///     .float_syntax(true)
+    ///     .build_strict();
+    ///
+    /// let parse = |x: &str| i64::from_lexical_with_options::<STANDARD>(x.as_bytes(), &OPTIONS);
+    /// assert_eq!(parse("12.0"), Ok(12));
+    /// assert_eq!(parse("1e3"), Ok(1000));
+    /// assert_eq!(parse("1.5"), Err(Error::NotAnInteger(2)));
+    /// assert_eq!(parse("1e19"), Err(Error::Overflow(2)));
+    /// ```
+    #[inline(always)]
+    pub const fn float_syntax(mut self, float_syntax: bool) -> Self {
+        self.float_syntax = float_syntax;
+        self
+    }
+
+    /// Set the character to designate the exponent component of a float.
+    ///
+    /// This is only used with [`float_syntax`]. Any non-control character
+    /// is valid, but `\t` to `\r` are also valid. The full range is
+    /// `[0x09, 0x0D]` and `[0x20, 0x7F]`. Defaults to `e`.
+    ///
+    /// [`float_syntax`]: Self::float_syntax
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lexical_parse_integer::Options;
+    ///
+    /// const OPTIONS: Options = Options::builder()
+    ///     .exponent(b'^')
+    ///     .build_strict();
+    /// assert_eq!(OPTIONS.get_exponent(), b'^');
+    /// ```
+    #[inline(always)]
+    pub const fn exponent(mut self, exponent: u8) -> Self {
+        self.exponent = exponent;
+        self
+    }
+
+    /// Set the character to separate the integer from the fraction components.
+    ///
+    /// This is only used with [`float_syntax`]. Any non-control character
+    /// is valid, but `\t` to `\r` are also valid. The full range is
+    /// `[0x09, 0x0D]` and `[0x20, 0x7F]`. Defaults to `.`.
+    ///
+    /// [`float_syntax`]: Self::float_syntax
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lexical_parse_integer::Options;
+    ///
+    /// const OPTIONS: Options = Options::builder()
+    ///     .decimal_point(b',')
+    ///     .build_strict();
+    /// assert_eq!(OPTIONS.get_decimal_point(), b',');
+    /// ```
+    #[inline(always)]
+    pub const fn decimal_point(mut self, decimal_point: u8) -> Self {
+        self.decimal_point = decimal_point;
+        self
+    }
+
     // BUILDERS
 
-    /// Check if the builder state is valid (always [`true`]).
+    /// Check if the builder state is valid.
     #[inline(always)]
     pub const fn is_valid(&self) -> bool {
-        true
+        is_valid_ascii(self.exponent) && is_valid_ascii(self.decimal_point)
     }
 
     /// Build the [`Options`] struct without validation.
@@ -128,18 +280,18 @@ impl OptionsBuilder {
     pub const fn build_unchecked(&self) -> Options {
         Options {
             no_multi_digit: self.no_multi_digit,
+            float_syntax: self.float_syntax,
+            exponent: self.exponent,
+            decimal_point: self.decimal_point,
         }
     }
 
-    /// Build the [`Options`] struct.
-    ///
-    /// This can never panic.
+    /// Build the [`Options`] struct, panicking if the builder is invalid.
     ///
-    /// <!-- # Panics
+    /// # Panics
     ///
     /// If the built options are not valid. This should always
     /// be used within a const context to avoid panics at runtime.
-    /// -->
     #[inline(always)]
     pub const fn build_strict(&self) -> Options {
         match self.build() {
@@ -148,10 +300,20 @@ impl OptionsBuilder {
         }
     }
 
-    /// Build the [`Options`] struct. Always [`Ok`].
+    /// Build the [`Options`] struct.
+    ///
+    /// # Errors
+    ///
+    /// If the exponent or decimal point characters are not valid ASCII.
     #[inline(always)]
     pub const fn build(&self) -> Result<Options> {
-        Ok(self.build_unchecked())
+        if !is_valid_ascii(self.exponent) {
+            Err(Error::InvalidExponentSymbol)
+        } else if !is_valid_ascii(self.decimal_point) {
+            Err(Error::InvalidDecimalPoint)
+        } else {
+            Ok(self.build_unchecked())
+        }
     }
 }
 
@@ -188,6 +350,12 @@ pub struct Options {
     /// increased branching can decrease performance for simple
     /// strings by 5-20%. Choose based on your inputs.
     no_multi_digit: bool,
+    /// Accept a fraction and exponent if the value is an exact integer.
+    float_syntax: bool,
+    /// Character to designate the exponent component of a float.
+    exponent: u8,
+    /// Character to separate the integer from the fraction components.
+    decimal_point: u8,
 }
 
 impl Options {
@@ -199,15 +367,22 @@ impl Options {
     }
 
     /// Create the default options for a given radix.
+    ///
+    /// This sets the exponent to `^` for any radix where `e`
+    /// would be a valid digit.
     #[inline(always)]
     #[cfg(feature = "power-of-two")]
-    pub const fn from_radix(_: u8) -> Self {
-        Self::new()
+    pub const fn from_radix(radix: u8) -> Self {
+        let mut builder = Self::builder();
+        if radix >= 15 {
+            builder = builder.exponent(b'^');
+        }
+        builder.build_unchecked()
     }
 
     // GETTERS
 
-    /// Check if the builder state is valid (always [`true`]).
+    /// Check if the options state is valid.
     #[inline(always)]
     pub const fn is_valid(&self) -> bool {
         self.rebuild().is_valid()
@@ -232,6 +407,57 @@ impl Options {
         self.no_multi_digit
     }
 
+    /// Get if we accept float syntax for exact integers.
+    ///
+    /// Defaults to [`false`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lexical_parse_integer::options::Options;
+    ///
+    /// const OPTIONS: Options = Options::builder()
+    ///     .float_syntax(true)
+    ///     .build_strict();
+    /// assert_eq!(OPTIONS.get_float_syntax(), true);
+    /// ```
+    #[inline(always)]
+    pub const fn get_float_syntax(&self) -> bool {
+        self.float_syntax
+    }
+
+    /// Get the character to designate the exponent component of a float.
+    ///
+    /// Defaults to `e`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lexical_parse_integer::options::Options;
+    ///
+    /// assert_eq!(Options::new().get_exponent(), b'e');
+    /// ```
+    #[inline(always)]
+    pub const fn get_exponent(&self) -> u8 {
+        self.exponent
+    }
+
+    /// Get the character to separate the integer from the fraction components.
+    ///
+    /// Defaults to `.`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lexical_parse_integer::options::Options;
+    ///
+    /// assert_eq!(Options::new().get_decimal_point(), b'.');
+    /// ```
+    #[inline(always)]
+    pub const fn get_decimal_point(&self) -> u8 {
+        self.decimal_point
+    }
+
     // SETTERS
 
     /// Set if we disable the use of multi-digit optimizations.
@@ -261,6 +487,9 @@ impl Options {
     pub const fn rebuild(&self) -> OptionsBuilder {
         OptionsBuilder {
             no_multi_digit: self.no_multi_digit,
+            float_syntax: self.float_syntax,
+            exponent: self.exponent,
+            decimal_point: self.decimal_point,
         }
     }
 }
@@ -277,6 +506,16 @@ impl ParseOptions for Options {
     fn is_valid(&self) -> bool {
         Self::is_valid(self)
     }
+
+    #[inline(always)]
+    fn decimal_point(&self) -> u8 {
+        self.get_decimal_point()
+    }
+
+    #[inline(always)]
+    fn exponent(&self) -> u8 {
+        self.get_exponent()
+    }
 }
 
 // PRE-DEFINED CONSTANTS
@@ -297,3 +536,13 @@ pub const SMALL_NUMBERS: Options = Options::builder()
 pub const LARGE_NUMBERS: Options = Options::builder()
     .no_multi_digit(false)
     .build_strict();
+
+/// Options to parse exact integers from `JSON` numbers.
+///
+/// Many `JSON` producers write integers as floats, like `1.0e6` or
+/// `100.00`. Use this with the `JSON` number format to validate the float
+/// syntax.
+#[rustfmt::skip]
+pub const JSON: Options = Options::builder()
+    .float_syntax(true)
+    .build_strict();
@@ -6,7 +6,12 @@
 use lexical_util::num::Integer;
 use lexical_util::result::Result;
 
-use crate::algorithm::{algorithm_complete, algorithm_partial};
+use crate::algorithm::{
+    algorithm_bounded_complete,
+    algorithm_bounded_partial,
+    algorithm_complete,
+    algorithm_partial,
+};
 use crate::Options;
 
 /// Parse integer trait, implemented in terms of the optimized back-end.
@@ -22,6 +27,30 @@ pub trait ParseInteger: Integer {
     fn parse_partial<const FORMAT: u128>(bytes: &[u8], options: &Options) -> Result<(Self, usize)> {
         algorithm_partial::<_, { FORMAT }>(bytes, options)
     }
+
+    /// Forward complete parser parameters to the backend, within the
+    /// inclusive bounds.
+    #[cfg_attr(not(feature = "compact"), inline(always))]
+    fn parse_complete_bounded<const FORMAT: u128>(
+        bytes: &[u8],
+        options: &Options,
+        min: Self,
+        max: Self,
+    ) -> Result<Self> {
+        algorithm_bounded_complete::<_, { FORMAT }>(bytes, options, min, max)
+    }
+
+    /// Forward partial parser parameters to the backend, within the
+    /// inclusive bounds.
+    #[cfg_attr(not(feature = "compact"), inline(always))]
+    fn parse_partial_bounded<const FORMAT: u128>(
+        bytes: &[u8],
+        options: &Options,
+        min: Self,
+        max: Self,
+    ) -> Result<(Self, usize)> {
+        algorithm_bounded_partial::<_, { FORMAT }>(bytes, options, min, max)
+    }
 }
 
 macro_rules! parse_integer_impl {
@@ -0,0 +1,110 @@
+//! Parse integers incrementally from chunked input.
+//!
+//! This buffers the bytes of an integer split across multiple chunks,
+//! such as network reads, in a caller-provided buffer until the integer
+//! is complete, using the same number format validation and error
+//! indexes as parsing the integer from a single slice.
+//!
+//! ```rust
+//! use lexical_parse_integer::stream::{Status, StreamParser};
+//!
+//! let mut buffer = [0u8; 64];
+//! let mut parser = StreamParser::<u64>::new(&mut buffer);
+//! assert_eq!(parser.push(b"12"), Ok(Status::Incomplete));
+//! assert_eq!(parser.push(b"34,56"), Ok(Status::Complete(1234, 4)));
+//! assert_eq!(parser.push(b"56"), Ok(Status::Incomplete));
+//! assert_eq!(parser.finish(), Ok((56, 2)));
+//! ```
+
+use core::marker::PhantomData;
+
+use lexical_util::format::STANDARD;
+use lexical_util::result::Result;
+pub use lexical_util::stream::Status;
+use lexical_util::stream::StreamBuffer;
+
+use crate::options::Options;
+use crate::FromLexicalWithOptions;
+
+/// Default options for the stream parser.
+const DEFAULT_OPTIONS: Options = Options::new();
+
+/// Resumable parser for integers split across chunks of input.
+///
+/// The integer is complete once a byte that cannot be part of the
+/// integer is found, or once the caller signals the end of the input
+/// with [`finish`]. After a complete integer or an error, the parser is
+/// reset to parse the next integer.
+///
+/// The buffer must be at least 1 byte longer than the longest integer to
+/// parse, otherwise [`push`] returns [`Error::BufferFull`].
+///
+/// [`finish`]: Self::finish
+/// [`push`]: Self::push
+/// [`Error::BufferFull`]: lexical_util::error::Error::BufferFull
+#[derive(Debug)]
+pub struct StreamParser<'a, T, const FORMAT: u128 = STANDARD> {
+    /// The bytes of the integer buffered so far.
+    buffer: StreamBuffer<'a>,
+    /// The options to parse the integer.
+    options: &'a Options,
+    /// Marker for the integer type.
+    marker: PhantomData<T>,
+}
+
+impl<'a, T, const FORMAT: u128> StreamParser<'a, T, FORMAT>
+where
+    T: FromLexicalWithOptions<Options = Options>,
+{
+    /// Create a stream parser with the default options.
+    #[inline(always)]
+    pub fn new(buffer: &'a mut [u8]) -> Self {
+        Self::with_options(buffer, &DEFAULT_OPTIONS)
+    }
+
+    /// Create a stream parser with custom options.
+    #[inline(always)]
+    pub fn with_options(buffer: &'a mut [u8], options: &'a Options) -> Self {
+        Self {
+            buffer: StreamBuffer::new(buffer),
+            options,
+            marker: PhantomData,
+        }
+    }
+
+    /// Get the bytes of the current integer buffered so far.
+    #[inline(always)]
+    pub fn buffered(&self) -> &[u8] {
+        self.buffer.as_slice()
+    }
+
+    /// Discard any buffered bytes, to start parsing a new integer.
+    #[inline(always)]
+    pub fn reset(&mut self) {
+        self.buffer.clear();
+    }
+
+    /// Parse the next chunk of input.
+    ///
+    /// Returns [`Status::Incomplete`] if the integer may continue in the
+    /// next chunk, otherwise the integer and the number of bytes of the
+    /// integer. The count and error indexes are relative to the start of
+    /// the integer, across all chunks, so the integer may end before the
+    /// last chunk.
+    #[inline]
+    pub fn push(&mut self, chunk: &[u8]) -> Result<Status<T>> {
+        let options = self.options;
+        let parse = |bytes: &[u8]| T::from_lexical_partial_with_options::<FORMAT>(bytes, options);
+        self.buffer.push(chunk, parse, |_| false)
+    }
+
+    /// Parse the buffered bytes at the end of the input.
+    ///
+    /// Returns the integer and the number of buffered bytes that were
+    /// part of the integer.
+    #[inline]
+    pub fn finish(&mut self) -> Result<(T, usize)> {
+        let options = self.options;
+        self.buffer.finish(|bytes| T::from_lexical_partial_with_options::<FORMAT>(bytes, options))
+    }
+}
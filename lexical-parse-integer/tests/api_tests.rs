@@ -357,3 +357,180 @@ fn base_prefix_and_suffix_test() {
     assert!(i32::from_lexical_with_options::<FORMAT>(b"+h", &OPTIONS).is_err());
     assert!(i32::from_lexical_with_options::<FORMAT>(b"+0x", &OPTIONS).is_err());
 }
+
+#[test]
+fn float_syntax_test() {
+    const OPTIONS: Options = Options::builder().float_syntax(true).build_strict();
+    let parse = |x: &[u8]| i64::from_lexical_with_options::<STANDARD>(x, &OPTIONS);
+    let parse_u8 = |x: &[u8]| u8::from_lexical_with_options::<STANDARD>(x, &OPTIONS);
+    let parse_partial = |x: &[u8]| i64::from_lexical_partial_with_options::<STANDARD>(x, &OPTIONS);
+
+    // Exact integers.
+    assert_eq!(parse(b"0"), Ok(0));
+    assert_eq!(parse(b"-0.0"), Ok(0));
+    assert_eq!(parse(b"1234"), Ok(1234));
+    assert_eq!(parse(b"12.0"), Ok(12));
+    assert_eq!(parse(b"12."), Ok(12));
+    assert_eq!(parse(b"-12.000"), Ok(-12));
+    assert_eq!(parse(b"1e3"), Ok(1000));
+    assert_eq!(parse(b"1E3"), Ok(1000));
+    assert_eq!(parse(b"1.5e1"), Ok(15));
+    assert_eq!(parse(b"0.0001e4"), Ok(1));
+    assert_eq!(parse(b"1200e-2"), Ok(12));
+    assert_eq!(parse(b"0e999999999999"), Ok(0));
+    assert_eq!(parse(b"0.5e-999999999999"), Err(Error::NotAnInteger(2)));
+    assert_eq!(parse(b"9223372036854775807.0"), Ok(i64::MAX));
+    assert_eq!(parse(b"-9.223372036854775808e18"), Ok(i64::MIN));
+    assert_eq!(parse(b"92233720368547758070e-1"), Ok(i64::MAX));
+
+    // Not exact integers.
+    assert_eq!(parse(b"1.5"), Err(Error::NotAnInteger(2)));
+    assert_eq!(parse(b"-1.05"), Err(Error::NotAnInteger(4)));
+    assert_eq!(parse(b"1.25e1"), Err(Error::NotAnInteger(3)));
+    assert_eq!(parse(b"5e-1"), Err(Error::NotAnInteger(0)));
+    assert_eq!(parse(b"1201e-2"), Err(Error::NotAnInteger(3)));
+
+    // Invalid syntax.
+    assert_eq!(parse(b""), Err(Error::Empty(0)));
+    assert_eq!(parse(b"."), Err(Error::EmptyMantissa(1)));
+    assert_eq!(parse(b"1e"), Err(Error::EmptyExponent(2)));
+    assert_eq!(parse(b"1.5x"), Err(Error::InvalidDigit(3)));
+    assert_eq!(parse(b"x1"), Err(Error::InvalidDigit(0)));
+    assert_eq!(parse(b"NaN"), Err(Error::InvalidDigit(0)));
+    assert_eq!(parse_u8(b"-1.0"), Err(Error::InvalidDigit(0)));
+
+    // Values that do not fit.
+    assert_eq!(parse(b"9223372036854775808"), Err(Error::Overflow(18)));
+    assert_eq!(parse(b"-9223372036854775809.0"), Err(Error::Underflow(19)));
+    assert_eq!(parse(b"1e19"), Err(Error::Overflow(2)));
+    assert_eq!(parse(b"-1e+19"), Err(Error::Underflow(4)));
+    assert_eq!(parse(b"1e999999999999"), Err(Error::Overflow(2)));
+    assert_eq!(parse_u8(b"25.6e1"), Err(Error::Overflow(3)));
+    assert_eq!(parse_u8(b"2.55e2"), Ok(255));
+
+    // Partial parsers stop after the number.
+    assert_eq!(parse_partial(b"12.0,"), Ok((12, 4)));
+    assert_eq!(parse_partial(b"1e3 "), Ok((1000, 3)));
+    assert_eq!(parse_partial(b"1.5,"), Err(Error::NotAnInteger(2)));
+
+    // Float syntax is still rejected without the option.
+    assert_eq!(i64::from_lexical(b"12.0"), Err(Error::InvalidDigit(2)));
+    assert_eq!(i64::from_lexical(b"1e3"), Err(Error::InvalidDigit(1)));
+}
+
+#[test]
+#[cfg(feature = "radix")]
+fn float_syntax_radix_test() {
+    const FORMAT: u128 = from_radix(16);
+    const OPTIONS: Options = Options::builder().float_syntax(true).exponent(b'^').build_strict();
+    let parse = |x: &[u8]| i32::from_lexical_with_options::<FORMAT>(x, &OPTIONS);
+
+    assert_eq!(parse(b"1E.0"), Ok(30));
+    assert_eq!(parse(b"1.8^1"), Ok(24));
+    assert_eq!(parse(b"1.8"), Err(Error::NotAnInteger(2)));
+
+    // The exponent cannot be a valid digit.
+    const INVALID: Options = Options::builder().float_syntax(true).build_strict();
+    let result = i32::from_lexical_with_options::<FORMAT>(b"1e3", &INVALID);
+    assert_eq!(result, Err(Error::InvalidPunctuation));
+    let result = i32::from_lexical_with_options::<FORMAT>(b"1e3", &Options::from_radix(16));
+    assert_eq!(result, Ok(0x1e3));
+}
+
+#[test]
+#[cfg(feature = "format")]
+fn float_syntax_json_test() {
+    use lexical_parse_integer::options::JSON;
+    use lexical_util::format;
+
+    let parse = |x: &[u8]| i64::from_lexical_with_options::<{ format::JSON }>(x, &JSON);
+    let parse_u16 = |x: &[u8]| u16::from_lexical_with_options::<{ format::JSON }>(x, &JSON);
+
+    // Exact integers.
+    assert_eq!(parse(b"1.0e6"), Ok(1000000));
+    assert_eq!(parse(b"1.0E+6"), Ok(1000000));
+    assert_eq!(parse(b"100.00"), Ok(100));
+    assert_eq!(parse(b"-12.50e1"), Ok(-125));
+    assert_eq!(parse(b"0.0"), Ok(0));
+    assert_eq!(parse(b"-0"), Ok(0));
+    assert_eq!(parse(b"1e0"), Ok(1));
+    assert_eq!(parse(b"25e-1"), Err(Error::NotAnInteger(1)));
+
+    // Non-zero fractional parts.
+    assert_eq!(parse(b"100.01"), Err(Error::NotAnInteger(5)));
+    assert_eq!(parse(b"1.5e0"), Err(Error::NotAnInteger(2)));
+    assert_eq!(parse(b"1.25e1"), Err(Error::NotAnInteger(3)));
+
+    // Invalid JSON syntax.
+    assert_eq!(parse(b"+1.0"), Err(Error::InvalidPositiveSign(0)));
+    assert_eq!(parse(b"1."), Err(Error::EmptyFraction(2)));
+    assert_eq!(parse(b".1"), Err(Error::EmptyInteger(0)));
+    assert_eq!(parse(b"01.0"), Err(Error::InvalidLeadingZeros(0)));
+    assert_eq!(parse(b"1e"), Err(Error::EmptyExponent(2)));
+    assert_eq!(parse(b"1.0e6 "), Err(Error::InvalidDigit(5)));
+    assert_eq!(parse(b"NaN"), Err(Error::EmptyInteger(0)));
+
+    // Values that do not fit point to the first offending digit.
+    assert_eq!(parse_u16(b"65535.0"), Ok(65535));
+    assert_eq!(parse_u16(b"65536.0"), Err(Error::Overflow(4)));
+    assert_eq!(parse_u16(b"6.5536e4"), Err(Error::Overflow(5)));
+    assert_eq!(parse_u16(b"1.0e6"), Err(Error::Overflow(4)));
+    assert_eq!(parse_u16(b"1.0e-1"), Err(Error::NotAnInteger(0)));
+    assert_eq!(parse(b"-9.223372036854775809e18"), Err(Error::Underflow(20)));
+    assert_eq!(parse(b"-1e19"), Err(Error::Underflow(3)));
+}
+
+#[cfg(feature = "std")]
+fn utf16(string: &str) -> Vec<u16> {
+    string.encode_utf16().collect()
+}
+
+#[cfg(feature = "std")]
+fn utf32(string: &str) -> Vec<u32> {
+    string.chars().map(u32::from).collect()
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn from_lexical_units_test() {
+    assert_eq!(i32::from_lexical_units(&utf16("-12345")), Ok(-12345));
+    assert_eq!(u64::from_lexical_units(&utf32("18446744073709551615")), Ok(u64::MAX));
+    assert_eq!(u8::from_lexical_units(&utf16("256")), Err(Error::Overflow(2)));
+    assert_eq!(i8::from_lexical_units(&utf16("")), Err(Error::Empty(0)));
+
+    // Non-ASCII characters are invalid digits, and indexes are in code units.
+    assert_eq!(i32::from_lexical_units(&utf16("12\u{3c0}")), Err(Error::InvalidDigit(2)));
+    assert_eq!(i32::from_lexical_units(&utf16("\u{1f600}1")), Err(Error::InvalidDigit(0)));
+    assert_eq!(i32::from_lexical_partial_units(&utf16("12\u{3c0}")), Ok((12, 2)));
+    assert_eq!(i32::from_lexical_partial_units(&utf32("-7\u{1f600}")), Ok((-7, 2)));
+
+    // Code units that would be ASCII digits if truncated.
+    assert_eq!(i32::from_lexical_units(&[0x31u16, 0x130]), Err(Error::InvalidDigit(1)));
+    assert_eq!(i32::from_lexical_units(&[0x31u32, 0x10032]), Err(Error::InvalidDigit(1)));
+
+    // Leading zeros longer than the stack buffer.
+    let mut units = vec![u16::from(b'0'); 1000];
+    units.push(u16::from(b'7'));
+    assert_eq!(u8::from_lexical_units(&units), Ok(7));
+    units.push(0x3c0);
+    assert_eq!(u8::from_lexical_units(&units), Err(Error::InvalidDigit(1001)));
+}
+
+#[test]
+#[cfg(all(feature = "std", feature = "format"))]
+fn from_lexical_units_with_options_test() {
+    const FORMAT: u128 = NumberFormatBuilder::new()
+        .digit_separator(std::num::NonZeroU8::new(b'_'))
+        .integer_internal_digit_separator(true)
+        .build_strict();
+    const OPTIONS: Options = Options::new();
+
+    let parse = |units: &[u16]| i64::from_lexical_units_with_options::<FORMAT, _>(units, &OPTIONS);
+    assert_eq!(parse(&utf16("1_234")), Ok(1234));
+    assert_eq!(parse(&utf16("_1234")), Err(Error::InvalidDigit(0)));
+    assert_eq!(parse(&utf16("1_2\u{3c0}")), Err(Error::InvalidDigit(3)));
+
+    let units = utf32("1_234 m");
+    let result = i64::from_lexical_partial_units_with_options::<FORMAT, _>(&units, &OPTIONS);
+    assert_eq!(result, Ok((1234, 5)));
+}
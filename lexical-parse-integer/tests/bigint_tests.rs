@@ -0,0 +1,180 @@
+#![cfg(feature = "bigint")]
+
+use lexical_parse_integer::{
+    parse_bigint,
+    parse_biguint,
+    parse_partial_bigint,
+    parse_partial_biguint,
+    BigInt,
+    BigUint,
+    FromLexicalWithOptions,
+    Options,
+};
+use lexical_util::error::Error;
+#[cfg(feature = "format")]
+use lexical_util::format::NumberFormatBuilder;
+use lexical_util::format::STANDARD;
+
+const OPTIONS: Options = Options::new();
+
+/// Check the big integer parsers match the native parsers, other than overflow.
+///
+/// The native complete parsers stop at the first digit that overflows, so
+/// the big integer parsers may find an invalid digit after it.
+fn check_native<const FORMAT: u128>(bytes: &[u8]) {
+    let big = parse_biguint::<FORMAT>(bytes, &OPTIONS);
+    match u128::from_lexical_with_options::<FORMAT>(bytes, &OPTIONS) {
+        Ok(value) => assert_eq!(big, Ok(BigUint::from(value))),
+        Err(Error::Overflow(_)) => {
+            assert!(big.is_ok() || matches!(big, Err(Error::InvalidDigit(_))))
+        },
+        Err(error) => assert_eq!(big, Err(error)),
+    }
+    let big = parse_partial_biguint::<FORMAT>(bytes, &OPTIONS);
+    match u128::from_lexical_partial_with_options::<FORMAT>(bytes, &OPTIONS) {
+        Ok((value, count)) => assert_eq!(big, Ok((BigUint::from(value), count))),
+        Err(Error::Overflow(_)) => assert!(big.is_ok()),
+        Err(error) => assert_eq!(big, Err(error)),
+    }
+
+    let big = parse_bigint::<FORMAT>(bytes, &OPTIONS);
+    match i128::from_lexical_with_options::<FORMAT>(bytes, &OPTIONS) {
+        Ok(value) => assert_eq!(big, Ok(BigInt::from(value))),
+        Err(Error::Overflow(_) | Error::Underflow(_)) => {
+            assert!(big.is_ok() || matches!(big, Err(Error::InvalidDigit(_))));
+        },
+        Err(error) => assert_eq!(big, Err(error)),
+    }
+    let big = parse_partial_bigint::<FORMAT>(bytes, &OPTIONS);
+    match i128::from_lexical_partial_with_options::<FORMAT>(bytes, &OPTIONS) {
+        Ok((value, count)) => assert_eq!(big, Ok((BigInt::from(value), count))),
+        Err(Error::Overflow(_) | Error::Underflow(_)) => assert!(big.is_ok()),
+        Err(error) => assert_eq!(big, Err(error)),
+    }
+}
+
+#[test]
+fn parse_biguint_test() {
+    let parse = |x: &[u8]| parse_biguint::<STANDARD>(x, &OPTIONS);
+    assert_eq!(parse(b"0"), Ok(BigUint::new()));
+    assert_eq!(parse(b"000123"), Ok(BigUint::from(123u64)));
+    assert_eq!(parse(b"18446744073709551616"), Ok(BigUint::from(1u128 << 64)));
+    assert_eq!(parse(b""), Err(Error::Empty(0)));
+    assert_eq!(parse(b"+"), Err(Error::Empty(1)));
+    assert_eq!(parse(b"-1"), Err(Error::InvalidDigit(0)));
+    assert_eq!(parse(b"12a"), Err(Error::InvalidDigit(2)));
+
+    let mut digits = vec![b'9'; 10000];
+    let value = parse(&digits).unwrap();
+    assert_eq!(value.to_digits(10), vec![9; 10000]);
+    digits.push(b'x');
+    assert_eq!(parse(&digits), Err(Error::InvalidDigit(10000)));
+    assert_eq!(parse_partial_biguint::<STANDARD>(&digits, &OPTIONS), Ok((value, 10000)));
+}
+
+#[test]
+fn parse_bigint_test() {
+    let parse = |x: &[u8]| parse_bigint::<STANDARD>(x, &OPTIONS);
+    assert_eq!(parse(b"-0"), Ok(BigInt::new()));
+    assert_eq!(
+        parse(b"-170141183460469231731687303715884105729").map(|x| x.is_negative()),
+        Ok(true)
+    );
+    assert_eq!(
+        parse(b"-340282366920938463463374607431768211455"),
+        Ok(BigInt::from_parts(true, BigUint::from(u128::MAX)))
+    );
+    assert_eq!(parse(b"-"), Err(Error::Empty(1)));
+    assert_eq!(parse(b"--1"), Err(Error::InvalidDigit(1)));
+}
+
+#[test]
+fn native_test() {
+    let inputs: [&[u8]; 16] = [
+        b"",
+        b"0",
+        b"-0",
+        b"+1",
+        b"1234",
+        b"-1234",
+        b"12 34",
+        b"1a",
+        b"-",
+        b"+",
+        b"340282366920938463463374607431768211455",
+        b"340282366920938463463374607431768211456",
+        b"-170141183460469231731687303715884105728",
+        b"-170141183460469231731687303715884105729",
+        b"99999999999999999999999999999999999999999,",
+        b"00000000000000000000000000000000000000001",
+    ];
+    for input in inputs.iter() {
+        check_native::<STANDARD>(input);
+    }
+}
+
+#[test]
+#[cfg(feature = "radix")]
+fn native_radix_test() {
+    const BASE36: u128 = NumberFormatBuilder::from_radix(36);
+    let inputs: [&[u8]; 6] =
+        [b"zz", b"-ZZ", b"z z", b"0", b"zzzzzzzzzzzzzzzzzzzzzzzzzzzzzz", b"10!"];
+    for input in inputs.iter() {
+        check_native::<BASE36>(input);
+    }
+}
+
+#[test]
+#[cfg(feature = "format")]
+fn native_format_test() {
+    use core::num;
+
+    const SEPARATOR: u128 = NumberFormatBuilder::new()
+        .digit_separator(num::NonZeroU8::new(b'_'))
+        .integer_internal_digit_separator(true)
+        .build_strict();
+    let inputs: [&[u8]; 6] = [
+        b"1_2",
+        b"_12",
+        b"12_",
+        b"1__2",
+        b"1_000_000_000_000_000_000_000_000_000_000_000_000_000",
+        b"1_2x",
+    ];
+    for input in inputs.iter() {
+        check_native::<SEPARATOR>(input);
+    }
+
+    const JSON: u128 = lexical_util::format::JSON;
+    let inputs: [&[u8]; 6] =
+        [b"0", b"-0", b"01", b"+1", b"0x", b"100000000000000000000000000000000000000000"];
+    for input in inputs.iter() {
+        check_native::<JSON>(input);
+    }
+}
+
+#[test]
+#[cfg(all(feature = "power-of-two", feature = "format"))]
+fn native_base_prefix_suffix_test() {
+    use core::num;
+
+    const FORMAT: u128 = NumberFormatBuilder::new()
+        .base_prefix(num::NonZeroU8::new(b'x'))
+        .base_suffix(num::NonZeroU8::new(b'h'))
+        .build_strict();
+    let inputs: [&[u8]; 10] = [
+        b"+3h",
+        b"+0x3",
+        b"+0x3h",
+        b"+0x3h ",
+        b"+0xh",
+        b"+h",
+        b"+0x",
+        b"-0x012h",
+        b"0x0000000000000000000000000000000000000000000000000012h",
+        b"0x100000000000000000000000000000000000000000000000000",
+    ];
+    for input in inputs.iter() {
+        check_native::<FORMAT>(input);
+    }
+}
@@ -0,0 +1,137 @@
+use lexical_parse_integer::{
+    parse_in_range,
+    parse_partial_in_range,
+    parse_partial_with_bits,
+    parse_with_bits,
+    Options,
+};
+use lexical_util::error::Error;
+#[cfg(feature = "format")]
+use lexical_util::format::NumberFormatBuilder;
+use lexical_util::format::STANDARD;
+
+const OPTIONS: Options = Options::new();
+
+#[test]
+fn parse_in_range_test() {
+    let port = |x: &[u8]| parse_in_range::<u16, STANDARD>(x, 1..=65535, &OPTIONS);
+    assert_eq!(port(b"1"), Ok(1));
+    assert_eq!(port(b"8080"), Ok(8080));
+    assert_eq!(port(b"65535"), Ok(65535));
+    assert_eq!(port(b"0"), Err(Error::Underflow(0)));
+    assert_eq!(port(b"00000"), Err(Error::Underflow(0)));
+    assert_eq!(port(b"65536"), Err(Error::Overflow(4)));
+    assert_eq!(port(b"655351"), Err(Error::Overflow(5)));
+    assert_eq!(port(b"99999999"), Err(Error::Overflow(4)));
+    assert_eq!(port(b"65536x"), Err(Error::Overflow(4)));
+    assert_eq!(port(b"6553x"), Err(Error::InvalidDigit(4)));
+    assert_eq!(port(b""), Err(Error::Empty(0)));
+    assert_eq!(port(b"-1"), Err(Error::InvalidDigit(0)));
+
+    let parse = |x: &[u8]| parse_in_range::<i32, STANDARD>(x, -1000..=-10, &OPTIONS);
+    assert_eq!(parse(b"-10"), Ok(-10));
+    assert_eq!(parse(b"-1000"), Ok(-1000));
+    assert_eq!(parse(b"-1001"), Err(Error::Underflow(4)));
+    assert_eq!(parse(b"-99999"), Err(Error::Underflow(4)));
+    assert_eq!(parse(b"-9"), Err(Error::Overflow(1)));
+    assert_eq!(parse(b"-0"), Err(Error::Overflow(1)));
+    assert_eq!(parse(b"5"), Err(Error::Overflow(0)));
+    assert_eq!(parse(b"+50"), Err(Error::Overflow(1)));
+
+    let parse = |x: &[u8]| parse_in_range::<i8, STANDARD>(x, i8::MIN..=i8::MAX, &OPTIONS);
+    assert_eq!(parse(b"-128"), Ok(-128));
+    assert_eq!(parse(b"127"), Ok(127));
+    assert_eq!(parse(b"128"), Err(Error::Overflow(2)));
+    assert_eq!(parse(b"-129"), Err(Error::Underflow(3)));
+    assert_eq!(parse(b"1000"), Err(Error::Overflow(3)));
+}
+
+#[test]
+fn parse_partial_in_range_test() {
+    let parse = |x: &[u8]| parse_partial_in_range::<u8, STANDARD>(x, 0..=23, &OPTIONS);
+    assert_eq!(parse(b"23:59"), Ok((23, 2)));
+    assert_eq!(parse(b"7"), Ok((7, 1)));
+    assert_eq!(parse(b"24:00"), Err(Error::Overflow(1)));
+    assert_eq!(parse(b"123"), Err(Error::Overflow(2)));
+
+    let parse = |x: &[u8]| parse_partial_in_range::<u16, STANDARD>(x, 10..=20, &OPTIONS);
+    assert_eq!(parse(b"9,10"), Err(Error::Underflow(0)));
+    assert_eq!(parse(b"15,10"), Ok((15, 2)));
+}
+
+#[test]
+fn parse_with_bits_test() {
+    let parse = |x: &[u8]| parse_with_bits::<u16, STANDARD>(x, 12, &OPTIONS);
+    assert_eq!(parse(b"0"), Ok(0));
+    assert_eq!(parse(b"4095"), Ok(4095));
+    assert_eq!(parse(b"4096"), Err(Error::Overflow(3)));
+    assert_eq!(parse(b"65536"), Err(Error::Overflow(3)));
+
+    let parse = |x: &[u8]| parse_with_bits::<i16, STANDARD>(x, 12, &OPTIONS);
+    assert_eq!(parse(b"-2048"), Ok(-2048));
+    assert_eq!(parse(b"2047"), Ok(2047));
+    assert_eq!(parse(b"-2049"), Err(Error::Underflow(4)));
+    assert_eq!(parse(b"2048"), Err(Error::Overflow(3)));
+
+    assert_eq!(parse_with_bits::<u8, STANDARD>(b"255", 8, &OPTIONS), Ok(255));
+    assert_eq!(parse_with_bits::<i8, STANDARD>(b"-1", 1, &OPTIONS), Ok(-1));
+    assert_eq!(parse_with_bits::<i8, STANDARD>(b"1", 1, &OPTIONS), Err(Error::Overflow(0)));
+    assert_eq!(parse_with_bits::<u128, STANDARD>(b"1", 1, &OPTIONS), Ok(1));
+    assert_eq!(parse_with_bits::<u128, STANDARD>(b"2", 1, &OPTIONS), Err(Error::Overflow(0)));
+
+    let parse = |x: &[u8]| parse_partial_with_bits::<i8, STANDARD>(x, 4, &OPTIONS);
+    assert_eq!(parse(b"-8,7"), Ok((-8, 2)));
+    assert_eq!(parse(b"8,7"), Err(Error::Overflow(0)));
+}
+
+#[test]
+#[should_panic]
+fn parse_with_zero_bits_test() {
+    _ = parse_with_bits::<u8, STANDARD>(b"0", 0, &OPTIONS);
+}
+
+#[test]
+#[should_panic]
+fn parse_with_too_many_bits_test() {
+    _ = parse_with_bits::<u8, STANDARD>(b"0", 9, &OPTIONS);
+}
+
+#[test]
+fn parse_in_range_float_syntax_test() {
+    const OPTIONS: Options = Options::builder().float_syntax(true).build_strict();
+    let port = |x: &[u8]| parse_in_range::<u32, STANDARD>(x, 1..=65535, &OPTIONS);
+    assert_eq!(port(b"8.08e3"), Ok(8080));
+    assert_eq!(port(b"65535.0"), Ok(65535));
+    assert_eq!(port(b"65536.0"), Err(Error::Overflow(4)));
+    assert_eq!(port(b"6.5536e4"), Err(Error::Overflow(5)));
+    assert_eq!(port(b"7e4"), Err(Error::Overflow(2)));
+    assert_eq!(port(b"0.0"), Err(Error::Underflow(0)));
+    assert_eq!(port(b"1.5"), Err(Error::NotAnInteger(2)));
+}
+
+#[test]
+#[cfg(feature = "format")]
+fn parse_in_range_format_test() {
+    use core::num;
+
+    const FORMAT: u128 = NumberFormatBuilder::new()
+        .digit_separator(num::NonZeroU8::new(b'_'))
+        .integer_internal_digit_separator(true)
+        .build_strict();
+    let parse = |x: &[u8]| parse_in_range::<u32, FORMAT>(x, 0..=99_999, &OPTIONS);
+    assert_eq!(parse(b"99_999"), Ok(99_999));
+    assert_eq!(parse(b"100_000"), Err(Error::Overflow(6)));
+}
+
+#[test]
+#[cfg(all(feature = "power-of-two", feature = "format"))]
+fn parse_in_range_base_prefix_test() {
+    use core::num;
+
+    const HEX: u128 =
+        NumberFormatBuilder::new().base_prefix(num::NonZeroU8::new(b'x')).build_strict();
+    let parse = |x: &[u8]| parse_in_range::<u32, HEX>(x, 5..=10, &OPTIONS);
+    assert_eq!(parse(b"0x10"), Ok(10));
+    assert_eq!(parse(b"0x11"), Err(Error::Overflow(3)));
+    assert_eq!(parse(b"0x4"), Err(Error::Underflow(0)));
+}
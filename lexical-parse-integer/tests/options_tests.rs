@@ -15,3 +15,29 @@ fn options_tests() {
     assert!(OptionsBuilder::default().is_valid());
     assert_eq!(options.rebuild(), Options::builder());
 }
+
+#[test]
+fn invalid_exponent_test() {
+    let mut builder = OptionsBuilder::default();
+    builder = builder.exponent(b'\x00');
+    assert!(!builder.is_valid());
+    builder = builder.exponent(b'\x7f');
+    assert!(!builder.is_valid());
+    assert!(builder.build().is_err());
+    builder = builder.exponent(b'^');
+    assert!(builder.is_valid());
+    assert!(builder.build().is_ok());
+}
+
+#[test]
+fn invalid_decimal_point_test() {
+    let mut builder = OptionsBuilder::default();
+    builder = builder.decimal_point(b'\x00');
+    assert!(!builder.is_valid());
+    builder = builder.decimal_point(b'\x7f');
+    assert!(!builder.is_valid());
+    assert!(builder.build().is_err());
+    builder = builder.decimal_point(b',');
+    assert!(builder.is_valid());
+    assert!(builder.build().is_ok());
+}
@@ -0,0 +1,97 @@
+use lexical_parse_integer::stream::{Status, StreamParser};
+use lexical_parse_integer::FromLexical;
+use lexical_util::error::Error;
+
+/// Parse the input split at every index, and check the result matches
+/// parsing the input as a single slice.
+fn check_split<T>(input: &[u8])
+where
+    T: FromLexical
+        + lexical_parse_integer::FromLexicalWithOptions<Options = lexical_parse_integer::Options>
+        + core::fmt::Debug
+        + PartialEq,
+{
+    let expected = T::from_lexical_partial(input);
+    for index in 0..=input.len() {
+        let mut buffer = [0u8; 64];
+        let mut parser = StreamParser::<T>::new(&mut buffer);
+        let (first, second) = input.split_at(index);
+        let result = match parser.push(first) {
+            Ok(Status::Complete(value, count)) => Ok((value, count)),
+            Ok(Status::Incomplete) => match parser.push(second) {
+                Ok(Status::Complete(value, count)) => Ok((value, count)),
+                Ok(Status::Incomplete) => parser.finish(),
+                Err(error) => Err(error),
+            },
+            Err(error) => Err(error),
+        };
+        assert_eq!(result, expected, "input={:?}, index={}", input, index);
+    }
+}
+
+#[test]
+fn split_test() {
+    check_split::<u64>(b"12345,");
+    check_split::<u64>(b"12345");
+    check_split::<i32>(b"-2147483648 ");
+    check_split::<i32>(b"-2147483649 ");
+    check_split::<u8>(b"256,");
+    check_split::<u8>(b"-1");
+    check_split::<i8>(b"-");
+    check_split::<i8>(b"-1,");
+    check_split::<u8>(b"-1,");
+    check_split::<i8>(b"+,");
+    check_split::<u64>(b"");
+}
+
+#[test]
+fn chunks_test() {
+    let mut buffer = [0u8; 32];
+    let mut parser = StreamParser::<u32>::new(&mut buffer);
+    assert_eq!(parser.push(b"1"), Ok(Status::Incomplete));
+    assert_eq!(parser.push(b"2"), Ok(Status::Incomplete));
+    assert_eq!(parser.buffered(), b"12");
+    assert_eq!(parser.push(b"3 45"), Ok(Status::Complete(123, 3)));
+    assert!(parser.buffered().is_empty());
+    assert_eq!(parser.push(b"45"), Ok(Status::Incomplete));
+    assert_eq!(parser.push(b""), Ok(Status::Incomplete));
+    assert_eq!(parser.finish(), Ok((45, 2)));
+
+    // Errors use the index from the start of the number.
+    assert_eq!(parser.push(b"429496"), Ok(Status::Incomplete));
+    assert_eq!(parser.push(b"72960"), Err(Error::Overflow(9)));
+    assert_eq!(parser.push(b"+"), Ok(Status::Incomplete));
+    assert_eq!(parser.finish(), Err(Error::Empty(1)));
+    assert_eq!(parser.finish(), Err(Error::Empty(0)));
+}
+
+#[test]
+fn buffer_full_test() {
+    // One byte of the buffer is reserved.
+    let mut buffer = [0u8; 5];
+    let mut parser = StreamParser::<u64>::new(&mut buffer);
+    assert_eq!(parser.push(b"1234"), Ok(Status::Incomplete));
+    assert_eq!(parser.push(b"5"), Err(Error::BufferFull(4)));
+
+    // Still parses numbers that fit.
+    assert_eq!(parser.push(b"12"), Ok(Status::Incomplete));
+    assert_eq!(parser.push(b"3,456"), Ok(Status::Complete(123, 3)));
+    assert_eq!(parser.push(b"12345"), Err(Error::BufferFull(4)));
+}
+
+#[test]
+#[cfg(feature = "format")]
+fn format_test() {
+    use lexical_parse_integer::Options;
+    use lexical_util::format::NumberFormatBuilder;
+
+    const FORMAT: u128 = NumberFormatBuilder::new()
+        .digit_separator(core::num::NonZeroU8::new(b'_'))
+        .integer_internal_digit_separator(true)
+        .build_strict();
+    const OPTIONS: Options = Options::new();
+    let mut buffer = [0u8; 32];
+    let mut parser = StreamParser::<u64, FORMAT>::with_options(&mut buffer, &OPTIONS);
+    assert_eq!(parser.push(b"1_"), Ok(Status::Incomplete));
+    assert_eq!(parser.push(b"000 "), Ok(Status::Complete(1000, 5)));
+}
@@ -71,6 +71,58 @@ macro_rules! from_lexical {
             #[doc = concat!("assert_eq!(parsed, Ok((", stringify!($value), ", ", stringify!($len), ")));")]
             /// ```
             fn from_lexical_partial(bytes: &[u8]) -> lexical_util::result::Result<(Self, usize)>;
+
+            /// Checked parser for a string-to-number conversion from code units.
+            ///
+            /// This is identical to [`from_lexical`][Self::from_lexical], but
+            /// parses UTF-16 or UTF-32 code units, after narrowing the number to
+            /// bytes. Any error indexes are in code units.
+            ///
+            /// * `units`   - Slice containing a numeric string.
+            ///
+            /// # Examples
+            ///
+            /// ```rust
+            #[doc = concat!("use ", $name, "::FromLexical;")]
+            ///
+            #[doc = concat!("let value: Vec<u16> = \"", stringify!($value), "\".encode_utf16().collect();")]
+            #[doc = concat!("let parsed = ", stringify!($t), "::from_lexical_units(&value);")]
+            #[doc = concat!("assert_eq!(parsed, Ok(", stringify!($value), "));")]
+            /// ```
+            #[cfg(feature = "std")]
+            #[inline]
+            fn from_lexical_units<C: lexical_util::code_unit::CodeUnit>(
+                units: &[C],
+            ) -> lexical_util::result::Result<Self> {
+                // The standard format only uses `.` as punctuation.
+                C::with_bytes(units, b".", Self::from_lexical)
+            }
+
+            /// Checked parser for a string-to-number conversion from code units.
+            ///
+            /// This is identical to [`from_lexical_partial`][Self::from_lexical_partial],
+            /// but parses UTF-16 or UTF-32 code units, after narrowing the number to
+            /// bytes. The number of processed elements and any error indexes
+            /// are in code units.
+            ///
+            /// * `units`   - Slice containing a numeric string.
+            ///
+            /// # Examples
+            ///
+            /// ```rust
+            #[doc = concat!("use ", $name, "::FromLexical;")]
+            ///
+            #[doc = concat!("let value: Vec<u32> = \"", stringify!($value), "\".chars().map(u32::from).collect();")]
+            #[doc = concat!("let parsed = ", stringify!($t), "::from_lexical_partial_units(&value);")]
+            #[doc = concat!("assert_eq!(parsed, Ok((", stringify!($value), ", ", stringify!($len), ")));")]
+            /// ```
+            #[cfg(feature = "std")]
+            #[inline]
+            fn from_lexical_partial_units<C: lexical_util::code_unit::CodeUnit>(
+                units: &[C],
+            ) -> lexical_util::result::Result<(Self, usize)> {
+                C::with_bytes(units, b".", Self::from_lexical_partial)
+            }
         }
     };
 }
@@ -184,6 +236,87 @@ macro_rules! from_lexical_with_options {
                 bytes: &[u8],
                 options: &Self::Options,
             ) -> lexical_util::result::Result<(Self, usize)>;
+
+            /// Checked parser for a string-to-number conversion from code units.
+            ///
+            /// This is identical to
+            /// [`from_lexical_with_options`][Self::from_lexical_with_options],
+            /// but parses UTF-16 or UTF-32 code units, after narrowing the number to
+            /// bytes. Any error indexes are in code units.
+            ///
+            /// * `FORMAT`  - Flags and characters designating the number grammar.
+            /// * `units`   - Slice containing a numeric string.
+            /// * `options` - Options to dictate number parsing.
+            ///
+            /// # Examples
+            ///
+            /// ```rust
+            #[doc = concat!("use ", $name, "::{format, FromLexicalWithOptions, ", stringify!($ops_t), "};")]
+            ///
+            /// const FORMAT: u128 = format::STANDARD;
+            #[doc = concat!("const OPTIONS: ", stringify!($ops_t), " = ", stringify!($ops_t), "::new();")]
+            #[doc = concat!("let value: Vec<u16> = \"", stringify!($value), "\".encode_utf16().collect();")]
+            #[doc = concat!("let parsed = ", stringify!($t), "::from_lexical_units_with_options::<FORMAT, _>(&value, &OPTIONS);")]
+            #[doc = concat!("assert_eq!(parsed, Ok(", stringify!($value), "));")]
+            /// ```
+            #[cfg(feature = "std")]
+            #[inline]
+            fn from_lexical_units_with_options<
+                const FORMAT: u128,
+                C: lexical_util::code_unit::CodeUnit,
+            >(
+                units: &[C],
+                options: &Self::Options,
+            ) -> lexical_util::result::Result<Self> {
+                let punctuation =
+                    lexical_util::options::ParseOptions::punctuation::<FORMAT>(options);
+                C::with_bytes(units, &punctuation, |bytes| {
+                    Self::from_lexical_with_options::<FORMAT>(bytes, options)
+                })
+            }
+
+            /// Checked parser for a string-to-number conversion from code units.
+            ///
+            /// This is identical to
+            /// [`from_lexical_partial_with_options`][Self::from_lexical_partial_with_options],
+            /// but parses UTF-16 or UTF-32 code units, after narrowing the number to
+            /// bytes. The number of processed elements and any error indexes
+            /// are in code units.
+            ///
+            /// * `FORMAT`  - Flags and characters designating the number grammar.
+            /// * `units`   - Slice containing a numeric string.
+            /// * `options` - Options to dictate number parsing.
+            ///
+            /// # Examples
+            ///
+            /// ```rust
+            #[doc = concat!("use ", $name, "::{format, FromLexicalWithOptions, ", stringify!($ops_t), "};")]
+            ///
+            /// const FORMAT: u128 = format::STANDARD;
+            #[doc = concat!("const OPTIONS: ", stringify!($ops_t), " = ", stringify!($ops_t), "::new();")]
+            #[doc = concat!("let value: Vec<u16> = \"", stringify!($value), "\".encode_utf16().collect();")]
+            #[doc = concat!(
+                "let parsed = ",
+                stringify!($t),
+                "::from_lexical_partial_units_with_options::<FORMAT, _>(&value, &OPTIONS);"
+            )]
+            #[doc = concat!("assert_eq!(parsed, Ok((", stringify!($value), ", ", stringify!($len), ")));")]
+            /// ```
+            #[cfg(feature = "std")]
+            #[inline]
+            fn from_lexical_partial_units_with_options<
+                const FORMAT: u128,
+                C: lexical_util::code_unit::CodeUnit,
+            >(
+                units: &[C],
+                options: &Self::Options,
+            ) -> lexical_util::result::Result<(Self, usize)> {
+                let punctuation =
+                    lexical_util::options::ParseOptions::punctuation::<FORMAT>(options);
+                C::with_bytes(units, &punctuation, |bytes| {
+                    Self::from_lexical_partial_with_options::<FORMAT>(bytes, options)
+                })
+            }
         }
     };
 }
@@ -0,0 +1,750 @@
+//! Arbitrary-precision integers and radix conversions.
+//!
+//! This stores the magnitude as little-endian limbs, using the same limb
+//! type as the big integers for float parsing and writing, and converts
+//! between digits and limbs using divide-and-conquer algorithms, so both
+//! directions are subquadratic for large numbers:
+//!
+//! - Digits are grouped into chunks of the largest power of the radix that fits
+//!   in a limb, using the [`step`] tables, and adjacent halves are combined
+//!   with a multiplication by a power of the radix.
+//! - Limbs are split by dividing by a power of the radix, using a
+//!   Newton-iteration reciprocal, so each division only requires a few
+//!   multiplications.
+//!
+//! Multiplication uses Karatsuba's algorithm for large operands. Unlike
+//! the fixed-capacity `StackVec` used for floats, the limbs are stored in
+//! a [`Vec`], since the values have no upper bound.
+//!
+//! [`step`]: crate::step
+
+#![cfg(feature = "bigint")]
+
+use core::cmp::Ordering;
+
+use crate::format_flags::is_valid_radix;
+pub use crate::limb::Limb;
+use crate::limb::{compare, scalar_add, scalar_mul, Wide};
+use crate::step::min_step;
+
+/// The number of limbs for the shorter operand to use Karatsuba multiplication.
+const KARATSUBA_CUTOFF: usize = 32;
+
+/// The number of limbs or chunks to use the quadratic radix conversions.
+const CONVERSION_CUTOFF: usize = 32;
+
+// BIGUINT
+// -------
+
+/// Arbitrary-precision unsigned integer.
+///
+/// This is a thin wrapper around the little-endian limbs of the value,
+/// which never has any trailing zero limbs, so a value of `0` has no limbs.
+/// Limbs are 64 bits on 64-bit platforms with fast 128-bit
+/// multiplication, and 32 bits otherwise.
+///
+/// # Examples
+///
+/// ```rust
+/// use lexical_util::bigint::BigUint;
+///
+/// let value = BigUint::from_digits(&[1, 8, 4, 4, 6, 7, 4, 4, 0, 7, 3, 7, 0, 9, 5, 5, 1, 6, 1, 6], 10);
+/// assert_eq!(value.to_u128(), Some(1 << 64));
+/// assert_eq!(value.to_digits(10).len(), 20);
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct BigUint {
+    /// The little-endian limbs of the value.
+    limbs: Vec<Limb>,
+}
+
+impl BigUint {
+    /// Create a big integer with a value of `0`.
+    #[inline(always)]
+    pub const fn new() -> Self {
+        Self {
+            limbs: Vec::new(),
+        }
+    }
+
+    /// Create a big integer from little-endian limbs.
+    #[inline]
+    pub fn from_limbs(mut limbs: Vec<Limb>) -> Self {
+        normalize(&mut limbs);
+        Self {
+            limbs,
+        }
+    }
+
+    /// Get the little-endian limbs of the value.
+    #[inline(always)]
+    pub fn limbs(&self) -> &[Limb] {
+        &self.limbs
+    }
+
+    /// Get the little-endian limbs of the value, consuming the big integer.
+    #[inline(always)]
+    pub fn into_limbs(self) -> Vec<Limb> {
+        self.limbs
+    }
+
+    /// Get if the value is `0`.
+    #[inline(always)]
+    pub fn is_zero(&self) -> bool {
+        self.limbs.is_empty()
+    }
+
+    /// Get the number of bits required to store the value.
+    #[inline]
+    pub fn bit_length(&self) -> usize {
+        match self.limbs.last() {
+            Some(&hi) => self.limbs.len() * Limb::BITS as usize - hi.leading_zeros() as usize,
+            None => 0,
+        }
+    }
+
+    /// Get the value as a [`u64`], if it fits.
+    #[inline]
+    pub fn to_u64(&self) -> Option<u64> {
+        self.to_u128().and_then(|value| u64::try_from(value).ok())
+    }
+
+    /// Get the value as a [`u128`], if it fits.
+    #[inline]
+    pub fn to_u128(&self) -> Option<u128> {
+        if self.bit_length() > 128 {
+            return None;
+        }
+        Some(self.limbs.iter().rev().fold(0, |acc, &limb| acc << Limb::BITS | limb as u128))
+    }
+
+    /// Create a big integer from digits in the radix.
+    ///
+    /// The digits are the values of each digit, not the characters, and
+    /// start with the most significant digit. An empty slice is `0`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the radix is not supported by the enabled features, that
+    /// is `10`, powers of two with `power-of-two`, or `2..=36` with `radix`.
+    /// Digits must be smaller than the radix, otherwise the value is
+    /// unspecified.
+    pub fn from_digits(digits: &[u8], radix: u32) -> Self {
+        let (base, chunk_digits) = chunk_base(radix);
+        let first = match digits.len() % chunk_digits {
+            0 => chunk_digits,
+            count => count,
+        };
+        let mut chunks = Vec::with_capacity(digits.len() / chunk_digits + 1);
+        let mut index = 0;
+        let mut count = first;
+        while index < digits.len() {
+            let chunk = digits[index..index + count]
+                .iter()
+                .fold(0, |acc: Limb, &digit| acc * radix as Limb + digit as Limb);
+            chunks.push(chunk);
+            index += count;
+            count = chunk_digits;
+        }
+        Self::from_limbs(from_chunks(&chunks, base, &mut Vec::new()))
+    }
+
+    /// Get the digits of the value in the radix.
+    ///
+    /// The digits are the values of each digit, not the characters, and
+    /// start with the most significant digit. A value of `0` has a single
+    /// `0` digit.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the radix is not supported by the enabled features. See
+    /// [`from_digits`][Self::from_digits] for more details.
+    pub fn to_digits(&self, radix: u32) -> Vec<u8> {
+        let (base, _) = chunk_base(radix);
+        let mut digits = Vec::new();
+        if self.limbs.len() <= CONVERSION_CUTOFF {
+            small_to_digits(&self.limbs, radix, &mut digits, None);
+            return digits;
+        }
+
+        // Get the divisors, until the square of the last is larger than the value.
+        let mut divisors: Vec<Divisor> = Vec::new();
+        let mut power = vec![base];
+        loop {
+            let square = mul(&power, &power);
+            divisors.push(Divisor::new(power));
+            if compare(&square, &self.limbs) == Ordering::Greater {
+                break;
+            }
+            power = square;
+        }
+        large_to_digits(&self.limbs, &divisors, radix, &mut digits, None);
+        digits
+    }
+}
+
+impl PartialOrd for BigUint {
+    #[inline(always)]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for BigUint {
+    #[inline(always)]
+    fn cmp(&self, other: &Self) -> Ordering {
+        compare(&self.limbs, &other.limbs)
+    }
+}
+
+impl From<u64> for BigUint {
+    #[inline]
+    fn from(value: u64) -> Self {
+        Self::from(value as u128)
+    }
+}
+
+impl From<u128> for BigUint {
+    #[inline]
+    fn from(mut value: u128) -> Self {
+        let mut limbs = Vec::new();
+        while value != 0 {
+            limbs.push(value as Limb);
+            value >>= Limb::BITS;
+        }
+        Self {
+            limbs,
+        }
+    }
+}
+
+// BIGINT
+// ------
+
+/// Arbitrary-precision signed integer.
+///
+/// This stores the sign separately from the magnitude, and a value of `0`
+/// is never negative.
+///
+/// # Examples
+///
+/// ```rust
+/// use lexical_util::bigint::{BigInt, BigUint};
+///
+/// let value = BigInt::from_parts(true, BigUint::from(5u64));
+/// assert!(value.is_negative());
+/// assert_eq!(value.magnitude().to_u64(), Some(5));
+/// assert!(!BigInt::from_parts(true, BigUint::new()).is_negative());
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct BigInt {
+    /// If the value is negative.
+    is_negative: bool,
+    /// The absolute value.
+    magnitude: BigUint,
+}
+
+impl BigInt {
+    /// Create a big integer with a value of `0`.
+    #[inline(always)]
+    pub const fn new() -> Self {
+        Self {
+            is_negative: false,
+            magnitude: BigUint::new(),
+        }
+    }
+
+    /// Create a big integer from the sign and the absolute value.
+    #[inline]
+    pub fn from_parts(is_negative: bool, magnitude: BigUint) -> Self {
+        Self {
+            is_negative: is_negative && !magnitude.is_zero(),
+            magnitude,
+        }
+    }
+
+    /// Get the sign and the absolute value, consuming the big integer.
+    #[inline(always)]
+    pub fn into_parts(self) -> (bool, BigUint) {
+        (self.is_negative, self.magnitude)
+    }
+
+    /// Get if the value is negative.
+    #[inline(always)]
+    pub const fn is_negative(&self) -> bool {
+        self.is_negative
+    }
+
+    /// Get the absolute value.
+    #[inline(always)]
+    pub const fn magnitude(&self) -> &BigUint {
+        &self.magnitude
+    }
+
+    /// Get if the value is `0`.
+    #[inline(always)]
+    pub fn is_zero(&self) -> bool {
+        self.magnitude.is_zero()
+    }
+}
+
+impl PartialOrd for BigInt {
+    #[inline(always)]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for BigInt {
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self.is_negative, other.is_negative) {
+            (false, false) => self.magnitude.cmp(&other.magnitude),
+            (true, true) => other.magnitude.cmp(&self.magnitude),
+            (false, true) => Ordering::Greater,
+            (true, false) => Ordering::Less,
+        }
+    }
+}
+
+impl From<BigUint> for BigInt {
+    #[inline(always)]
+    fn from(value: BigUint) -> Self {
+        Self::from_parts(false, value)
+    }
+}
+
+impl From<i64> for BigInt {
+    #[inline]
+    fn from(value: i64) -> Self {
+        Self::from_parts(value < 0, BigUint::from(value.unsigned_abs()))
+    }
+}
+
+impl From<i128> for BigInt {
+    #[inline]
+    fn from(value: i128) -> Self {
+        Self::from_parts(value < 0, BigUint::from(value.unsigned_abs()))
+    }
+}
+
+// RADIX
+// -----
+
+/// Get a large power of the radix that fits in a limb, and its exponent.
+///
+/// This uses the step for a signed limb, so the power of the radix fits
+/// in a limb and not just the values smaller than it.
+///
+/// # Panics
+///
+/// Panics if the radix is not supported by the enabled features.
+#[inline]
+fn chunk_base(radix: u32) -> (Limb, usize) {
+    assert!(is_valid_radix(radix), "radix is not supported");
+    let chunk_digits = min_step(radix, Limb::BITS as usize, true);
+    ((radix as Limb).pow(chunk_digits as u32), chunk_digits)
+}
+
+/// Combine chunks of digits, starting with the most significant chunk.
+///
+/// Each chunk is smaller than the base, so this splits the chunks so the
+/// least significant half has a power-of-two number of chunks, and combines
+/// them as `hi * base^(2^i) + lo`. The powers `base^(2^i)` are cached in
+/// `cache` and shared by all the recursive calls.
+fn from_chunks(chunks: &[Limb], base: Limb, cache: &mut Vec<Vec<Limb>>) -> Vec<Limb> {
+    if chunks.len() <= CONVERSION_CUTOFF {
+        let mut value = Vec::new();
+        for &chunk in chunks {
+            mul_small(&mut value, base);
+            add_small(&mut value, chunk);
+        }
+        return value;
+    }
+
+    // Split so the lower half has at least half the chunks.
+    let mut index = 0;
+    let mut lo_count = 1;
+    while lo_count * 2 < chunks.len() {
+        index += 1;
+        lo_count *= 2;
+    }
+    let (hi, lo) = chunks.split_at(chunks.len() - lo_count);
+    let hi = from_chunks(hi, base, cache);
+    let lo = from_chunks(lo, base, cache);
+    while cache.len() <= index {
+        let power = match cache.last() {
+            Some(power) => mul(power, power),
+            None => vec![base],
+        };
+        cache.push(power);
+    }
+    let mut value = mul(&hi, &cache[index]);
+    add_assign(&mut value, &lo);
+    value
+}
+
+/// Write the digits of a small value using repeated division.
+///
+/// If `width` is provided, this pads the digits with leading zeros.
+fn small_to_digits(x: &[Limb], radix: u32, digits: &mut Vec<u8>, width: Option<usize>) {
+    let (base, chunk_digits) = chunk_base(radix);
+    let start = digits.len();
+    let mut value = x.to_vec();
+    while !value.is_empty() {
+        let mut chunk = divrem_small(&mut value, base);
+        for _ in 0..chunk_digits {
+            digits.push((chunk % radix as Limb) as u8);
+            chunk /= radix as Limb;
+        }
+    }
+    // Remove the leading zeros of the most significant chunk.
+    while digits.len() > start && digits.last() == Some(&0) {
+        digits.pop();
+    }
+    match width {
+        Some(width) => digits.resize(start + width, 0),
+        None if digits.len() == start => digits.push(0),
+        None => (),
+    }
+    digits[start..].reverse();
+}
+
+/// Write the digits of a large value using divide-and-conquer.
+///
+/// The value must be smaller than the square of the last divisor, and if
+/// `width` is provided, this pads the digits with leading zeros.
+fn large_to_digits(
+    x: &[Limb],
+    divisors: &[Divisor],
+    radix: u32,
+    digits: &mut Vec<u8>,
+    width: Option<usize>,
+) {
+    let (divisor, rest) = match divisors.split_last() {
+        Some(split) if x.len() > CONVERSION_CUTOFF => split,
+        _ => return small_to_digits(x, radix, digits, width),
+    };
+
+    // The divisor is `base^(2^i)`, so the remainder has `2^i` chunks.
+    let (_, chunk_digits) = chunk_base(radix);
+    let lo_width = chunk_digits << rest.len();
+    let (hi, lo) = divisor.divrem(x);
+    match width {
+        None if hi.is_empty() => large_to_digits(&lo, rest, radix, digits, None),
+        None => {
+            large_to_digits(&hi, rest, radix, digits, None);
+            large_to_digits(&lo, rest, radix, digits, Some(lo_width));
+        },
+        Some(width) => {
+            large_to_digits(&hi, rest, radix, digits, Some(width - lo_width));
+            large_to_digits(&lo, rest, radix, digits, Some(lo_width));
+        },
+    }
+}
+
+// DIVISION
+// --------
+
+/// A divisor with a pre-computed reciprocal for fast division.
+struct Divisor {
+    /// The divisor, shifted so the most significant bit is set.
+    value: Vec<Limb>,
+    /// The number of bits the divisor was shifted.
+    shift: u32,
+    /// The reciprocal, `floor(B^(2n) / value)`, for `n` limbs.
+    reciprocal: Vec<Limb>,
+}
+
+impl Divisor {
+    /// Create a divisor from a non-zero value.
+    fn new(value: Vec<Limb>) -> Self {
+        let shift = value.last().map_or(0, |hi| hi.leading_zeros());
+        let value = shl_bits(&value, shift);
+        let reciprocal = reciprocal(&value);
+        Self {
+            value,
+            shift,
+            reciprocal,
+        }
+    }
+
+    /// Get the quotient and remainder of `x` divided by the divisor.
+    ///
+    /// `x` must be smaller than the square of the divisor.
+    fn divrem(&self, x: &[Limb]) -> (Vec<Limb>, Vec<Limb>) {
+        let n = self.value.len();
+        let x = shl_bits(x, self.shift);
+        debug_assert!(x.len() <= 2 * n, "value must be smaller than the divisor squared");
+
+        // Barrett reduction: the estimate is at most a few smaller than the quotient.
+        let top = x.get(n - 1..).unwrap_or(&[]);
+        let product = mul(top, &self.reciprocal);
+        let mut quotient = product.get(n + 1..).unwrap_or(&[]).to_vec();
+        let mut remainder = x;
+        sub_assign(&mut remainder, &mul(&quotient, &self.value));
+        while compare(&remainder, &self.value) != Ordering::Less {
+            sub_assign(&mut remainder, &self.value);
+            add_small(&mut quotient, 1);
+        }
+        (quotient, shr_bits(&remainder, self.shift))
+    }
+}
+
+/// Calculate `floor(B^(2n) / d)`, where `d` has `n` limbs and the most
+/// significant bit set.
+///
+/// This calculates the reciprocal of the upper half of the limbs, then
+/// uses a Newton iteration to double the precision, followed by a small
+/// correction to get the exact value.
+fn reciprocal(d: &[Limb]) -> Vec<Limb> {
+    let n = d.len();
+    if n == 1 {
+        // `B^2 / d` does not fit in a wide type, but `d` is normalized.
+        let d = d[0] as Wide;
+        let mut r = Wide::MAX / d;
+        if Wide::MAX % d == d - 1 {
+            r += 1;
+        }
+        return vec![r as Limb, (r >> Limb::BITS) as Limb];
+    }
+
+    // Get the approximate reciprocal from the upper half of the limbs.
+    let h = (n + 1) / 2;
+    let mut r = shl_limbs(&reciprocal(&d[n - h..]), n - h);
+
+    // Newton iteration: `r + r * (B^(2n) - d * r) / B^(2n)`.
+    let one = shl_limbs(&[1], 2 * n);
+    let product = mul(d, &r);
+    if compare(&product, &one) != Ordering::Greater {
+        let mut error = one.clone();
+        sub_assign(&mut error, &product);
+        let delta = mul(&r, &error);
+        add_assign(&mut r, delta.get(2 * n..).unwrap_or(&[]));
+    } else {
+        let mut error = product;
+        sub_assign(&mut error, &one);
+        let delta = mul(&r, &error);
+        sub_assign(&mut r, delta.get(2 * n..).unwrap_or(&[]));
+    }
+
+    // Correct the estimate, which is only off by a small amount.
+    let mut product = mul(d, &r);
+    while compare(&product, &one) == Ordering::Greater {
+        sub_assign(&mut r, &[1]);
+        sub_assign(&mut product, d);
+    }
+    let mut error = one;
+    sub_assign(&mut error, &product);
+    while compare(&error, d) != Ordering::Less {
+        add_small(&mut r, 1);
+        sub_assign(&mut error, d);
+    }
+    r
+}
+
+// ARITHMETIC
+// ----------
+
+/// Remove any trailing zero limbs.
+#[inline(always)]
+fn normalize(x: &mut Vec<Limb>) {
+    while x.last() == Some(&0) {
+        x.pop();
+    }
+}
+
+/// Get the limbs without any trailing zero limbs.
+#[inline(always)]
+fn trim(x: &[Limb]) -> &[Limb] {
+    let count = x.iter().rev().take_while(|&&limb| limb == 0).count();
+    &x[..x.len() - count]
+}
+
+/// Multiply the value by a small value in-place.
+#[inline]
+fn mul_small(x: &mut Vec<Limb>, y: Limb) {
+    let mut carry = 0;
+    for limb in x.iter_mut() {
+        (*limb, carry) = scalar_mul(*limb, y, carry);
+    }
+    if carry != 0 {
+        x.push(carry);
+    }
+    normalize(x);
+}
+
+/// Add a small value to the value in-place.
+#[inline]
+fn add_small(x: &mut Vec<Limb>, y: Limb) {
+    add_at(x, &[y], 0);
+    normalize(x);
+}
+
+/// Divide the value by a small value in-place, returning the remainder.
+#[inline]
+fn divrem_small(x: &mut Vec<Limb>, y: Limb) -> Limb {
+    let mut remainder: Wide = 0;
+    for limb in x.iter_mut().rev() {
+        let value = remainder << Limb::BITS | *limb as Wide;
+        *limb = (value / y as Wide) as Limb;
+        remainder = value % y as Wide;
+    }
+    normalize(x);
+    remainder as Limb
+}
+
+/// Add `y` to `x` starting at limb `offset`, growing `x` as needed.
+fn add_at(x: &mut Vec<Limb>, y: &[Limb], offset: usize) {
+    if x.len() < offset + y.len() {
+        x.resize(offset + y.len(), 0);
+    }
+    let mut carry = false;
+    for (xi, &yi) in x[offset..].iter_mut().zip(y) {
+        let (value, c1) = scalar_add(*xi, yi);
+        let (value, c2) = scalar_add(value, carry as Limb);
+        *xi = value;
+        carry = c1 || c2;
+    }
+    let mut index = offset + y.len();
+    while carry {
+        if index == x.len() {
+            x.push(0);
+        }
+        let (value, c) = scalar_add(x[index], 1);
+        x[index] = value;
+        carry = c;
+        index += 1;
+    }
+}
+
+/// Add `y` to `x` in-place.
+#[inline]
+fn add_assign(x: &mut Vec<Limb>, y: &[Limb]) {
+    add_at(x, y, 0);
+    normalize(x);
+}
+
+/// Subtract `y` from `x` in-place, where `x >= y`.
+fn sub_assign(x: &mut Vec<Limb>, y: &[Limb]) {
+    debug_assert!(compare(trim(x), trim(y)) != Ordering::Less, "cannot subtract a larger value");
+    let mut borrow = false;
+    for (index, xi) in x.iter_mut().enumerate() {
+        let yi = y.get(index).copied().unwrap_or(0);
+        if index >= y.len() && !borrow {
+            break;
+        }
+        let (value, b1) = xi.overflowing_sub(yi);
+        let (value, b2) = value.overflowing_sub(borrow as Limb);
+        *xi = value;
+        borrow = b1 || b2;
+    }
+    normalize(x);
+}
+
+/// Shift the value left by whole limbs.
+#[inline]
+fn shl_limbs(x: &[Limb], n: usize) -> Vec<Limb> {
+    let mut result = vec![0; n];
+    result.extend_from_slice(x);
+    result
+}
+
+/// Shift the value left by less than a limb.
+fn shl_bits(x: &[Limb], n: u32) -> Vec<Limb> {
+    if n == 0 {
+        return x.to_vec();
+    }
+    let mut result = Vec::with_capacity(x.len() + 1);
+    let mut prev = 0;
+    for &limb in x {
+        result.push(limb << n | prev >> (Limb::BITS - n));
+        prev = limb;
+    }
+    result.push(prev >> (Limb::BITS - n));
+    normalize(&mut result);
+    result
+}
+
+/// Shift the value right by less than a limb.
+fn shr_bits(x: &[Limb], n: u32) -> Vec<Limb> {
+    if n == 0 {
+        return x.to_vec();
+    }
+    let mut result: Vec<Limb> = x
+        .iter()
+        .zip(x.iter().skip(1).chain(core::iter::once(&0)))
+        .map(|(&lo, &hi)| lo >> n | hi << (Limb::BITS - n))
+        .collect();
+    normalize(&mut result);
+    result
+}
+
+/// Multiply two values.
+fn mul(x: &[Limb], y: &[Limb]) -> Vec<Limb> {
+    let (x, y) = (trim(x), trim(y));
+    let (x, y) = if x.len() >= y.len() {
+        (x, y)
+    } else {
+        (y, x)
+    };
+    if y.is_empty() {
+        Vec::new()
+    } else if y.len() < KARATSUBA_CUTOFF {
+        long_mul(x, y)
+    } else if 2 * y.len() <= x.len() {
+        // Unbalanced: multiply by chunks of the longer value.
+        let mut result = Vec::with_capacity(x.len() + y.len());
+        for (index, chunk) in x.chunks(y.len()).enumerate() {
+            add_at(&mut result, &mul(chunk, y), index * y.len());
+        }
+        normalize(&mut result);
+        result
+    } else {
+        karatsuba_mul(x, y)
+    }
+}
+
+/// Multiply two values using the schoolbook algorithm.
+fn long_mul(x: &[Limb], y: &[Limb]) -> Vec<Limb> {
+    let mut result = vec![0; x.len() + y.len()];
+    for (i, &xi) in x.iter().enumerate() {
+        let mut carry = 0;
+        for (j, &yj) in y.iter().enumerate() {
+            let (value, hi) = scalar_mul(xi, yj, carry);
+            let (value, overflow) = scalar_add(value, result[i + j]);
+            result[i + j] = value;
+            carry = hi + overflow as Limb;
+        }
+        result[i + y.len()] = carry;
+    }
+    normalize(&mut result);
+    result
+}
+
+/// Multiply two values of similar size using Karatsuba's algorithm.
+///
+/// `x` must have at least as many limbs as `y`, and `y` must have more than
+/// half as many limbs as `x`.
+fn karatsuba_mul(x: &[Limb], y: &[Limb]) -> Vec<Limb> {
+    let half = x.len() / 2;
+    let (x0, x1) = x.split_at(half);
+    let (y0, y1) = y.split_at(half);
+    let z0 = mul(x0, y0);
+    let z2 = mul(x1, y1);
+
+    let mut xs = trim(x0).to_vec();
+    add_assign(&mut xs, x1);
+    let mut ys = trim(y0).to_vec();
+    add_assign(&mut ys, y1);
+    let mut z1 = mul(&xs, &ys);
+    sub_assign(&mut z1, &z0);
+    sub_assign(&mut z1, &z2);
+
+    let mut result = Vec::with_capacity(x.len() + y.len());
+    add_at(&mut result, &z0, 0);
+    add_at(&mut result, &z1, half);
+    add_at(&mut result, &z2, 2 * half);
+    normalize(&mut result);
+    result
+}
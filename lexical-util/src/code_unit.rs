@@ -0,0 +1,109 @@
+//! Parse numbers from UTF-16 and UTF-32 code units.
+//!
+//! The parsers are not generic over the code unit: they only operate on
+//! bytes, so the code units are not parsed in place. Instead, every
+//! character in a valid number is ASCII, so the code units of the number
+//! are narrowed to bytes before parsing, with 1 byte per code unit. This
+//! means error indexes and the number of processed elements are the same
+//! for the bytes and the code units, and the parsers keep all their
+//! optimizations for contiguous bytes. Code units outside of the ASCII
+//! range are narrowed to a byte that is never valid in a number, so they
+//! are reported as invalid digits rather than truncated to an ASCII
+//! character.
+//!
+//! Narrowing stops after the first code unit that can't be part of a
+//! number, so only the number is copied, and not the rest of the input.
+//! Short numbers are narrowed into a buffer on the stack, and only long
+//! numbers, such as floats with hundreds of digits, are narrowed into a
+//! buffer on the heap, which requires the `std` feature.
+
+#![cfg(all(feature = "std", any(feature = "parse-floats", feature = "parse-integers")))]
+
+/// The number of code units that can be narrowed without allocating.
+pub const STACK_BUFFER_SIZE: usize = 128;
+
+/// The byte for code units outside of the ASCII range.
+///
+/// This is never a valid UTF-8 byte, so it cannot match any character
+/// of a number.
+pub const INVALID_BYTE: u8 = 0xFF;
+
+/// A code unit of an encoded string, such as a UTF-16 code unit.
+pub trait CodeUnit: Copy {
+    /// Narrow the code unit to a byte.
+    ///
+    /// Code units outside of the ASCII range return [`INVALID_BYTE`],
+    /// except for bytes, which are returned unchanged.
+    fn to_byte(self) -> u8;
+
+    /// Call a function with the code units of a number narrowed to bytes.
+    ///
+    /// The bytes end after the first code unit that isn't an ASCII letter,
+    /// digit, sign, or in the `punctuation`, since neither it nor any
+    /// following code units can be part of the number.
+    #[inline(always)]
+    fn with_bytes<T, F>(units: &[Self], punctuation: &[u8], f: F) -> T
+    where
+        F: FnOnce(&[u8]) -> T,
+    {
+        let is_number_byte =
+            |c: u8| c.is_ascii_alphanumeric() || c == b'+' || c == b'-' || punctuation.contains(&c);
+        let length = match units.iter().position(|&unit| !is_number_byte(unit.to_byte())) {
+            Some(index) => index + 1,
+            None => units.len(),
+        };
+        let units = &units[..length];
+        let narrow = |buffer: &mut [u8]| {
+            for (byte, &unit) in buffer.iter_mut().zip(units.iter()) {
+                *byte = unit.to_byte();
+            }
+        };
+        if units.len() <= STACK_BUFFER_SIZE {
+            let mut buffer = [0u8; STACK_BUFFER_SIZE];
+            let buffer = &mut buffer[..units.len()];
+            narrow(buffer);
+            f(buffer)
+        } else {
+            let mut buffer = vec![0u8; units.len()];
+            narrow(&mut buffer);
+            f(&buffer)
+        }
+    }
+}
+
+impl CodeUnit for u8 {
+    #[inline(always)]
+    fn to_byte(self) -> u8 {
+        self
+    }
+
+    #[inline(always)]
+    fn with_bytes<T, F>(units: &[Self], _: &[u8], f: F) -> T
+    where
+        F: FnOnce(&[u8]) -> T,
+    {
+        f(units)
+    }
+}
+
+impl CodeUnit for u16 {
+    #[inline(always)]
+    fn to_byte(self) -> u8 {
+        if self < 0x80 {
+            self as u8
+        } else {
+            INVALID_BYTE
+        }
+    }
+}
+
+impl CodeUnit for u32 {
+    #[inline(always)]
+    fn to_byte(self) -> u8 {
+        if self < 0x80 {
+            self as u8
+        } else {
+            INVALID_BYTE
+        }
+    }
+}
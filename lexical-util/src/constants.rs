@@ -5,6 +5,8 @@
 
 #[cfg(feature = "f16")]
 use crate::bf16::bf16;
+#[cfg(feature = "f128")]
+use crate::f128::f128;
 #[cfg(feature = "f16")]
 use crate::f16::f16;
 
@@ -85,7 +87,6 @@ formatted_size_impl! {
     //f16 64 256 ;
     f32 64 256 ;
     f64 64 256 ;
-    //f128 128 512 ;
     //f256 256 1024 ;
 }
 
@@ -95,6 +96,9 @@ formatted_size_impl! {
     bf16 64 256 ;
 }
 
+#[cfg(feature = "f128")]
+formatted_size_impl! { f128 128 512 ; }
+
 #[cfg(target_pointer_width = "16")]
 formatted_size_impl! { isize 6 32 ; }
 #[cfg(target_pointer_width = "16")]
@@ -0,0 +1,646 @@
+//! Diagnostic errors with spans and expected-token information.
+//!
+//! [`Error`] only stores the kind of error and a single index, so it is
+//! cheap to return from the parsers. A [`Diagnostic`] is created from the
+//! error after parsing fails, from the input and the number format, and
+//! records the span of the offending characters, the component of the
+//! number, the class of characters that was expected, and the number
+//! format rule that rejected the input. This means the diagnostics have
+//! no cost unless an error occurs and a diagnostic is requested.
+//!
+//! The [`Display`][fmt::Display] implementation renders the input with a
+//! caret under the span, like a compiler error:
+//!
+//! ```text
+//! lexical parse error: invalid `+` sign in exponent at 4..5
+//!  |
+//!  | 1.1e+15
+//!  |     ^ expected a digit or `-` sign in the exponent, found `+`
+//!  = note: this format forbids positive exponent signs
+//! ```
+
+#![cfg(feature = "diagnostic")]
+
+use core::fmt;
+use core::ops::Range;
+
+use crate::digit::char_to_digit_const;
+use crate::error::Error;
+use crate::format::NumberFormat;
+
+/// A syntactic component of a number.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Component {
+    /// The sign before the significant digits.
+    Sign,
+    /// The base prefix, such as `0x`.
+    Prefix,
+    /// The integer digits.
+    Integer,
+    /// The decimal point and the fraction digits.
+    Fraction,
+    /// The exponent marker, sign, and digits.
+    Exponent,
+    /// The base suffix, such as `h`.
+    Suffix,
+}
+
+impl fmt::Display for Component {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Self::Sign => "sign",
+            Self::Prefix => "base prefix",
+            Self::Integer => "integer",
+            Self::Fraction => "fraction",
+            Self::Exponent => "exponent",
+            Self::Suffix => "base suffix",
+        };
+        formatter.write_str(name)
+    }
+}
+
+/// The class of characters expected at the span of an error.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Expected {
+    /// A digit of the radix.
+    Digit(u32),
+    /// A digit other than `0`.
+    NonZeroDigit,
+    /// A `+` or `-` sign.
+    Sign,
+    /// A digit or a `-` sign.
+    NegativeSignOrDigit,
+    /// A digit or a `+` sign.
+    PositiveSignOrDigit,
+    /// The decimal point character.
+    DecimalPoint(u8),
+    /// The exponent marker character.
+    ExponentMarker(u8),
+    /// The end of the number.
+    End,
+}
+
+impl fmt::Display for Expected {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Digit(10) => formatter.write_str("a digit"),
+            Self::Digit(radix) => write!(formatter, "a base-{radix} digit"),
+            Self::NonZeroDigit => formatter.write_str("a non-zero digit"),
+            Self::Sign => formatter.write_str("a `+` or `-` sign"),
+            Self::NegativeSignOrDigit => formatter.write_str("a digit or `-` sign"),
+            Self::PositiveSignOrDigit => formatter.write_str("a digit or `+` sign"),
+            Self::DecimalPoint(c) => write!(formatter, "the decimal point `{}`", char::from(*c)),
+            Self::ExponentMarker(c) => {
+                write!(formatter, "the exponent marker `{}`", char::from(*c))
+            },
+            Self::End => formatter.write_str("the end of the number"),
+        }
+    }
+}
+
+/// The [`NumberFormat`] rule that rejected the input.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Rule {
+    /// [`required_integer_digits`][NumberFormat::required_integer_digits].
+    RequiredIntegerDigits,
+    /// [`required_fraction_digits`][NumberFormat::required_fraction_digits].
+    RequiredFractionDigits,
+    /// [`required_exponent_digits`][NumberFormat::required_exponent_digits].
+    RequiredExponentDigits,
+    /// [`required_mantissa_digits`][NumberFormat::required_mantissa_digits].
+    RequiredMantissaDigits,
+    /// [`no_positive_mantissa_sign`][NumberFormat::no_positive_mantissa_sign].
+    NoPositiveMantissaSign,
+    /// [`required_mantissa_sign`][NumberFormat::required_mantissa_sign].
+    RequiredMantissaSign,
+    /// [`no_exponent_notation`][NumberFormat::no_exponent_notation].
+    NoExponentNotation,
+    /// [`no_positive_exponent_sign`][NumberFormat::no_positive_exponent_sign].
+    NoPositiveExponentSign,
+    /// [`required_exponent_sign`][NumberFormat::required_exponent_sign].
+    RequiredExponentSign,
+    /// [`no_exponent_without_fraction`][NumberFormat::no_exponent_without_fraction].
+    NoExponentWithoutFraction,
+    /// [`no_integer_leading_zeros`][NumberFormat::no_integer_leading_zeros].
+    NoIntegerLeadingZeros,
+    /// [`no_float_leading_zeros`][NumberFormat::no_float_leading_zeros].
+    NoFloatLeadingZeros,
+    /// [`required_exponent_notation`][NumberFormat::required_exponent_notation].
+    RequiredExponentNotation,
+    /// Leading digit separators are not enabled for the component.
+    LeadingDigitSeparator(Component),
+    /// Internal digit separators are not enabled for the component.
+    InternalDigitSeparator(Component),
+    /// Trailing digit separators are not enabled for the component.
+    TrailingDigitSeparator(Component),
+    /// Consecutive digit separators are not enabled for the component.
+    ConsecutiveDigitSeparator(Component),
+}
+
+impl fmt::Display for Rule {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::RequiredIntegerDigits => formatter.write_str("requires integer digits"),
+            Self::RequiredFractionDigits => {
+                formatter.write_str("requires digits after the decimal point")
+            },
+            Self::RequiredExponentDigits => {
+                formatter.write_str("requires digits after the exponent marker")
+            },
+            Self::RequiredMantissaDigits => formatter.write_str("requires significant digits"),
+            Self::NoPositiveMantissaSign => formatter.write_str("forbids positive signs"),
+            Self::RequiredMantissaSign => formatter.write_str("requires a `+` or `-` sign"),
+            Self::NoExponentNotation => formatter.write_str("forbids exponent notation"),
+            Self::NoPositiveExponentSign => formatter.write_str("forbids positive exponent signs"),
+            Self::RequiredExponentSign => {
+                formatter.write_str("requires a `+` or `-` sign for the exponent")
+            },
+            Self::NoExponentWithoutFraction => {
+                formatter.write_str("forbids an exponent without a fraction")
+            },
+            Self::NoIntegerLeadingZeros => formatter.write_str("forbids leading zeros in integers"),
+            Self::NoFloatLeadingZeros => formatter.write_str("forbids leading zeros in floats"),
+            Self::RequiredExponentNotation => formatter.write_str("requires exponent notation"),
+            Self::LeadingDigitSeparator(component) => {
+                write!(formatter, "forbids leading digit separators in the {component}")
+            },
+            Self::InternalDigitSeparator(component) => {
+                write!(formatter, "forbids internal digit separators in the {component}")
+            },
+            Self::TrailingDigitSeparator(component) => {
+                write!(formatter, "forbids trailing digit separators in the {component}")
+            },
+            Self::ConsecutiveDigitSeparator(component) => {
+                write!(formatter, "forbids consecutive digit separators in the {component}")
+            },
+        }
+    }
+}
+
+/// The type of number a diagnostic is created for.
+#[derive(Copy, Clone)]
+enum Target {
+    /// A float, with the decimal point and exponent characters.
+    Float(u8, u8),
+    /// An integer, and if it can hold negative values.
+    Integer(bool),
+}
+
+/// A parse error with the span, component, expected characters, and
+/// format rule of the error.
+///
+/// Errors without an index, such as invalid number formats or options,
+/// have no span, component, expected characters, or rule.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Diagnostic<'a> {
+    /// The error returned by the parser.
+    error: Error,
+    /// The parsed input.
+    input: &'a [u8],
+    /// The span of the error in the input.
+    span: Option<Range<usize>>,
+    /// The component of the number containing the error.
+    component: Option<Component>,
+    /// The class of characters expected at the span.
+    expected: Option<Expected>,
+    /// The number format rule that rejected the input.
+    rule: Option<Rule>,
+}
+
+impl<'a> Diagnostic<'a> {
+    /// Create a diagnostic for an error parsing a float, with the default
+    /// punctuation.
+    ///
+    /// This uses `.` as the decimal point and `e` as the exponent marker,
+    /// the defaults of the parse options.
+    ///
+    /// * `FORMAT`  - Packed struct containing the number format.
+    /// * `error`   - The error returned by the parser.
+    /// * `input`   - The parsed input.
+    #[inline]
+    pub fn new<const FORMAT: u128>(error: Error, input: &'a [u8]) -> Self {
+        Self::with_punctuation::<FORMAT>(error, input, b'.', b'e')
+    }
+
+    /// Create a diagnostic for an error parsing a float, with custom
+    /// punctuation.
+    ///
+    /// * `FORMAT`          - Packed struct containing the number format.
+    /// * `error`           - The error returned by the parser.
+    /// * `input`           - The parsed input.
+    /// * `decimal_point`   - The decimal point character of the options.
+    /// * `exponent`        - The exponent character of the options.
+    #[inline]
+    pub fn with_punctuation<const FORMAT: u128>(
+        error: Error,
+        input: &'a [u8],
+        decimal_point: u8,
+        exponent: u8,
+    ) -> Self {
+        Self::create::<FORMAT>(error, input, Target::Float(decimal_point, exponent))
+    }
+
+    /// Create a diagnostic for an error parsing an integer.
+    ///
+    /// Integers have no fraction or exponent, so decimal points and
+    /// exponent characters are invalid digits in the integer, and negative
+    /// signs are invalid signs for unsigned integers.
+    ///
+    /// * `FORMAT`      - Packed struct containing the number format.
+    /// * `error`       - The error returned by the parser.
+    /// * `input`       - The parsed input.
+    /// * `is_signed`   - If the integer type can hold negative values.
+    #[inline]
+    pub fn integer<const FORMAT: u128>(error: Error, input: &'a [u8], is_signed: bool) -> Self {
+        Self::create::<FORMAT>(error, input, Target::Integer(is_signed))
+    }
+
+    /// Create a diagnostic for an error parsing a number of the target type.
+    fn create<const FORMAT: u128>(error: Error, input: &'a [u8], target: Target) -> Self {
+        let index = match error.index() {
+            Some(&index) => index.min(input.len()),
+            None => {
+                return Self {
+                    error,
+                    input,
+                    span: None,
+                    component: None,
+                    expected: None,
+                    rule: None,
+                }
+            },
+        };
+
+        let format = NumberFormat::<{ FORMAT }> {};
+        let punctuation = match target {
+            Target::Float(decimal_point, exponent) => Some((decimal_point, exponent)),
+            Target::Integer(_) => None,
+        };
+        let (decimal_point, exponent) = punctuation.unwrap_or((b'.', b'e'));
+        let scanned = component_at::<FORMAT>(input, index, punctuation);
+        let mut span = index..index + char_width(&input[index..]);
+        let (component, expected, rule) = match error {
+            Error::Overflow(_) | Error::Underflow(_) => (scanned, None, None),
+            Error::BufferFull(_) | Error::NotAnInteger(_) => (scanned, None, None),
+            Error::InvalidDigit(_) | Error::Empty(_)
+                if scanned == Component::Sign && matches!(target, Target::Integer(false)) =>
+            {
+                // Unsigned integers only accept positive signs.
+                (Component::Sign, Some(Expected::PositiveSignOrDigit), None)
+            },
+            Error::InvalidDigit(_) | Error::Empty(_) => {
+                let radix = component_radix::<FORMAT>(scanned);
+                let rule = separator_rule::<FORMAT>(input, index, scanned);
+                (scanned, Some(Expected::Digit(radix)), rule)
+            },
+            Error::EmptyMantissa(_) => (
+                scanned,
+                Some(Expected::Digit(format.mantissa_radix())),
+                Some(Rule::RequiredMantissaDigits),
+            ),
+            Error::EmptyInteger(_) => (
+                Component::Integer,
+                Some(Expected::Digit(format.mantissa_radix())),
+                Some(Rule::RequiredIntegerDigits),
+            ),
+            Error::EmptyFraction(_) => (
+                Component::Fraction,
+                Some(Expected::Digit(format.mantissa_radix())),
+                Some(Rule::RequiredFractionDigits),
+            ),
+            Error::EmptyExponent(_) => (
+                Component::Exponent,
+                Some(Expected::Digit(format.exponent_radix())),
+                Some(Rule::RequiredExponentDigits),
+            ),
+            Error::InvalidPositiveMantissaSign(_) | Error::InvalidPositiveSign(_) => (
+                Component::Sign,
+                Some(Expected::NegativeSignOrDigit),
+                Some(Rule::NoPositiveMantissaSign),
+            ),
+            Error::MissingMantissaSign(_) | Error::MissingSign(_) => {
+                (Component::Sign, Some(Expected::Sign), Some(Rule::RequiredMantissaSign))
+            },
+            Error::InvalidNegativeSign(_) => {
+                (Component::Sign, Some(Expected::PositiveSignOrDigit), None)
+            },
+            Error::InvalidExponent(_) => {
+                (Component::Exponent, Some(Expected::End), Some(Rule::NoExponentNotation))
+            },
+            Error::InvalidPositiveExponentSign(_) => (
+                Component::Exponent,
+                Some(Expected::NegativeSignOrDigit),
+                Some(Rule::NoPositiveExponentSign),
+            ),
+            Error::MissingExponentSign(_) => {
+                (Component::Exponent, Some(Expected::Sign), Some(Rule::RequiredExponentSign))
+            },
+            Error::ExponentWithoutFraction(_) => (
+                Component::Exponent,
+                Some(Expected::DecimalPoint(decimal_point)),
+                Some(Rule::NoExponentWithoutFraction),
+            ),
+            Error::MissingExponent(_) => (
+                Component::Exponent,
+                Some(Expected::ExponentMarker(exponent)),
+                Some(Rule::RequiredExponentNotation),
+            ),
+            Error::InvalidLeadingZeros(_) => {
+                // Span all the leading zeros.
+                let zeros = input[index..].iter().take_while(|&&c| c == b'0').count();
+                span = index..index + zeros.max(span.len());
+                let has_float_syntax = input[index..]
+                    .iter()
+                    .any(|&c| c == decimal_point || c.eq_ignore_ascii_case(&exponent));
+                let rule = if punctuation.is_some()
+                    && format.no_float_leading_zeros()
+                    && (has_float_syntax || !format.no_integer_leading_zeros())
+                {
+                    Rule::NoFloatLeadingZeros
+                } else {
+                    Rule::NoIntegerLeadingZeros
+                };
+                (Component::Integer, Some(Expected::NonZeroDigit), Some(rule))
+            },
+            _ => (scanned, None, None),
+        };
+
+        Self {
+            error,
+            input,
+            span: Some(span),
+            component: Some(component),
+            expected,
+            rule,
+        }
+    }
+
+    /// Get the error returned by the parser.
+    #[inline(always)]
+    pub const fn error(&self) -> Error {
+        self.error
+    }
+
+    /// Get the parsed input.
+    #[inline(always)]
+    pub const fn input(&self) -> &'a [u8] {
+        self.input
+    }
+
+    /// Get the byte span of the error in the input.
+    ///
+    /// The span is empty if the error is at the end of the input.
+    #[inline(always)]
+    pub fn span(&self) -> Option<Range<usize>> {
+        self.span.clone()
+    }
+
+    /// Get the component of the number containing the error.
+    #[inline(always)]
+    pub const fn component(&self) -> Option<Component> {
+        self.component
+    }
+
+    /// Get the class of characters expected at the span.
+    #[inline(always)]
+    pub const fn expected(&self) -> Option<Expected> {
+        self.expected
+    }
+
+    /// Get the number format rule that rejected the input.
+    #[inline(always)]
+    pub const fn rule(&self) -> Option<Rule> {
+        self.rule
+    }
+
+    /// Get the bytes found at the span, or `None` at the end of the input.
+    #[inline]
+    pub fn found(&self) -> Option<&'a [u8]> {
+        match &self.span {
+            Some(span) if !span.is_empty() => Some(&self.input[span.clone()]),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for Diagnostic<'_> {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let span = match &self.span {
+            Some(span) => span,
+            None => return fmt::Display::fmt(&self.error, formatter),
+        };
+
+        let description = self.error.description().trim_matches('\'');
+        writeln!(formatter, "lexical parse error: {description} at {}..{}", span.start, span.end)?;
+        writeln!(formatter, " |")?;
+        formatter.write_str(" | ")?;
+        let columns = write_escaped(formatter, self.input, span)?;
+        writeln!(formatter)?;
+        formatter.write_str(" | ")?;
+        for _ in 0..columns.start {
+            formatter.write_str(" ")?;
+        }
+        for _ in columns.start..columns.end.max(columns.start + 1) {
+            formatter.write_str("^")?;
+        }
+        if let Some(expected) = self.expected {
+            write!(formatter, " expected {expected}")?;
+            if let Some(component) = self.component {
+                write!(formatter, " in the {component}")?;
+            }
+            match self.found() {
+                Some(found) => {
+                    formatter.write_str(", found `")?;
+                    write_escaped(formatter, found, &(0..0))?;
+                    formatter.write_str("`")?;
+                },
+                None => formatter.write_str(", found the end of the input")?,
+            }
+        }
+        if let Some(rule) = self.rule {
+            write!(formatter, "\n = note: this format {rule}")?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for Diagnostic<'_> {
+}
+
+/// Get the component of the number containing the byte at the index.
+///
+/// Integers have no `punctuation`, so all their digits are in the integer.
+fn component_at<const FORMAT: u128>(
+    input: &[u8],
+    index: usize,
+    punctuation: Option<(u8, u8)>,
+) -> Component {
+    let format = NumberFormat::<{ FORMAT }> {};
+    let radix = format.mantissa_radix();
+    let mut start = 0;
+    if matches!(input.first(), Some(b'+' | b'-')) {
+        if index == 0 {
+            return Component::Sign;
+        }
+        start = 1;
+    }
+
+    let prefix = format.base_prefix();
+    if prefix != 0
+        && input.get(start) == Some(&b'0')
+        && input
+            .get(start + 1)
+            .map_or(false, |&c| is_cased(c, prefix, format.case_sensitive_base_prefix()))
+    {
+        if index < start + 2 {
+            return Component::Prefix;
+        }
+        start += 2;
+    }
+
+    let suffix = format.base_suffix();
+    if suffix != 0
+        && index > start
+        && index + 1 == input.len()
+        && is_cased(input[index], suffix, format.case_sensitive_base_suffix())
+        && char_to_digit_const(input[index], radix).is_none()
+    {
+        return Component::Suffix;
+    }
+
+    let mut component = Component::Integer;
+    let (decimal_point, exponent) = match punctuation {
+        Some(punctuation) => punctuation,
+        None => return component,
+    };
+    for (i, &c) in input.iter().enumerate().skip(start) {
+        if component == Component::Integer && c == decimal_point {
+            component = Component::Fraction;
+        } else if component != Component::Exponent
+            && is_cased(c, exponent, format.case_sensitive_exponent())
+            && char_to_digit_const(c, radix).is_none()
+        {
+            component = Component::Exponent;
+        }
+        if i == index {
+            break;
+        }
+    }
+    component
+}
+
+/// Get the radix of the digits of a component.
+#[inline(always)]
+fn component_radix<const FORMAT: u128>(component: Component) -> u32 {
+    let format = NumberFormat::<{ FORMAT }> {};
+    match component {
+        Component::Exponent => format.exponent_radix(),
+        _ => format.mantissa_radix(),
+    }
+}
+
+/// Get the digit separator rule if the byte at the index is a digit
+/// separator.
+fn separator_rule<const FORMAT: u128>(
+    input: &[u8],
+    index: usize,
+    component: Component,
+) -> Option<Rule> {
+    let format = NumberFormat::<{ FORMAT }> {};
+    let separator = format.digit_separator();
+    if separator == 0 || input.get(index) != Some(&separator) {
+        return None;
+    }
+    if !matches!(component, Component::Integer | Component::Fraction | Component::Exponent) {
+        return None;
+    }
+
+    let radix = component_radix::<FORMAT>(component);
+    let is_digit = |c: Option<&u8>| c.map_or(false, |&c| char_to_digit_const(c, radix).is_some());
+    let previous = index.checked_sub(1).and_then(|i| input.get(i));
+    let next = input.get(index + 1);
+    if previous == Some(&separator) || next == Some(&separator) {
+        Some(Rule::ConsecutiveDigitSeparator(component))
+    } else if !is_digit(previous) {
+        Some(Rule::LeadingDigitSeparator(component))
+    } else if !is_digit(next) {
+        Some(Rule::TrailingDigitSeparator(component))
+    } else {
+        Some(Rule::InternalDigitSeparator(component))
+    }
+}
+
+/// Check if a byte matches a character, optionally ignoring case.
+#[inline(always)]
+fn is_cased(c: u8, expected: u8, is_case_sensitive: bool) -> bool {
+    if is_case_sensitive {
+        c == expected
+    } else {
+        c.eq_ignore_ascii_case(&expected)
+    }
+}
+
+/// Get the number of bytes in the UTF-8 character at the start of the
+/// input, which is 1 for invalid UTF-8 and 0 for empty input.
+#[inline]
+fn char_width(input: &[u8]) -> usize {
+    let width = match input.first() {
+        None => return 0,
+        Some(0xC2..=0xDF) => 2,
+        Some(0xE0..=0xEF) => 3,
+        Some(0xF0..=0xF4) => 4,
+        Some(_) => 1,
+    };
+    match input.get(..width).map(core::str::from_utf8) {
+        Some(Ok(_)) => width,
+        _ => 1,
+    }
+}
+
+/// Write the input, escaping control characters and invalid UTF-8.
+///
+/// Returns the columns of the span in the written input, counting each
+/// character as 1 column.
+fn write_escaped(
+    formatter: &mut fmt::Formatter<'_>,
+    input: &[u8],
+    span: &Range<usize>,
+) -> core::result::Result<Range<usize>, fmt::Error> {
+    let mut columns = 0..0;
+    let mut column = 0;
+    let mut index = 0;
+    while index < input.len() {
+        if index == span.start {
+            columns.start = column;
+        }
+        let width = char_width(&input[index..]);
+        let c = core::str::from_utf8(&input[index..index + width])
+            .ok()
+            .and_then(|string| string.chars().next());
+        match c {
+            Some(c) if !c.is_control() => {
+                write!(formatter, "{c}")?;
+                column += 1;
+            },
+            Some(c) => {
+                for escaped in c.escape_default() {
+                    write!(formatter, "{escaped}")?;
+                    column += 1;
+                }
+            },
+            None => {
+                write!(formatter, "\\x{:02x}", input[index])?;
+                column += 4;
+            },
+        }
+        index += width;
+        if index == span.end {
+            columns.end = column;
+        }
+    }
+    if span.start >= input.len() {
+        columns = column..column;
+    }
+    Ok(columns)
+}
@@ -52,6 +52,10 @@ pub enum Error {
     InvalidPositiveSign(usize),
     /// Invalid negative sign for an unsigned type was found.
     InvalidNegativeSign(usize),
+    /// The number is too long for the buffer when parsing chunked input.
+    BufferFull(usize),
+    /// The number has valid syntax, but it is not an exact integer.
+    NotAnInteger(usize),
 
     // NUMBER FORMAT ERRORS
     /// Invalid radix for the mantissa (significant) digits.
@@ -88,6 +92,8 @@ pub enum Error {
     InvalidConsecutiveExponentDigitSeparator,
     /// Invalid flags were set without the format feature.
     InvalidFlags,
+    /// The format is valid, but it is not a prebuilt format.
+    UnsupportedFormat,
 
     // OPTION ERRORS
     /// Invalid NaN string: must start with an `n` character.
@@ -114,6 +120,10 @@ pub enum Error {
     InvalidNegativeExponentBreak,
     /// Invalid positive exponent break: break is below 0.
     InvalidPositiveExponentBreak,
+    /// Invalid group separator character.
+    InvalidGroupSeparator,
+    /// Invalid fraction digits for writing floats: too many digits.
+    InvalidFractionDigits,
 
     // NOT AN ERROR
     /// An error did not actually occur, and the result was successful.
@@ -159,6 +169,8 @@ impl Error {
             Self::MissingSign(_) => "'missing required `+/-` sign for integer'",
             Self::InvalidPositiveSign(_) => "'invalid `+` sign for an integer was found'",
             Self::InvalidNegativeSign(_) => "'invalid `-` sign for an unsigned type was found'",
+            Self::BufferFull(_) => "'number is too long for the stream buffer'",
+            Self::NotAnInteger(_) => "'number is not an exact integer'",
 
             // NUMBER FORMAT ERRORS
             Self::InvalidMantissaRadix => "'invalid radix for mantissa digits'",
@@ -178,6 +190,7 @@ impl Error {
             Self::InvalidConsecutiveFractionDigitSeparator => "'enabled consecutive digit separators in the fraction without setting a valid location'",
             Self::InvalidConsecutiveExponentDigitSeparator => "'enabled consecutive digit separators in the exponent without setting a valid location'",
             Self::InvalidFlags => "'invalid flags enabled without the format feature'",
+            Self::UnsupportedFormat => "'format is not a prebuilt format'",
 
             // OPTION ERRORS
             Self::InvalidNanString => "'NaN string must started with `n`'",
@@ -192,6 +205,8 @@ impl Error {
             Self::InvalidFloatPrecision => "'invalid float precision: min digits is larger than max digits'",
             Self::InvalidNegativeExponentBreak => "'invalid negative exponent break: value is above 0'",
             Self::InvalidPositiveExponentBreak => "'invalid positive exponent break: value is below 0'",
+            Self::InvalidGroupSeparator => "'invalid group separator character'",
+            Self::InvalidFractionDigits => "'invalid fraction digits: value is above the maximum'",
 
             // NOT AN ERROR
             Self::Success => "'not actually an error'",
@@ -222,6 +237,8 @@ impl Error {
             Self::MissingSign(index) => Some(index),
             Self::InvalidPositiveSign(index) => Some(index),
             Self::InvalidNegativeSign(index) => Some(index),
+            Self::BufferFull(index) => Some(index),
+            Self::NotAnInteger(index) => Some(index),
 
             // NUMBER FORMAT ERRORS
             Self::InvalidMantissaRadix => None,
@@ -241,6 +258,7 @@ impl Error {
             Self::InvalidConsecutiveFractionDigitSeparator => None,
             Self::InvalidConsecutiveExponentDigitSeparator => None,
             Self::InvalidFlags => None,
+            Self::UnsupportedFormat => None,
 
             // OPTION ERRORS
             Self::InvalidNanString => None,
@@ -255,6 +273,79 @@ impl Error {
             Self::InvalidFloatPrecision => None,
             Self::InvalidNegativeExponentBreak => None,
             Self::InvalidPositiveExponentBreak => None,
+            Self::InvalidGroupSeparator => None,
+            Self::InvalidFractionDigits => None,
+
+            // NOT AN ERROR
+            Self::Success => None,
+        }
+    }
+
+    /// Get a mutable reference to the index for the parsing error.
+    ///
+    /// This is used to translate the index when the parsed bytes are
+    /// not the bytes of the input, such as after decoding the digits.
+    #[inline]
+    pub fn index_mut(&mut self) -> Option<&mut usize> {
+        match self {
+            // PARSE ERRORS
+            Self::Overflow(index) => Some(index),
+            Self::Underflow(index) => Some(index),
+            Self::InvalidDigit(index) => Some(index),
+            Self::Empty(index) => Some(index),
+            Self::EmptyMantissa(index) => Some(index),
+            Self::EmptyExponent(index) => Some(index),
+            Self::EmptyInteger(index) => Some(index),
+            Self::EmptyFraction(index) => Some(index),
+            Self::InvalidPositiveMantissaSign(index) => Some(index),
+            Self::MissingMantissaSign(index) => Some(index),
+            Self::InvalidExponent(index) => Some(index),
+            Self::InvalidPositiveExponentSign(index) => Some(index),
+            Self::MissingExponentSign(index) => Some(index),
+            Self::ExponentWithoutFraction(index) => Some(index),
+            Self::InvalidLeadingZeros(index) => Some(index),
+            Self::MissingExponent(index) => Some(index),
+            Self::MissingSign(index) => Some(index),
+            Self::InvalidPositiveSign(index) => Some(index),
+            Self::InvalidNegativeSign(index) => Some(index),
+            Self::BufferFull(index) => Some(index),
+            Self::NotAnInteger(index) => Some(index),
+
+            // NUMBER FORMAT ERRORS
+            Self::InvalidMantissaRadix => None,
+            Self::InvalidExponentBase => None,
+            Self::InvalidExponentRadix => None,
+            Self::InvalidDigitSeparator => None,
+            Self::InvalidDecimalPoint => None,
+            Self::InvalidExponentSymbol => None,
+            Self::InvalidBasePrefix => None,
+            Self::InvalidBaseSuffix => None,
+            Self::InvalidPunctuation => None,
+            Self::InvalidExponentFlags => None,
+            Self::InvalidMantissaSign => None,
+            Self::InvalidExponentSign => None,
+            Self::InvalidSpecial => None,
+            Self::InvalidConsecutiveIntegerDigitSeparator => None,
+            Self::InvalidConsecutiveFractionDigitSeparator => None,
+            Self::InvalidConsecutiveExponentDigitSeparator => None,
+            Self::InvalidFlags => None,
+            Self::UnsupportedFormat => None,
+
+            // OPTION ERRORS
+            Self::InvalidNanString => None,
+            Self::NanStringTooLong => None,
+            Self::InvalidInfString => None,
+            Self::InfStringTooLong => None,
+            Self::InvalidInfinityString => None,
+            Self::InfinityStringTooLong => None,
+            Self::InfinityStringTooShort => None,
+            Self::InvalidFloatParseAlgorithm => None,
+            Self::InvalidRadix => None,
+            Self::InvalidFloatPrecision => None,
+            Self::InvalidNegativeExponentBreak => None,
+            Self::InvalidPositiveExponentBreak => None,
+            Self::InvalidGroupSeparator => None,
+            Self::InvalidFractionDigits => None,
 
             // NOT AN ERROR
             Self::Success => None,
@@ -280,6 +371,8 @@ impl Error {
     is_error_type!(is_missing_sign, MissingSign(_));
     is_error_type!(is_invalid_positive_sign, InvalidPositiveSign(_));
     is_error_type!(is_invalid_negative_sign, InvalidNegativeSign(_));
+    is_error_type!(is_buffer_full, BufferFull(_));
+    is_error_type!(is_not_an_integer, NotAnInteger(_));
     is_error_type!(is_invalid_mantissa_radix, InvalidMantissaRadix);
     is_error_type!(is_invalid_exponent_base, InvalidExponentBase);
     is_error_type!(is_invalid_exponent_radix, InvalidExponentRadix);
@@ -306,6 +399,7 @@ impl Error {
         InvalidConsecutiveExponentDigitSeparator
     );
     is_error_type!(is_invalid_flags, InvalidFlags);
+    is_error_type!(is_unsupported_format, UnsupportedFormat);
     is_error_type!(is_invalid_nan_string, InvalidNanString);
     is_error_type!(is_nan_string_too_long, NanStringTooLong);
     is_error_type!(is_invalid_inf_string, InvalidInfString);
@@ -318,6 +412,8 @@ impl Error {
     is_error_type!(is_invalid_float_precision, InvalidFloatPrecision);
     is_error_type!(is_invalid_negative_exponent_break, InvalidNegativeExponentBreak);
     is_error_type!(is_invalid_positive_exponent_break, InvalidPositiveExponentBreak);
+    is_error_type!(is_invalid_group_separator, InvalidGroupSeparator);
+    is_error_type!(is_invalid_fraction_digits, InvalidFractionDigits);
     is_error_type!(is_success, Success);
 }
 
@@ -372,6 +468,8 @@ impl fmt::Display for Error {
             Self::MissingSign(index) => write_parse_error!(formatter, description, index),
             Self::InvalidPositiveSign(index) => write_parse_error!(formatter, description, index),
             Self::InvalidNegativeSign(index) => write_parse_error!(formatter, description, index),
+            Self::BufferFull(index) => write_parse_error!(formatter, description, index),
+            Self::NotAnInteger(index) => write_parse_error!(formatter, description, index),
 
             // NUMBER FORMAT ERRORS
             Self::InvalidMantissaRadix => format_message!(formatter, description),
@@ -397,6 +495,7 @@ impl fmt::Display for Error {
                 format_message!(formatter, description)
             },
             Self::InvalidFlags => format_message!(formatter, description),
+            Self::UnsupportedFormat => format_message!(formatter, description),
 
             // OPTION ERRORS
             Self::InvalidNanString => options_message!(formatter, description),
@@ -411,6 +510,8 @@ impl fmt::Display for Error {
             Self::InvalidFloatPrecision => options_message!(formatter, description),
             Self::InvalidNegativeExponentBreak => options_message!(formatter, description),
             Self::InvalidPositiveExponentBreak => options_message!(formatter, description),
+            Self::InvalidGroupSeparator => options_message!(formatter, description),
+            Self::InvalidFractionDigits => options_message!(formatter, description),
 
             // NOT AN ERROR
             Self::Success => write!(formatter, "{description}"),
@@ -0,0 +1,670 @@
+//! Quadruple-precision IEEE-754 floating point implementation.
+//!
+//! f128 is meant as an interchange format for the IEEE binary128 type,
+//! which has no stable native representation in Rust. All arithmetic is
+//! implemented in software and is correctly rounded (round-nearest,
+//! ties-to-even), but it is not meant to be fast.
+
+#![cfg(feature = "f128")]
+
+use core::{cmp, fmt, ops};
+
+/// Number of explicitly stored mantissa bits.
+const MANTISSA_SIZE: i32 = 112;
+/// Bitmask for the sign bit.
+const SIGN_MASK: u128 = 1 << 127;
+/// Bitmask for the biased exponent.
+const EXPONENT_MASK: u128 = 0x7FFF << MANTISSA_SIZE;
+/// Bitmask for the hidden bit.
+const HIDDEN_BIT_MASK: u128 = 1 << MANTISSA_SIZE;
+/// Bitmask for the explicitly stored mantissa bits.
+const MANTISSA_MASK: u128 = HIDDEN_BIT_MASK - 1;
+/// Maximum biased exponent, which is reserved for special values.
+const MAX_BIASED_EXPONENT: i32 = 0x7FFF;
+/// Exponent of the smallest mantissa unit, for a biased exponent of `1`.
+const DENORMAL_EXPONENT: i32 = 1 - 16383 - MANTISSA_SIZE;
+/// Quiet NaN bits, with no sign.
+const NAN_BITS: u128 = EXPONENT_MASK | (HIDDEN_BIT_MASK >> 1);
+
+/// A 128-bit (quadruple-precision) IEEE-754 floating point type.
+///
+/// This is stored as the raw bits of an IEEE binary128 value, with a 15-bit
+/// exponent and 113 bits of precision, so it can be exchanged with native
+/// quadruple-precision types using [`f128::from_bits`] and
+/// [`f128::to_bits`].
+///
+/// # Examples
+///
+/// ```rust
+/// use lexical_util::f128::f128;
+///
+/// let x = f128::from_f64(1.5);
+/// assert_eq!(x + x, f128::from_f64(3.0));
+/// assert_eq!((f128::from_f64(1.0) / f128::from_f64(3.0)).as_f64(), 1.0 / 3.0);
+/// ```
+#[allow(non_camel_case_types)] // reason="consistent with the native float types"
+#[derive(Clone, Copy, Default)]
+#[repr(transparent)]
+pub struct f128 {
+    bits: u128,
+}
+
+impl f128 {
+    /// Raw transmutation from the IEEE binary128 bits.
+    #[must_use]
+    #[inline(always)]
+    pub const fn from_bits(bits: u128) -> Self {
+        Self {
+            bits,
+        }
+    }
+
+    /// Raw transmutation to the IEEE binary128 bits.
+    #[must_use]
+    #[inline(always)]
+    pub const fn to_bits(self) -> u128 {
+        self.bits
+    }
+
+    /// Returns true if the value is NaN.
+    #[must_use]
+    #[inline(always)]
+    pub const fn is_nan(self) -> bool {
+        self.bits & !SIGN_MASK > EXPONENT_MASK
+    }
+
+    /// Returns true if the value is positive or negative infinity.
+    #[must_use]
+    #[inline(always)]
+    pub const fn is_infinite(self) -> bool {
+        self.bits & !SIGN_MASK == EXPONENT_MASK
+    }
+
+    /// Returns true if the value is neither infinite nor NaN.
+    #[must_use]
+    #[inline(always)]
+    pub const fn is_finite(self) -> bool {
+        self.bits & EXPONENT_MASK != EXPONENT_MASK
+    }
+
+    /// Returns true if the sign bit is set, including for `-0.0` and NaNs.
+    #[must_use]
+    #[inline(always)]
+    pub const fn is_sign_negative(self) -> bool {
+        self.bits & SIGN_MASK != 0
+    }
+
+    /// Returns true if the sign bit is not set, including for `+0.0` and NaNs.
+    #[must_use]
+    #[inline(always)]
+    pub const fn is_sign_positive(self) -> bool {
+        !self.is_sign_negative()
+    }
+
+    /// Get the absolute value of the float.
+    #[must_use]
+    #[inline(always)]
+    pub const fn abs(self) -> Self {
+        Self::from_bits(self.bits & !SIGN_MASK)
+    }
+
+    /// Create from an [`f32`], which is always exact.
+    #[must_use]
+    #[inline]
+    pub fn from_f32(value: f32) -> Self {
+        Self::from_f64(value as f64)
+    }
+
+    /// Create from an [`f64`], which is always exact.
+    #[must_use]
+    #[inline]
+    pub fn from_f64(value: f64) -> Self {
+        let bits = value.to_bits();
+        let sign = bits >> 63 != 0;
+        let biased = ((bits >> 52) & 0x7FF) as i32;
+        let mantissa = (bits & 0x000F_FFFF_FFFF_FFFF) as u128;
+        if biased == 0x7FF {
+            // Keep the NaN payload in the upper mantissa bits.
+            let bits = EXPONENT_MASK | (mantissa << (MANTISSA_SIZE - 52));
+            Self::from_bits(bits | ((sign as u128) << 127))
+        } else if biased == 0 {
+            round_pack(sign, -1074, mantissa, false)
+        } else {
+            round_pack(sign, biased - 1075, mantissa | (1 << 52), false)
+        }
+    }
+
+    /// Convert to an [`f32`], rounding to the nearest value.
+    #[must_use]
+    #[inline]
+    pub fn as_f32(self) -> f32 {
+        f32::from_bits(self.to_native(23, 8) as u32)
+    }
+
+    /// Convert to an [`f64`], rounding to the nearest value.
+    #[must_use]
+    #[inline]
+    pub fn as_f64(self) -> f64 {
+        f64::from_bits(self.to_native(52, 11))
+    }
+
+    /// Create from a [`u128`], rounding to the nearest value.
+    #[must_use]
+    #[inline]
+    pub fn from_u128(value: u128) -> Self {
+        round_pack(false, 0, value, false)
+    }
+
+    /// Create from an [`i128`], rounding to the nearest value.
+    #[must_use]
+    #[inline]
+    pub fn from_i128(value: i128) -> Self {
+        round_pack(value < 0, 0, value.unsigned_abs(), false)
+    }
+
+    /// Create from `mantissa * 2^exponent`, rounding to the nearest value.
+    ///
+    /// `is_truncated` marks non-zero bits that were truncated below the
+    /// mantissa: if it is set, the mantissa must have at least 115
+    /// significant bits, so rounding is never ambiguous. This is used to
+    /// correctly round values produced by arbitrary-precision algorithms.
+    #[must_use]
+    #[inline]
+    pub fn from_parts(
+        is_negative: bool,
+        mantissa: u128,
+        exponent: i32,
+        is_truncated: bool,
+    ) -> Self {
+        debug_assert!(
+            !is_truncated || mantissa.leading_zeros() <= 13,
+            "truncated mantissas must have at least 115 bits"
+        );
+        round_pack(is_negative, exponent, mantissa, is_truncated)
+    }
+
+    /// Convert to a [`u128`], truncating toward zero and saturating at
+    /// the bounds of the type. NaN is converted to `0`.
+    #[must_use]
+    #[inline]
+    pub fn as_u128(self) -> u128 {
+        if self.is_nan() || self.is_sign_negative() {
+            return 0;
+        }
+        self.truncate().unwrap_or(u128::MAX)
+    }
+
+    /// Convert to an [`i128`], truncating toward zero and saturating at
+    /// the bounds of the type. NaN is converted to `0`.
+    #[must_use]
+    #[inline]
+    pub fn as_i128(self) -> i128 {
+        if self.is_nan() {
+            return 0;
+        }
+        let limit = i128::MIN.unsigned_abs() - !self.is_sign_negative() as u128;
+        let value = cmp::min(self.truncate().unwrap_or(u128::MAX), limit);
+        if self.is_sign_negative() {
+            (value as i128).wrapping_neg()
+        } else {
+            value as i128
+        }
+    }
+
+    /// Returns the largest integer less than or equal to `self`.
+    #[must_use]
+    #[inline]
+    pub fn floor(self) -> Self {
+        let (sign, exp, mant) = match self.unpack() {
+            Some(parts) => parts,
+            None => return self,
+        };
+        if exp >= 0 {
+            return self;
+        }
+        let fraction_bits = (-exp) as u32;
+        if fraction_bits >= 128 || mant >> fraction_bits == 0 {
+            // The value is in the range `(0, 1)`.
+            return if sign {
+                -Self::from_f64(1.0)
+            } else {
+                Self::from_bits(0)
+            };
+        }
+        let unit = 1u128 << fraction_bits;
+        let fraction = mant & (unit - 1);
+        let mut integer = mant & !(unit - 1);
+        if fraction != 0 && sign {
+            integer += unit;
+        }
+        round_pack(sign, exp, integer, false)
+    }
+
+    /// Decompose a finite, non-zero float into its sign, exponent, and
+    /// mantissa, so the value is `mantissa * 2^exponent`.
+    #[inline]
+    fn unpack(self) -> Option<(bool, i32, u128)> {
+        let sign = self.is_sign_negative();
+        let biased = ((self.bits & EXPONENT_MASK) >> MANTISSA_SIZE) as i32;
+        let mantissa = self.bits & MANTISSA_MASK;
+        if biased == MAX_BIASED_EXPONENT || (biased == 0 && mantissa == 0) {
+            None
+        } else if biased == 0 {
+            Some((sign, DENORMAL_EXPONENT, mantissa))
+        } else {
+            Some((sign, biased - 1 + DENORMAL_EXPONENT, mantissa | HIDDEN_BIT_MASK))
+        }
+    }
+
+    /// Get the integral magnitude, truncated toward zero, if it fits.
+    #[inline]
+    fn truncate(self) -> Option<u128> {
+        if self.is_infinite() {
+            return None;
+        }
+        match self.unpack() {
+            None => Some(0),
+            Some((_, exp, mant)) if exp >= 0 => {
+                // Shifting by more than the leading zeros would overflow.
+                if exp as u32 > mant.leading_zeros() {
+                    None
+                } else {
+                    Some(mant << exp)
+                }
+            },
+            Some((_, exp, mant)) => Some(mant.checked_shr((-exp) as u32).unwrap_or(0)),
+        }
+    }
+
+    /// Round to a smaller native IEEE-754 type, returning the raw bits.
+    #[inline]
+    fn to_native(self, mantissa_size: i32, exponent_size: i32) -> u64 {
+        let max_biased = (1i32 << exponent_size) - 1;
+        let exponent_mask = (max_biased as u64) << mantissa_size;
+        let sign = (self.is_sign_negative() as u64) << (mantissa_size + exponent_size);
+        if self.is_nan() {
+            return exponent_mask | (1 << (mantissa_size - 1)) | sign;
+        } else if self.is_infinite() {
+            return exponent_mask | sign;
+        }
+        let (_, exp, mant) = match self.unpack() {
+            Some(parts) => parts,
+            None => return sign,
+        };
+
+        // Same as `round_pack`, just for the native layout.
+        let bias = (max_biased >> 1) + mantissa_size;
+        let denormal_exponent = 1 - bias;
+        let bit_length = 128 - mant.leading_zeros() as i32;
+        let mut shift = bit_length - mantissa_size - 1;
+        if exp + shift < denormal_exponent {
+            shift = denormal_exponent - exp;
+        }
+        let mut value = shift_round(mant, shift, false);
+        let mut exp = exp + shift;
+        if value >> (mantissa_size + 1) != 0 {
+            value >>= 1;
+            exp += 1;
+        }
+        let hidden = 1u128 << mantissa_size;
+        if value < hidden {
+            // Denormal or zero, which has a biased exponent of 0.
+            value as u64 | sign
+        } else {
+            let biased = exp + bias;
+            if biased >= max_biased {
+                exponent_mask | sign
+            } else {
+                let mantissa = (value & (hidden - 1)) as u64;
+                ((biased as u64) << mantissa_size) | mantissa | sign
+            }
+        }
+    }
+}
+
+/// Shift the mantissa right by `shift` bits, rounding to the nearest value
+/// with ties-to-even. `sticky` marks truncated, non-zero bits below the
+/// mantissa. Negative shifts are exact left shifts.
+#[inline]
+fn shift_round(mant: u128, shift: i32, sticky: bool) -> u128 {
+    if shift <= 0 {
+        debug_assert!(!sticky, "cannot shift truncated bits into the mantissa");
+        return mant << -shift;
+    } else if shift > 128 {
+        // Below the halfway point, round-down.
+        return 0;
+    }
+    let shift = shift as u32;
+    let value = mant.checked_shr(shift).unwrap_or(0);
+    let truncated = mant & (u128::MAX >> (128 - shift));
+    let halfway = 1u128 << (shift - 1);
+    let is_above = truncated > halfway || (truncated == halfway && sticky);
+    let is_halfway = truncated == halfway && !sticky;
+    if is_above || (is_halfway && value & 1 == 1) {
+        value + 1
+    } else {
+        value
+    }
+}
+
+/// Round and pack a value of `mant * 2^exp` into a float.
+///
+/// `sticky` marks truncated, non-zero bits below the mantissa: if it is
+/// set, the mantissa must have at least 2 more bits than the float has
+/// precision, so those bits are never shifted into the result.
+pub(crate) fn round_pack(sign: bool, exp: i32, mant: u128, sticky: bool) -> f128 {
+    let sign_bits = (sign as u128) << 127;
+    if mant == 0 {
+        return f128::from_bits(sign_bits);
+    }
+
+    let bit_length = 128 - mant.leading_zeros() as i32;
+    let mut shift = bit_length - MANTISSA_SIZE - 1;
+    if exp.saturating_add(shift) < DENORMAL_EXPONENT {
+        shift = DENORMAL_EXPONENT.saturating_sub(exp);
+    }
+    let mut value = shift_round(mant, shift, sticky);
+    let mut exp = exp.saturating_add(shift);
+    if value >> (MANTISSA_SIZE + 1) != 0 {
+        // Carried to the next power of two.
+        value >>= 1;
+        exp = exp.saturating_add(1);
+    }
+
+    if value < HIDDEN_BIT_MASK {
+        // Denormal or zero, which has a biased exponent of 0.
+        f128::from_bits(value | sign_bits)
+    } else {
+        let biased = exp.saturating_sub(DENORMAL_EXPONENT - 1);
+        if biased >= MAX_BIASED_EXPONENT {
+            f128::from_bits(EXPONENT_MASK | sign_bits)
+        } else {
+            let bits = ((biased as u128) << MANTISSA_SIZE) | (value & MANTISSA_MASK);
+            f128::from_bits(bits | sign_bits)
+        }
+    }
+}
+
+/// Normalize the mantissa so the most-significant bit is at `bit`.
+#[inline(always)]
+fn normalize(exp: i32, mant: u128, bit: u32) -> (i32, u128) {
+    let shift = mant.leading_zeros() as i32 - (127 - bit as i32);
+    (exp - shift, mant << shift)
+}
+
+/// Shift right, setting the least-significant bit if any bits were truncated.
+#[inline(always)]
+fn shr_jam(mant: u128, shift: u32) -> u128 {
+    if shift == 0 {
+        mant
+    } else if shift >= 128 {
+        (mant != 0) as u128
+    } else {
+        (mant >> shift) | ((mant << (128 - shift) != 0) as u128)
+    }
+}
+
+/// Multiply two 128-bit values, returning the high and low bits.
+#[inline]
+fn full_mul(x: u128, y: u128) -> (u128, u128) {
+    const MASK: u128 = u64::MAX as u128;
+    let (x_hi, x_lo) = (x >> 64, x & MASK);
+    let (y_hi, y_lo) = (y >> 64, y & MASK);
+    let lo_lo = x_lo * y_lo;
+    let hi_lo = x_hi * y_lo;
+    let lo_hi = x_lo * y_hi;
+    let hi_hi = x_hi * y_hi;
+    let cross = (lo_lo >> 64) + (hi_lo & MASK) + (lo_hi & MASK);
+    let hi = hi_hi + (hi_lo >> 64) + (lo_hi >> 64) + (cross >> 64);
+    let lo = (cross << 64) | (lo_lo & MASK);
+    (hi, lo)
+}
+
+#[inline]
+fn add(x: f128, y: f128) -> f128 {
+    if x.is_nan() || y.is_nan() {
+        return f128::from_bits(NAN_BITS);
+    } else if x.is_infinite() || y.is_infinite() {
+        return match (x.is_infinite(), y.is_infinite()) {
+            (true, true) if x.is_sign_negative() != y.is_sign_negative() => {
+                f128::from_bits(NAN_BITS)
+            },
+            (true, _) => x,
+            _ => y,
+        };
+    }
+
+    let (x_parts, y_parts) = match (x.unpack(), y.unpack()) {
+        (Some(x_parts), Some(y_parts)) => (x_parts, y_parts),
+        (Some(_), None) => return x,
+        (None, Some(_)) => return y,
+        // Both are zero, so only `-0.0 + -0.0` is negative.
+        (None, None) => return f128::from_bits(x.bits & y.bits),
+    };
+
+    // Normalize to 126 bits, so the sum cannot overflow, and so small
+    // shifts never truncate bits. Larger shifts can only cancel at most 1
+    // bit when subtracting, so jamming the truncated bits is always exact.
+    let (x_sign, x_exp, x_mant) = x_parts;
+    let (y_sign, y_exp, y_mant) = y_parts;
+    let (x_exp, x_mant) = normalize(x_exp, x_mant, 125);
+    let (y_exp, y_mant) = normalize(y_exp, y_mant, 125);
+    let ((sign, exp, large), (other_sign, small)) = if (x_exp, x_mant) >= (y_exp, y_mant) {
+        ((x_sign, x_exp, x_mant), (y_sign, shr_jam(y_mant, (x_exp - y_exp) as u32)))
+    } else {
+        ((y_sign, y_exp, y_mant), (x_sign, shr_jam(x_mant, (y_exp - x_exp) as u32)))
+    };
+
+    if sign == other_sign {
+        round_pack(sign, exp, large + small, false)
+    } else if large == small {
+        f128::from_bits(0)
+    } else {
+        round_pack(sign, exp, large - small, false)
+    }
+}
+
+#[inline]
+fn mul(x: f128, y: f128) -> f128 {
+    let sign = x.is_sign_negative() != y.is_sign_negative();
+    let sign_bits = (sign as u128) << 127;
+    if x.is_nan() || y.is_nan() {
+        return f128::from_bits(NAN_BITS);
+    } else if x.is_infinite() || y.is_infinite() {
+        let is_zero = |z: f128| z.is_finite() && z.unpack().is_none();
+        return match is_zero(x) || is_zero(y) {
+            true => f128::from_bits(NAN_BITS),
+            false => f128::from_bits(EXPONENT_MASK | sign_bits),
+        };
+    }
+
+    let ((_, x_exp, x_mant), (_, y_exp, y_mant)) = match (x.unpack(), y.unpack()) {
+        (Some(x_parts), Some(y_parts)) => (x_parts, y_parts),
+        _ => return f128::from_bits(sign_bits),
+    };
+    let (x_exp, x_mant) = normalize(x_exp, x_mant, 127);
+    let (y_exp, y_mant) = normalize(y_exp, y_mant, 127);
+    let (hi, lo) = full_mul(x_mant, y_mant);
+    let mant = hi | ((lo != 0) as u128);
+    round_pack(sign, x_exp + y_exp + 128, mant, false)
+}
+
+#[inline]
+fn div(x: f128, y: f128) -> f128 {
+    let sign = x.is_sign_negative() != y.is_sign_negative();
+    let sign_bits = (sign as u128) << 127;
+    if x.is_nan() || y.is_nan() || (x.is_infinite() && y.is_infinite()) {
+        return f128::from_bits(NAN_BITS);
+    } else if x.is_infinite() {
+        return f128::from_bits(EXPONENT_MASK | sign_bits);
+    } else if y.is_infinite() {
+        return f128::from_bits(sign_bits);
+    }
+
+    let ((_, x_exp, x_mant), (_, y_exp, y_mant)) = match (x.unpack(), y.unpack()) {
+        (Some(x_parts), Some(y_parts)) => (x_parts, y_parts),
+        (None, None) => return f128::from_bits(NAN_BITS),
+        (Some(_), None) => return f128::from_bits(EXPONENT_MASK | sign_bits),
+        (None, Some(_)) => return f128::from_bits(sign_bits),
+    };
+
+    // Normalize so `y <= x < 2y`, so every quotient has the same number
+    // of bits, then use a restoring division for 116 bits of precision.
+    let (mut x_exp, mut x_mant) = normalize(x_exp, x_mant, 112);
+    let (y_exp, y_mant) = normalize(y_exp, y_mant, 112);
+    if x_mant < y_mant {
+        x_mant <<= 1;
+        x_exp -= 1;
+    }
+    let mut quotient = 0u128;
+    let mut remainder = x_mant;
+    for _ in 0..116 {
+        quotient <<= 1;
+        if remainder >= y_mant {
+            remainder -= y_mant;
+            quotient |= 1;
+        }
+        remainder <<= 1;
+    }
+    round_pack(sign, x_exp - y_exp - 115, quotient, remainder != 0)
+}
+
+#[inline]
+fn rem(x: f128, y: f128) -> f128 {
+    if x.is_nan() || y.is_nan() || x.is_infinite() {
+        return f128::from_bits(NAN_BITS);
+    }
+    let (sign, x_exp, x_mant) = match x.unpack() {
+        Some(parts) => parts,
+        None => return x,
+    };
+    let (y_exp, y_mant) = match y.unpack() {
+        Some((_, y_exp, y_mant)) => (y_exp, y_mant),
+        None if y.is_infinite() => return x,
+        None => return f128::from_bits(NAN_BITS),
+    };
+    if x.abs() < y.abs() {
+        return x;
+    }
+
+    // The remainder is always exact: since `|x| >= |y|`, we can scale
+    // the divisor to the exponent of `x` if it has a larger exponent,
+    // otherwise we reduce `x * 2^(x_exp - y_exp)` one bit at a time.
+    if x_exp >= y_exp {
+        let mut remainder = x_mant % y_mant;
+        for _ in 0..x_exp - y_exp {
+            remainder <<= 1;
+            if remainder >= y_mant {
+                remainder -= y_mant;
+            }
+        }
+        round_pack(sign, y_exp, remainder, false)
+    } else {
+        let y_mant = y_mant << (y_exp - x_exp);
+        round_pack(sign, x_exp, x_mant % y_mant, false)
+    }
+}
+
+impl PartialEq for f128 {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.partial_cmp(other) == Some(cmp::Ordering::Equal)
+    }
+}
+
+impl PartialOrd for f128 {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<cmp::Ordering> {
+        if self.is_nan() || other.is_nan() {
+            return None;
+        }
+        // Map to a signed integer so both zeros compare equal.
+        let key = |x: &f128| {
+            let magnitude = (x.bits & !SIGN_MASK) as i128;
+            match x.is_sign_negative() {
+                true => -magnitude,
+                false => magnitude,
+            }
+        };
+        key(self).partial_cmp(&key(other))
+    }
+}
+
+impl ops::Neg for f128 {
+    type Output = Self;
+
+    #[inline(always)]
+    fn neg(self) -> Self {
+        Self::from_bits(self.bits ^ SIGN_MASK)
+    }
+}
+
+macro_rules! binary_op {
+    ($($trait:ident $meth:ident $assign_trait:ident $assign:ident $func:ident ;)*) => ($(
+        impl ops::$trait for f128 {
+            type Output = Self;
+
+            #[inline]
+            fn $meth(self, rhs: Self) -> Self {
+                $func(self, rhs)
+            }
+        }
+
+        impl ops::$assign_trait for f128 {
+            #[inline]
+            fn $assign(&mut self, rhs: Self) {
+                *self = $func(*self, rhs);
+            }
+        }
+    )*);
+}
+
+binary_op! {
+    Add add AddAssign add_assign add ;
+    Sub sub SubAssign sub_assign sub ;
+    Mul mul MulAssign mul_assign mul ;
+    Div div DivAssign div_assign div ;
+    Rem rem RemAssign rem_assign rem ;
+}
+
+#[inline]
+fn sub(x: f128, y: f128) -> f128 {
+    add(x, -y)
+}
+
+impl fmt::Display for f128 {
+    /// Formats the exact value as a hexadecimal float, like `0x1.8p+1`.
+    ///
+    /// Decimal output requires arbitrary-precision arithmetic, so use
+    /// a lexical writer to get the shortest decimal representation.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_nan() {
+            return f.write_str("NaN");
+        }
+        if self.is_sign_negative() {
+            f.write_str("-")?;
+        }
+        if self.is_infinite() {
+            return f.write_str("inf");
+        }
+        let (exp, mant) = match self.unpack() {
+            Some((_, exp, mant)) => normalize(exp, mant, 112),
+            None => return f.write_str("0x0p+0"),
+        };
+        let fraction = mant & MANTISSA_MASK;
+        if fraction == 0 {
+            write!(f, "0x1p{:+}", exp + MANTISSA_SIZE)
+        } else {
+            let digits = 28 - fraction.trailing_zeros() as usize / 4;
+            let fraction = fraction >> ((28 - digits) * 4);
+            write!(f, "0x1.{:0width$x}p{:+}", fraction, exp + MANTISSA_SIZE, width = digits)
+        }
+    }
+}
+
+impl fmt::Debug for f128 {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self, f)
+    }
+}
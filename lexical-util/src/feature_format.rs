@@ -1148,6 +1148,8 @@ impl<const FORMAT: u128> NumberFormat<FORMAT> {
     ///
     /// - Parse Float
     /// - Parse Integer
+    /// - Write Float
+    /// - Write Integer
     #[inline(always)]
     pub const fn base_prefix(&self) -> u8 {
         Self::BASE_PREFIX
@@ -1186,6 +1188,8 @@ impl<const FORMAT: u128> NumberFormat<FORMAT> {
     ///
     /// - Parse Float
     /// - Parse Integer
+    /// - Write Float
+    /// - Write Integer
     #[inline(always)]
     pub const fn base_suffix(&self) -> u8 {
         Self::BASE_SUFFIX
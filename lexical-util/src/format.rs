@@ -144,6 +144,8 @@
     doc = "
 - [`C_HEX_LITERAL`]: Number format for a [`C`] literal hexadecimal floating-point number.
 - [`C_HEX_STRING`]: Number format for a [`C`] string hexadecimal floating-point number.
+- [`C_HEX_PRINTF`]: Number format for a [`C`] hexadecimal float written with `printf`.
+- [`C_HEX_STRTOD`]: Number format to parse a [`C`] hexadecimal float with `strtod`.
 "
 )]
 #![cfg_attr(
@@ -653,5 +655,14 @@ pub const fn format_error<const FORMAT: u128>() -> Error {
     NumberFormat::<FORMAT> {}.error()
 }
 
+/// Get the error type from a format packed struct only known at runtime.
+///
+/// This is identical to [`format_error`], but does not require the format
+/// to be a constant.
+#[inline(always)]
+pub const fn runtime_format_error(format: u128) -> Error {
+    format_error_impl(format)
+}
+
 /// Standard number format. This is identical to the Rust string format.
 pub const STANDARD: u128 = NumberFormatBuilder::new().build_strict();
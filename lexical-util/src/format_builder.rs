@@ -156,7 +156,13 @@ const fn unwrap_or_zero(option: OptionU8) -> u8 {
 ///
 /// # Write Integer Fields
 ///
-/// No fields are used for writing integers.
+/// These fields are used for writing integers:
+///
+/// - [`mantissa_radix`]: Radix for mantissa digits.
+/// - [`base_prefix`]: Optional character for the base prefix.
+/// - [`base_suffix`]: Optional character for the base suffix.
+/// - [`required_mantissa_sign`]: If positive sign before the mantissa is
+///   required.
 ///
 /// # Parse Integer Fields
 ///
@@ -188,6 +194,8 @@ const fn unwrap_or_zero(option: OptionU8) -> u8 {
 /// - [`mantissa_radix`]: Radix for mantissa digits.
 /// - [`exponent_base`]: Base for the exponent.
 /// - [`exponent_radix`]: Radix for the exponent digits.
+/// - [`base_prefix`]: Optional character for the base prefix.
+/// - [`base_suffix`]: Optional character for the base suffix.
 /// - [`no_positive_mantissa_sign`]: If positive sign before the mantissa is not
 ///   allowed.
 /// - [`required_mantissa_sign`]: If positive sign before the mantissa is
@@ -683,6 +691,8 @@ impl NumberFormatBuilder {
     ///
     /// - Parse Float
     /// - Parse Integer
+    /// - Write Float
+    /// - Write Integer
     #[inline(always)]
     pub const fn get_base_prefix(&self) -> OptionU8 {
         self.base_prefix
@@ -710,6 +720,8 @@ impl NumberFormatBuilder {
     ///
     /// - Parse Float
     /// - Parse Integer
+    /// - Write Float
+    /// - Write Integer
     #[inline(always)]
     pub const fn get_base_suffix(&self) -> OptionU8 {
         self.base_suffix
@@ -1635,6 +1647,8 @@ impl NumberFormatBuilder {
     ///
     /// - Parse Float
     /// - Parse Integer
+    /// - Write Float
+    /// - Write Integer
     ///
     /// <!-- TEST
     /// ```rust
@@ -1682,6 +1696,8 @@ impl NumberFormatBuilder {
     ///
     /// - Parse Float
     /// - Parse Integer
+    /// - Write Float
+    /// - Write Integer
     ///
     /// <!-- TEST
     /// ```rust
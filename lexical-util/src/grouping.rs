@@ -0,0 +1,99 @@
+//! Shared utilities to write grouped digits, such as `1,234,567`.
+//!
+//! The digits are first written without separators, and then the
+//! integer digits are shifted in-place to insert a separator between
+//! each group. Groups are counted from the least significant digit,
+//! where the first group may have a different size than the remaining
+//! groups, such as Indian grouping (`12,34,567`).
+
+#![cfg(any(feature = "write-floats", feature = "write-integers"))]
+#![doc(hidden)]
+
+use crate::ascii::is_valid_ascii;
+use crate::format::OptionU8;
+
+/// Default number of digits in each group.
+pub const DEFAULT_GROUP_SIZE: usize = 3;
+
+/// Determine if the group separator is valid.
+///
+/// The separator must be a valid ASCII character, and cannot be a
+/// decimal digit or a sign.
+#[inline(always)]
+pub const fn is_valid_group_separator(separator: OptionU8) -> bool {
+    match separator {
+        Some(separator) => {
+            let c = separator.get();
+            is_valid_ascii(c) && !c.is_ascii_digit() && c != b'+' && c != b'-'
+        },
+        None => true,
+    }
+}
+
+/// Get the size of the first group and of the remaining groups.
+///
+/// The first group defaults to [`DEFAULT_GROUP_SIZE`], and the remaining
+/// groups default to the size of the first group.
+#[inline(always)]
+pub const fn group_sizes(group_size: OptionU8, secondary_group_size: OptionU8) -> (usize, usize) {
+    let primary = match group_size {
+        Some(size) => size.get() as usize,
+        None => DEFAULT_GROUP_SIZE,
+    };
+    let secondary = match secondary_group_size {
+        Some(size) => size.get() as usize,
+        None => primary,
+    };
+    (primary, secondary)
+}
+
+/// Get the number of separators to group the integer digits.
+#[inline(always)]
+pub const fn separator_count(digits: usize, primary: usize, secondary: usize) -> usize {
+    if digits <= primary {
+        0
+    } else {
+        1 + (digits - primary - 1) / secondary
+    }
+}
+
+/// Insert separators between groups of the leading integer digits.
+///
+/// The first `digits` bytes of the buffer are the integer digits, which
+/// are followed by the remaining `length - digits` written bytes, such as
+/// the fraction or exponent. Returns the number of bytes written after
+/// inserting the separators.
+///
+/// # Panics
+///
+/// Panics if the buffer is not large enough to hold the separators.
+#[inline]
+pub fn insert_separators(
+    bytes: &mut [u8],
+    digits: usize,
+    length: usize,
+    separator: u8,
+    primary: usize,
+    secondary: usize,
+) -> usize {
+    let count = separator_count(digits, primary, secondary);
+    if count == 0 {
+        return length;
+    }
+
+    // Shift the bytes after the integer digits, then move each group from
+    // the least significant digit, until the remaining digits are in place.
+    bytes.copy_within(digits..length, digits + count);
+    let mut src = digits;
+    let mut dst = digits + count;
+    let mut size = primary;
+    while dst != src {
+        bytes.copy_within(src - size..src, dst - size);
+        src -= size;
+        dst -= size + 1;
+        bytes[dst] = separator;
+        size = secondary;
+    }
+
+    length + count
+}
@@ -22,11 +22,20 @@
 //! * `compact` - Reduce code size at the cost of performance.
 //! * `f16` - Enable support for half-precision [`f16`][`ieee-f16`] and
 //!   [`bf16`][`brain-float`] floats.
+//! * `f128` - Enable support for quadruple-precision [`f128`][`ieee-f128`]
+//!   floats, using a software implementation.
+//! * `bigint` - Enable support for arbitrary-precision integers. This requires
+//!   the `std` feature.
+//! * `unicode` - Enable support for Unicode decimal digits. This requires the
+//!   `std` feature.
+//! * `diagnostic` - Enable diagnostic errors with spans and expected-token
+//!   information.
 //! * `std` (Default) - Disable to allow use in a [`no_std`] environment.
 //!
 //! [`no_std`]: https://docs.rust-embedded.org/book/intro/no-std.html
 //! [`ieee-f16`]: https://en.wikipedia.org/wiki/Half-precision_floating-point_format
 //! [`brain-float`]: https://en.wikipedia.org/wiki/Bfloat16_floating-point_format
+//! [`ieee-f128`]: https://en.wikipedia.org/wiki/Quadruple-precision_floating-point_format
 //!
 //! # Public API
 //!
@@ -108,19 +117,27 @@ pub mod algorithm;
 pub mod ascii;
 pub mod assert;
 pub mod bf16;
+pub mod bigint;
+pub mod code_unit;
 pub mod constants;
+pub mod diagnostic;
 pub mod digit;
 pub mod div128;
 pub mod error;
 pub mod extended_float;
+pub mod f128;
 pub mod f16;
 pub mod format;
+pub mod grouping;
 pub mod iterator;
+pub mod limb;
 pub mod mul;
 pub mod num;
 pub mod options;
 pub mod result;
 pub mod step;
+pub mod stream;
+pub mod unicode;
 
 mod api;
 mod feature_format;
@@ -8,6 +8,8 @@ use core::{fmt, mem, ops};
 
 #[cfg(feature = "f16")]
 use crate::bf16::bf16;
+#[cfg(feature = "f128")]
+use crate::f128::f128;
 #[cfg(feature = "f16")]
 use crate::f16::f16;
 #[cfg(all(not(feature = "std"), any(feature = "parse-floats", feature = "write-floats")))]
@@ -77,10 +79,15 @@ pub trait AsPrimitive: Copy + PartialEq + PartialOrd + Send + Sync + Sized {
     /// if [`struct@bf16`] was a primitive type.
     #[cfg(feature = "f16")]
     fn as_bf16(self) -> bf16;
+
+    /// Convert the value to an [`struct@f128`], identical to `value as f128`
+    /// if [`struct@f128`] was a primitive type.
+    #[cfg(feature = "f128")]
+    fn as_f128(self) -> f128;
 }
 
 macro_rules! as_primitive {
-    ($($t:ty)*) => ($(
+    ($($t:ty, $from:ident ; )*) => ($(
         impl AsPrimitive for $t {
             #[inline(always)]
             fn as_u8(self) -> u8 {
@@ -173,11 +180,33 @@ macro_rules! as_primitive {
             fn as_bf16(self) -> bf16 {
                 bf16::from_f32(self as f32)
             }
+
+            #[cfg(feature = "f128")]
+            #[inline(always)]
+            #[allow(clippy::as_underscore)] // reason="intentionally used in a generic sense"
+            fn as_f128(self) -> f128 {
+                f128::$from(self as _)
+            }
         }
     )*)
 }
 
-as_primitive! { u8 u16 u32 u64 u128 usize i8 i16 i32 i64 i128 isize f32 f64 }
+as_primitive! {
+    u8, from_u128 ;
+    u16, from_u128 ;
+    u32, from_u128 ;
+    u64, from_u128 ;
+    u128, from_u128 ;
+    usize, from_u128 ;
+    i8, from_i128 ;
+    i16, from_i128 ;
+    i32, from_i128 ;
+    i64, from_i128 ;
+    i128, from_i128 ;
+    isize, from_i128 ;
+    f32, from_f32 ;
+    f64, from_f64 ;
+}
 
 #[cfg(feature = "f16")]
 macro_rules! half_as_primitive {
@@ -274,6 +303,12 @@ macro_rules! half_as_primitive {
             fn as_bf16(self) -> bf16 {
                 bf16::from_f32(self.as_f32())
             }
+
+            #[cfg(feature = "f128")]
+            #[inline(always)]
+            fn as_f128(self) -> f128 {
+                f128::from_f32(self.as_f32())
+            }
         }
     )*)
 }
@@ -281,6 +316,106 @@ macro_rules! half_as_primitive {
 #[cfg(feature = "f16")]
 half_as_primitive! { f16 bf16 }
 
+#[cfg(feature = "f128")]
+impl AsPrimitive for f128 {
+    #[inline(always)]
+    fn as_u8(self) -> u8 {
+        self.as_u128().min(u8::MAX as u128) as u8
+    }
+
+    #[inline(always)]
+    fn as_u16(self) -> u16 {
+        self.as_u128().min(u16::MAX as u128) as u16
+    }
+
+    #[inline(always)]
+    fn as_u32(self) -> u32 {
+        self.as_u128().min(u32::MAX as u128) as u32
+    }
+
+    #[inline(always)]
+    fn as_u64(self) -> u64 {
+        self.as_u128().min(u64::MAX as u128) as u64
+    }
+
+    #[inline(always)]
+    fn as_u128(self) -> u128 {
+        f128::as_u128(self)
+    }
+
+    #[inline(always)]
+    fn as_usize(self) -> usize {
+        self.as_u128().min(usize::MAX as u128) as usize
+    }
+
+    #[inline(always)]
+    fn as_i8(self) -> i8 {
+        self.as_i128().clamp(i8::MIN as i128, i8::MAX as i128) as i8
+    }
+
+    #[inline(always)]
+    fn as_i16(self) -> i16 {
+        self.as_i128().clamp(i16::MIN as i128, i16::MAX as i128) as i16
+    }
+
+    #[inline(always)]
+    fn as_i32(self) -> i32 {
+        self.as_i128().clamp(i32::MIN as i128, i32::MAX as i128) as i32
+    }
+
+    #[inline(always)]
+    fn as_i64(self) -> i64 {
+        self.as_i128().clamp(i64::MIN as i128, i64::MAX as i128) as i64
+    }
+
+    #[inline(always)]
+    fn as_i128(self) -> i128 {
+        f128::as_i128(self)
+    }
+
+    #[inline(always)]
+    fn as_isize(self) -> isize {
+        self.as_i128().clamp(isize::MIN as i128, isize::MAX as i128) as isize
+    }
+
+    #[inline(always)]
+    fn as_f32(self) -> f32 {
+        f128::as_f32(self)
+    }
+
+    #[inline(always)]
+    fn as_f64(self) -> f64 {
+        f128::as_f64(self)
+    }
+
+    #[inline(always)]
+    fn from_u32(value: u32) -> Self {
+        Self::from_u128(value as u128)
+    }
+
+    #[inline(always)]
+    fn from_u64(value: u64) -> Self {
+        Self::from_u128(value as u128)
+    }
+
+    #[cfg(feature = "f16")]
+    #[inline(always)]
+    fn as_f16(self) -> f16 {
+        f16::from_f64(self.as_f64())
+    }
+
+    #[cfg(feature = "f16")]
+    #[inline(always)]
+    fn as_bf16(self) -> bf16 {
+        bf16::from_f64(self.as_f64())
+    }
+
+    #[inline(always)]
+    fn as_f128(self) -> f128 {
+        self
+    }
+}
+
 // AS CAST
 // -------
 
@@ -352,6 +487,11 @@ as_cast!(
     bf16, as_bf16 ;
 );
 
+#[cfg(feature = "f128")]
+as_cast!(
+    f128, as_f128 ;
+);
+
 // PRIMITIVE
 // ---------
 
@@ -371,6 +511,9 @@ primitive! { u8 u16 u32 u64 u128 usize i8 i16 i32 i64 i128 isize f32 f64 }
 #[cfg(feature = "f16")]
 primitive! { f16 bf16 }
 
+#[cfg(feature = "f128")]
+primitive! { f128 }
+
 // NUMBER
 // ------
 
@@ -392,38 +535,45 @@ pub trait Number:
 {
     /// If the number can hold negative values.
     const IS_SIGNED: bool;
+    /// If the number is an integer.
+    const IS_INTEGER: bool;
 }
 
 macro_rules! number_impl {
-    ($($t:tt $is_signed:literal ; )*) => ($(
+    ($($t:tt $is_signed:literal $is_integer:literal ; )*) => ($(
         impl Number for $t {
             const IS_SIGNED: bool = $is_signed;
+            const IS_INTEGER: bool = $is_integer;
         }
     )*)
 }
 
 number_impl! {
-    u8 false ;
-    u16 false ;
-    u32 false ;
-    u64 false ;
-    u128 false ;
-    usize false ;
-    i8 true ;
-    i16 true ;
-    i32 true ;
-    i64 true ;
-    i128 true ;
-    isize true ;
-    f32 true ;
-    f64 true ;
-    // f128 true
+    u8 false true ;
+    u16 false true ;
+    u32 false true ;
+    u64 false true ;
+    u128 false true ;
+    usize false true ;
+    i8 true true ;
+    i16 true true ;
+    i32 true true ;
+    i64 true true ;
+    i128 true true ;
+    isize true true ;
+    f32 true false ;
+    f64 true false ;
 }
 
 #[cfg(feature = "f16")]
 number_impl! {
-    f16 true ;
-    bf16 true ;
+    f16 true false ;
+    bf16 true false ;
+}
+
+#[cfg(feature = "f128")]
+number_impl! {
+    f128 true false ;
 }
 
 // INTEGER
@@ -1219,10 +1369,6 @@ macro_rules! float_masks {
     };
 }
 
-//  Due to missing specifics or types for the following float types,
-//  `Float` is not yet fully implemented for:
-//      - f128
-
 #[cfg(feature = "f16")]
 macro_rules! float_one {
     ($f:ident) => {
@@ -1486,20 +1632,61 @@ impl Float for f64 {
     }
 }
 
-// #[cfg(feature = "f128")]
-// impl Float for f128 {
-//     type Unsigned = u128;
-//     float_literals!(f128);
-//     float_masks!(
-//         float => Self,
-//         sign_mask => 0x80000000000000000000000000000000,
-//         exponent_mask => 0x7FFF0000000000000000000000000000,
-//         hidden_bit_mask => 0x00010000000000000000000000000000,
-//         mantissa_mask => 0x0000FFFFFFFFFFFFFFFFFFFFFFFFFFFF,
-//     );
-//     const EXPONENT_SIZE: i32 = 15;
-//     const MANTISSA_SIZE: i32 = 112;
-//     const EXPONENT_BIAS: i32 = 16383 + Self::MANTISSA_SIZE;
-//     const DENORMAL_EXPONENT: i32 = 1 - Self::EXPONENT_BIAS;
-//     const MAX_EXPONENT: i32 = 0x7FFF - Self::EXPONENT_BIAS;
-// }
+#[cfg(feature = "f128")]
+impl Float for f128 {
+    type Unsigned = u128;
+
+    const ZERO: Self = Self::from_bits(0);
+    const ONE: Self = Self::from_bits(0x3FFF << Self::MANTISSA_SIZE);
+    const TWO: Self = Self::from_bits(0x4000 << Self::MANTISSA_SIZE);
+    const MAX: Self =
+        Self::from_bits((Self::EXPONENT_MASK ^ Self::HIDDEN_BIT_MASK) | Self::MANTISSA_MASK);
+    const MIN: Self = Self::from_bits(Self::MAX.to_bits() | Self::SIGN_MASK);
+    const INFINITY: Self = Self::from_bits(Self::INFINITY_BITS);
+    const NEG_INFINITY: Self = Self::from_bits(Self::NEGATIVE_INFINITY_BITS);
+    const NAN: Self = Self::from_bits(Self::EXPONENT_MASK | (Self::HIDDEN_BIT_MASK >> 1));
+    const BITS: usize = mem::size_of::<Self>() * 8;
+
+    float_masks!(
+        float => Self,
+        sign_mask => 0x80000000000000000000000000000000,
+        exponent_mask => 0x7FFF0000000000000000000000000000,
+        hidden_bit_mask => 0x00010000000000000000000000000000,
+        mantissa_mask => 0x0000FFFFFFFFFFFFFFFFFFFFFFFFFFFF,
+    );
+    const EXPONENT_SIZE: i32 = 15;
+    const MANTISSA_SIZE: i32 = 112;
+    const EXPONENT_BIAS: i32 = 16383 + Self::MANTISSA_SIZE;
+    const DENORMAL_EXPONENT: i32 = 1 - Self::EXPONENT_BIAS;
+    const MAX_EXPONENT: i32 = 0x7FFF - Self::EXPONENT_BIAS;
+
+    #[inline(always)]
+    fn to_bits(self) -> u128 {
+        f128::to_bits(self)
+    }
+
+    #[inline(always)]
+    fn from_bits(u: u128) -> f128 {
+        f128::from_bits(u)
+    }
+
+    #[inline(always)]
+    fn ln(self) -> f128 {
+        f128::from_f64(<f64 as Float>::ln(self.as_f64()))
+    }
+
+    #[inline(always)]
+    fn floor(self) -> f128 {
+        f128::floor(self)
+    }
+
+    #[inline(always)]
+    fn is_sign_positive(self) -> bool {
+        f128::is_sign_positive(self)
+    }
+
+    #[inline(always)]
+    fn is_sign_negative(self) -> bool {
+        f128::is_sign_negative(self)
+    }
+}
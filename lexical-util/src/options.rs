@@ -92,6 +92,36 @@ pub trait WriteOptions: Default {
 pub trait ParseOptions: Default {
     /// Determine if the options are valid.
     fn is_valid(&self) -> bool;
+
+    /// Get the character to separate the integer from the fraction components.
+    #[inline(always)]
+    fn decimal_point(&self) -> u8 {
+        b'.'
+    }
+
+    /// Get the character to designate the exponent component.
+    #[inline(always)]
+    fn exponent(&self) -> u8 {
+        b'e'
+    }
+
+    /// Get the ASCII punctuation other than signs that can be part of a
+    /// number, for a number format.
+    ///
+    /// This is the decimal point and exponent characters of the options,
+    /// and the digit separator, base prefix, and base suffix of the format,
+    /// which are `0` if they are not used.
+    #[inline(always)]
+    fn punctuation<const FORMAT: u128>(&self) -> [u8; 5] {
+        let format = crate::format::NumberFormat::<{ FORMAT }> {};
+        [
+            self.decimal_point(),
+            self.exponent(),
+            format.digit_separator(),
+            format.base_prefix(),
+            format.base_suffix(),
+        ]
+    }
 }
 
 // PRE-DEFINED CONSTANTS
@@ -1007,6 +1007,40 @@ pub const C_HEX_LITERAL: u128 = C18_HEX_LITERAL;
 #[cfg(feature = "power-of-two")]
 pub const C_HEX_STRING: u128 = C18_HEX_STRING;
 
+// C HEX PRINTF [01345678MN]
+/// Number format for a [`C`] hexadecimal float written with `printf("%a")`.
+///
+/// This always writes the `0x` prefix and the sign of the exponent.
+///
+/// [`C`]: https://en.cppreference.com/w/c
+#[rustfmt::skip]
+#[cfg(all(feature = "power-of-two", feature = "format"))]
+pub const C_HEX_PRINTF: u128 = NumberFormatBuilder::new()
+    .required_exponent_notation(true)
+    .required_exponent_sign(true)
+    .mantissa_radix(16)
+    .exponent_base(num::NonZeroU8::new(2))
+    .exponent_radix(num::NonZeroU8::new(10))
+    .base_prefix(num::NonZeroU8::new(b'x'))
+    .build_strict();
+
+// C HEX STRTOD [0134567MN]
+/// Number format to parse a [`C`] hexadecimal float with `strtod`.
+///
+/// The `0x` prefix and the exponent are optional, and the prefix and
+/// exponent character are case-insensitive. Unlike `strtod`, strings
+/// without the prefix are still parsed as hexadecimal floats.
+///
+/// [`C`]: https://en.cppreference.com/w/c
+#[rustfmt::skip]
+#[cfg(all(feature = "power-of-two", feature = "format"))]
+pub const C_HEX_STRTOD: u128 = NumberFormatBuilder::new()
+    .mantissa_radix(16)
+    .exponent_base(num::NonZeroU8::new(2))
+    .exponent_radix(num::NonZeroU8::new(10))
+    .base_prefix(num::NonZeroU8::new(b'x'))
+    .build_strict();
+
 // C18 LITERAL [01345678MN]
 /// Number format for a [`C18`] literal floating-point number.
 ///
@@ -16,6 +16,7 @@
     feature = "parse-integers",
     feature = "write-floats",
     feature = "write-integers",
+    feature = "bigint",
 ))]
 
 // NOTE:
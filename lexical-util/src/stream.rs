@@ -0,0 +1,175 @@
+//! Shared utilities to parse numbers from chunked input.
+//!
+//! A number may be split across multiple reads, so this buffers the
+//! bytes of the number in a caller-provided buffer until we know the
+//! number is complete. A number is complete once the parser stops before
+//! the last buffered byte and appending another digit does not change
+//! the result, since partial parsers may stop before a trailing sign or
+//! digit separator that could be followed by more digits. It is also
+//! complete once the caller signals the end of the input.
+//!
+//! Since the buffered bytes are parsed with the same partial parsers as
+//! contiguous input, the number format validation is identical, and the
+//! counts and error indexes are relative to the start of the number, as
+//! if all chunks were a single slice. A number may end before the last
+//! chunk, such as `inf` followed by `I` which could have started
+//! `infinity`, so the count may be smaller than the previous chunks.
+
+#![cfg(any(feature = "parse-floats", feature = "parse-integers"))]
+#![doc(hidden)]
+
+use crate::error::Error;
+use crate::result::Result;
+
+/// The state of a number parsed from chunked input.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Status<T> {
+    /// The number may continue in the next chunk, so more input is needed.
+    Incomplete,
+    /// The parsed number, and the number of bytes of the number, from
+    /// the start of its first chunk.
+    Complete(T, usize),
+}
+
+/// Buffer for the bytes of a number split across chunks.
+#[derive(Debug)]
+pub struct StreamBuffer<'a> {
+    /// The storage for the buffered bytes.
+    buffer: &'a mut [u8],
+    /// The number of buffered bytes.
+    length: usize,
+}
+
+impl<'a> StreamBuffer<'a> {
+    /// Create an empty stream buffer from storage for the bytes.
+    ///
+    /// The storage must be at least 1 byte longer than the longest number
+    /// to parse.
+    #[inline(always)]
+    pub fn new(buffer: &'a mut [u8]) -> Self {
+        Self {
+            buffer,
+            length: 0,
+        }
+    }
+
+    /// Get the buffered bytes.
+    #[inline(always)]
+    pub fn as_slice(&self) -> &[u8] {
+        &self.buffer[..self.length]
+    }
+
+    /// Get the number of buffered bytes.
+    #[inline(always)]
+    pub const fn len(&self) -> usize {
+        self.length
+    }
+
+    /// Get if no bytes have been buffered.
+    #[inline(always)]
+    pub const fn is_empty(&self) -> bool {
+        self.length == 0
+    }
+
+    /// Get the maximum number of bytes that can be buffered.
+    ///
+    /// The last byte of the storage is reserved to check if the number
+    /// may continue after the buffered bytes.
+    #[inline(always)]
+    pub fn capacity(&self) -> usize {
+        self.buffer.len().saturating_sub(1)
+    }
+
+    /// Discard any buffered bytes, to start parsing a new number.
+    #[inline(always)]
+    pub fn clear(&mut self) {
+        self.length = 0;
+    }
+
+    /// Buffer the next chunk of input and try to parse the number.
+    ///
+    /// `parse` is the partial parser for the number, and `may_continue`
+    /// checks if a result that stopped before the last byte could still
+    /// change with more input, such as a prefix of a special float string.
+    /// Once the result is final, the buffer is cleared for the next number.
+    pub fn push<T, Parse, MayContinue>(
+        &mut self,
+        chunk: &[u8],
+        parse: Parse,
+        may_continue: MayContinue,
+    ) -> Result<Status<T>>
+    where
+        Parse: Fn(&[u8]) -> Result<(T, usize)>,
+        MayContinue: FnOnce(&[u8]) -> bool,
+    {
+        let start = self.length;
+        let count = chunk.len().min(self.capacity() - start);
+        self.buffer[start..start + count].copy_from_slice(&chunk[..count]);
+        self.length += count;
+
+        let result = parse(self.as_slice());
+        let stop = stop_index(&result);
+        let is_incomplete = match stop {
+            // We consumed every buffered byte, so the number may continue.
+            Some(index) if index >= self.length => true,
+            Some(_) if may_continue(self.as_slice()) => true,
+            Some(_) => self.is_probe_different(&parse, &result),
+            None => false,
+        };
+
+        if !is_incomplete {
+            self.clear();
+            result.map(|(value, index)| Status::Complete(value, index))
+        } else if count < chunk.len() {
+            // The number may continue, but we have no room to buffer it.
+            let index = self.length;
+            self.clear();
+            Err(Error::BufferFull(index))
+        } else {
+            Ok(Status::Incomplete)
+        }
+    }
+
+    /// Determine if appending a digit to the buffered bytes changes the result.
+    ///
+    /// The digit is written to the reserved byte after the buffered bytes.
+    /// `1` is a valid digit in every radix.
+    #[inline]
+    fn is_probe_different<T, Parse>(&mut self, parse: &Parse, result: &Result<(T, usize)>) -> bool
+    where
+        Parse: Fn(&[u8]) -> Result<(T, usize)>,
+    {
+        if self.length >= self.buffer.len() {
+            return false;
+        }
+        self.buffer[self.length] = b'1';
+        let probe = parse(&self.buffer[..self.length + 1]);
+        match (result, &probe) {
+            (Ok((_, x)), Ok((_, y))) => x != y,
+            (Err(x), Err(y)) => x != y,
+            _ => true,
+        }
+    }
+
+    /// Parse the buffered bytes as a complete number, at the end of input.
+    ///
+    /// Returns the number and the number of bytes that were part of the
+    /// number, and clears the buffer for the next number.
+    pub fn finish<T, Parse>(&mut self, parse: Parse) -> Result<(T, usize)>
+    where
+        Parse: Fn(&[u8]) -> Result<(T, usize)>,
+    {
+        let result = parse(self.as_slice());
+        self.clear();
+        result
+    }
+}
+
+/// Get the index where the parser stopped, if known.
+#[inline(always)]
+fn stop_index<T>(result: &Result<(T, usize)>) -> Option<usize> {
+    match result {
+        Ok((_, index)) => Some(*index),
+        Err(error) => error.index().copied(),
+    }
+}
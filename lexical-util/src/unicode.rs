@@ -0,0 +1,361 @@
+//! Unicode decimal digits.
+//!
+//! This supports the decimal digits of any Unicode block with the `Nd`
+//! (decimal number) general category, such as fullwidth `１２３` or
+//! Arabic-Indic `١٢٣` digits. Every block contains the digits `0-9` as
+//! contiguous code points, so a block is identified by its zero digit.
+//!
+//! To parse numbers, the UTF-8 encoded digits are decoded to ASCII digits
+//! before parsing, up to the first character that can't be part of a
+//! number, and any error indexes or the number of processed bytes are
+//! translated back to the input. All other characters, such as signs,
+//! digit separators and the decimal point, must be ASCII, so the number
+//! format is validated exactly like for ASCII digits. Only ASCII letters,
+//! digits, signs, and the punctuation of the format can be part of a
+//! number, so decoding stops at any other character, and numbers with only
+//! ASCII characters are parsed without decoding. All the digits of a
+//! number must come from a single block: a digit from another block is an
+//! invalid digit.
+//!
+//! To write numbers, the ASCII digits `0-9` are encoded as the digits of
+//! the chosen block, after writing the number.
+
+#![cfg(feature = "unicode")]
+
+use crate::error::Error;
+use crate::result::Result;
+
+/// The zero digits of every block of Unicode decimal digits, as of
+/// Unicode 15.0, in ascending order.
+const ZEROS: [u32; 68] = [
+    0x30, 0x660, 0x6F0, 0x7C0, 0x966, 0x9E6, 0xA66, 0xAE6, 0xB66, 0xBE6, 0xC66, 0xCE6, 0xD66,
+    0xDE6, 0xE50, 0xED0, 0xF20, 0x1040, 0x1090, 0x17E0, 0x1810, 0x1946, 0x19D0, 0x1A80, 0x1A90,
+    0x1B50, 0x1BB0, 0x1C40, 0x1C50, 0xA620, 0xA8D0, 0xA900, 0xA9D0, 0xA9F0, 0xAA50, 0xABF0, 0xFF10,
+    0x104A0, 0x10D30, 0x11066, 0x110F0, 0x11136, 0x111D0, 0x112F0, 0x11450, 0x114D0, 0x11650,
+    0x116C0, 0x11730, 0x118E0, 0x11950, 0x11C50, 0x11D50, 0x11DA0, 0x11F50, 0x16A60, 0x16AC0,
+    0x16B50, 0x1D7CE, 0x1D7D8, 0x1D7E2, 0x1D7EC, 0x1D7F6, 0x1E140, 0x1E2F0, 0x1E4F0, 0x1E950,
+    0x1FBF0,
+];
+
+/// The number of decoded bytes that can be stored without allocating.
+const STACK_BUFFER_SIZE: usize = 128;
+
+/// The byte for characters which cannot be part of a number.
+///
+/// This is never a valid UTF-8 byte, so it cannot match any character
+/// of a number.
+const INVALID_BYTE: u8 = 0xFF;
+
+/// A block of Unicode decimal digits.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct DigitBlock {
+    /// The zero digit of the block.
+    zero: char,
+}
+
+impl DigitBlock {
+    /// ASCII digits: `0123456789`.
+    pub const ASCII: Self = Self {
+        zero: '0',
+    };
+    /// Arabic-Indic digits: `٠١٢٣٤٥٦٧٨٩`.
+    pub const ARABIC_INDIC: Self = Self {
+        zero: '\u{660}',
+    };
+    /// Extended Arabic-Indic digits, used for Persian and Urdu: `۰۱۲۳۴۵۶۷۸۹`.
+    pub const EXTENDED_ARABIC_INDIC: Self = Self {
+        zero: '\u{6F0}',
+    };
+    /// Devanagari digits: `०१२३४५६७८९`.
+    pub const DEVANAGARI: Self = Self {
+        zero: '\u{966}',
+    };
+    /// Bengali digits: `০১২৩৪৫৬৭৮৯`.
+    pub const BENGALI: Self = Self {
+        zero: '\u{9E6}',
+    };
+    /// Thai digits: `๐๑๒๓๔๕๖๗๘๙`.
+    pub const THAI: Self = Self {
+        zero: '\u{E50}',
+    };
+    /// Fullwidth digits: `０１２３４５６７８９`.
+    pub const FULLWIDTH: Self = Self {
+        zero: '\u{FF10}',
+    };
+
+    /// Create a block from its zero digit.
+    ///
+    /// Returns `None` if the character is not the zero digit of a block of
+    /// Unicode decimal digits.
+    #[inline]
+    pub fn new(zero: char) -> Option<Self> {
+        match ZEROS.binary_search(&(zero as u32)) {
+            Ok(_) => Some(Self {
+                zero,
+            }),
+            Err(_) => None,
+        }
+    }
+
+    /// Get the block and the value of a Unicode decimal digit.
+    ///
+    /// Returns `None` if the character is not a decimal digit.
+    #[inline]
+    pub fn from_digit(c: char) -> Option<(Self, u32)> {
+        let c = c as u32;
+        let index = match ZEROS.binary_search(&c) {
+            Ok(index) => index,
+            Err(0) => return None,
+            Err(index) => index - 1,
+        };
+        let zero = ZEROS[index];
+        let digit = c - zero;
+        if digit < 10 {
+            // SAFETY: safe since all zero digits are valid characters.
+            let zero = unsafe { char::from_u32_unchecked(zero) };
+            Some((
+                Self {
+                    zero,
+                },
+                digit,
+            ))
+        } else {
+            None
+        }
+    }
+
+    /// Get the zero digit of the block.
+    #[inline(always)]
+    pub const fn zero(&self) -> char {
+        self.zero
+    }
+
+    /// Get the character for a digit value.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the digit is not in the range `0-9`.
+    #[inline]
+    pub fn to_char(self, digit: u32) -> char {
+        assert!(digit < 10, "digit must be in the range 0-9");
+        // SAFETY: safe since every block contains 10 valid characters.
+        unsafe { char::from_u32_unchecked(self.zero as u32 + digit) }
+    }
+
+    /// Get the number of bytes to encode a digit as UTF-8.
+    #[inline(always)]
+    pub const fn utf8_len(&self) -> usize {
+        self.zero.len_utf8()
+    }
+
+    /// Get the required buffer size to write a number with these digits.
+    ///
+    /// * `size` - The required buffer size to write the number with ASCII
+    ///   digits.
+    #[inline(always)]
+    pub const fn buffer_size(&self, size: usize) -> usize {
+        size * self.utf8_len()
+    }
+
+    /// Encode the ASCII digits of a written number as digits of the block,
+    /// in place.
+    ///
+    /// The written number is the first `count` bytes of the buffer. Returns
+    /// the number of bytes of the encoded number.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the buffer is not large enough for the encoded number.
+    #[inline]
+    pub fn encode_digits(&self, bytes: &mut [u8], count: usize) -> usize {
+        let width = self.utf8_len();
+        let digits = bytes[..count].iter().filter(|c| c.is_ascii_digit()).count();
+        let length = count + digits * (width - 1);
+        assert!(length <= bytes.len(), "buffer is too small to encode the digits");
+
+        // Encode from the end, so we never overwrite bytes we need to read.
+        let mut end = length;
+        for index in (0..count).rev() {
+            let c = bytes[index];
+            if c.is_ascii_digit() {
+                let digit = self.to_char((c - b'0') as u32);
+                end -= width;
+                digit.encode_utf8(&mut bytes[end..end + width]);
+            } else {
+                end -= 1;
+                bytes[end] = c;
+            }
+        }
+        debug_assert!(end == 0);
+        length
+    }
+}
+
+/// Lazily decode the UTF-8 encoded digits to ASCII digits.
+///
+/// This yields the decoded byte and the number of input bytes for each
+/// character. Decoding stops after the first character that can't be part
+/// of a number, such as a non-ASCII character that isn't a digit, a digit
+/// from another block, or ASCII punctuation that isn't a sign or in the
+/// punctuation of the format, which is decoded to [`INVALID_BYTE`].
+struct Decoder<'a> {
+    /// The UTF-8 encoded input.
+    input: &'a [u8],
+    /// The ASCII punctuation other than signs that can be part of a number.
+    punctuation: &'a [u8],
+    /// The index of the next character in the input.
+    index: usize,
+    /// The block of the digits decoded so far.
+    block: Option<DigitBlock>,
+}
+
+impl<'a> Decoder<'a> {
+    /// Create a decoder for the input.
+    #[inline(always)]
+    const fn new(input: &'a [u8], punctuation: &'a [u8]) -> Self {
+        Self {
+            input,
+            punctuation,
+            index: 0,
+            block: None,
+        }
+    }
+
+    /// Check if an ASCII character that isn't a digit can be part of a number.
+    #[inline(always)]
+    fn is_number_byte(&self, c: u8) -> bool {
+        c.is_ascii_alphabetic() || c == b'+' || c == b'-' || self.punctuation.contains(&c)
+    }
+}
+
+impl Iterator for Decoder<'_> {
+    type Item = (u8, usize);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let first = *self.input.get(self.index)?;
+        let (byte, width) = decode_byte(&self.input[self.index..]);
+        let byte = match byte {
+            Some((c, block)) if self.block.map_or(true, |b| b == block) => {
+                self.block = Some(block);
+                c
+            },
+            None if self.is_number_byte(first) => first,
+            _ => {
+                // No following characters can be part of the number.
+                self.index = self.input.len();
+                return Some((INVALID_BYTE, width));
+            },
+        };
+        self.index += width;
+        Some((byte, width))
+    }
+}
+
+/// Decode the digits, and call a function with the decoded bytes.
+///
+/// Short numbers are decoded into a buffer on the stack, and only long
+/// numbers are decoded into a buffer on the heap. Numbers with only ASCII
+/// characters are not decoded, and the function is called with the input.
+///
+/// Returns the result of the function, and if the input was decoded.
+#[inline]
+fn with_decoded<T, F>(input: &[u8], punctuation: &[u8], f: F) -> (T, bool)
+where
+    F: FnOnce(&[u8]) -> T,
+{
+    // The input only differs from the decoded bytes after the end of an
+    // ASCII number, where neither can be part of the number.
+    let mut decoder = Decoder::new(input, punctuation);
+    if decoder.all(|(byte, width)| width == 1 || byte == INVALID_BYTE) {
+        return (f(input), false);
+    }
+
+    let mut decoder = Decoder::new(input, punctuation);
+    let mut buffer = [0u8; STACK_BUFFER_SIZE];
+    let mut length = 0;
+    for (byte, _) in decoder.by_ref().take(STACK_BUFFER_SIZE) {
+        buffer[length] = byte;
+        length += 1;
+    }
+    if decoder.index >= input.len() {
+        return (f(&buffer[..length]), true);
+    }
+    let mut bytes = buffer.to_vec();
+    bytes.extend(decoder.map(|(byte, _)| byte));
+    (f(&bytes), true)
+}
+
+/// Translate an index in the decoded bytes to the input.
+#[inline]
+fn input_index(input: &[u8], punctuation: &[u8], index: usize) -> usize {
+    Decoder::new(input, punctuation).take(index).map(|(_, width)| width).sum()
+}
+
+/// Translate the index of an error to the input.
+#[inline]
+fn input_error(input: &[u8], punctuation: &[u8], mut error: Error) -> Error {
+    if let Some(index) = error.index_mut() {
+        *index = input_index(input, punctuation, *index);
+    }
+    error
+}
+
+/// Decode the first character of the input if it is a decimal digit.
+///
+/// Returns the ASCII digit and its block if the character is a digit, and
+/// the number of bytes in the character, which is 1 for invalid UTF-8.
+#[inline]
+fn decode_byte(input: &[u8]) -> (Option<(u8, DigitBlock)>, usize) {
+    let width = match input[0] {
+        0x00..=0x7F => 1,
+        0xC2..=0xDF => 2,
+        0xE0..=0xEF => 3,
+        0xF0..=0xF4 => 4,
+        _ => return (None, 1),
+    };
+    let c = match input.get(..width).map(core::str::from_utf8) {
+        Some(Ok(string)) => string.chars().next(),
+        _ => return (None, 1),
+    };
+    match c.and_then(DigitBlock::from_digit) {
+        Some((block, digit)) => (Some((b'0' + digit as u8, block)), width),
+        None => (None, width),
+    }
+}
+
+/// Parse a complete number with Unicode decimal digits.
+///
+/// The parser is called with the decoded ASCII digits, and any error
+/// indexes are translated back to the input. The `punctuation` is the
+/// ASCII characters other than letters, digits, and signs that can be part
+/// of a number, such as the decimal point and the digit separator.
+#[inline]
+pub fn parse_complete<T, Parse>(bytes: &[u8], punctuation: &[u8], parse: Parse) -> Result<T>
+where
+    Parse: FnOnce(&[u8]) -> Result<T>,
+{
+    match with_decoded(bytes, punctuation, parse) {
+        (Err(error), true) => Err(input_error(bytes, punctuation, error)),
+        (result, _) => result,
+    }
+}
+
+/// Parse a partial number with Unicode decimal digits.
+///
+/// The parser is called with the decoded ASCII digits, and the number of
+/// processed bytes and any error indexes are translated back to the input.
+/// The `punctuation` is the ASCII characters other than letters, digits,
+/// and signs that can be part of a number, such as the decimal point and
+/// the digit separator.
+#[inline]
+pub fn parse_partial<T, Parse>(bytes: &[u8], punctuation: &[u8], parse: Parse) -> Result<(T, usize)>
+where
+    Parse: FnOnce(&[u8]) -> Result<(T, usize)>,
+{
+    match with_decoded(bytes, punctuation, parse) {
+        (Ok((value, count)), true) => Ok((value, input_index(bytes, punctuation, count))),
+        (Err(error), true) => Err(input_error(bytes, punctuation, error)),
+        (result, false) => result,
+    }
+}
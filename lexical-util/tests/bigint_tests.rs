@@ -0,0 +1,158 @@
+#![cfg(feature = "bigint")]
+
+use lexical_util::bigint::{BigInt, BigUint, Limb};
+
+/// Simple xorshift generator for reproducible limbs.
+fn random_limbs(count: usize, mut state: u64) -> Vec<Limb> {
+    (0..count)
+        .map(|_| {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            state as Limb
+        })
+        .collect()
+}
+
+/// Parse digits with Horner's method, independent of the library.
+fn horner(digits: &[u8], radix: u32) -> Vec<Limb> {
+    let mut limbs: Vec<Limb> = Vec::new();
+    for &digit in digits {
+        let mut carry = digit as u128;
+        for limb in limbs.iter_mut() {
+            let value = *limb as u128 * radix as u128 + carry;
+            *limb = value as Limb;
+            carry = value >> Limb::BITS;
+        }
+        if carry != 0 {
+            limbs.push(carry as Limb);
+        }
+    }
+    limbs
+}
+
+/// Get the hexadecimal digits directly from the limbs.
+fn hex_digits(limbs: &[Limb]) -> Vec<u8> {
+    let mut digits: Vec<u8> = Vec::new();
+    for &limb in limbs.iter().rev() {
+        for shift in (0..Limb::BITS / 4).rev() {
+            digits.push(((limb >> (shift * 4)) & 0xf) as u8);
+        }
+    }
+    let zeros = digits.iter().take_while(|&&digit| digit == 0).count();
+    digits.drain(..zeros.min(digits.len() - 1));
+    digits
+}
+
+#[test]
+fn biguint_small_test() {
+    assert!(BigUint::new().is_zero());
+    assert_eq!(BigUint::new().to_digits(10), vec![0]);
+    assert_eq!(BigUint::from_digits(&[], 10), BigUint::new());
+    assert_eq!(BigUint::from_digits(&[0, 0, 0], 10), BigUint::new());
+    assert_eq!(BigUint::from_limbs(vec![1, 0, 0]).limbs(), &[1]);
+    assert_eq!(BigUint::from(0u64).limbs(), &[] as &[Limb]);
+    assert_eq!(BigUint::from(u128::MAX).limbs(), &[Limb::MAX; 128 / Limb::BITS as usize]);
+    assert_eq!(BigUint::from(u128::MAX).to_u128(), Some(u128::MAX));
+    assert_eq!(BigUint::from(u128::MAX).to_u64(), None);
+    assert_eq!(BigUint::from(u128::MAX).bit_length(), 128);
+    assert_eq!(BigUint::from(5u64).bit_length(), 3);
+    assert_eq!(BigUint::from_digits(&[1, 2, 3, 4], 10).to_u64(), Some(1234));
+    #[cfg(feature = "radix")]
+    assert_eq!(BigUint::from_digits(&[35, 35], 36).to_u64(), Some(1295));
+    assert_eq!(BigUint::from(1234u64).to_digits(10), vec![1, 2, 3, 4]);
+    #[cfg(feature = "power-of-two")]
+    assert_eq!(BigUint::from(255u64).to_digits(2), vec![1; 8]);
+    assert!(BigUint::from(u128::MAX) > BigUint::from(u64::MAX));
+}
+
+#[test]
+#[should_panic]
+fn biguint_invalid_radix_test() {
+    _ = BigUint::from_digits(&[1], 37);
+}
+
+#[test]
+#[cfg(feature = "radix")]
+fn biguint_u128_test() {
+    let values = [1u128, 9, 10, 99, 1 << 64, u64::MAX as u128, u128::MAX, 12345678901234567890123];
+    for radix in 2..=36 {
+        for &value in values.iter() {
+            let big = BigUint::from(value);
+            let digits = big.to_digits(radix);
+            let mut expected = Vec::new();
+            let mut x = value;
+            while x != 0 {
+                expected.push((x % radix as u128) as u8);
+                x /= radix as u128;
+            }
+            expected.reverse();
+            assert_eq!(digits, expected);
+            assert_eq!(BigUint::from_digits(&digits, radix), big);
+        }
+    }
+}
+
+#[test]
+#[cfg(feature = "power-of-two")]
+fn biguint_large_hex_test() {
+    for &count in [33, 64, 100, 257, 1000].iter() {
+        let limbs = random_limbs(count, 0x2545_f491_4f6c_dd1d + count as u64);
+        let value = BigUint::from_limbs(limbs.clone());
+        let digits = hex_digits(&limbs);
+        assert_eq!(value.to_digits(16), digits);
+        assert_eq!(BigUint::from_digits(&digits, 16).limbs(), &limbs[..]);
+    }
+}
+
+#[test]
+#[cfg(feature = "radix")]
+fn biguint_large_roundtrip_test() {
+    for &radix in [2, 3, 7, 10, 16, 36].iter() {
+        for &count in [31, 33, 65, 200, 700].iter() {
+            let limbs = random_limbs(count, 0x9e37_79b9_7f4a_7c15 ^ (radix as u64 * count as u64));
+            let value = BigUint::from_limbs(limbs);
+            let digits = value.to_digits(radix);
+            assert_ne!(digits[0], 0);
+            assert_eq!(horner(&digits, radix), value.limbs());
+            assert_eq!(BigUint::from_digits(&digits, radix), value);
+        }
+    }
+}
+
+#[test]
+fn biguint_powers_test() {
+    // Powers of the radix and one less than them stress the carries and
+    // the quotient corrections.
+    for &count in [100, 1000, 5000].iter() {
+        let mut digits = vec![0; count + 1];
+        digits[0] = 1;
+        let power = BigUint::from_digits(&digits, 10);
+        assert_eq!(power.limbs(), &horner(&digits, 10)[..]);
+        assert_eq!(power.to_digits(10), digits);
+
+        let nines = vec![9; count];
+        let value = BigUint::from_digits(&nines, 10);
+        assert_eq!(value.limbs(), &horner(&nines, 10)[..]);
+        assert_eq!(value.to_digits(10), nines);
+    }
+
+    let ones = BigUint::from_limbs(vec![Limb::MAX; 300]);
+    #[cfg(feature = "power-of-two")]
+    assert_eq!(ones.to_digits(2), vec![1; 300 * Limb::BITS as usize]);
+    assert_eq!(BigUint::from_digits(&ones.to_digits(10), 10), ones);
+}
+
+#[test]
+fn bigint_test() {
+    let value = BigInt::from(-5i64);
+    assert!(value.is_negative());
+    assert_eq!(value.magnitude().to_u64(), Some(5));
+    assert_eq!(BigInt::from(i128::MIN).magnitude().to_u128(), Some(1 << 127));
+    assert!(!BigInt::from_parts(true, BigUint::new()).is_negative());
+    assert!(BigInt::from(-5i64) < BigInt::from(-4i64));
+    assert!(BigInt::from(-5i64) < BigInt::from(0i64));
+    assert!(BigInt::from(5i64) > BigInt::from(4i64));
+    assert_eq!(BigInt::from(BigUint::from(3u64)), BigInt::from(3i64));
+    assert_eq!(BigInt::from(-3i64).into_parts(), (true, BigUint::from(3u64)));
+}
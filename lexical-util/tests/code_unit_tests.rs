@@ -0,0 +1,52 @@
+#![cfg(all(feature = "std", any(feature = "parse-floats", feature = "parse-integers")))]
+
+use lexical_util::code_unit::{CodeUnit, INVALID_BYTE, STACK_BUFFER_SIZE};
+
+#[test]
+fn to_byte_test() {
+    assert_eq!(b'1'.to_byte(), b'1');
+    assert_eq!(0xE9u8.to_byte(), 0xE9);
+    assert_eq!(u16::from(b'1').to_byte(), b'1');
+    assert_eq!(0x7Fu16.to_byte(), 0x7F);
+    assert_eq!(0x80u16.to_byte(), INVALID_BYTE);
+    // These would be ASCII digits if truncated.
+    assert_eq!(0x130u16.to_byte(), INVALID_BYTE);
+    assert_eq!(0x10030u32.to_byte(), INVALID_BYTE);
+    assert_eq!(u32::from(b'e').to_byte(), b'e');
+}
+
+#[test]
+fn with_bytes_test() {
+    let units: Vec<u16> = "1.5e\u{3c0}".encode_utf16().collect();
+    let bytes = u16::with_bytes(&units, b".", |bytes| bytes.to_vec());
+    assert_eq!(bytes, [b'1', b'.', b'5', b'e', INVALID_BYTE]);
+
+    let units: Vec<u32> = "-12".chars().map(u32::from).collect();
+    assert_eq!(u32::with_bytes(&units, b".", |bytes| bytes.to_vec()), b"-12");
+
+    let units = [0u16; 0];
+    assert!(u16::with_bytes(&units, b".", |bytes| bytes.is_empty()));
+
+    // Only the number is narrowed, up to the first code unit that can't be
+    // part of a number.
+    let units: Vec<u16> = "1.5,2.5,3.5".encode_utf16().collect();
+    assert_eq!(u16::with_bytes(&units, b".", |bytes| bytes.to_vec()), b"1.5,");
+    assert_eq!(u16::with_bytes(&units, b".,", |bytes| bytes.len()), units.len());
+    let units: Vec<u16> = "12 kg".encode_utf16().collect();
+    assert_eq!(u16::with_bytes(&units, b".", |bytes| bytes.to_vec()), b"12 ");
+    let units: Vec<u16> = "1\u{3c0}2".encode_utf16().collect();
+    assert_eq!(u16::with_bytes(&units, b".", |bytes| bytes.to_vec()), [b'1', INVALID_BYTE]);
+
+    // Bytes are never narrowed.
+    assert_eq!(u8::with_bytes(b"12 kg", b".", |bytes| bytes.len()), 5);
+}
+
+#[test]
+fn with_bytes_large_test() {
+    for length in [STACK_BUFFER_SIZE - 1, STACK_BUFFER_SIZE, STACK_BUFFER_SIZE + 1, 1000] {
+        let units = vec![u16::from(b'9'); length];
+        let bytes = u16::with_bytes(&units, b".", |bytes| bytes.to_vec());
+        assert_eq!(bytes.len(), length);
+        assert!(bytes.iter().all(|&c| c == b'9'));
+    }
+}
@@ -0,0 +1,182 @@
+#![cfg(feature = "diagnostic")]
+
+use lexical_util::diagnostic::{Component, Diagnostic, Expected, Rule};
+use lexical_util::error::Error;
+use lexical_util::format::STANDARD;
+
+#[test]
+fn component_test() {
+    let diagnostic = Diagnostic::new::<STANDARD>(Error::InvalidDigit(0), b"+");
+    assert_eq!(diagnostic.component(), Some(Component::Sign));
+
+    let diagnostic = Diagnostic::new::<STANDARD>(Error::InvalidDigit(2), b"-1a.5e3");
+    assert_eq!(diagnostic.component(), Some(Component::Integer));
+    assert_eq!(diagnostic.expected(), Some(Expected::Digit(10)));
+    assert_eq!(diagnostic.found(), Some(&b"a"[..]));
+
+    let diagnostic = Diagnostic::new::<STANDARD>(Error::InvalidDigit(4), b"-12.a");
+    assert_eq!(diagnostic.component(), Some(Component::Fraction));
+
+    let diagnostic = Diagnostic::new::<STANDARD>(Error::InvalidDigit(5), b"1.5e3a");
+    assert_eq!(diagnostic.component(), Some(Component::Exponent));
+
+    // Custom punctuation.
+    let diagnostic =
+        Diagnostic::with_punctuation::<STANDARD>(Error::InvalidDigit(4), b"1,5^a", b',', b'^');
+    assert_eq!(diagnostic.component(), Some(Component::Exponent));
+    let diagnostic =
+        Diagnostic::with_punctuation::<STANDARD>(Error::InvalidDigit(3), b"1.5e", b',', b'^');
+    assert_eq!(diagnostic.component(), Some(Component::Integer));
+}
+
+#[test]
+fn integer_test() {
+    // Decimal points and exponent characters are invalid digits.
+    let diagnostic = Diagnostic::integer::<STANDARD>(Error::InvalidDigit(1), b"1e5", false);
+    assert_eq!(diagnostic.component(), Some(Component::Integer));
+    assert_eq!(diagnostic.expected(), Some(Expected::Digit(10)));
+    assert_eq!(diagnostic.found(), Some(&b"e"[..]));
+    let diagnostic = Diagnostic::integer::<STANDARD>(Error::InvalidDigit(2), b"-1.5", true);
+    assert_eq!(diagnostic.component(), Some(Component::Integer));
+
+    // Negative signs are invalid for unsigned integers.
+    let diagnostic = Diagnostic::integer::<STANDARD>(Error::InvalidDigit(0), b"-", false);
+    assert_eq!(diagnostic.component(), Some(Component::Sign));
+    assert_eq!(diagnostic.expected(), Some(Expected::PositiveSignOrDigit));
+    assert_eq!(diagnostic.found(), Some(&b"-"[..]));
+    let diagnostic = Diagnostic::integer::<STANDARD>(Error::Empty(1), b"-", true);
+    assert_eq!(diagnostic.component(), Some(Component::Integer));
+    assert_eq!(diagnostic.expected(), Some(Expected::Digit(10)));
+}
+
+#[test]
+fn span_test() {
+    let diagnostic = Diagnostic::new::<STANDARD>(Error::EmptyExponent(4), b"1.5e");
+    assert_eq!(diagnostic.span(), Some(4..4));
+    assert_eq!(diagnostic.found(), None);
+    assert_eq!(diagnostic.component(), Some(Component::Exponent));
+    assert_eq!(diagnostic.rule(), Some(Rule::RequiredExponentDigits));
+
+    // Spans cover complete UTF-8 characters.
+    let diagnostic = Diagnostic::new::<STANDARD>(Error::InvalidDigit(1), "1½".as_bytes());
+    assert_eq!(diagnostic.span(), Some(1..3));
+
+    // Out of bounds indexes are clamped to the input.
+    let diagnostic = Diagnostic::new::<STANDARD>(Error::Empty(5), b"");
+    assert_eq!(diagnostic.span(), Some(0..0));
+
+    // Errors without an index have no span.
+    let diagnostic = Diagnostic::new::<STANDARD>(Error::InvalidMantissaRadix, b"1");
+    assert_eq!(diagnostic.span(), None);
+    assert_eq!(diagnostic.component(), None);
+    assert_eq!(diagnostic.expected(), None);
+    assert_eq!(diagnostic.rule(), None);
+}
+
+#[test]
+fn rule_test() {
+    let diagnostic = Diagnostic::new::<STANDARD>(Error::InvalidPositiveExponentSign(4), b"1.1e+15");
+    assert_eq!(diagnostic.span(), Some(4..5));
+    assert_eq!(diagnostic.component(), Some(Component::Exponent));
+    assert_eq!(diagnostic.expected(), Some(Expected::NegativeSignOrDigit));
+    assert_eq!(diagnostic.rule(), Some(Rule::NoPositiveExponentSign));
+
+    let diagnostic = Diagnostic::new::<STANDARD>(Error::MissingSign(0), b"1");
+    assert_eq!(diagnostic.component(), Some(Component::Sign));
+    assert_eq!(diagnostic.expected(), Some(Expected::Sign));
+    assert_eq!(diagnostic.rule(), Some(Rule::RequiredMantissaSign));
+
+    let diagnostic = Diagnostic::new::<STANDARD>(Error::ExponentWithoutFraction(1), b"1e5");
+    assert_eq!(diagnostic.expected(), Some(Expected::DecimalPoint(b'.')));
+    assert_eq!(diagnostic.rule(), Some(Rule::NoExponentWithoutFraction));
+
+    let diagnostic = Diagnostic::new::<STANDARD>(Error::Overflow(3), b"256");
+    assert_eq!(diagnostic.expected(), None);
+    assert_eq!(diagnostic.rule(), None);
+}
+
+#[test]
+fn leading_zeros_test() {
+    let diagnostic = Diagnostic::new::<STANDARD>(Error::InvalidLeadingZeros(1), b"-001");
+    assert_eq!(diagnostic.span(), Some(1..3));
+    assert_eq!(diagnostic.found(), Some(&b"00"[..]));
+    assert_eq!(diagnostic.expected(), Some(Expected::NonZeroDigit));
+    assert_eq!(diagnostic.rule(), Some(Rule::NoIntegerLeadingZeros));
+    let diagnostic = Diagnostic::integer::<STANDARD>(Error::InvalidLeadingZeros(0), b"01", false);
+    assert_eq!(diagnostic.rule(), Some(Rule::NoIntegerLeadingZeros));
+}
+
+#[test]
+#[cfg(feature = "format")]
+fn digit_separator_test() {
+    use core::num::NonZeroU8;
+
+    use lexical_util::format::NumberFormatBuilder;
+
+    const FORMAT: u128 = NumberFormatBuilder::new()
+        .digit_separator(NonZeroU8::new(b'_'))
+        .integer_internal_digit_separator(true)
+        .build_strict();
+
+    let diagnostic = Diagnostic::new::<FORMAT>(Error::InvalidDigit(0), b"_1");
+    assert_eq!(diagnostic.rule(), Some(Rule::LeadingDigitSeparator(Component::Integer)));
+    let diagnostic = Diagnostic::new::<FORMAT>(Error::InvalidDigit(1), b"1_");
+    assert_eq!(diagnostic.rule(), Some(Rule::TrailingDigitSeparator(Component::Integer)));
+    let diagnostic = Diagnostic::new::<FORMAT>(Error::InvalidDigit(1), b"1__2");
+    assert_eq!(diagnostic.rule(), Some(Rule::ConsecutiveDigitSeparator(Component::Integer)));
+    let diagnostic = Diagnostic::new::<FORMAT>(Error::InvalidDigit(3), b"1.2_3");
+    assert_eq!(diagnostic.rule(), Some(Rule::InternalDigitSeparator(Component::Fraction)));
+    let diagnostic = Diagnostic::new::<FORMAT>(Error::InvalidDigit(1), b"1a2");
+    assert_eq!(diagnostic.rule(), None);
+}
+
+#[test]
+#[cfg(all(feature = "format", feature = "power-of-two"))]
+fn prefix_suffix_test() {
+    use core::num::NonZeroU8;
+
+    use lexical_util::format::NumberFormatBuilder;
+
+    const FORMAT: u128 = NumberFormatBuilder::new()
+        .base_prefix(NonZeroU8::new(b'd'))
+        .base_suffix(NonZeroU8::new(b'h'))
+        .build_strict();
+
+    let diagnostic = Diagnostic::new::<FORMAT>(Error::InvalidDigit(2), b"-0d");
+    assert_eq!(diagnostic.component(), Some(Component::Prefix));
+    let diagnostic = Diagnostic::new::<FORMAT>(Error::InvalidDigit(3), b"0d1a");
+    assert_eq!(diagnostic.component(), Some(Component::Integer));
+    let diagnostic = Diagnostic::new::<FORMAT>(Error::InvalidDigit(2), b"12h");
+    assert_eq!(diagnostic.component(), Some(Component::Suffix));
+}
+
+#[test]
+fn display_test() {
+    let diagnostic = Diagnostic::new::<STANDARD>(Error::InvalidPositiveExponentSign(4), b"1.1e+15");
+    let expected = "lexical parse error: invalid `+` sign in exponent at 4..5
+ |
+ | 1.1e+15
+ |     ^ expected a digit or `-` sign in the exponent, found `+`
+ = note: this format forbids positive exponent signs";
+    assert_eq!(diagnostic.to_string(), expected);
+
+    let diagnostic = Diagnostic::new::<STANDARD>(Error::EmptyExponent(4), b"1.5e");
+    let expected = "lexical parse error: exponent notation found without an exponent at 4..4
+ |
+ | 1.5e
+ |     ^ expected a digit in the exponent, found the end of the input
+ = note: this format requires digits after the exponent marker";
+    assert_eq!(diagnostic.to_string(), expected);
+
+    // Carets are aligned by characters, and control characters are escaped.
+    let diagnostic = Diagnostic::new::<STANDARD>(Error::InvalidDigit(2), "½\t1x".as_bytes());
+    let expected = "lexical parse error: invalid digit found at 2..3
+ |
+ | ½\\t1x
+ |  ^^ expected a digit in the integer, found `\\t`";
+    assert_eq!(diagnostic.to_string(), expected);
+
+    // Errors without an index use the error message.
+    let diagnostic = Diagnostic::new::<STANDARD>(Error::InvalidMantissaRadix, b"1");
+    assert_eq!(diagnostic.to_string(), Error::InvalidMantissaRadix.to_string());
+}
@@ -0,0 +1,150 @@
+#![cfg(feature = "f128")]
+
+use lexical_util::f128::f128;
+use lexical_util::num::{AsPrimitive, Float};
+
+#[test]
+fn as_f64_test() {
+    assert_eq!(f128::ZERO.as_f64(), 0.0f64);
+    assert_eq!(f128::ZERO.to_bits(), 0);
+    assert_eq!(f128::ONE.as_f64(), 1.0f64);
+    assert_eq!(f128::ONE.to_bits(), 0x3FFF << 112);
+    assert_eq!(f128::TWO.as_f64(), 2.0f64);
+    assert_eq!(f128::TWO.to_bits(), 0x4000 << 112);
+    assert_eq!(f128::from_bits(0x3FFE << 112).as_f64(), 0.5f64);
+    assert_eq!(f128::from_bits(1).as_f64(), 0.0f64);
+    assert_eq!(f128::MAX.as_f64(), f64::INFINITY);
+    assert_eq!(f128::MIN.as_f64(), f64::NEG_INFINITY);
+    assert!(f128::NAN.as_f64().is_nan());
+    assert!(f128::INFINITY.as_f64().is_inf());
+    assert!(f128::NEG_INFINITY.as_f64().is_inf());
+
+    // Round-nearest, tie-even.
+    let halfway = f128::from_bits((0x3FFF << 112) | (1 << 59));
+    assert_eq!(halfway.as_f64(), 1.0);
+    let above = f128::from_bits((0x3FFF << 112) | (1 << 59) | 1);
+    assert_eq!(above.as_f64(), 1.0000000000000002);
+    assert_eq!(f128::from_bits(0x3BCC << 112).as_f64(), 0.0);
+    assert_eq!(f128::from_bits(0x3BCD << 112).as_f64(), 5e-324);
+    assert_eq!(f128::from_f64(5e-324).as_f64(), 5e-324);
+    assert_eq!(f128::from_bits(0x3FFE << 112).as_f32(), 0.5f32);
+}
+
+#[test]
+fn from_f64_test() {
+    assert_eq!(f128::from_f64(0.0).to_bits(), 0);
+    assert_eq!(f128::from_f64(-0.0).to_bits(), 1 << 127);
+    assert_eq!(f128::from_f64(1.0), f128::ONE);
+    assert_eq!(f128::from_f64(1.5).to_bits(), (0x3FFF << 112) | (1 << 111));
+    assert_eq!(f128::from_f64(5e-324).to_bits(), (0x3BCD << 112));
+    assert!(f128::from_f64(f64::NAN).is_nan());
+    assert!(f128::from_f64(f64::INFINITY).is_inf());
+    assert!(f128::from_f64(f64::NEG_INFINITY).is_inf());
+    for value in [0.1, 1e300, -2.5e-310, f64::MAX, f64::MIN_POSITIVE] {
+        assert_eq!(f128::from_f64(value).as_f64(), value);
+        assert_eq!(f128::from_f32(value as f32).as_f32(), value as f32);
+    }
+}
+
+#[test]
+fn integer_test() {
+    assert_eq!(f128::from_u128(u128::MAX).to_bits(), 0x407F << 112);
+    assert_eq!(f128::from_i128(-3).as_f64(), -3.0);
+    assert_eq!(f128::from_f64(1e38).as_u128(), 99999999999999997748809823456034029568);
+    assert_eq!(f128::from_f64(-1.5).as_i128(), -1);
+    assert_eq!(f128::from_f64(-1.5).as_u8(), 0);
+    assert_eq!(f128::from_f64(300.0).as_u8(), 255);
+    assert_eq!(f128::from_f64(-300.0).as_i8(), -128);
+    assert_eq!(f128::INFINITY.as_i128(), i128::MAX);
+    assert_eq!(f128::NEG_INFINITY.as_i128(), i128::MIN);
+    assert_eq!(f128::NAN.as_u64(), 0);
+    assert_eq!(12u8.as_f128(), f128::from_f64(12.0));
+    assert_eq!((-12i64).as_f128(), f128::from_f64(-12.0));
+}
+
+#[test]
+fn math_tests() {
+    assert_eq!(f128::ONE + f128::ONE, f128::TWO);
+    assert_eq!(f128::ONE * f128::ONE, f128::ONE);
+    assert_eq!(f128::ONE / f128::ONE, f128::ONE);
+    assert_eq!(f128::ONE - f128::ONE, f128::ZERO);
+    assert_eq!(f128::ONE % f128::ONE, f128::ZERO);
+
+    // Correctly rounded results.
+    let three = f128::from_f64(3.0);
+    let third = f128::ONE / three;
+    assert_eq!(third.to_bits(), 0x3FFD5555555555555555555555555555);
+    assert_eq!((f128::TWO / three).to_bits(), 0x3FFE5555555555555555555555555555);
+    assert_eq!(third * three, f128::ONE);
+    let tenth = f128::ONE / f128::from_f64(10.0);
+    assert_eq!(tenth.to_bits(), 0x3FFB999999999999999999999999999A);
+    assert_eq!((tenth + tenth + tenth).to_bits(), 0x3FFD3333333333333333333333333334);
+    let epsilon = f128::from_bits((0x3FFF - 112) << 112);
+    assert_eq!(f128::ONE + epsilon / f128::TWO, f128::ONE);
+    assert_eq!((f128::ONE + epsilon) - f128::ONE, epsilon);
+
+    // Denormal, overflow, and special values.
+    let min = f128::from_bits(1);
+    assert_eq!(min / f128::TWO, f128::ZERO);
+    assert_eq!((min * three) / f128::TWO, f128::from_bits(2));
+    assert_eq!(f128::MAX * f128::TWO, f128::INFINITY);
+    assert_eq!(f128::MAX + f128::MAX, f128::INFINITY);
+    assert!((f128::INFINITY - f128::INFINITY).is_nan());
+    assert!((f128::ZERO / f128::ZERO).is_nan());
+    assert_eq!(f128::ONE / f128::ZERO, f128::INFINITY);
+    assert_eq!(f128::ONE / -f128::ZERO, f128::NEG_INFINITY);
+    assert!((-f128::ZERO + -f128::ZERO).is_sign_negative());
+    assert!((f128::ONE - f128::ONE).is_sign_positive());
+
+    // Exact remainders.
+    assert_eq!(f128::from_f64(7.5) % f128::TWO, f128::from_f64(1.5));
+    assert_eq!(f128::from_f64(-7.5) % f128::TWO, f128::from_f64(-1.5));
+    assert_eq!(f128::from_f64(1e300) % three, f128::from_f64(1e300 % 3.0));
+    assert_eq!(f128::from_f64(0.75) % f128::from_f64(0.5), f128::from_f64(0.25));
+
+    // Compare to native operations on exactly representable values.
+    let values = [0.1, 0.25, 1.0, 3.5, 1e10, 1e-10, 123456.789, -7.0, 2.5e-300];
+    for &x in values.iter() {
+        for &y in values.iter() {
+            let (fx, fy) = (f128::from_f64(x), f128::from_f64(y));
+            assert_eq!((fx * fy).as_f64(), x * y);
+            assert_eq!((fx + fy).as_f64(), x + y);
+            assert_eq!((fx - fy).as_f64(), x - y);
+            assert_eq!((fx % fy).as_f64(), x % y);
+        }
+    }
+}
+
+#[test]
+fn floor_test() {
+    assert_eq!(f128::from_f64(1.5).floor(), f128::ONE);
+    assert_eq!(f128::from_f64(-1.5).floor(), f128::from_f64(-2.0));
+    assert_eq!(f128::from_f64(0.5).floor(), f128::ZERO);
+    assert_eq!(f128::from_f64(-0.5).floor(), -f128::ONE);
+    assert_eq!(f128::from_f64(1e300).floor(), f128::from_f64(1e300));
+    assert_eq!(f128::from_bits(1).floor(), f128::ZERO);
+    assert!((-f128::ZERO).floor().is_sign_negative());
+}
+
+#[test]
+fn ordering_test() {
+    assert!(f128::ONE < f128::TWO);
+    assert!(-f128::TWO < -f128::ONE);
+    assert!(f128::MIN < f128::MAX);
+    assert_eq!(f128::ZERO, -f128::ZERO);
+    assert!(f128::NAN != f128::NAN);
+    assert_eq!(f128::NAN.partial_cmp(&f128::ONE), None);
+}
+
+#[test]
+fn display_test() {
+    assert_eq!(f128::ZERO.to_string(), "0x0p+0");
+    assert_eq!((-f128::ZERO).to_string(), "-0x0p+0");
+    assert_eq!(f128::ONE.to_string(), "0x1p+0");
+    assert_eq!(f128::from_f64(-3.0).to_string(), "-0x1.8p+1");
+    assert_eq!(f128::from_f64(0.1).to_string(), "0x1.999999999999ap-4");
+    assert_eq!(f128::from_bits(1).to_string(), "0x1p-16494");
+    assert_eq!(f128::MAX.to_string(), "0x1.ffffffffffffffffffffffffffffp+16383");
+    assert_eq!(f128::NAN.to_string(), "NaN");
+    assert_eq!(f128::NEG_INFINITY.to_string(), "-inf");
+}
@@ -0,0 +1,131 @@
+#![cfg(feature = "unicode")]
+
+use lexical_util::error::Error;
+use lexical_util::unicode::{parse_complete, parse_partial, DigitBlock};
+
+#[test]
+fn digit_block_test() {
+    assert_eq!(DigitBlock::new('0'), Some(DigitBlock::ASCII));
+    assert_eq!(DigitBlock::new('\u{FF10}'), Some(DigitBlock::FULLWIDTH));
+    assert_eq!(DigitBlock::new('\u{1D7CE}').map(|b| b.zero()), Some('\u{1D7CE}'));
+    assert_eq!(DigitBlock::new('1'), None);
+    assert_eq!(DigitBlock::new('a'), None);
+
+    assert_eq!(DigitBlock::from_digit('7'), Some((DigitBlock::ASCII, 7)));
+    assert_eq!(DigitBlock::from_digit('\u{663}'), Some((DigitBlock::ARABIC_INDIC, 3)));
+    assert_eq!(DigitBlock::from_digit('\u{6F9}'), Some((DigitBlock::EXTENDED_ARABIC_INDIC, 9)));
+    assert_eq!(DigitBlock::from_digit('\u{FF15}'), Some((DigitBlock::FULLWIDTH, 5)));
+    // Adjacent blocks of mathematical digits.
+    let (block, digit) = DigitBlock::from_digit('\u{1D7D8}').unwrap();
+    assert_eq!((block.zero(), digit), ('\u{1D7D8}', 0));
+    assert_eq!(DigitBlock::from_digit('/'), None);
+    assert_eq!(DigitBlock::from_digit(':'), None);
+    assert_eq!(DigitBlock::from_digit('\u{66A}'), None);
+    assert_eq!(DigitBlock::from_digit('\u{FF1A}'), None);
+    assert_eq!(DigitBlock::from_digit('\u{10FFFF}'), None);
+
+    let block = DigitBlock::DEVANAGARI;
+    for digit in 0..10 {
+        assert_eq!(DigitBlock::from_digit(block.to_char(digit)), Some((block, digit)));
+    }
+    assert_eq!(DigitBlock::ASCII.utf8_len(), 1);
+    assert_eq!(DigitBlock::ARABIC_INDIC.utf8_len(), 2);
+    assert_eq!(DigitBlock::FULLWIDTH.buffer_size(10), 30);
+}
+
+#[test]
+#[should_panic]
+fn to_char_panic_test() {
+    DigitBlock::THAI.to_char(10);
+}
+
+#[test]
+fn encode_digits_test() {
+    let mut buffer = [0u8; 64];
+    buffer[..6].copy_from_slice(b"-1.5e3");
+    let count = DigitBlock::ARABIC_INDIC.encode_digits(&mut buffer, 6);
+    assert_eq!(&buffer[..count], "-١.٥e٣".as_bytes());
+
+    buffer[..3].copy_from_slice(b"NaN");
+    assert_eq!(DigitBlock::FULLWIDTH.encode_digits(&mut buffer, 3), 3);
+    assert_eq!(&buffer[..3], b"NaN");
+
+    buffer[..4].copy_from_slice(b"1f_0");
+    let count = DigitBlock::FULLWIDTH.encode_digits(&mut buffer, 4);
+    assert_eq!(&buffer[..count], "１f_０".as_bytes());
+
+    let mut buffer = [0u8; 4];
+    buffer[..2].copy_from_slice(b"12");
+    assert_eq!(DigitBlock::ASCII.encode_digits(&mut buffer, 2), 2);
+    assert_eq!(DigitBlock::ARABIC_INDIC.encode_digits(&mut buffer, 2), 4);
+    assert_eq!(&buffer, "١٢".as_bytes());
+}
+
+#[test]
+#[should_panic]
+fn encode_digits_panic_test() {
+    let mut buffer = [b'1', b'2', 0, 0, 0];
+    DigitBlock::FULLWIDTH.encode_digits(&mut buffer, 2);
+}
+
+fn decoded(bytes: &[u8]) -> Vec<u8> {
+    parse_complete(bytes, b"._", |bytes| Ok(bytes.to_vec())).unwrap()
+}
+
+#[test]
+fn parse_complete_test() {
+    assert_eq!(decoded(b"-12.5e3"), b"-12.5e3");
+    assert_eq!(decoded("-١٢.٥".as_bytes()), b"-12.5");
+    assert_eq!(decoded("１_０".as_bytes()), b"1_0");
+    // Mixed blocks, other characters, and invalid UTF-8 are invalid, and
+    // end the decoded bytes.
+    assert_eq!(decoded("１2".as_bytes()), b"1\xFF");
+    assert_eq!(decoded("١π".as_bytes()), b"1\xFF");
+    assert_eq!(decoded("١π2".as_bytes()), b"1\xFF");
+    assert_eq!(decoded(b"\xD9\xA1\xE0\xA5"), b"1\xFF");
+    assert_eq!(decoded("١ ٢".as_bytes()), b"1\xFF");
+    assert_eq!(decoded("١,٢".as_bytes()), b"1\xFF");
+
+    // Numbers with only ASCII characters are not decoded.
+    assert_eq!(decoded("12 ٣".as_bytes()), "12 ٣".as_bytes());
+    assert_eq!(decoded("1π2".as_bytes()), "1π2".as_bytes());
+
+    // Long numbers are decoded on the heap.
+    let string = "١".repeat(300);
+    assert_eq!(decoded(string.as_bytes()), vec![b'1'; 300]);
+    let string = string + "π" + &"١".repeat(300);
+    let mut expected = vec![b'1'; 300];
+    expected.push(b'\xFF');
+    assert_eq!(decoded(string.as_bytes()), expected);
+
+    // Error indexes are translated to the input.
+    let bytes = "١٢x".as_bytes();
+    let result: Result<(), Error> = parse_complete(bytes, b".", |_| Err(Error::InvalidDigit(2)));
+    assert_eq!(result, Err(Error::InvalidDigit(4)));
+    let result: Result<(), Error> = parse_complete(bytes, b".", |_| Err(Error::Empty(3)));
+    assert_eq!(result, Err(Error::Empty(5)));
+    let result: Result<(), Error> = parse_complete(bytes, b".", |_| Err(Error::InvalidFlags));
+    assert_eq!(result, Err(Error::InvalidFlags));
+}
+
+#[test]
+fn parse_partial_test() {
+    let bytes = "٣.٥kg".as_bytes();
+    assert_eq!(parse_partial(bytes, b".", |bytes| Ok((bytes.len(), 3))), Ok((5, 5)));
+    assert_eq!(parse_partial(bytes, b".", |bytes| Ok((bytes.len(), 5))), Ok((5, 7)));
+    assert_eq!(parse_partial(b"12 kg", b".", |bytes| Ok((bytes.len(), 2))), Ok((5, 2)));
+    let result: Result<((), usize), Error> =
+        parse_partial(bytes, b".", |_| Err(Error::Overflow(2)));
+    assert_eq!(result, Err(Error::Overflow(3)));
+
+    // The decoded bytes end after the first character that can't be part
+    // of a number.
+    let bytes = "٣€".repeat(100);
+    let result = parse_partial(bytes.as_bytes(), b".", |bytes| Ok((bytes.len(), 2)));
+    assert_eq!(result, Ok((2, 5)));
+    let bytes = "٣,".repeat(100);
+    let result = parse_partial(bytes.as_bytes(), b".", |bytes| Ok((bytes.len(), 2)));
+    assert_eq!(result, Ok((2, 3)));
+    let result = parse_partial(bytes.as_bytes(), b",", |bytes| Ok((bytes.len(), 2)));
+    assert_eq!(result, Ok((200, 3)));
+}
@@ -0,0 +1,133 @@
+// Generate the `printf("%a")` corpus for `tests/c_hex_tests.rs`.
+//
+// Build and run with a C99 libc, such as glibc:
+//      cc -std=c99 -O2 etc/printf_hex.c -o printf_hex
+//      ./printf_hex > tests/corpus/printf_hex.txt
+//
+// Each line is `<a|A> <f64|f32> <bits> <precision|-> <output>`, where the
+// bits are the hex representation of the float, and `-` means no precision.
+
+#include <inttypes.h>
+#include <stdio.h>
+#include <string.h>
+
+static uint64_t state = 0x853c49e6748fea9bULL;
+
+// Deterministic xorshift64* generator, so the corpus is reproducible.
+static uint64_t next(void) {
+    state ^= state >> 12;
+    state ^= state << 25;
+    state ^= state >> 27;
+    return state * 0x2545f4914f6cdd1dULL;
+}
+
+static const int PRECISIONS[] = { -1, 0, 1, 2, 3, 5, 8, 12, 13, 14, 20 };
+static const size_t PRECISIONS_LEN = sizeof(PRECISIONS) / sizeof(PRECISIONS[0]);
+
+static void write_f64(uint64_t bits, int precision, int upper) {
+    double value;
+    char buffer[128];
+    memcpy(&value, &bits, sizeof(value));
+    if (precision < 0) {
+        snprintf(buffer, sizeof(buffer), upper ? "%A" : "%a", value);
+        printf("%s f64 %016" PRIx64 " - %s\n", upper ? "A" : "a", bits, buffer);
+    } else {
+        snprintf(buffer, sizeof(buffer), upper ? "%.*A" : "%.*a", precision, value);
+        printf("%s f64 %016" PRIx64 " %d %s\n", upper ? "A" : "a", bits, precision, buffer);
+    }
+}
+
+static void write_f32(uint32_t bits, int precision, int upper) {
+    float value;
+    char buffer[128];
+    memcpy(&value, &bits, sizeof(value));
+    if (precision < 0) {
+        snprintf(buffer, sizeof(buffer), upper ? "%A" : "%a", (double)value);
+        printf("%s f32 %08" PRIx32 " - %s\n", upper ? "A" : "a", bits, buffer);
+    } else {
+        snprintf(buffer, sizeof(buffer), upper ? "%.*A" : "%.*a", precision, (double)value);
+        printf("%s f32 %08" PRIx32 " %d %s\n", upper ? "A" : "a", bits, precision, buffer);
+    }
+}
+
+static void write_all_f64(uint64_t bits) {
+    for (size_t i = 0; i < PRECISIONS_LEN; i++) {
+        write_f64(bits, PRECISIONS[i], 0);
+    }
+    write_f64(bits, -1, 1);
+    write_f64(bits, 3, 1);
+}
+
+static void write_all_f32(uint32_t bits) {
+    for (size_t i = 0; i < PRECISIONS_LEN; i++) {
+        write_f32(bits, PRECISIONS[i], 0);
+    }
+    write_f32(bits, -1, 1);
+}
+
+int main(void) {
+    static const uint64_t F64_SPECIAL[] = {
+        0x0000000000000000ULL,  // 0
+        0x8000000000000000ULL,  // -0
+        0x0000000000000001ULL,  // smallest denormal
+        0x000fffffffffffffULL,  // largest denormal
+        0x0008000000000000ULL,  // denormal with a single bit
+        0x0010000000000000ULL,  // smallest normal
+        0x3ff0000000000000ULL,  // 1
+        0xbff8000000000000ULL,  // -1.5
+        0x3ff0800000000000ULL,  // 1.03125, halfway with 0 fraction digits
+        0x3ff1800000000000ULL,  // halfway, rounds to even
+        0x3ff8000000000000ULL,  // 1.5, carries into the leading digit
+        0x3fffffffffffffffULL,  // carries into the leading digit
+        0x3ff0000000000008ULL,  // halfway in the last digit
+        0x3ff0000000000018ULL,  // halfway in the last digit, odd
+        0x4000000000000000ULL,  // 2
+        0x4059000000000000ULL,  // 100
+        0x3fb999999999999aULL,  // 0.1
+        0x400921fb54442d18ULL,  // pi
+        0x7fefffffffffffffULL,  // largest normal
+        0x7ff0000000000000ULL,  // inf
+        0xfff0000000000000ULL,  // -inf
+        0x7ff8000000000000ULL,  // nan
+        0xfff8000000000000ULL,  // -nan
+    };
+    static const uint32_t F32_SPECIAL[] = {
+        0x00000000UL,  // 0
+        0x80000000UL,  // -0
+        0x00000001UL,  // smallest denormal
+        0x007fffffUL,  // largest denormal
+        0x00800000UL,  // smallest normal
+        0x3f800000UL,  // 1
+        0x3dcccccdUL,  // 0.1
+        0x40490fdbUL,  // pi
+        0x7f7fffffUL,  // largest normal
+        0x7f800000UL,  // inf
+        0xff800000UL,  // -inf
+    };
+
+    for (size_t i = 0; i < sizeof(F64_SPECIAL) / sizeof(F64_SPECIAL[0]); i++) {
+        write_all_f64(F64_SPECIAL[i]);
+    }
+    for (size_t i = 0; i < sizeof(F32_SPECIAL) / sizeof(F32_SPECIAL[0]); i++) {
+        write_all_f32(F32_SPECIAL[i]);
+    }
+
+    // Random floats, skipping special values, and random denormal floats.
+    for (int i = 0; i < 120; i++) {
+        uint64_t bits = next();
+        if ((bits & 0x7ff0000000000000ULL) != 0x7ff0000000000000ULL) {
+            write_all_f64(bits);
+        }
+    }
+    for (int i = 0; i < 20; i++) {
+        write_all_f64(next() & 0x800fffffffffffffULL);
+    }
+    for (int i = 0; i < 60; i++) {
+        uint32_t bits = (uint32_t)(next() >> 32);
+        if ((bits & 0x7f800000UL) != 0x7f800000UL) {
+            write_all_f32(bits);
+        }
+    }
+
+    return 0;
+}
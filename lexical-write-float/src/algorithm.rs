@@ -28,6 +28,8 @@
 
 #[cfg(feature = "f16")]
 use lexical_util::bf16::bf16;
+#[cfg(feature = "f128")]
+use lexical_util::f128::f128;
 #[cfg(feature = "f16")]
 use lexical_util::f16::f16;
 use lexical_util::format::NumberFormat;
@@ -1468,7 +1470,7 @@ impl DragonboxFloat for f64 {
     }
 }
 
-#[cfg(feature = "f16")]
+#[cfg(any(feature = "f16", feature = "f128"))]
 macro_rules! dragonbox_unimpl {
     ($($t:ident)*) => ($(
         impl DragonboxFloat for $t {
@@ -1552,3 +1554,6 @@ macro_rules! dragonbox_unimpl {
 
 #[cfg(feature = "f16")]
 dragonbox_unimpl! { bf16 f16 }
+
+#[cfg(feature = "f128")]
+dragonbox_unimpl! { f128 }
@@ -4,6 +4,8 @@
 
 #[cfg(feature = "f16")]
 use lexical_util::bf16::bf16;
+#[cfg(feature = "f128")]
+use lexical_util::f128::f128;
 #[cfg(feature = "f16")]
 use lexical_util::f16::f16;
 use lexical_util::format::STANDARD;
@@ -56,3 +58,7 @@ float_to_lexical! {
     f16 ;
     bf16 ;
 }
+#[cfg(feature = "f128")]
+float_to_lexical! {
+    f128 ;
+}
@@ -0,0 +1,318 @@
+//! A simple big-integer type for exact digit generation.
+//!
+//! This is a minimal subset of the big-integer arithmetic used by
+//! `lexical-parse-float`, only supporting the operations required to
+//! generate the exact significant digits of a float.
+
+#![doc(hidden)]
+
+// BIGINT
+// ------
+
+/// Number of bits in a Bigint.
+///
+/// This needs to be at least the number of bits required to store
+/// the exact significant digits of the float as an integer, which is
+/// `mantissa * 5^-exp` for the smallest denormal, or `2^53 * 5^1074`
+/// for an `f64`. This is `log2(2^53 * 5^1074) ≅ 2547`, rounded-up.
+pub const BIGINT_BITS: usize = 2560;
+
+/// The number of limbs for the bigint.
+pub const BIGINT_LIMBS: usize = BIGINT_BITS / Limb::BITS as usize;
+
+/// Number of bits in a Bigint for writing an [`f128`].
+///
+/// This needs to store the scaled values for the shortest digits of the
+/// smallest denormal `f128`, which is `~2^16497` for the denominator,
+/// with a few extra bits since the numerator may be up to the radix
+/// times larger.
+///
+/// [`f128`]: lexical_util::f128::f128
+#[cfg(feature = "f128")]
+pub const QUAD_BITS: usize = 16_640;
+
+/// The number of limbs for the bigint for writing an [`f128`].
+///
+/// [`f128`]: lexical_util::f128::f128
+#[cfg(feature = "f128")]
+pub const QUAD_LIMBS: usize = QUAD_BITS / Limb::BITS as usize;
+
+/// Number of bits in a Bigint for the exact digits of an [`f128`].
+///
+/// This is `log2(2^113 * 5^16494) ≅ 38411`, rounded-up, for the smallest
+/// denormal `f128`.
+///
+/// [`f128`]: lexical_util::f128::f128
+#[cfg(feature = "f128")]
+pub const QUAD_EXACT_BITS: usize = 38_464;
+
+/// The number of limbs for the bigint for the exact digits of an [`f128`].
+///
+/// [`f128`]: lexical_util::f128::f128
+#[cfg(feature = "f128")]
+pub const QUAD_EXACT_LIMBS: usize = QUAD_EXACT_BITS / Limb::BITS as usize;
+
+/// Storage for a big integer type.
+///
+/// The limbs are stored in little-endian order, and the length is always
+/// normalized, so there are no most-significant zero limbs.
+#[derive(Clone, PartialEq, Eq)]
+pub struct Bigint<const SIZE: usize = BIGINT_LIMBS> {
+    /// The limbs of the big integer, in LE order.
+    data: [Limb; SIZE],
+    /// The number of initialized limbs.
+    length: usize,
+}
+
+impl<const SIZE: usize> Bigint<SIZE> {
+    /// Construct a bigint representing 0.
+    #[inline(always)]
+    pub const fn new() -> Self {
+        Self {
+            data: [0; SIZE],
+            length: 0,
+        }
+    }
+
+    /// Construct a bigint from an integer.
+    #[inline(always)]
+    pub fn from_u64(value: u64) -> Self {
+        let mut x = Self::new();
+        #[cfg(not(all(target_pointer_width = "64", not(target_arch = "sparc"))))]
+        {
+            x.data[0] = value as Limb;
+            x.data[1] = (value >> 32) as Limb;
+            x.length = 2;
+        }
+        #[cfg(all(target_pointer_width = "64", not(target_arch = "sparc")))]
+        {
+            x.data[0] = value;
+            x.length = 1;
+        }
+        x.normalize();
+        x
+    }
+
+    /// Get the initialized limbs, in LE order.
+    #[inline(always)]
+    pub fn as_slice(&self) -> &[Limb] {
+        &self.data[..self.length]
+    }
+
+    /// Get if the big integer is zero.
+    #[inline(always)]
+    pub const fn is_zero(&self) -> bool {
+        self.length == 0
+    }
+
+    /// Remove any most-significant zero limbs.
+    #[inline(always)]
+    pub fn normalize(&mut self) {
+        while self.length != 0 && self.data[self.length - 1] == 0 {
+            self.length -= 1;
+        }
+    }
+
+    /// Push a limb to the end of the buffer.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the bigint would overflow the buffer.
+    #[inline(always)]
+    fn push(&mut self, value: Limb) {
+        assert!(self.length < SIZE, "bigint overflowed its storage");
+        self.data[self.length] = value;
+        self.length += 1;
+    }
+
+    /// Multiply the bigint by a small integer.
+    #[inline(always)]
+    pub fn mul_small(&mut self, y: Limb) {
+        let mut carry: Limb = 0;
+        for xi in self.data[..self.length].iter_mut() {
+            let z = (*xi as Wide) * (y as Wide) + (carry as Wide);
+            *xi = z as Limb;
+            carry = (z >> Limb::BITS) as Limb;
+        }
+        if carry != 0 {
+            self.push(carry);
+        }
+    }
+
+    /// Multiply the bigint by `5^exp`.
+    #[inline]
+    pub fn pow5(&mut self, mut exp: u32) {
+        while exp >= LARGE_POW5_STEP {
+            self.mul_small(LARGE_POW5);
+            exp -= LARGE_POW5_STEP;
+        }
+        if exp != 0 {
+            self.mul_small((5 as Limb).pow(exp));
+        }
+    }
+
+    /// Shift the bigint left by `n` bits.
+    #[inline]
+    pub fn shl(&mut self, n: usize) {
+        if self.is_zero() {
+            return;
+        }
+
+        let bits = Limb::BITS as usize;
+        let limbs = n / bits;
+        let rem = n % bits;
+        if rem != 0 {
+            let mut prev: Limb = 0;
+            for xi in self.data[..self.length].iter_mut() {
+                let tmp = *xi;
+                *xi = (tmp << rem) | (prev >> (bits - rem));
+                prev = tmp;
+            }
+            let carry = prev >> (bits - rem);
+            if carry != 0 {
+                self.push(carry);
+            }
+        }
+        if limbs != 0 {
+            assert!(self.length + limbs <= SIZE, "bigint overflowed its storage");
+            self.data.copy_within(..self.length, limbs);
+            self.data[..limbs].fill(0);
+            self.length += limbs;
+        }
+    }
+
+    /// Divide the bigint by a small integer in-place, returning the remainder.
+    #[inline]
+    pub fn div_small(&mut self, y: Limb) -> Limb {
+        debug_assert!(y != 0, "cannot divide by zero");
+        let mut rem: Wide = 0;
+        for xi in self.data[..self.length].iter_mut().rev() {
+            let z = (rem << Limb::BITS) | (*xi as Wide);
+            *xi = (z / y as Wide) as Limb;
+            rem = z % y as Wide;
+        }
+        self.normalize();
+        rem as Limb
+    }
+
+    /// Construct a bigint from a 128-bit integer.
+    #[inline(always)]
+    #[cfg(any(feature = "radix", feature = "f128"))]
+    pub fn from_u128(value: u128) -> Self {
+        let mut x = Self::from_u64((value >> 64) as u64);
+        x.shl(64);
+        x.add(&Self::from_u64(value as u64));
+        x
+    }
+
+    /// Multiply the bigint by `base^exp`.
+    #[inline]
+    #[cfg(any(feature = "radix", feature = "f128"))]
+    pub fn pow(&mut self, base: u32, mut exp: u32) {
+        // Get the largest power of the base that fits in a limb.
+        let base = base as Limb;
+        let mut large = base;
+        let mut step = 1;
+        while let Some(next) = large.checked_mul(base) {
+            large = next;
+            step += 1;
+        }
+        while exp >= step {
+            self.mul_small(large);
+            exp -= step;
+        }
+        if exp != 0 {
+            self.mul_small(base.pow(exp));
+        }
+    }
+
+    /// Add a bigint to the bigint.
+    #[inline]
+    #[cfg(any(feature = "radix", feature = "f128"))]
+    pub fn add(&mut self, y: &Self) {
+        while self.length < y.length {
+            self.push(0);
+        }
+        let mut carry = false;
+        for (index, xi) in self.data[..self.length].iter_mut().enumerate() {
+            let yi = if index < y.length {
+                y.data[index]
+            } else {
+                0
+            };
+            let (v, c1) = xi.overflowing_add(yi);
+            let (v, c2) = v.overflowing_add(carry as Limb);
+            *xi = v;
+            carry = c1 || c2;
+            if !carry && index >= y.length {
+                break;
+            }
+        }
+        if carry {
+            self.push(1);
+        }
+    }
+
+    /// Subtract a bigint from the bigint, where `self >= y`.
+    #[inline]
+    #[cfg(any(feature = "radix", feature = "f128"))]
+    pub fn sub(&mut self, y: &Self) {
+        debug_assert!(
+            self.compare(y) != core::cmp::Ordering::Less,
+            "cannot subtract a larger bigint"
+        );
+        let mut borrow = false;
+        for (index, xi) in self.data[..self.length].iter_mut().enumerate() {
+            let yi = if index < y.length {
+                y.data[index]
+            } else {
+                0
+            };
+            let (v, c1) = xi.overflowing_sub(yi);
+            let (v, c2) = v.overflowing_sub(borrow as Limb);
+            *xi = v;
+            borrow = c1 || c2;
+            if !borrow && index >= y.length {
+                break;
+            }
+        }
+        self.normalize();
+    }
+
+    /// Compare two normalized bigints.
+    #[inline]
+    #[cfg(any(feature = "radix", feature = "f128"))]
+    pub fn compare(&self, y: &Self) -> core::cmp::Ordering {
+        self.length.cmp(&y.length).then_with(|| {
+            let x = self.as_slice().iter().rev();
+            let y = y.as_slice().iter().rev();
+            x.cmp(y)
+        })
+    }
+}
+
+impl<const SIZE: usize> Default for Bigint<SIZE> {
+    #[inline(always)]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// LIMB
+// ----
+
+pub use lexical_util::limb::{Limb, Wide};
+
+/// The largest power of 5 that fits in a limb.
+#[cfg(all(target_pointer_width = "64", not(target_arch = "sparc")))]
+const LARGE_POW5: Limb = 7450580596923828125;
+/// The exponent of the largest power of 5 that fits in a limb.
+#[cfg(all(target_pointer_width = "64", not(target_arch = "sparc")))]
+const LARGE_POW5_STEP: u32 = 27;
+
+/// The largest power of 5 that fits in a limb.
+#[cfg(not(all(target_pointer_width = "64", not(target_arch = "sparc"))))]
+const LARGE_POW5: Limb = 1220703125;
+/// The exponent of the largest power of 5 that fits in a limb.
+#[cfg(not(all(target_pointer_width = "64", not(target_arch = "sparc"))))]
+const LARGE_POW5_STEP: u32 = 13;
@@ -26,6 +26,8 @@ use lexical_util::algorithm::{copy_to_dst, rtrim_char_count};
 #[cfg(feature = "f16")]
 use lexical_util::bf16::bf16;
 use lexical_util::digit::digit_to_char_const;
+#[cfg(feature = "f128")]
+use lexical_util::f128::f128;
 #[cfg(feature = "f16")]
 use lexical_util::f16::f16;
 use lexical_util::format::NumberFormat;
@@ -552,7 +554,7 @@ macro_rules! grisu_impl {
 
 grisu_impl! { f32 f64 }
 
-#[cfg(feature = "f16")]
+#[cfg(any(feature = "f16", feature = "f128"))]
 macro_rules! grisu_unimpl {
     ($($t:ident)*) => ($(
         impl GrisuFloat for $t {
@@ -566,3 +568,6 @@ macro_rules! grisu_unimpl {
 
 #[cfg(feature = "f16")]
 grisu_unimpl! { bf16 f16 }
+
+#[cfg(feature = "f128")]
+grisu_unimpl! { f128 }
@@ -0,0 +1,489 @@
+//! Exact float-to-string algorithm for decimal strings.
+//!
+//! Rather than writing the shortest digits that round-trip, this writes
+//! digits from the exact decimal expansion of the binary value. Every
+//! binary float has a finite decimal representation: a float with
+//! mantissa `m` and binary exponent `e` is exactly `m * 2^e`, which for
+//! negative exponents is `m * 5^-e * 10^e`. This generates those digits
+//! using big-integer arithmetic, and then rounds them correctly to the
+//! number of significant digits requested, so `0.1` with 34 significant
+//! digits is `1.000000000000000055511151231257827e-1`.
+//!
+//! This is much slower than the shortest algorithms, and is only used
+//! when [`exact_digits`] is enabled.
+//!
+//! [`exact_digits`]: crate::OptionsBuilder::exact_digits
+
+#![doc(hidden)]
+
+use lexical_util::algorithm::{copy_to_dst, ltrim_char_count, rtrim_char_count};
+#[cfg(feature = "f128")]
+use lexical_util::f128::f128;
+use lexical_util::format::NumberFormat;
+use lexical_util::num::{AsPrimitive, Float};
+
+#[cfg(feature = "f128")]
+use crate::bigint::QUAD_EXACT_LIMBS;
+use crate::bigint::{Bigint, Limb};
+use crate::options::{Options, RoundMode};
+use crate::shared;
+
+/// The maximum number of significant digits in the exact representation
+/// of any supported float.
+///
+/// This is for the largest subnormal `f64`, since `2^53 * 5^1074` has 767
+/// digits, of which at most 767 are significant.
+pub const MAX_EXACT_DIGITS: usize = 767;
+
+/// The maximum number of integer digits of any supported float.
+///
+/// This is for `f64::MAX`, which is `1.7976931348623157e308`.
+pub const MAX_INTEGER_DIGITS: usize = 309;
+
+/// The maximum number of significant digits in the exact representation
+/// of any [`f128`].
+///
+/// This is for the largest subnormal `f128`, since `2^113 * 5^16494` has
+/// 11563 digits.
+///
+/// [`f128`]: lexical_util::f128::f128
+pub const QUAD_MAX_EXACT_DIGITS: usize = 11563;
+
+/// The maximum number of integer digits of any [`f128`].
+///
+/// This is for the largest `f128`, which is `1.189731495357231765e4932`.
+///
+/// [`f128`]: lexical_util::f128::f128
+pub const QUAD_MAX_INTEGER_DIGITS: usize = 4933;
+
+/// Number of digits generated per big-integer division.
+#[cfg(all(target_pointer_width = "64", not(target_arch = "sparc")))]
+const CHUNK_DIGITS: usize = 19;
+/// The largest power of 10 that fits in a limb.
+#[cfg(all(target_pointer_width = "64", not(target_arch = "sparc")))]
+const CHUNK_POWER: Limb = 10_000_000_000_000_000_000;
+
+/// Number of digits generated per big-integer division.
+#[cfg(not(all(target_pointer_width = "64", not(target_arch = "sparc"))))]
+const CHUNK_DIGITS: usize = 9;
+/// The largest power of 10 that fits in a limb.
+#[cfg(not(all(target_pointer_width = "64", not(target_arch = "sparc"))))]
+const CHUNK_POWER: Limb = 1_000_000_000;
+
+/// Size of the temporary buffer for the exact digits.
+///
+/// We write full chunks from the end of the buffer, so we need room
+/// for leading zeros in the most-significant chunk.
+pub const DIGIT_BUFFER_SIZE: usize = MAX_EXACT_DIGITS + CHUNK_DIGITS;
+
+/// Size of the temporary buffer for the exact digits of an [`f128`].
+///
+/// [`f128`]: lexical_util::f128::f128
+#[cfg(feature = "f128")]
+pub const QUAD_DIGIT_BUFFER_SIZE: usize = QUAD_MAX_EXACT_DIGITS + CHUNK_DIGITS;
+
+/// Exact float-to-string algorithm for decimal strings.
+///
+/// This assumes the float is:
+///     1). Non-special (NaN or Infinite).
+///     2). Non-negative.
+pub fn write_float<F: Float, const FORMAT: u128>(
+    float: F,
+    bytes: &mut [u8],
+    options: &Options,
+) -> usize {
+    debug_assert!(!float.is_special());
+    debug_assert!(float >= F::ZERO);
+
+    let mut buffer = [b'0'; DIGIT_BUFFER_SIZE];
+    let (start, digit_count, sci_exp) = exact_digits(float, &mut buffer);
+    write_digits::<_, FORMAT>(
+        float,
+        &mut buffer[start..start + digit_count],
+        sci_exp,
+        bytes,
+        options,
+    )
+}
+
+/// Exact float-to-string algorithm for quadruple-precision floats.
+///
+/// This assumes the float is:
+///     1). Non-special (NaN or Infinite).
+///     2). Non-negative.
+#[cfg(feature = "f128")]
+pub fn write_quad<const FORMAT: u128>(float: f128, bytes: &mut [u8], options: &Options) -> usize {
+    debug_assert!(!float.is_special());
+    debug_assert!(float.is_sign_positive());
+
+    let mut buffer = [b'0'; QUAD_DIGIT_BUFFER_SIZE];
+    let (start, digit_count, sci_exp) = quad_exact_digits(float, &mut buffer);
+    write_digits::<_, FORMAT>(
+        float,
+        &mut buffer[start..start + digit_count],
+        sci_exp,
+        bytes,
+        options,
+    )
+}
+
+/// Round and write the exact significant digits of the float.
+#[inline(always)]
+fn write_digits<F: Float, const FORMAT: u128>(
+    float: F,
+    digits: &mut [u8],
+    sci_exp: i32,
+    bytes: &mut [u8],
+    options: &Options,
+) -> usize {
+    let digit_count = digits.len();
+
+    // Round the exact digits: since no digits have been discarded, the
+    // truncated digits always resolve halfway cases correctly.
+    let (digit_count, carried) = shared::truncate_and_round_decimal(digits, digit_count, options);
+    let sci_exp = sci_exp + carried as i32;
+
+    // Trim trailing zeros, always keeping at least 1 digit.
+    let zeros = rtrim_char_count(&digits[1..digit_count], b'0');
+    let digits = &digits[..digit_count - zeros];
+
+    write_float!(
+        float,
+        FORMAT,
+        sci_exp,
+        options,
+        write_float_scientific,
+        write_float_positive_exponent,
+        write_float_negative_exponent,
+        bytes => bytes,
+        args => digits, sci_exp, options,
+    )
+}
+
+/// Exact float-to-string algorithm for decimal strings in fixed-point notation.
+///
+/// This always writes [`fraction_digits`] digits after the decimal point,
+/// and never uses scientific notation.
+///
+/// This assumes the float is:
+///     1). Non-special (NaN or Infinite).
+///     2). Non-negative.
+///
+/// [`fraction_digits`]: crate::Options::fraction_digits
+pub fn write_float_fixed<F: Float, const FORMAT: u128>(
+    float: F,
+    bytes: &mut [u8],
+    options: &Options,
+) -> usize {
+    debug_assert!(!float.is_special());
+    debug_assert!(float >= F::ZERO);
+
+    let mut buffer = [b'0'; DIGIT_BUFFER_SIZE];
+    let (start, digit_count, sci_exp) = exact_digits(float, &mut buffer);
+    write_fixed_digits(&mut buffer[start..start + digit_count], sci_exp, bytes, options)
+}
+
+/// Exact float-to-string algorithm for quadruple-precision floats in
+/// fixed-point notation.
+///
+/// This assumes the float is:
+///     1). Non-special (NaN or Infinite).
+///     2). Non-negative.
+#[cfg(feature = "f128")]
+pub fn write_quad_fixed<const FORMAT: u128>(
+    float: f128,
+    bytes: &mut [u8],
+    options: &Options,
+) -> usize {
+    debug_assert!(!float.is_special());
+    debug_assert!(float.is_sign_positive());
+
+    let mut buffer = [b'0'; QUAD_DIGIT_BUFFER_SIZE];
+    let (start, digit_count, sci_exp) = quad_exact_digits(float, &mut buffer);
+    write_fixed_digits(&mut buffer[start..start + digit_count], sci_exp, bytes, options)
+}
+
+/// Round and write the exact significant digits of the float in
+/// fixed-point notation.
+#[inline(always)]
+fn write_fixed_digits(
+    digits: &mut [u8],
+    sci_exp: i32,
+    bytes: &mut [u8],
+    options: &Options,
+) -> usize {
+    let fraction_digits = options.fraction_digits().unwrap_or(0);
+    let digit_count = digits.len();
+
+    // Round to the last fraction digit. If the last fraction digit is
+    // before our first significant digit, the value is either rounded
+    // down to 0 or up to the last fraction digit, so handle it manually.
+    let max_digits = sci_exp as isize + 1 + fraction_digits as isize;
+    let (digit_count, sci_exp) = if max_digits > 0 {
+        let (count, carried) =
+            shared::round_decimal(digits, digit_count, max_digits as usize, options.round_mode());
+        (count, sci_exp + carried as i32)
+    } else if max_digits == 0 && is_above_halfway(digits, options) {
+        digits[0] = b'1';
+        (1, sci_exp + 1)
+    } else {
+        (0, 0)
+    };
+    let digits = &digits[..digit_count];
+
+    // Write the integer digits, using the significant digits when available.
+    let mut cursor: usize;
+    if digit_count == 0 || sci_exp < 0 {
+        bytes[0] = b'0';
+        cursor = 1;
+    } else {
+        let integer_count = sci_exp as usize + 1;
+        let count = integer_count.min(digit_count);
+        copy_to_dst(bytes, &digits[..count]);
+        bytes[count..integer_count].fill(b'0');
+        cursor = integer_count;
+    }
+
+    // Write the fraction digits, where the first fraction digit is
+    // at index `sci_exp + 1` in our digits.
+    if fraction_digits != 0 {
+        bytes[cursor] = options.decimal_point();
+        cursor += 1;
+        let fraction = &mut bytes[cursor..cursor + fraction_digits];
+        fraction.fill(b'0');
+        if digit_count != 0 {
+            let first = sci_exp as isize + 1;
+            for (index, &digit) in digits.iter().enumerate() {
+                let position = index as isize - first;
+                if position >= 0 {
+                    fraction[position as usize] = digit;
+                }
+            }
+        }
+        cursor += fraction_digits;
+    }
+
+    cursor
+}
+
+/// Determine if the digits, all of which are being rounded, are above halfway.
+///
+/// This is the case where we are rounding to the digit before our first
+/// significant digit, so we round up to a single digit if we're above
+/// halfway, or at halfway and that digit is odd, which is never the case
+/// since it's a leading `0`.
+#[inline(always)]
+fn is_above_halfway(digits: &[u8], options: &Options) -> bool {
+    if options.round_mode() == RoundMode::Truncate {
+        false
+    } else if digits[0] != b'5' {
+        digits[0] > b'5'
+    } else {
+        digits[1..].iter().any(|&x| x != b'0')
+    }
+}
+
+/// Generate the exact significant digits of the float.
+///
+/// The digits are written to the end of `buffer`, and this returns the
+/// index of the first significant digit, the number of significant digits
+/// without any trailing zeros, and the scientific exponent of the first
+/// digit. Zero is written as a
+/// single `0` digit with an exponent of `0`.
+#[inline]
+pub fn exact_digits<F: Float>(float: F, buffer: &mut [u8]) -> (usize, usize, i32) {
+    debug_assert!(buffer.len() >= DIGIT_BUFFER_SIZE);
+
+    let mantissa: u64 = float.mantissa().as_u64();
+    let bigint: Bigint = Bigint::from_u64(mantissa);
+    bigint_digits(bigint, float.exponent(), buffer)
+}
+
+/// Generate the exact significant digits of a quadruple-precision float.
+///
+/// This is identical to [`exact_digits`], except for the larger exponents
+/// and mantissa of an [`f128`].
+///
+/// [`f128`]: lexical_util::f128::f128
+#[inline]
+#[cfg(feature = "f128")]
+pub fn quad_exact_digits(float: f128, buffer: &mut [u8]) -> (usize, usize, i32) {
+    debug_assert!(buffer.len() >= QUAD_DIGIT_BUFFER_SIZE);
+
+    let bigint: Bigint<QUAD_EXACT_LIMBS> = Bigint::from_u128(float.mantissa());
+    bigint_digits(bigint, float.exponent(), buffer)
+}
+
+/// Generate the exact significant digits of the float from its mantissa,
+/// as a big integer, and its binary exponent.
+#[inline(always)]
+fn bigint_digits<const SIZE: usize>(
+    mut bigint: Bigint<SIZE>,
+    exp: i32,
+    buffer: &mut [u8],
+) -> (usize, usize, i32) {
+    if bigint.is_zero() {
+        let start = buffer.len() - 1;
+        buffer[start] = b'0';
+        return (start, 1, 0);
+    }
+
+    // Scale our float to an exact integer: `m * 2^e` if `e >= 0`,
+    // otherwise `m * 5^-e`, with a decimal exponent of `e`.
+    let decimal_exp = if exp >= 0 {
+        bigint.shl(exp as usize);
+        0
+    } else {
+        bigint.pow5(exp.unsigned_abs());
+        exp
+    };
+
+    // Write the digits, from least to most significant, in chunks.
+    let mut end = buffer.len();
+    while !bigint.is_zero() {
+        let mut chunk = bigint.div_small(CHUNK_POWER);
+        for index in (end - CHUNK_DIGITS..end).rev() {
+            buffer[index] = b'0' + (chunk % 10) as u8;
+            chunk /= 10;
+        }
+        end -= CHUNK_DIGITS;
+    }
+
+    // Trim the leading zeros from the most-significant chunk, and any
+    // trailing zeros, which are not significant.
+    let start = end + ltrim_char_count(&buffer[end..], b'0');
+    let sci_exp = decimal_exp + (buffer.len() - start) as i32 - 1;
+    let digit_count = buffer.len() - start - rtrim_char_count(&buffer[start..], b'0');
+
+    (start, digit_count, sci_exp)
+}
+
+/// Write float to string in scientific notation.
+///
+/// The digits must be rounded and have no trailing zeros.
+#[inline]
+pub fn write_float_scientific<const FORMAT: u128>(
+    bytes: &mut [u8],
+    digits: &[u8],
+    sci_exp: i32,
+    options: &Options,
+) -> usize {
+    let format = NumberFormat::<{ FORMAT }> {};
+    assert!(format.is_valid());
+    let decimal_point = options.decimal_point();
+    let digit_count = digits.len();
+
+    // Determine the exact number of digits to write.
+    let exact_count = shared::min_exact_digits(digit_count, options);
+
+    // Write the significant digits, with the decimal point after the first.
+    let mut cursor: usize;
+    bytes[0] = digits[0];
+    bytes[1] = decimal_point;
+    copy_to_dst(&mut bytes[2..], &digits[1..]);
+    if !format.no_exponent_without_fraction() && digit_count == 1 && options.trim_floats() {
+        cursor = 1;
+    } else if digit_count < exact_count {
+        // Adjust the number of digits written, by appending zeros.
+        cursor = digit_count + 1;
+        let zeros = exact_count - digit_count;
+        bytes[cursor..cursor + zeros].fill(b'0');
+        cursor += zeros;
+    } else if digit_count == 1 {
+        bytes[2] = b'0';
+        cursor = 3;
+    } else {
+        cursor = digit_count + 1;
+    }
+
+    // Now, write our scientific notation.
+    shared::write_exponent::<FORMAT>(bytes, &mut cursor, sci_exp, options.exponent());
+
+    cursor
+}
+
+/// Write negative float to string without scientific notation.
+///
+/// Has a negative exponent (shift right) and no scientific notation.
+/// The digits must be rounded and have no trailing zeros.
+#[inline]
+pub fn write_float_negative_exponent<const FORMAT: u128>(
+    bytes: &mut [u8],
+    digits: &[u8],
+    sci_exp: i32,
+    options: &Options,
+) -> usize {
+    debug_assert!(sci_exp < 0);
+
+    // Write our leading `0.000` digits.
+    let decimal_point = options.decimal_point();
+    let mut cursor = sci_exp.wrapping_neg() as usize + 1;
+    bytes[..cursor].fill(b'0');
+    bytes[1] = decimal_point;
+
+    // Write out our significant digits.
+    let digit_count = digits.len();
+    cursor += copy_to_dst(&mut bytes[cursor..], digits);
+
+    // Write any trailing zeros.
+    let exact_count = shared::min_exact_digits(digit_count, options);
+    if digit_count < exact_count {
+        let zeros = exact_count - digit_count;
+        bytes[cursor..cursor + zeros].fill(b'0');
+        cursor += zeros;
+    }
+
+    cursor
+}
+
+/// Write positive float to string without scientific notation.
+///
+/// Has a positive exponent (shift left) and no scientific notation.
+/// The digits must be rounded and have no trailing zeros.
+#[inline]
+pub fn write_float_positive_exponent<const FORMAT: u128>(
+    bytes: &mut [u8],
+    digits: &[u8],
+    sci_exp: i32,
+    options: &Options,
+) -> usize {
+    debug_assert!(sci_exp >= 0);
+
+    let decimal_point = options.decimal_point();
+    let leading_digits = sci_exp as usize + 1;
+    let mut digit_count = digits.len();
+    let mut cursor: usize;
+    let mut trimmed = false;
+    if leading_digits >= digit_count {
+        // All our digits are in the integer component: write trailing
+        // zeros and an optional decimal point.
+        copy_to_dst(bytes, digits);
+        bytes[digit_count..leading_digits].fill(b'0');
+        cursor = leading_digits;
+        digit_count = leading_digits;
+        if !options.trim_floats() {
+            bytes[cursor] = decimal_point;
+            bytes[cursor + 1] = b'0';
+            cursor += 2;
+            digit_count += 1;
+        } else {
+            trimmed = true;
+        }
+    } else {
+        // Write the integer digits, the decimal point, and then the fraction.
+        copy_to_dst(bytes, &digits[..leading_digits]);
+        bytes[leading_digits] = decimal_point;
+        cursor = leading_digits + 1;
+        cursor += copy_to_dst(&mut bytes[cursor..], &digits[leading_digits..]);
+    }
+
+    // Write any trailing zeros.
+    let exact_count = shared::min_exact_digits(digit_count, options);
+    if !trimmed && exact_count > digit_count {
+        let zeros = exact_count - digit_count;
+        bytes[cursor..cursor + zeros].fill(b'0');
+        cursor += zeros;
+    }
+
+    cursor
+}
@@ -7,6 +7,8 @@
 #[cfg(feature = "f16")]
 use lexical_util::bf16::bf16;
 use lexical_util::extended_float::ExtendedFloat;
+#[cfg(feature = "f128")]
+use lexical_util::f128::f128;
 #[cfg(feature = "f16")]
 use lexical_util::f16::f16;
 
@@ -37,3 +39,6 @@ impl RawFloat for f16 {
 #[cfg(feature = "f16")]
 impl RawFloat for bf16 {
 }
+#[cfg(feature = "f128")]
+impl RawFloat for f128 {
+}
@@ -19,6 +19,7 @@
 
 use lexical_util::algorithm::rtrim_char_count;
 use lexical_util::constants::{FormattedSize, BUFFER_SIZE};
+use lexical_util::digit::digit_to_char_const;
 use lexical_util::format::NumberFormat;
 use lexical_util::num::{Float, Integer};
 use lexical_write_integer::write::WriteInteger;
@@ -71,6 +72,11 @@ where
         (4, 2) | (8, 2) | (16, 2) | (32, 2) | (16, 4)
     ));
 
+    // Write like C's `%a`, which always uses the `f64` representation.
+    if options.normalize_hex() {
+        return write_float_normalized::<FORMAT>(float.as_f64(), bytes, options);
+    }
+
     // Quickly calculate the number of bits we would have written.
     // This simulates writing the digits, so we can calculate the
     // scientific exponent. Since this number is often constant
@@ -201,6 +207,101 @@ where
     cursor
 }
 
+/// Write float to string with a normalized leading digit, like C's `%a`.
+///
+/// The leading digit is `1` for normal floats, and `0` for zero and
+/// denormal floats, which use the exponent of the smallest normal float.
+/// The fraction is written with the shortest digits that represent the
+/// float exactly, or rounded to the number of [`fraction_digits`], with
+/// ties to even. Like glibc, rounding can carry into the leading digit,
+/// so `1.f8` rounded to 1 fraction digit is written as `2.0`, not `1.0`
+/// with a larger exponent.
+///
+/// The exponent is always written, in powers of 2.
+///
+/// # Panics
+///
+/// Panics if the exponent base is not 2.
+///
+/// [`fraction_digits`]: crate::Options::fraction_digits
+pub fn write_float_normalized<const FORMAT: u128>(
+    float: f64,
+    bytes: &mut [u8],
+    options: &Options,
+) -> usize {
+    let format = NumberFormat::<{ FORMAT }> {};
+    assert!(format.exponent_base() == 2, "normalized hex floats require a binary exponent");
+    debug_assert!(!float.is_special());
+    debug_assert!(float >= 0.0);
+
+    // Align the fraction bits to a whole number of digits.
+    let bits_per_digit = fast_log2(format.mantissa_radix()) as usize;
+    let digit_mask = (1u64 << bits_per_digit) - 1;
+    let max_digits = fast_ceildiv(f64::MANTISSA_SIZE, bits_per_digit as i32) as usize;
+    let shift = max_digits * bits_per_digit - f64::MANTISSA_SIZE as usize;
+    let bits = float.to_bits();
+    let mut fraction = (bits & f64::MANTISSA_MASK) << shift;
+    let biased_exp = ((bits & f64::EXPONENT_MASK) >> f64::MANTISSA_SIZE) as i32;
+    let (mut leading, exp) = match (biased_exp, fraction) {
+        (0, 0) => (0u64, 0),
+        (0, _) => (0u64, 1 - f64::EXPONENT_BIAS + f64::MANTISSA_SIZE),
+        _ => (1u64, biased_exp - f64::EXPONENT_BIAS + f64::MANTISSA_SIZE),
+    };
+
+    // Round or trim the fraction digits.
+    let mut digits = max_digits;
+    match options.fraction_digits() {
+        Some(count) if count < max_digits => {
+            let truncated_bits = (max_digits - count) * bits_per_digit;
+            let truncated = fraction & ((1u64 << truncated_bits) - 1);
+            let halfway = 1u64 << (truncated_bits - 1);
+            fraction >>= truncated_bits;
+            let last_digit = if count == 0 {
+                leading
+            } else {
+                fraction
+            };
+            if truncated > halfway || (truncated == halfway && last_digit & 1 == 1) {
+                // Carry into the leading digit if we overflow the fraction.
+                fraction += 1;
+                if fraction >> (count * bits_per_digit) != 0 {
+                    fraction = 0;
+                    leading += 1;
+                }
+            }
+            digits = count;
+        },
+        Some(_) => (),
+        None => {
+            while digits > 0 && fraction & digit_mask == 0 {
+                fraction >>= bits_per_digit;
+                digits -= 1;
+            }
+        },
+    }
+
+    // Write the leading digit, and the fraction with any trailing zeros.
+    let radix = format.mantissa_radix();
+    bytes[0] = digit_to_char_const(leading as u32, radix);
+    let mut cursor = 1;
+    let fraction_digits = options.fraction_digits().unwrap_or(digits);
+    if fraction_digits != 0 {
+        bytes[cursor] = options.decimal_point();
+        cursor += 1;
+        for index in (0..digits).rev() {
+            let digit = (fraction >> (index * bits_per_digit)) & digit_mask;
+            bytes[cursor] = digit_to_char_const(digit as u32, radix);
+            cursor += 1;
+        }
+        let digits_end = cursor + fraction_digits - digits;
+        bytes[cursor..digits_end].fill(b'0');
+        cursor = digits_end;
+    }
+
+    shared::write_exponent::<FORMAT>(bytes, &mut cursor, exp, options.exponent());
+    cursor
+}
+
 // ALGORITHM
 // ---------
 
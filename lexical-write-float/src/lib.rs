@@ -86,11 +86,14 @@
 //! * `compact` - Reduce code size at the cost of performance.
 //! * `f16` - Enable support for half-precision [`f16`][`ieee-f16`] and
 //!   [`bf16`][`brain-float`] floats.
+//! * `f128` - Enable support for quadruple-precision [`f128`][`ieee-f128`]
+//!   floats.
 //! * `std` (Default) - Disable to allow use in a [`no_std`] environment.
 //!
 //! [`no_std`]: https://docs.rust-embedded.org/book/intro/no-std.html
 //! [`ieee-f16`]: https://en.wikipedia.org/wiki/Half-precision_floating-point_format
 //! [`brain-float`]: https://en.wikipedia.org/wiki/Bfloat16_floating-point_format
+//! [`ieee-f128`]: https://en.wikipedia.org/wiki/Quadruple-precision_floating-point_format
 //!
 //! A complete description of supported features includes:
 //!
@@ -244,6 +247,31 @@
 //! # }
 //! ```
 //!
+//! #### f128
+//!
+//! This enables the use of the quadruple-precision float
+//! [`f128`][`ieee-f128`], using a software implementation. The shortest
+//! digits that round-trip are generated with arbitrary-precision
+//! arithmetic, so writing is much slower than for native floats. The
+//! mantissa radix must equal the exponent base. The [`exact_digits`]
+//! option writes up to 11563 significant digits, and [`fraction_digits`]
+//! up to 4933 integer digits, so both require large buffers.
+//!
+//! ```rust
+//! # #[cfg(feature = "f128")] {
+//! # use core::str;
+//! use lexical_write_float::{f128, FormattedSize, ToLexical};
+//!
+//! let mut buffer = [0u8; f128::FORMATTED_SIZE_DECIMAL];
+//! let value = f128::from_bits(0x3FFB999999999999999999999999999A);
+//! let digits = value.to_lexical(&mut buffer);
+//! assert_eq!(str::from_utf8(digits), Ok("0.1"));
+//! # }
+//! ```
+//!
+//! [`exact_digits`]: crate::OptionsBuilder::exact_digits
+//! [`fraction_digits`]: crate::OptionsBuilder::fraction_digits
+//!
 //! #### std
 //!
 //! Enable use of the standard library. Currently, the standard library
@@ -332,6 +360,10 @@
 //!   to write.
 //! - The rounding [`mode`][Options::round_mode] when truncating significant
 //!   digits while writing.
+//! - Whether to write the [`exact`][Options::exact_digits] decimal
+//!   representation of the float, rather than the shortest digits.
+//! - The number of [`fraction digits`][Options::fraction_digits] to write in
+//!   fixed-point notation.
 //!
 //! In addition, pre-defined constants for each category of options may
 //! be found in their respective modules, for example, [`JSON`][`JSON-OPTS`].
@@ -408,14 +440,15 @@
 //!
 //! # Algorithms
 //!
-//! There's currently 5 algorithms used, depending on the requirements.
+//! There's currently 6 algorithms used, depending on the requirements.
 //!
 //! 1. Compact for decimal strings uses the Grisu algorithm.
 //! 2. An optimized algorithm based on the Dragonbox algorithm.
 //! 3. An optimized algorithm for formatting to string with power-of-two
 //!    radixes.
 //! 4. An optimized algorithm for hexadecimal floats.
-//! 5. A fallback algorithm for all other radixes.
+//! 5. A big-integer algorithm for the shortest digits in all other radixes.
+//! 6. A big-integer algorithm for the exact digits of decimal strings.
 //!
 //! The Grisu algorithm is based on "Printing Floating-Point Numbers Quickly
 //! and Accurately with Integers", by Florian Loitsch, available online
@@ -424,8 +457,9 @@
 //! hosted [here](https://github.com/jk-jeon/dragonbox/), and the algorithm
 //! is described in depth
 //! [here](https://github.com/jk-jeon/dragonbox/blob/master/other_files/Dragonbox.pdf).
-//! The radix algorithm is adapted from the V8 codebase, and may be found
-//! [here](https://github.com/v8/v8).
+//! The radix algorithm is the free-format algorithm from "Printing
+//! Floating-Point Numbers Quickly and Accurately", by Robert G. Burger and
+//! R. Kent Dybvig.
 //!
 //! # Design
 //!
@@ -473,12 +507,16 @@ mod index;
 mod shared;
 
 pub mod algorithm;
+pub mod bigint;
 pub mod binary;
 pub mod compact;
+pub mod exact;
 pub mod float;
 pub mod hex;
 pub mod options;
+pub mod quad;
 pub mod radix;
+pub mod shortest;
 pub mod table;
 pub mod write;
 
@@ -491,6 +529,8 @@ mod table_grisu;
 pub use lexical_util::bf16::bf16;
 pub use lexical_util::constants::{FormattedSize, BUFFER_SIZE};
 pub use lexical_util::error::Error;
+#[cfg(feature = "f128")]
+pub use lexical_util::f128::f128;
 #[cfg(feature = "f16")]
 pub use lexical_util::f16::f16;
 pub use lexical_util::format::{self, NumberFormat, NumberFormatBuilder};
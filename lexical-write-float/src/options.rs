@@ -39,6 +39,8 @@
 //! - [`DECIMAL_COMMA`]: Numerical format with a decimal comma.
 //! - [`HEX_FLOAT`]: Numerical format for hexadecimal floats, which use a `p`
 //!   exponent.
+//! - [`C_HEX_PRINTF`]: Numerical format for hexadecimal floats written like C's
+//!   `printf("%a")`.
 //! - [`CARAT_EXPONENT`]: Numerical format where `^` is used as the exponent
 //!   notation character.
 //! - [`RUST_LITERAL`]: Number format for a [`Rust`] literal floating-point
@@ -202,10 +204,18 @@ use core::num;
 use lexical_util::ascii::{is_valid_ascii, is_valid_letter_slice};
 use lexical_util::constants::FormattedSize;
 use lexical_util::error::Error;
-use lexical_util::format::NumberFormat;
+use lexical_util::format::{NumberFormat, OptionU8};
+use lexical_util::grouping::{group_sizes, is_valid_group_separator, separator_count};
 use lexical_util::options::{self, WriteOptions};
 use lexical_util::result::Result;
 
+use crate::exact::{
+    MAX_EXACT_DIGITS,
+    MAX_INTEGER_DIGITS,
+    QUAD_MAX_EXACT_DIGITS,
+    QUAD_MAX_INTEGER_DIGITS,
+};
+
 // NOTE: Rust guarantees the sizes are the same:
 //  https://doc.rust-lang.org/std/num/struct.NonZero.html
 
@@ -261,6 +271,12 @@ pub enum RoundMode {
 /// Maximum length for a special string.
 pub const MAX_SPECIAL_STRING_LENGTH: usize = 50;
 
+/// Maximum number of fraction digits.
+///
+/// This is larger than the number of fraction digits of the exact
+/// representation of any float, including quadruple-precision floats.
+pub const MAX_FRACTION_DIGITS: usize = 0x8000;
+
 /// Builder for [`Options`].
 ///
 /// This enables extensive control over how the float is written, from
@@ -322,6 +338,17 @@ pub struct OptionsBuilder {
     /// Rounding mode for writing digits with precision control.
     round_mode: RoundMode,
 
+    /// Write digits from the exact decimal representation of the float.
+    ///
+    /// This is only used for decimal strings.
+    exact_digits: bool,
+
+    /// Number of digits to write after the decimal point in fixed-point
+    /// notation.
+    ///
+    /// This is only used for decimal strings.
+    fraction_digits: Option<usize>,
+
     /// Trim the trailing ".0" from integral float strings.
     ///
     /// If used in conjunction with [`min_significant_digits`],
@@ -331,12 +358,27 @@ pub struct OptionsBuilder {
     /// [`min_significant_digits`]: Self::min_significant_digits
     trim_floats: bool,
 
+    /// Write lowercase digits for radixes above 10.
+    lowercase_digits: bool,
+
+    /// Write hexadecimal floats with a normalized leading digit.
+    normalize_hex: bool,
+
     /// Character to designate the exponent component of a float.
     exponent: u8,
 
     /// Character to separate the integer from the fraction components.
     decimal_point: u8,
 
+    /// Character to separate groups of integer digits.
+    group_separator: OptionU8,
+
+    /// Number of digits in the least significant group.
+    group_size: OptionU8,
+
+    /// Number of digits in the remaining groups.
+    secondary_group_size: OptionU8,
+
     /// String representation of Not A Number, aka `NaN`.
     nan_string: Option<&'static [u8]>,
 
@@ -355,9 +397,16 @@ impl OptionsBuilder {
             positive_exponent_break: None,
             negative_exponent_break: None,
             round_mode: RoundMode::Round,
+            exact_digits: false,
+            fraction_digits: None,
             trim_floats: false,
+            lowercase_digits: false,
+            normalize_hex: false,
             exponent: b'e',
             decimal_point: b'.',
+            group_separator: None,
+            group_size: None,
+            secondary_group_size: None,
             nan_string: Some(b"NaN"),
             inf_string: Some(b"inf"),
         }
@@ -467,6 +516,55 @@ impl OptionsBuilder {
         self.round_mode
     }
 
+    /// Get if we should write digits from the exact decimal representation
+    /// of the float.
+    ///
+    /// By default, the shortest digits that round-trip to the same float
+    /// are written, and [`max_significant_digits`] rounds those digits.
+    /// If set, the digits are instead generated from the exact value of
+    /// the binary float, so `0.1` written with 25 significant digits is
+    /// `0.1000000000000000055511151`. This is only used for decimal
+    /// strings, and is significantly slower. Defaults to [`false`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lexical_write_float::Options;
+    ///
+    /// let builder = Options::builder();
+    /// assert_eq!(builder.get_exact_digits(), false);
+    /// ```
+    ///
+    /// [`max_significant_digits`]: Self::max_significant_digits
+    #[inline(always)]
+    pub const fn get_exact_digits(&self) -> bool {
+        self.exact_digits
+    }
+
+    /// Get the number of digits to write after the decimal point, using
+    /// fixed-point notation.
+    ///
+    /// If set, exactly this many fraction digits are always written and
+    /// scientific notation is never used, correctly rounded from the exact
+    /// value of the float using the [`round_mode`]. If set to `0`, no
+    /// decimal point is written. This is only used for decimal strings.
+    /// Defaults to [`None`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lexical_write_float::Options;
+    ///
+    /// let builder = Options::builder();
+    /// assert_eq!(builder.get_fraction_digits(), None);
+    /// ```
+    ///
+    /// [`round_mode`]: Self::round_mode
+    #[inline(always)]
+    pub const fn get_fraction_digits(&self) -> Option<usize> {
+        self.fraction_digits
+    }
+
     /// Get if we should trim a trailing `".0"` from integral floats.
     ///
     /// If used in conjunction with [`min_significant_digits`],
@@ -488,6 +586,36 @@ impl OptionsBuilder {
         self.trim_floats
     }
 
+    /// Get if lowercase digits are written for radixes above 10.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lexical_write_float::Options;
+    ///
+    /// let builder = Options::builder();
+    /// assert_eq!(builder.get_lowercase_digits(), false);
+    /// ```
+    #[inline(always)]
+    pub const fn get_lowercase_digits(&self) -> bool {
+        self.lowercase_digits
+    }
+
+    /// Get if hexadecimal floats are written with a normalized leading digit.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lexical_write_float::Options;
+    ///
+    /// let builder = Options::builder();
+    /// assert_eq!(builder.get_normalize_hex(), false);
+    /// ```
+    #[inline(always)]
+    pub const fn get_normalize_hex(&self) -> bool {
+        self.normalize_hex
+    }
+
     /// Get the character to designate the exponent component of a float.
     ///
     /// Any non-control character is valid, but `\t` to `\r` are also valid.
@@ -524,6 +652,51 @@ impl OptionsBuilder {
         self.decimal_point
     }
 
+    /// Get the character to separate groups of integer digits.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lexical_write_float::Options;
+    ///
+    /// let builder = Options::builder();
+    /// assert_eq!(builder.get_group_separator(), None);
+    /// ```
+    #[inline(always)]
+    pub const fn get_group_separator(&self) -> OptionU8 {
+        self.group_separator
+    }
+
+    /// Get the number of digits in the least significant group.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lexical_write_float::Options;
+    ///
+    /// let builder = Options::builder();
+    /// assert_eq!(builder.get_group_size(), None);
+    /// ```
+    #[inline(always)]
+    pub const fn get_group_size(&self) -> OptionU8 {
+        self.group_size
+    }
+
+    /// Get the number of digits in the remaining groups.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lexical_write_float::Options;
+    ///
+    /// let builder = Options::builder();
+    /// assert_eq!(builder.get_secondary_group_size(), None);
+    /// ```
+    #[inline(always)]
+    pub const fn get_secondary_group_size(&self) -> OptionU8 {
+        self.secondary_group_size
+    }
+
     /// Get the string representation for `NaN`.
     ///
     /// The first character must start with `N` or `n` and all characters must
@@ -760,6 +933,100 @@ impl OptionsBuilder {
         self
     }
 
+    /// Set if we should write digits from the exact decimal representation
+    /// of the float.
+    ///
+    /// By default, the shortest digits that round-trip to the same float
+    /// are written, and [`max_significant_digits`] rounds those digits.
+    /// If set, the digits are instead generated from the exact value of
+    /// the binary float, and then rounded using the [`round_mode`], similar
+    /// to `printf("%.40e")`. If no [`max_significant_digits`] is provided,
+    /// the full decimal expansion is written. [`min_significant_digits`]
+    /// pads the exact digits with trailing zeros. This is only used for
+    /// decimal strings, and is significantly slower. Defaults to [`false`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "format")] {
+    /// use core::{num, str};
+    ///
+    /// use lexical_write_float::{NumberFormatBuilder, Options, ToLexicalWithOptions};
+    ///
+    /// const FORMAT: u128 = NumberFormatBuilder::new()
+    ///     // always write floats in scientific notation
+    ///     .required_exponent_notation(true)
+    ///     .build_strict();
+    /// const EXACT: Options = Options::builder()
+    ///     // write the digits from the exact value of the float
+    ///     .exact_digits(true)
+    ///     // the number of significant digits to write
+    ///     .max_significant_digits(num::NonZeroUsize::new(34))
+    ///     .build_strict();
+    /// const SIZE: usize = EXACT.buffer_size_const::<f64, FORMAT>();
+    /// let mut buffer = [0u8; SIZE];
+    /// let digits = 0.1f64.to_lexical_with_options::<FORMAT>(&mut buffer, &EXACT);
+    /// assert_eq!(str::from_utf8(digits), Ok("1.000000000000000055511151231257827e-1"));
+    /// # }
+    /// ```
+    ///
+    /// [`max_significant_digits`]: Self::max_significant_digits
+    /// [`min_significant_digits`]: Self::min_significant_digits
+    /// [`round_mode`]: Self::round_mode
+    #[inline(always)]
+    pub const fn exact_digits(mut self, exact_digits: bool) -> Self {
+        self.exact_digits = exact_digits;
+        self
+    }
+
+    /// Set the number of digits to write after the decimal point, using
+    /// fixed-point notation.
+    ///
+    /// If set, exactly this many fraction digits are always written and
+    /// scientific notation is never used, similar to `printf("%.2f")`. The
+    /// digits are correctly rounded from the exact value of the float using
+    /// the [`round_mode`], so `0.125` with 2 fraction digits is `0.12`, while
+    /// `0.135` is `0.14`, since the nearest float is above the halfway point.
+    /// If set to `0`, no decimal point is written. The significant digit and
+    /// exponent break options are ignored, and this is only used for decimal
+    /// strings, or hexadecimal floats with [`normalize_hex`], which are
+    /// rounded like `printf("%.2a")`. The value must not be larger than
+    /// [`MAX_FRACTION_DIGITS`]. Defaults to [`None`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use core::str;
+    ///
+    /// use lexical_write_float::{Options, ToLexicalWithOptions};
+    /// use lexical_write_float::format::STANDARD;
+    ///
+    /// const FIXED: Options = Options::builder()
+    ///     // always write 2 digits after the decimal point
+    ///     .fraction_digits(Some(2))
+    ///     .build_strict();
+    /// const SIZE: usize = FIXED.buffer_size_const::<f64, STANDARD>();
+    /// let mut buffer = [0u8; SIZE];
+    ///
+    /// let digits = 1234.5f64.to_lexical_with_options::<STANDARD>(&mut buffer, &FIXED);
+    /// assert_eq!(str::from_utf8(digits), Ok("1234.50"));
+    ///
+    /// let digits = 1.005f64.to_lexical_with_options::<STANDARD>(&mut buffer, &FIXED);
+    /// assert_eq!(str::from_utf8(digits), Ok("1.00"));
+    ///
+    /// let digits = 1e15f64.to_lexical_with_options::<STANDARD>(&mut buffer, &FIXED);
+    /// assert_eq!(str::from_utf8(digits), Ok("1000000000000000.00"));
+    /// ```
+    ///
+    /// [`round_mode`]: Self::round_mode
+    /// [`normalize_hex`]: Self::normalize_hex
+    /// [`MAX_FRACTION_DIGITS`]: crate::options::MAX_FRACTION_DIGITS
+    #[inline(always)]
+    pub const fn fraction_digits(mut self, fraction_digits: Option<usize>) -> Self {
+        self.fraction_digits = fraction_digits;
+        self
+    }
+
     /// Set if we should trim a trailing `".0"` from integral floats.
     ///
     /// If used in conjunction with [`min_significant_digits`],
@@ -783,6 +1050,63 @@ impl OptionsBuilder {
         self
     }
 
+    /// Set if lowercase digits are written for radixes above 10.
+    ///
+    /// Digits with a value of 10 or more are written as `A-Z` by default,
+    /// such as `1.8P+1` for hexadecimal floats. If set, they are written
+    /// as `a-z` instead. This only affects the significant digits and
+    /// exponent digits: the [`exponent`] character, and the base prefix
+    /// and suffix, are written as provided. For example, C's `%a` uses
+    /// lowercase digits with `p`, and `%A` uses uppercase digits with
+    /// `P`. Defaults to [`false`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lexical_write_float::Options;
+    ///
+    /// let builder = Options::builder()
+    ///     .lowercase_digits(true);
+    /// assert_eq!(builder.get_lowercase_digits(), true);
+    /// ```
+    ///
+    /// [`exponent`]: Self::exponent
+    #[inline(always)]
+    pub const fn lowercase_digits(mut self, lowercase_digits: bool) -> Self {
+        self.lowercase_digits = lowercase_digits;
+        self
+    }
+
+    /// Set if hexadecimal floats are written with a normalized leading digit.
+    ///
+    /// If set, floats where the mantissa radix differs from the binary
+    /// exponent base, such as hexadecimal floats, are written like C's
+    /// `%a`: a leading `1` for normal floats (`0` for zero and denormal
+    /// floats), the shortest fraction digits that represent the float
+    /// exactly, and an exponent that is always written. [`fraction_digits`]
+    /// rounds the fraction like `%.Na`, with ties to even. Like C, `f32`
+    /// values are written as if they were converted to `f64`, and the
+    /// exponent break and significant digit options are ignored. This
+    /// requires the `power-of-two` feature, and does not support `f128`.
+    /// Defaults to [`false`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lexical_write_float::Options;
+    ///
+    /// let builder = Options::builder()
+    ///     .normalize_hex(true);
+    /// assert_eq!(builder.get_normalize_hex(), true);
+    /// ```
+    ///
+    /// [`fraction_digits`]: Self::fraction_digits
+    #[inline(always)]
+    pub const fn normalize_hex(mut self, normalize_hex: bool) -> Self {
+        self.normalize_hex = normalize_hex;
+        self
+    }
+
     /// Set the character to designate the exponent component of a float.
     ///
     /// Any non-control character is valid, but `\t` to `\r` are also valid.
@@ -823,6 +1147,80 @@ impl OptionsBuilder {
         self
     }
 
+    /// Set the character to separate groups of integer digits.
+    ///
+    /// If set, the integer digits are grouped from the least significant
+    /// digit, such as `1,234,567.89`. The fraction and exponent digits are
+    /// never grouped. The separator must be a valid ASCII character that
+    /// is not a digit, a sign, the decimal point, or the exponent character.
+    /// Defaults to `None`, which does not group digits.
+    ///
+    /// To parse the written floats, use a number format with the same
+    /// digit separator and internal integer digit separators.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use core::num::NonZeroU8;
+    ///
+    /// use lexical_write_float::Options;
+    ///
+    /// let builder = Options::builder()
+    ///     .group_separator(NonZeroU8::new(b','));
+    /// assert_eq!(builder.get_group_separator(), NonZeroU8::new(b','));
+    /// ```
+    #[inline(always)]
+    pub const fn group_separator(mut self, group_separator: OptionU8) -> Self {
+        self.group_separator = group_separator;
+        self
+    }
+
+    /// Set the number of digits in the least significant group.
+    ///
+    /// Defaults to `None`, which uses groups of 3 digits.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use core::num::NonZeroU8;
+    ///
+    /// use lexical_write_float::Options;
+    ///
+    /// let builder = Options::builder()
+    ///     .group_size(NonZeroU8::new(4));
+    /// assert_eq!(builder.get_group_size(), NonZeroU8::new(4));
+    /// ```
+    #[inline(always)]
+    pub const fn group_size(mut self, group_size: OptionU8) -> Self {
+        self.group_size = group_size;
+        self
+    }
+
+    /// Set the number of digits in the remaining groups.
+    ///
+    /// For example, Indian grouping uses a group of 3 digits followed by
+    /// groups of 2 digits, such as `12,34,567.89`. Defaults to `None`,
+    /// which uses the [`group_size`] for all groups.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use core::num::NonZeroU8;
+    ///
+    /// use lexical_write_float::Options;
+    ///
+    /// let builder = Options::builder()
+    ///     .secondary_group_size(NonZeroU8::new(2));
+    /// assert_eq!(builder.get_secondary_group_size(), NonZeroU8::new(2));
+    /// ```
+    ///
+    /// [`group_size`]: Self::group_size
+    #[inline(always)]
+    pub const fn secondary_group_size(mut self, secondary_group_size: OptionU8) -> Self {
+        self.secondary_group_size = secondary_group_size;
+        self
+    }
+
     /// Set the string representation for `NaN`.
     ///
     /// The first character must start with `N` or `n` and all characters must
@@ -919,6 +1317,30 @@ impl OptionsBuilder {
 
     // BUILDERS
 
+    /// Determine if the group separator is valid.
+    #[inline(always)]
+    pub const fn group_separator_is_valid(&self) -> bool {
+        match self.group_separator {
+            Some(separator) => {
+                let c = separator.get();
+                is_valid_group_separator(self.group_separator)
+                    && c != self.decimal_point
+                    && c != self.exponent
+            },
+            None => true,
+        }
+    }
+
+    /// Determine if [`fraction_digits`][`Self::fraction_digits`] is valid.
+    #[doc(hidden)]
+    #[inline(always)]
+    pub const fn fraction_digits_is_valid(&self) -> bool {
+        match self.fraction_digits {
+            Some(fraction_digits) => fraction_digits <= MAX_FRACTION_DIGITS,
+            None => true,
+        }
+    }
+
     /// Determine if [`nan_string`][`Self::nan_string`] is valid.
     #[doc(hidden)]
     #[inline(always)]
@@ -971,6 +1393,10 @@ impl OptionsBuilder {
             false
         } else if !is_valid_ascii(self.decimal_point) {
             false
+        } else if !self.group_separator_is_valid() {
+            false
+        } else if !self.fraction_digits_is_valid() {
+            false
         } else if !self.nan_str_is_valid() {
             false
         } else if !self.inf_str_is_valid() {
@@ -1002,9 +1428,16 @@ impl OptionsBuilder {
             positive_exponent_break: self.positive_exponent_break,
             negative_exponent_break: self.negative_exponent_break,
             round_mode: self.round_mode,
+            exact_digits: self.exact_digits,
+            fraction_digits: self.fraction_digits,
             trim_floats: self.trim_floats,
+            lowercase_digits: self.lowercase_digits,
+            normalize_hex: self.normalize_hex,
             exponent: self.exponent,
             decimal_point: self.decimal_point,
+            group_separator: self.group_separator,
+            group_size: self.group_size,
+            secondary_group_size: self.secondary_group_size,
             nan_string: self.nan_string,
             inf_string: self.inf_string,
         }
@@ -1065,6 +1498,10 @@ impl OptionsBuilder {
             Err(Error::InvalidExponentSymbol)
         } else if !is_valid_ascii(self.decimal_point) {
             Err(Error::InvalidDecimalPoint)
+        } else if !self.group_separator_is_valid() {
+            Err(Error::InvalidGroupSeparator)
+        } else if !self.fraction_digits_is_valid() {
+            Err(Error::InvalidFractionDigits)
         } else {
             Ok(self.build_unchecked())
         }
@@ -1135,6 +1572,17 @@ pub struct Options {
     /// Rounding mode for writing digits with precision control.
     round_mode: RoundMode,
 
+    /// Write digits from the exact decimal representation of the float.
+    ///
+    /// This is only used for decimal strings.
+    exact_digits: bool,
+
+    /// Number of digits to write after the decimal point in fixed-point
+    /// notation.
+    ///
+    /// This is only used for decimal strings.
+    fraction_digits: Option<usize>,
+
     /// Trim the trailing ".0" from integral float strings.
     ///
     /// If used in conjunction with [`min_significant_digits`],
@@ -1144,12 +1592,27 @@ pub struct Options {
     /// [`min_significant_digits`]: Self::min_significant_digits
     trim_floats: bool,
 
+    /// Write lowercase digits for radixes above 10.
+    lowercase_digits: bool,
+
+    /// Write hexadecimal floats with a normalized leading digit.
+    normalize_hex: bool,
+
     /// Character to designate the exponent component of a float.
     exponent: u8,
 
     /// Character to separate the integer from the fraction components.
     decimal_point: u8,
 
+    /// Character to separate groups of integer digits.
+    group_separator: OptionU8,
+
+    /// Number of digits in the least significant group.
+    group_size: OptionU8,
+
+    /// Number of digits in the remaining groups.
+    secondary_group_size: OptionU8,
+
     /// String representation of Not A Number, aka `NaN`.
     nan_string: Option<&'static [u8]>,
 
@@ -1279,6 +1742,32 @@ impl Options {
             T::FORMATTED_SIZE
         };
 
+        // The base prefix is a `0` and the prefix character.
+        let base_size = 2 * format.has_base_prefix() as usize + format.has_base_suffix() as usize;
+
+        // Quadruple-precision floats have much larger exponents and
+        // more significant digits.
+        let is_quad = core::mem::size_of::<T>() > 8;
+
+        // Fixed-point notation writes every integer digit, the decimal
+        // point, the fraction digits, and the sign.
+        if let (10, Some(fraction_digits)) = (format.radix(), self.fraction_digits()) {
+            let integer_digits = if is_quad {
+                QUAD_MAX_INTEGER_DIGITS
+            } else {
+                MAX_INTEGER_DIGITS
+            };
+            let count = max!(fraction_digits.saturating_add(integer_digits + 2), formatted_size);
+            return count.saturating_add(base_size + self.max_group_separators(count));
+        }
+
+        // Normalized hex floats write the sign, the leading digit, the
+        // decimal point, the fraction digits, and a binary exponent.
+        if let (true, Some(fraction_digits)) = (self.normalize_hex(), self.fraction_digits()) {
+            let count = max!(fraction_digits.saturating_add(20), formatted_size);
+            return count.saturating_add(base_size + self.max_group_separators(count));
+        }
+
         // At least 2 for the decimal point and sign.
         let mut count: usize = 2;
 
@@ -1294,9 +1783,15 @@ impl Options {
                 None => 9,
             };
             let exp = max!(min_exp.abs(), max_exp) as usize;
-            if cfg!(feature = "power-of-two") && exp < 13 {
+            if cfg!(feature = "power-of-two") && is_quad && exp < 17 {
+                // 15 for the exponent digits in binary, 1 for the sign, 1 for the symbol
+                count += 17;
+            } else if cfg!(feature = "power-of-two") && exp < 13 {
                 // 11 for the exponent digits in binary, 1 for the sign, 1 for the symbol
                 count += 13;
+            } else if is_quad && exp < 6 {
+                // 4 for the exponent digits in decimal, 1 for the sign, 1 for the symbol
+                count += 6;
             } else if exp < 5 {
                 // 3 for the exponent digits in decimal, 1 for the sign, 1 for the symbol
                 count += 5;
@@ -1304,6 +1799,12 @@ impl Options {
                 // More leading or trailing zeros than the exponent digits.
                 count += exp;
             }
+        } else if cfg!(feature = "power-of-two") && is_quad {
+            // Min is 2^-16494.
+            count += 16494;
+        } else if is_quad {
+            // Min is 10^-4966.
+            count += 4966;
         } else if cfg!(feature = "power-of-two") {
             // Min is 2^-1075.
             count += 1075;
@@ -1314,9 +1815,20 @@ impl Options {
 
         // Now add the number of significant digits.
         let radix = format.radix();
-        let formatted_digits = if radix == 10 {
+        let formatted_digits = if radix == 10 && is_quad && self.exact_digits() {
+            // The exact representation can have many more digits.
+            QUAD_MAX_EXACT_DIGITS
+        } else if radix == 10 && self.exact_digits() {
+            MAX_EXACT_DIGITS
+        } else if radix == 10 && is_quad {
+            // Really should be 36, but add some extra to be cautious.
+            40
+        } else if radix == 10 {
             // Really should be 18, but add some extra to be cautious.
             28
+        } else if is_quad {
+            // 113 significant mantissa bits for binary, add a few extra.
+            128
         } else {
             //  BINARY:
             //      53 significant mantissa bits for binary, add a few extra.
@@ -1342,11 +1854,34 @@ impl Options {
         };
         count += digits;
 
+        // If we have more significant digits than leading or trailing zeros,
+        // we also need room for the exponent in scientific notation: 1 for
+        // the symbol, 1 for the sign, and up to 11 for the exponent digits,
+        // since the integer writers need a buffer of at least 10 digits.
+        if !format.no_exponent_notation() && is_quad {
+            count = max!(count, digits + 19);
+        } else if !format.no_exponent_notation() {
+            count = max!(count, digits + 15);
+        }
+
         // we need to make sure we have at least enough room for the
         // default formatting size, no matter what, just as a precaution.
         count = max!(count, formatted_size);
 
-        count
+        // Finally, add room to separate groups of the integer digits, and
+        // for the base prefix and suffix.
+        count + base_size + self.max_group_separators(count)
+    }
+
+    /// Get the maximum number of group separators for the digits.
+    #[inline(always)]
+    const fn max_group_separators(&self, digits: usize) -> usize {
+        if self.group_separator.is_some() {
+            let (primary, secondary) = group_sizes(self.group_size, self.secondary_group_size);
+            separator_count(digits, primary, secondary)
+        } else {
+            0
+        }
     }
 
     // GETTERS
@@ -1481,6 +2016,59 @@ impl Options {
         self.round_mode
     }
 
+    /// Get if we should write digits from the exact decimal representation
+    /// of the float.
+    ///
+    /// By default, the shortest digits that round-trip to the same float
+    /// are written, and [`max_significant_digits`] rounds those digits.
+    /// If set, the digits are instead generated from the exact value of
+    /// the binary float, so `0.1` written with 25 significant digits is
+    /// `0.1000000000000000055511151`. This is only used for decimal
+    /// strings, and is significantly slower. Defaults to [`false`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lexical_write_float::Options;
+    ///
+    /// const OPTIONS: Options = Options::builder()
+    ///     .exact_digits(true)
+    ///     .build_strict();
+    /// assert_eq!(OPTIONS.exact_digits(), true);
+    /// ```
+    ///
+    /// [`max_significant_digits`]: Self::max_significant_digits
+    #[inline(always)]
+    pub const fn exact_digits(&self) -> bool {
+        self.exact_digits
+    }
+
+    /// Get the number of digits to write after the decimal point, using
+    /// fixed-point notation.
+    ///
+    /// If set, exactly this many fraction digits are always written and
+    /// scientific notation is never used, correctly rounded from the exact
+    /// value of the float using the [`round_mode`]. If set to `0`, no
+    /// decimal point is written. This is only used for decimal strings.
+    /// Defaults to [`None`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lexical_write_float::Options;
+    ///
+    /// const OPTIONS: Options = Options::builder()
+    ///     .fraction_digits(Some(2))
+    ///     .build_strict();
+    /// assert_eq!(OPTIONS.fraction_digits(), Some(2));
+    /// ```
+    ///
+    /// [`round_mode`]: Self::round_mode
+    #[inline(always)]
+    pub const fn fraction_digits(&self) -> Option<usize> {
+        self.fraction_digits
+    }
+
     /// Get if we should trim a trailing `".0"` from integral floats.
     ///
     /// If used in conjunction with [`min_significant_digits`],
@@ -1504,6 +2092,40 @@ impl Options {
         self.trim_floats
     }
 
+    /// Get if lowercase digits are written for radixes above 10.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lexical_write_float::Options;
+    ///
+    /// const OPTIONS: Options = Options::builder()
+    ///     .lowercase_digits(true)
+    ///     .build_strict();
+    /// assert_eq!(OPTIONS.lowercase_digits(), true);
+    /// ```
+    #[inline(always)]
+    pub const fn lowercase_digits(&self) -> bool {
+        self.lowercase_digits
+    }
+
+    /// Get if hexadecimal floats are written with a normalized leading digit.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lexical_write_float::Options;
+    ///
+    /// const OPTIONS: Options = Options::builder()
+    ///     .normalize_hex(true)
+    ///     .build_strict();
+    /// assert_eq!(OPTIONS.normalize_hex(), true);
+    /// ```
+    #[inline(always)]
+    pub const fn normalize_hex(&self) -> bool {
+        self.normalize_hex
+    }
+
     /// Get the character to designate the exponent component of a float.
     ///
     /// Any non-control character is valid, but `\t` to `\r` are also valid.
@@ -1544,6 +2166,63 @@ impl Options {
         self.decimal_point
     }
 
+    /// Get the character to separate groups of integer digits.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use core::num::NonZeroU8;
+    ///
+    /// use lexical_write_float::Options;
+    ///
+    /// const OPTIONS: Options = Options::builder()
+    ///     .group_separator(NonZeroU8::new(b'_'))
+    ///     .build_strict();
+    /// assert_eq!(OPTIONS.group_separator(), NonZeroU8::new(b'_'));
+    /// ```
+    #[inline(always)]
+    pub const fn group_separator(&self) -> OptionU8 {
+        self.group_separator
+    }
+
+    /// Get the number of digits in the least significant group.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use core::num::NonZeroU8;
+    ///
+    /// use lexical_write_float::Options;
+    ///
+    /// const OPTIONS: Options = Options::builder()
+    ///     .group_size(NonZeroU8::new(4))
+    ///     .build_strict();
+    /// assert_eq!(OPTIONS.group_size(), NonZeroU8::new(4));
+    /// ```
+    #[inline(always)]
+    pub const fn group_size(&self) -> OptionU8 {
+        self.group_size
+    }
+
+    /// Get the number of digits in the remaining groups.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use core::num::NonZeroU8;
+    ///
+    /// use lexical_write_float::Options;
+    ///
+    /// const OPTIONS: Options = Options::builder()
+    ///     .secondary_group_size(NonZeroU8::new(2))
+    ///     .build_strict();
+    /// assert_eq!(OPTIONS.secondary_group_size(), NonZeroU8::new(2));
+    /// ```
+    #[inline(always)]
+    pub const fn secondary_group_size(&self) -> OptionU8 {
+        self.secondary_group_size
+    }
+
     /// Get the string representation for `NaN`.
     ///
     /// The first character must start with `N` or `n` and all characters must
@@ -1761,9 +2440,16 @@ impl Options {
             positive_exponent_break: self.positive_exponent_break,
             negative_exponent_break: self.negative_exponent_break,
             round_mode: self.round_mode,
+            exact_digits: self.exact_digits,
+            fraction_digits: self.fraction_digits,
             trim_floats: self.trim_floats,
+            lowercase_digits: self.lowercase_digits,
+            normalize_hex: self.normalize_hex,
             exponent: self.exponent,
             decimal_point: self.decimal_point,
+            group_separator: self.group_separator,
+            group_size: self.group_size,
+            secondary_group_size: self.secondary_group_size,
             nan_string: self.nan_string,
             inf_string: self.inf_string,
         }
@@ -1848,6 +2534,20 @@ pub const HEX_FLOAT: Options = Options::builder()
     .exponent(b'p')
     .build_strict();
 
+/// Numerical format for hexadecimal floats written like C's `printf("%a")`.
+///
+/// This uses lowercase digits, a normalized leading digit, and `inf` and
+/// `nan` for special values. Use it with the `C_HEX_PRINTF` number
+/// format, and uppercase the output for `%A`.
+#[rustfmt::skip]
+pub const C_HEX_PRINTF: Options = Options::builder()
+    .exponent(b'p')
+    .lowercase_digits(true)
+    .normalize_hex(true)
+    .nan_string(Some(b"nan"))
+    .inf_string(Some(b"inf"))
+    .build_strict();
+
 /// Numerical format where `^` is used as the exponent notation character.
 ///
 /// This isn't very common, but is useful when `e` or `p` are valid digits.
@@ -0,0 +1,101 @@
+//! Shortest float-to-string algorithm for quadruple-precision floats.
+//!
+//! The extended-precision algorithms, Dragonbox and Grisu, only work with
+//! 64-bit mantissas, so for an [`f128`] we use the big-integer algorithm
+//! in [`shortest`], which gives the shortest digits that round-trip in
+//! any radix.
+//!
+//! This is much slower than the native algorithms, but still correct for
+//! every float, including denormal floats.
+//!
+//! [`f128`]: lexical_util::f128::f128
+//! [`shortest`]: crate::shortest
+
+#![cfg(feature = "f128")]
+#![doc(hidden)]
+
+use lexical_util::algorithm::rtrim_char_count;
+use lexical_util::digit::digit_to_char_const;
+use lexical_util::f128::f128;
+use lexical_util::format::NumberFormat;
+use lexical_util::num::Float;
+
+use crate::bigint::QUAD_LIMBS;
+use crate::exact::{
+    write_float_negative_exponent,
+    write_float_positive_exponent,
+    write_float_scientific,
+};
+use crate::options::Options;
+use crate::shortest::{self, round_digits};
+
+/// Size of the temporary buffer for the shortest digits.
+///
+/// The shortest digits never have more digits than the exact mantissa in
+/// radix 2, which has 113 significant digits.
+pub const DIGIT_BUFFER_SIZE: usize = 128;
+
+/// Shortest float-to-string algorithm for quadruple-precision floats.
+///
+/// This assumes the float is:
+///     1). Non-special (NaN or Infinite).
+///     2). Non-negative.
+///
+/// # Panics
+///
+/// Panics if the mantissa radix does not equal the exponent base.
+pub fn write_float<const FORMAT: u128>(float: f128, bytes: &mut [u8], options: &Options) -> usize {
+    let format = NumberFormat::<{ FORMAT }> {};
+    assert!(format.is_valid());
+    debug_assert!(!float.is_special());
+    debug_assert!(float.is_sign_positive());
+
+    // Validate our options: we don't support different exponent bases here.
+    let radix = format.mantissa_radix();
+    assert!(
+        radix == format.exponent_base(),
+        "f128 requires the mantissa radix to equal the exponent base."
+    );
+
+    let mut buffer = [0u8; DIGIT_BUFFER_SIZE];
+    let (digit_count, sci_exp) = shortest_digits(float, radix, &mut buffer);
+
+    // Round to the maximum number of significant digits.
+    let (digit_count, carried) = if let Some(max_digits) = options.max_significant_digits() {
+        round_digits(&mut buffer, digit_count, max_digits.get(), radix, options.round_mode())
+    } else {
+        (digit_count, false)
+    };
+    let sci_exp = sci_exp + carried as i32;
+
+    // Convert our digits to characters, and trim trailing zeros, always
+    // keeping at least 1 digit.
+    let digits = &mut buffer[..digit_count];
+    for digit in digits.iter_mut() {
+        *digit = digit_to_char_const(*digit as u32, radix);
+    }
+    let zeros = rtrim_char_count(&digits[1..], b'0');
+    let digits = &digits[..digit_count - zeros];
+
+    write_float!(
+        float,
+        FORMAT,
+        sci_exp,
+        options,
+        write_float_scientific,
+        write_float_positive_exponent,
+        write_float_negative_exponent,
+        bytes => bytes,
+        args => digits, sci_exp, options,
+    )
+}
+
+/// Generate the shortest digits that uniquely identify the float.
+///
+/// This writes the digit values, not the characters, to the start of
+/// `digits`, and returns the number of digits and the scientific exponent
+/// of the first digit. Zero is a single `0` digit with an exponent of `0`.
+#[inline(always)]
+pub fn shortest_digits(float: f128, radix: u32, digits: &mut [u8]) -> (usize, i32) {
+    shortest::shortest_digits::<_, QUAD_LIMBS>(float, radix, digits)
+}
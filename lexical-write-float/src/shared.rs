@@ -53,7 +53,6 @@ pub fn round_up(digits: &mut [u8], count: usize, radix: u32) -> (usize, bool) {
 /// relative to the digits. Returns the digit count, resulting exp, and if
 /// the input carried to the next digit.
 #[cfg_attr(not(feature = "compact"), inline(always))]
-#[allow(clippy::comparison_chain)] // reason="conditions are different logical concepts"
 pub fn truncate_and_round_decimal(
     digits: &mut [u8],
     digit_count: usize,
@@ -66,12 +65,32 @@ pub fn truncate_and_round_decimal(
     } else {
         return (digit_count, false);
     };
+
+    round_decimal(digits, digit_count, max_digits, options.round_mode())
+}
+
+/// Round the decimal digits to at most `max_digits` significant digits.
+///
+/// `digits` is a mutable buffer of the current digits, and `digit_count` is
+/// the length of the written digits in `digits`. Returns the digit count and
+/// if the input carried to the next digit. `max_digits` must be non-zero.
+#[cfg_attr(not(feature = "compact"), inline(always))]
+#[allow(clippy::comparison_chain)] // reason="conditions are different logical concepts"
+pub fn round_decimal(
+    digits: &mut [u8],
+    digit_count: usize,
+    max_digits: usize,
+    round_mode: RoundMode,
+) -> (usize, bool) {
+    debug_assert!(digit_count <= digits.len());
+    debug_assert!(max_digits != 0);
+
     if max_digits >= digit_count {
         return (digit_count, false);
     }
 
     // Check if we're truncating, if so, shorten the digits in the input.
-    if options.round_mode() == RoundMode::Truncate {
+    if round_mode == RoundMode::Truncate {
         // Don't round input, just shorten number of digits emitted.
         return (max_digits, false);
     }
@@ -0,0 +1,201 @@
+//! Shortest float-to-string algorithm for any radix.
+//!
+//! This is the free-format algorithm from Burger and Dybvig, "Printing
+//! Floating-Point Numbers Quickly and Accurately", which is a simplified
+//! variant of Dragon4. This scales the float and the boundaries to its
+//! neighbors by a power of the radix as big integers, and generates
+//! digits until the digits uniquely identify the float, so this gives
+//! the shortest digits that round-trip in any radix.
+//!
+//! This is much slower than the native algorithms, but correct for every
+//! float, including denormal floats. It is used for radixes that are not
+//! decimal or a power of two, and for quadruple-precision floats.
+
+#![cfg(any(feature = "radix", feature = "f128"))]
+#![doc(hidden)]
+
+use core::cmp::Ordering;
+
+use lexical_util::num::{AsPrimitive, Float};
+
+use crate::bigint::{Bigint, Limb};
+use crate::options::RoundMode;
+
+/// Generate the shortest digits that uniquely identify the float.
+///
+/// This writes the digit values, not the characters, to the start of
+/// `digits`, and returns the number of digits and the scientific exponent
+/// of the first digit. Zero is a single `0` digit with an exponent of `0`.
+/// The big integers must be able to store the scaled denominator for the
+/// smallest denormal float, which is `~2^1077` for an `f64`.
+pub fn shortest_digits<F: Float, const SIZE: usize>(
+    float: F,
+    radix: u32,
+    digits: &mut [u8],
+) -> (usize, i32) {
+    let mantissa = float.mantissa().as_u128();
+    if mantissa == 0 {
+        digits[0] = 0;
+        return (1, 0);
+    }
+
+    // Scale our float so `value = r / s`, and the boundaries to the
+    // previous and next floats are `m- / s` and `m+ / s`, respectively.
+    // The gap to the previous float is half as large for exact powers of
+    // two, other than the smallest normal float.
+    let exponent = float.exponent();
+    let is_even = mantissa & 1 == 0;
+    let is_unequal = mantissa == F::HIDDEN_BIT_MASK.as_u128() && exponent != F::DENORMAL_EXPONENT;
+    let shift = is_unequal as usize;
+    let mut r = Bigint::<SIZE>::from_u128(mantissa);
+    let mut s = Bigint::<SIZE>::from_u64(1);
+    let mut m_plus = Bigint::<SIZE>::from_u64(1);
+    let mut m_minus = Bigint::<SIZE>::from_u64(1);
+    if exponent >= 0 {
+        let exponent = exponent as usize;
+        r.shl(exponent + 1 + shift);
+        s.shl(1 + shift);
+        m_plus.shl(exponent + shift);
+        m_minus.shl(exponent);
+    } else {
+        r.shl(1 + shift);
+        s.shl(exponent.unsigned_abs() as usize + 1 + shift);
+        m_plus.shl(shift);
+    }
+
+    // Estimate the scientific exponent, which may be 1 too small, and scale
+    // by the power of the radix. The estimate is never too large, since we
+    // use the lower bound of the binary exponent.
+    let mantissa_bits = 128 - mantissa.leading_zeros() as i32;
+    let log2 = (exponent + mantissa_bits - 1) as f64;
+    let estimate = log2 * core::f64::consts::LN_2 / Float::ln(radix as f64) - 1e-10;
+    let mut k = -Float::floor(-estimate) as i32;
+    if k >= 0 {
+        s.pow(radix, k as u32);
+    } else {
+        let exp = k.unsigned_abs();
+        r.pow(radix, exp);
+        m_plus.pow(radix, exp);
+        m_minus.pow(radix, exp);
+    }
+    while is_high(&r, &m_plus, &s, is_even) {
+        s.mul_small(radix as Limb);
+        k += 1;
+    }
+
+    // Generate the digits, until the remainder is within the boundaries.
+    let mut count = 0;
+    loop {
+        r.mul_small(radix as Limb);
+        m_plus.mul_small(radix as Limb);
+        m_minus.mul_small(radix as Limb);
+        let mut digit = 0;
+        while r.compare(&s) != Ordering::Less {
+            r.sub(&s);
+            digit += 1;
+        }
+
+        let low = match r.compare(&m_minus) {
+            Ordering::Less => true,
+            Ordering::Equal => is_even,
+            Ordering::Greater => false,
+        };
+        let high = is_high(&r, &m_plus, &s, is_even);
+        if low || high {
+            // Choose the closer of the two digits, with ties to even.
+            if low && high {
+                let mut twice = r.clone();
+                twice.shl(1);
+                digit += match twice.compare(&s) {
+                    Ordering::Less => 0,
+                    Ordering::Equal => digit % 2,
+                    Ordering::Greater => 1,
+                };
+            } else if high {
+                digit += 1;
+            }
+            digits[count] = digit as u8;
+            count += 1;
+            break;
+        }
+        digits[count] = digit as u8;
+        count += 1;
+    }
+
+    (count, k - 1)
+}
+
+/// Determine if the upper boundary is above the scaled denominator.
+#[inline(always)]
+fn is_high<const SIZE: usize>(
+    r: &Bigint<SIZE>,
+    m_plus: &Bigint<SIZE>,
+    s: &Bigint<SIZE>,
+    is_even: bool,
+) -> bool {
+    let mut high = r.clone();
+    high.add(m_plus);
+    match high.compare(s) {
+        Ordering::Less => false,
+        Ordering::Equal => is_even,
+        Ordering::Greater => true,
+    }
+}
+
+/// Round the digit values to at most `max_digits` significant digits.
+///
+/// Returns the digit count and if the input carried to the next digit.
+/// `max_digits` must be non-zero.
+pub fn round_digits(
+    digits: &mut [u8],
+    digit_count: usize,
+    max_digits: usize,
+    radix: u32,
+    round_mode: RoundMode,
+) -> (usize, bool) {
+    debug_assert!(max_digits != 0);
+
+    if max_digits >= digit_count {
+        return (digit_count, false);
+    }
+    if round_mode == RoundMode::Truncate {
+        // Don't round input, just shorten number of digits emitted.
+        return (max_digits, false);
+    }
+
+    // For even radices, our halfway point is `$c00000...`, and for odd
+    // radices it is `$c$c$c$c...`, which can never be exactly halfway.
+    let halfway = (radix / 2) as u8;
+    let truncated = &digits[max_digits..digit_count];
+    let is_above = if radix % 2 == 0 {
+        match truncated[0].cmp(&halfway) {
+            Ordering::Less => false,
+            Ordering::Greater => true,
+            Ordering::Equal => {
+                truncated[1..].iter().any(|&x| x != 0) || digits[max_digits - 1] % 2 == 1
+            },
+        }
+    } else {
+        match truncated.iter().find(|&&x| x != halfway) {
+            Some(&x) => x > halfway,
+            None => false,
+        }
+    };
+    if !is_above {
+        return (max_digits, false);
+    }
+
+    // Round-up, carrying to the previous digits.
+    let max_digit = (radix - 1) as u8;
+    let mut index = max_digits;
+    while index != 0 {
+        if digits[index - 1] < max_digit {
+            digits[index - 1] += 1;
+            return (index, false);
+        }
+        index -= 1;
+    }
+    digits[0] = 1;
+
+    (1, true)
+}
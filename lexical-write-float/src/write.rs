@@ -4,23 +4,32 @@
 
 #[cfg(feature = "f16")]
 use lexical_util::bf16::bf16;
+use lexical_util::digit::char_is_digit_const;
+#[cfg(feature = "f128")]
+use lexical_util::f128::f128;
 #[cfg(feature = "f16")]
 use lexical_util::f16::f16;
 use lexical_util::format::NumberFormat;
+use lexical_util::grouping::{group_sizes, insert_separators};
+#[cfg(feature = "f128")]
+use lexical_util::num::Float;
 use lexical_util::{algorithm::copy_to_dst, constants::FormattedSize};
 use lexical_write_integer::write::WriteInteger;
 
 /// Select the back-end.
 #[cfg(not(feature = "compact"))]
-use crate::algorithm::write_float as write_float_decimal;
+use crate::algorithm::write_float as write_float_shortest;
 #[cfg(feature = "power-of-two")]
 use crate::binary;
 #[cfg(feature = "compact")]
-use crate::compact::write_float as write_float_decimal;
+use crate::compact::write_float as write_float_shortest;
+use crate::exact;
 use crate::float::RawFloat;
 #[cfg(feature = "power-of-two")]
 use crate::hex;
 use crate::options::Options;
+#[cfg(feature = "f128")]
+use crate::quad;
 #[cfg(feature = "radix")]
 use crate::radix;
 
@@ -57,6 +66,86 @@ fn write_inf(bytes: &mut [u8], options: &Options, count: usize) -> usize {
         )
 }
 
+/// Write a decimal float, using either the shortest or exact digits.
+///
+/// Fixed-point notation always uses the exact digits.
+#[cfg_attr(not(feature = "compact"), inline(always))]
+fn write_float_decimal<F: RawFloat, const FORMAT: u128>(
+    float: F,
+    bytes: &mut [u8],
+    options: &Options,
+) -> usize {
+    if options.fraction_digits().is_some() {
+        exact::write_float_fixed::<_, FORMAT>(float, bytes, options)
+    } else if options.exact_digits() {
+        exact::write_float::<_, FORMAT>(float, bytes, options)
+    } else {
+        write_float_shortest::<_, FORMAT>(float, bytes, options)
+    }
+}
+
+/// Insert separators between groups of the written integer digits.
+///
+/// The integer digits are the leading digits of the written float, which
+/// are followed by the decimal point, the exponent, or the end of the float.
+#[cfg_attr(not(feature = "compact"), inline(always))]
+fn group_digits<const FORMAT: u128>(bytes: &mut [u8], length: usize, options: &Options) -> usize {
+    if let Some(separator) = options.group_separator() {
+        let radix = NumberFormat::<FORMAT> {}.radix();
+        let exponent = options.exponent();
+        let digits = bytes[..length]
+            .iter()
+            .take_while(|&&c| c != exponent && char_is_digit_const(c, radix))
+            .count();
+        let (primary, secondary) =
+            group_sizes(options.group_size(), options.secondary_group_size());
+        insert_separators(bytes, digits, length, separator.get(), primary, secondary)
+    } else {
+        length
+    }
+}
+
+/// Write the base prefix, a `0` followed by the prefix character, if any.
+#[cfg_attr(not(feature = "compact"), inline(always))]
+fn write_base_prefix<const FORMAT: u128>(bytes: &mut [u8]) -> usize {
+    let format = NumberFormat::<FORMAT> {};
+    if cfg!(feature = "format") && format.has_base_prefix() {
+        bytes[0] = b'0';
+        bytes[1] = format.base_prefix();
+        2
+    } else {
+        0
+    }
+}
+
+/// Write the base suffix character, if any.
+#[cfg_attr(not(feature = "compact"), inline(always))]
+fn write_base_suffix<const FORMAT: u128>(bytes: &mut [u8]) -> usize {
+    let format = NumberFormat::<FORMAT> {};
+    if cfg!(feature = "format") && format.has_base_suffix() {
+        bytes[0] = format.base_suffix();
+        1
+    } else {
+        0
+    }
+}
+
+/// Convert the written digits to lowercase, if requested.
+///
+/// The exponent character and decimal point are written as provided.
+#[cfg_attr(not(feature = "compact"), inline(always))]
+fn lowercase_digits(bytes: &mut [u8], length: usize, options: &Options) {
+    if options.lowercase_digits() {
+        let exponent = options.exponent();
+        let decimal_point = options.decimal_point();
+        for c in bytes[..length].iter_mut() {
+            if *c != exponent && *c != decimal_point {
+                c.make_ascii_lowercase();
+            }
+        }
+    }
+}
+
 /// Check if a buffer is sufficiently large.
 #[inline(always)]
 fn check_buffer<T, const FORMAT: u128>(len: usize, options: &Options) -> bool
@@ -107,9 +196,13 @@ pub trait WriteFloat: RawFloat + FormattedSize {
             }
         }
 
+        // Like C, normalized hex floats write the sign of a negative NaN.
         let (float, count, bytes) = if self.needs_negative_sign() {
             bytes[0] = b'-';
             (-self, 1, &mut bytes[1..])
+        } else if options.normalize_hex() && self.is_nan() && self.is_sign_negative() {
+            bytes[0] = b'-';
+            (self, 1, &mut bytes[1..])
         } else if cfg!(feature = "format") && format.required_mantissa_sign() {
             bytes[0] = b'+';
             (self, 1, &mut bytes[1..])
@@ -117,42 +210,46 @@ pub trait WriteFloat: RawFloat + FormattedSize {
             (self, 0, bytes)
         };
 
-        // Handle special values.
+        // Handle special values. The base prefix and suffix only surround
+        // finite values, after any sign.
         if !self.is_special() {
+            let prefix = write_base_prefix::<FORMAT>(bytes);
+            let bytes = &mut bytes[prefix..];
+
             #[cfg(all(feature = "power-of-two", not(feature = "radix")))]
-            {
+            let length = {
                 let radix = format.radix();
                 let exponent_base = format.exponent_base();
-                count
-                    + if radix == 10 {
-                        write_float_decimal::<_, FORMAT>(float, bytes, options)
-                    } else if radix != exponent_base {
-                        hex::write_float::<_, FORMAT>(float, bytes, options)
-                    } else {
-                        binary::write_float::<_, FORMAT>(float, bytes, options)
-                    }
-            }
+                if radix == 10 {
+                    write_float_decimal::<_, FORMAT>(float, bytes, options)
+                } else if radix != exponent_base {
+                    hex::write_float::<_, FORMAT>(float, bytes, options)
+                } else {
+                    binary::write_float::<_, FORMAT>(float, bytes, options)
+                }
+            };
 
             #[cfg(feature = "radix")]
-            {
+            let length = {
                 let radix = format.radix();
                 let exponent_base = format.exponent_base();
-                count
-                    + if radix == 10 {
-                        write_float_decimal::<_, FORMAT>(float, bytes, options)
-                    } else if radix != exponent_base {
-                        hex::write_float::<_, FORMAT>(float, bytes, options)
-                    } else if matches!(radix, 2 | 4 | 8 | 16 | 32) {
-                        binary::write_float::<_, FORMAT>(float, bytes, options)
-                    } else {
-                        radix::write_float::<_, FORMAT>(float, bytes, options)
-                    }
-            }
+                if radix == 10 {
+                    write_float_decimal::<_, FORMAT>(float, bytes, options)
+                } else if radix != exponent_base {
+                    hex::write_float::<_, FORMAT>(float, bytes, options)
+                } else if matches!(radix, 2 | 4 | 8 | 16 | 32) {
+                    binary::write_float::<_, FORMAT>(float, bytes, options)
+                } else {
+                    radix::write_float::<_, FORMAT>(float, bytes, options)
+                }
+            };
 
             #[cfg(not(feature = "power-of-two"))]
-            {
-                count + write_float_decimal::<_, FORMAT>(float, bytes, options)
-            }
+            let length = write_float_decimal::<_, FORMAT>(float, bytes, options);
+
+            lowercase_digits(bytes, length, options);
+            let length = group_digits::<FORMAT>(bytes, length, options);
+            count + prefix + length + write_base_suffix::<FORMAT>(&mut bytes[length..])
         } else if self.is_nan() {
             write_nan(bytes, options, count)
         } else {
@@ -184,3 +281,53 @@ macro_rules! write_float_as_f32 {
 
 #[cfg(feature = "f16")]
 write_float_as_f32! { bf16 f16 }
+
+#[cfg(feature = "f128")]
+impl WriteFloat for f128 {
+    /// Write a quadruple-precision float.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number format is invalid, if the mantissa radix
+    /// does not equal the exponent base, or if `normalize_hex` is set.
+    /// It also panics if `options.nan_string` or `options.inf_string` is
+    /// None and asked to serialize a NaN or Inf value.
+    #[cfg_attr(not(feature = "compact"), inline(always))]
+    fn write_float<const FORMAT: u128>(self, bytes: &mut [u8], options: &Options) -> usize {
+        // Validate our format options.
+        assert!(check_buffer::<Self, { FORMAT }>(bytes.len(), options));
+        let format = NumberFormat::<FORMAT> {};
+        assert!(format.is_valid());
+        assert!(!options.normalize_hex(), "f128 does not support normalized hex floats.");
+
+        let (float, count, bytes) = if self.needs_negative_sign() {
+            bytes[0] = b'-';
+            (-self, 1, &mut bytes[1..])
+        } else if cfg!(feature = "format") && format.required_mantissa_sign() {
+            bytes[0] = b'+';
+            (self, 1, &mut bytes[1..])
+        } else {
+            (self, 0, bytes)
+        };
+
+        // Handle special values.
+        if !self.is_special() {
+            let prefix = write_base_prefix::<FORMAT>(bytes);
+            let bytes = &mut bytes[prefix..];
+            let length = if format.radix() == 10 && options.fraction_digits().is_some() {
+                exact::write_quad_fixed::<FORMAT>(float, bytes, options)
+            } else if format.radix() == 10 && options.exact_digits() {
+                exact::write_quad::<FORMAT>(float, bytes, options)
+            } else {
+                quad::write_float::<FORMAT>(float, bytes, options)
+            };
+            lowercase_digits(bytes, length, options);
+            let length = group_digits::<FORMAT>(bytes, length, options);
+            count + prefix + length + write_base_suffix::<FORMAT>(&mut bytes[length..])
+        } else if self.is_nan() {
+            write_nan(bytes, options, count)
+        } else {
+            write_inf(bytes, options, count)
+        }
+    }
+}
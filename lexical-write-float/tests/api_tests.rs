@@ -78,3 +78,154 @@ fn hex_test() {
     let result = float.to_lexical_with_options::<BASE16_2_10>(&mut buffer, &HEX_OPTIONS);
     assert_eq!(result, b"3.039^12");
 }
+
+#[test]
+#[cfg(all(feature = "format", feature = "power-of-two"))]
+fn base_prefix_suffix_test() {
+    use core::num;
+
+    use lexical_util::format::NumberFormatBuilder;
+
+    const HEX: u128 = NumberFormatBuilder::new()
+        .mantissa_radix(16)
+        .exponent_base(num::NonZeroU8::new(2))
+        .exponent_radix(num::NonZeroU8::new(10))
+        .base_prefix(num::NonZeroU8::new(b'x'))
+        .required_exponent_sign(true)
+        .build_strict();
+    const HEX_OPTIONS: Options = Options::builder().exponent(b'p').build_strict();
+
+    let mut buffer = [b'\x00'; 512];
+    let result = 12345.0f64.to_lexical_with_options::<HEX>(&mut buffer, &HEX_OPTIONS);
+    assert_eq!(result, b"0x3.039p+12");
+    let result = (-0.5f32).to_lexical_with_options::<HEX>(&mut buffer, &HEX_OPTIONS);
+    assert_eq!(result, b"-0x0.8");
+    let result = f64::NEG_INFINITY.to_lexical_with_options::<HEX>(&mut buffer, &HEX_OPTIONS);
+    assert_eq!(result, b"-inf");
+    let result = f64::NAN.to_lexical_with_options::<HEX>(&mut buffer, &HEX_OPTIONS);
+    assert_eq!(result, b"NaN");
+
+    const SUFFIX: u128 = NumberFormatBuilder::new()
+        .base_prefix(num::NonZeroU8::new(b'd'))
+        .base_suffix(num::NonZeroU8::new(b'f'))
+        .required_mantissa_sign(true)
+        .build_strict();
+    const OPTIONS: Options = Options::new();
+    let result = 1.5f64.to_lexical_with_options::<SUFFIX>(&mut buffer, &OPTIONS);
+    assert_eq!(result, b"+0d1.5f");
+    let result = (-1.2345e-7f64).to_lexical_with_options::<SUFFIX>(&mut buffer, &OPTIONS);
+    assert_eq!(result, b"-0d1.2345e-7f");
+
+    // The buffer size includes the prefix and suffix.
+    let size = OPTIONS.buffer_size_const::<f64, SUFFIX>();
+    assert_eq!(size, OPTIONS.buffer_size_const::<f64, STANDARD>() + 3);
+}
+
+#[test]
+#[cfg(feature = "power-of-two")]
+fn lowercase_digits_test() {
+    use core::num;
+
+    use lexical_util::format::NumberFormatBuilder;
+
+    const HEX: u128 = NumberFormatBuilder::new()
+        .mantissa_radix(16)
+        .exponent_base(num::NonZeroU8::new(2))
+        .exponent_radix(num::NonZeroU8::new(10))
+        .build_strict();
+    const UPPER: Options = Options::builder().exponent(b'P').build_strict();
+    const LOWER: Options = UPPER.rebuild().lowercase_digits(true).build_strict();
+    const LOWER_P: Options = LOWER.rebuild().exponent(b'p').build_strict();
+
+    let mut buffer = [b'\x00'; 512];
+    let float = 1234567.0f64;
+    assert_eq!(float.to_lexical_with_options::<HEX>(&mut buffer, &UPPER), b"1.2D687P20");
+    assert_eq!(float.to_lexical_with_options::<HEX>(&mut buffer, &LOWER), b"1.2d687P20");
+    assert_eq!(float.to_lexical_with_options::<HEX>(&mut buffer, &LOWER_P), b"1.2d687p20");
+
+    // Special values are written as provided.
+    assert_eq!(f64::NAN.to_lexical_with_options::<HEX>(&mut buffer, &LOWER), b"NaN");
+
+    // Decimal strings have no digits to change.
+    let float = 1.5e300f64;
+    assert_eq!(float.to_lexical_with_options::<STANDARD>(&mut buffer, &LOWER), b"1.5P300");
+}
+
+#[test]
+#[cfg(feature = "radix")]
+fn lowercase_digits_radix_test() {
+    use lexical_util::format::NumberFormatBuilder;
+
+    const BASE36: u128 = NumberFormatBuilder::from_radix(36);
+    const LOWER: Options = Options::builder().exponent(b'^').lowercase_digits(true).build_strict();
+
+    let mut buffer = [b'\x00'; 512];
+    let float = 1295.5f64;
+    assert_eq!(float.to_lexical_with_options::<BASE36>(&mut buffer, &LOWER), b"zz.i");
+    let float = 1e100f64;
+    assert_eq!(float.to_lexical_with_options::<BASE36>(&mut buffer, &LOWER), b"2.hqbczu2ow5^1s");
+}
+
+fn write_grouped(value: f64, options: &Options) -> String {
+    let mut buffer = [b'\x00'; 2048];
+    let size = options.buffer_size_const::<f64, STANDARD>();
+    let digits = value.to_lexical_with_options::<STANDARD>(&mut buffer[..size], options);
+    String::from_utf8(digits.to_vec()).unwrap()
+}
+
+#[test]
+fn group_separator_test() {
+    use core::num::{NonZeroI32, NonZeroU8};
+
+    let options = Options::builder().group_separator(NonZeroU8::new(b',')).build_strict();
+    assert_eq!(write_grouped(0.0, &options), "0.0");
+    assert_eq!(write_grouped(123.5, &options), "123.5");
+    assert_eq!(write_grouped(1234567.89, &options), "1,234,567.89");
+    assert_eq!(write_grouped(-123456789.0, &options), "-123,456,789.0");
+    assert_eq!(write_grouped(0.000012345, &options), "0.000012345");
+    assert_eq!(write_grouped(1.2345e-7, &options), "1.2345e-7");
+    assert_eq!(write_grouped(1.2345e30, &options), "1.2345e30");
+    assert_eq!(write_grouped(f64::NAN, &options), "NaN");
+
+    // Indian grouping uses groups of 2 after the first group of 3.
+    let options = options.rebuild().secondary_group_size(NonZeroU8::new(2)).build_strict();
+    assert_eq!(write_grouped(1234567.89, &options), "12,34,567.89");
+
+    // Fixed-point and exponent breaks with many integer digits.
+    let options = Options::builder()
+        .group_separator(NonZeroU8::new(b' '))
+        .fraction_digits(Some(2))
+        .build_strict();
+    assert_eq!(write_grouped(9876543.219, &options), "9 876 543.22");
+    let digits = write_grouped(f64::MAX, &options);
+    assert_eq!(digits.len(), 309 + 102 + 3);
+    assert!(digits.starts_with("179 769 313 486 231 570 814"));
+
+    let options = Options::builder()
+        .group_separator(NonZeroU8::new(b'_'))
+        .group_size(NonZeroU8::new(1))
+        .positive_exponent_break(NonZeroI32::new(400))
+        .trim_floats(true)
+        .build_strict();
+    assert_eq!(write_grouped(-1e20, &options), "-1_0_0_0_0_0_0_0_0_0_0_0_0_0_0_0_0_0_0_0_0");
+    assert_eq!(write_grouped(f64::MAX, &options).len(), 309 * 2 - 1);
+}
+
+#[test]
+#[cfg(feature = "radix")]
+fn group_separator_radix_test() {
+    use core::num::{NonZeroI32, NonZeroU8};
+
+    use lexical_util::format::NumberFormatBuilder;
+
+    const HEX: u128 = NumberFormatBuilder::from_radix(16);
+    let options = Options::builder()
+        .group_separator(NonZeroU8::new(b'\''))
+        .group_size(NonZeroU8::new(4))
+        .exponent(b'^')
+        .positive_exponent_break(NonZeroI32::new(100))
+        .build_strict();
+    let mut buffer = [b'\x00'; 512];
+    let digits = 3735928559.5f64.to_lexical_with_options::<HEX>(&mut buffer, &options);
+    assert_eq!(digits, b"DEAD'BEEF.8");
+}
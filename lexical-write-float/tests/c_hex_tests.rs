@@ -0,0 +1,63 @@
+#![cfg(all(feature = "power-of-two", feature = "format"))]
+
+use core::str;
+
+use lexical_util::format::C_HEX_PRINTF;
+use lexical_write_float::{options, Options, ToLexicalWithOptions};
+
+/// Reference `printf("%a")` output generated by `etc/printf_hex.c`.
+const PRINTF_HEX: &str = include_str!("corpus/printf_hex.txt");
+
+fn write<T: ToLexicalWithOptions<Options = Options>>(value: T, options: &Options) -> String {
+    let mut buffer = [b'\x00'; 512];
+    let digits = value.to_lexical_with_options::<C_HEX_PRINTF>(&mut buffer, options);
+    str::from_utf8(digits).unwrap().to_string()
+}
+
+#[test]
+fn printf_hex_test() {
+    let options = options::C_HEX_PRINTF;
+    assert_eq!(write(0.0f64, &options), "0x0p+0");
+    assert_eq!(write(-0.0f64, &options), "-0x0p+0");
+    assert_eq!(write(1.0f64, &options), "0x1p+0");
+    assert_eq!(write(3.0f64, &options), "0x1.8p+1");
+    assert_eq!(write(0.1f64, &options), "0x1.999999999999ap-4");
+    assert_eq!(write(0.1f32, &options), "0x1.99999ap-4");
+    assert_eq!(write(5e-324f64, &options), "0x0.0000000000001p-1022");
+    assert_eq!(write(f64::MAX, &options), "0x1.fffffffffffffp+1023");
+    assert_eq!(write(f64::INFINITY, &options), "inf");
+    assert_eq!(write(f64::NEG_INFINITY, &options), "-inf");
+    assert_eq!(write(f64::NAN, &options), "nan");
+    assert_eq!(write(-f64::NAN, &options), "-nan");
+
+    let options = options.rebuild().fraction_digits(Some(0)).build_strict();
+    assert_eq!(write(1.5f64, &options), "0x2p+0");
+    assert_eq!(write(1.03125f64, &options), "0x1p+0");
+    let options = options.rebuild().fraction_digits(Some(3)).build_strict();
+    assert_eq!(write(1.0f64, &options), "0x1.000p+0");
+    assert_eq!(write(1.9999999999999998f64, &options), "0x2.000p+0");
+}
+
+#[test]
+fn printf_hex_corpus_test() {
+    for line in PRINTF_HEX.lines() {
+        let fields: Vec<&str> = line.split(' ').collect();
+        let (conversion, kind, bits, precision, expected) =
+            (fields[0], fields[1], fields[2], fields[3], fields[4]);
+        let options = match precision {
+            "-" => options::C_HEX_PRINTF,
+            _ => options::C_HEX_PRINTF
+                .rebuild()
+                .fraction_digits(Some(precision.parse().unwrap()))
+                .build_strict(),
+        };
+        let mut actual = match kind {
+            "f64" => write(f64::from_bits(u64::from_str_radix(bits, 16).unwrap()), &options),
+            _ => write(f32::from_bits(u32::from_str_radix(bits, 16).unwrap()), &options),
+        };
+        if conversion == "A" {
+            actual.make_ascii_uppercase();
+        }
+        assert_eq!(actual, expected, "{}", line);
+    }
+}
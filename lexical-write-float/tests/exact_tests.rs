@@ -0,0 +1,284 @@
+use core::fmt::Write;
+use core::num;
+
+use lexical_util::constants::BUFFER_SIZE;
+use lexical_util::format::STANDARD;
+use lexical_write_float::{exact, Options, RoundMode, ToLexicalWithOptions};
+
+const EXACT: Options = Options::builder().exact_digits(true).build_strict();
+
+fn exact_digits(f: f64) -> (String, i32) {
+    let mut buffer = [b'0'; exact::DIGIT_BUFFER_SIZE];
+    let (start, count, sci_exp) = exact::exact_digits(f, &mut buffer);
+    let digits = std::str::from_utf8(&buffer[start..start + count]).unwrap();
+    (digits.to_string(), sci_exp)
+}
+
+fn write_exact(f: f64, options: &Options) -> String {
+    let mut buffer = [b'\x00'; 2048];
+    let size = options.buffer_size_const::<f64, STANDARD>();
+    assert!(size <= buffer.len());
+    let digits = f.to_lexical_with_options::<STANDARD>(&mut buffer[..size], options);
+    std::str::from_utf8(digits).unwrap().to_string()
+}
+
+fn precision(digits: usize) -> Options {
+    Options::builder()
+        .exact_digits(true)
+        .max_significant_digits(num::NonZeroUsize::new(digits))
+        .min_significant_digits(num::NonZeroUsize::new(digits))
+        .build_strict()
+}
+
+// Normalize a float string to the significant digits and scientific exponent.
+fn normalize(value: &str) -> (String, i32) {
+    let value = value.trim_start_matches('-');
+    let (mantissa, exp) = match value.split_once('e') {
+        Some((mantissa, exp)) => (mantissa, exp.parse::<i32>().unwrap()),
+        None => (value, 0),
+    };
+    let (integer, fraction) = mantissa.split_once('.').unwrap_or((mantissa, ""));
+    let mut digits = integer.to_string();
+    digits.push_str(fraction);
+    let leading = digits.len() - digits.trim_start_matches('0').len();
+    let digits = digits.trim_start_matches('0').trim_end_matches('0').to_string();
+    let sci_exp = exp + integer.len() as i32 - 1 - leading as i32;
+    (digits, sci_exp)
+}
+
+#[test]
+fn exact_digits_test() {
+    assert_eq!(exact_digits(0.0), ("0".to_string(), 0));
+    assert_eq!(exact_digits(1.0), ("1".to_string(), 0));
+    assert_eq!(exact_digits(0.5), ("5".to_string(), -1));
+    assert_eq!(
+        exact_digits(0.1),
+        ("1000000000000000055511151231257827021181583404541015625".to_string(), -1)
+    );
+    assert_eq!(exact_digits(1e23), ("99999999999999991611392".to_string(), 22));
+    assert_eq!(exact_digits(9007199254740993.0), ("9007199254740992".to_string(), 15));
+
+    let (digits, sci_exp) = exact_digits(f64::from_bits(1));
+    assert_eq!(digits.len(), 751);
+    assert!(
+        digits.starts_with("4940656458412465441765687928682213723650598026143247644255856825006")
+    );
+    assert!(digits.ends_with("5625"));
+    assert_eq!(sci_exp, -324);
+
+    let (digits, sci_exp) = exact_digits(f64::MAX);
+    assert_eq!(digits.len(), 309);
+    assert!(digits
+        .starts_with("17976931348623157081452742373170435679807056752584499659891747680315726"));
+    assert_eq!(sci_exp, 308);
+
+    // Largest number of significant digits for any `f64`.
+    let (digits, _) = exact_digits(f64::from_bits(0x000FFFFFFFFFFFFF));
+    assert_eq!(digits.len(), exact::MAX_EXACT_DIGITS);
+    let (digits, _) = exact_digits(f64::MIN_POSITIVE - f64::from_bits(1) * 2.0);
+    assert!(digits.len() <= exact::MAX_EXACT_DIGITS);
+}
+
+#[test]
+fn write_float_test() {
+    assert_eq!(write_exact(0.0, &EXACT), "0.0");
+    assert_eq!(write_exact(1.0, &EXACT), "1.0");
+    assert_eq!(write_exact(1.5, &EXACT), "1.5");
+    assert_eq!(
+        write_exact(0.1, &EXACT),
+        "0.1000000000000000055511151231257827021181583404541015625"
+    );
+    assert_eq!(write_exact(1e23, &EXACT), "9.9999999999999991611392e22");
+    assert_eq!(write_exact(123456.0, &EXACT), "123456.0");
+    assert_eq!(
+        write_exact(1e-7, &EXACT),
+        "9.99999999999999954748111825886258685613938723690807819366455078125e-8"
+    );
+
+    let options = precision(34);
+    assert_eq!(write_exact(0.1, &options), "0.1000000000000000055511151231257827");
+    assert_eq!(write_exact(-0.1, &options), "-0.1000000000000000055511151231257827");
+    assert_eq!(write_exact(0.5, &options), "0.5000000000000000000000000000000000");
+    assert_eq!(write_exact(1e-10, &options), "1.000000000000000036432197315497742e-10");
+
+    let options = precision(25);
+    assert_eq!(write_exact(1e23, &options), "9.999999999999999161139200e22");
+
+    // Round-up carries to the next exponent.
+    let options = precision(3);
+    assert_eq!(write_exact(9.9999, &options), "10.0");
+    assert_eq!(write_exact(0.0999999, &options), "0.100");
+    assert_eq!(write_exact(9.9999e20, &options), "1.00e21");
+
+    // Truncation.
+    let options = Options::builder()
+        .exact_digits(true)
+        .round_mode(RoundMode::Truncate)
+        .max_significant_digits(num::NonZeroUsize::new(5))
+        .build_strict();
+    assert_eq!(write_exact(2.0 / 3.0, &options), "0.66666");
+    assert_eq!(write_exact(0.3, &options), "0.29999");
+}
+
+#[test]
+fn halfway_test() {
+    // These are exact halfway cases, so they must round-to-even.
+    let options = Options::builder()
+        .exact_digits(true)
+        .max_significant_digits(num::NonZeroUsize::new(2))
+        .build_strict();
+    assert_eq!(write_exact(0.125, &options), "0.12");
+    assert_eq!(write_exact(0.375, &options), "0.38");
+    assert_eq!(write_exact(2.5, &options), "2.5");
+
+    let options = Options::builder()
+        .exact_digits(true)
+        .max_significant_digits(num::NonZeroUsize::new(1))
+        .build_strict();
+    assert_eq!(write_exact(2.5, &options), "2.0");
+    assert_eq!(write_exact(3.5, &options), "4.0");
+
+    // Not a halfway case, since `0.15` is slightly below `0.15`.
+    assert_eq!(write_exact(0.15, &options), "0.1");
+    // Not a halfway case, since `0.35` is slightly below `0.35`.
+    assert_eq!(write_exact(0.35, &options), "0.3");
+    // Not a halfway case, since `0.45` is slightly above `0.45`.
+    assert_eq!(write_exact(0.45, &options), "0.5");
+}
+
+#[test]
+fn large_buffer_test() {
+    // Make sure our buffer size is large enough for every significant digit.
+    let options = Options::builder()
+        .exact_digits(true)
+        .negative_exponent_break(num::NonZeroI32::new(-1))
+        .build_strict();
+    assert!(options.buffer_size_const::<f64, STANDARD>() >= exact::MAX_EXACT_DIGITS);
+    assert!(options.buffer_size_const::<f64, STANDARD>() > BUFFER_SIZE);
+
+    let value = f64::from_bits(0x000FFFFFFFFFFFFF);
+    let result = write_exact(value, &options);
+    assert!(result.starts_with("2.225073858507200889024586876085859887650423112240959465493524802"));
+    assert!(result.ends_with("e-308"));
+}
+
+#[test]
+fn std_comparison_test() {
+    // Rust's precision formatting is correctly-rounded from the exact value.
+    let values = [
+        0.1f64,
+        0.2,
+        0.3,
+        1.0 / 3.0,
+        2.0 / 3.0,
+        core::f64::consts::PI,
+        core::f64::consts::E,
+        1.7976931348623157e308,
+        2.2250738585072014e-308,
+        5e-324,
+        123456789.12345679,
+        9.87654321e-200,
+        1e100,
+    ];
+    for &value in values.iter() {
+        for digits in [1, 2, 5, 17, 20, 40, 100] {
+            let mut expected = String::new();
+            write!(expected, "{:.*e}", digits - 1, value).unwrap();
+            let actual = write_exact(value, &precision(digits));
+            let (expected, actual) = (normalize(&expected), normalize(&actual));
+            assert_eq!(actual, expected);
+        }
+    }
+}
+
+fn fixed(digits: usize) -> Options {
+    Options::builder().fraction_digits(Some(digits)).build_strict()
+}
+
+#[test]
+fn write_float_fixed_test() {
+    assert_eq!(write_exact(0.0, &fixed(0)), "0");
+    assert_eq!(write_exact(0.0, &fixed(3)), "0.000");
+    assert_eq!(write_exact(-0.0, &fixed(2)), "-0.00");
+    assert_eq!(write_exact(1.0, &fixed(2)), "1.00");
+    assert_eq!(write_exact(1234.5, &fixed(2)), "1234.50");
+    assert_eq!(write_exact(1234.5, &fixed(0)), "1234");
+    assert_eq!(write_exact(1235.5, &fixed(0)), "1236");
+    assert_eq!(write_exact(-1234.5678, &fixed(2)), "-1234.57");
+    assert_eq!(write_exact(0.1, &fixed(20)), "0.10000000000000000555");
+    assert_eq!(write_exact(1e21, &fixed(1)), "1000000000000000000000.0");
+    assert_eq!(write_exact(1e23, &fixed(0)), "99999999999999991611392");
+
+    // Correctly rounded from the exact value.
+    assert_eq!(write_exact(1.005, &fixed(2)), "1.00");
+    assert_eq!(write_exact(0.135, &fixed(2)), "0.14");
+    assert_eq!(write_exact(0.125, &fixed(2)), "0.12");
+    assert_eq!(write_exact(0.375, &fixed(2)), "0.38");
+    assert_eq!(write_exact(9.995, &fixed(2)), "9.99");
+    assert_eq!(write_exact(9.9951, &fixed(2)), "10.00");
+    assert_eq!(write_exact(99.5, &fixed(0)), "100");
+
+    // Rounding before the first significant digit.
+    assert_eq!(write_exact(0.0004, &fixed(3)), "0.000");
+    assert_eq!(write_exact(0.0005, &fixed(3)), "0.001");
+    assert_eq!(write_exact(0.0006, &fixed(3)), "0.001");
+    assert_eq!(write_exact(0.00006, &fixed(3)), "0.000");
+    assert_eq!(write_exact(0.5, &fixed(0)), "0");
+    assert_eq!(write_exact(0.75, &fixed(0)), "1");
+    assert_eq!(write_exact(5e-324, &fixed(2)), "0.00");
+
+    // Never uses scientific notation or significant digits.
+    let options = Options::builder()
+        .fraction_digits(Some(1))
+        .max_significant_digits(num::NonZeroUsize::new(2))
+        .positive_exponent_break(num::NonZeroI32::new(2))
+        .build_strict();
+    assert_eq!(write_exact(123456.75, &options), "123456.8");
+
+    // Truncation.
+    let options =
+        Options::builder().fraction_digits(Some(2)).round_mode(RoundMode::Truncate).build_strict();
+    assert_eq!(write_exact(1.999, &options), "1.99");
+    assert_eq!(write_exact(0.0099, &options), "0.00");
+
+    // Custom decimal point.
+    let options = Options::builder().fraction_digits(Some(2)).decimal_point(b',').build_strict();
+    assert_eq!(write_exact(4.12345, &options), "4,12");
+
+    // Largest values.
+    let result = write_exact(f64::MAX, &fixed(2));
+    assert_eq!(result.len(), 312);
+    assert!(
+        result.starts_with("179769313486231570814527423731704356798070567525844996598917476803157")
+    );
+    assert!(result.ends_with("58368.00"));
+    let result = write_exact(-f64::MAX, &fixed(2));
+    assert_eq!(result.len(), 313);
+}
+
+#[test]
+fn std_fixed_comparison_test() {
+    // Rust's precision formatting is correctly-rounded from the exact value.
+    let values = [
+        0.1f64,
+        0.3,
+        0.5,
+        1.5,
+        2.5,
+        1.0 / 3.0,
+        core::f64::consts::PI,
+        123456789.12345679,
+        9.87654321e-5,
+        1e22,
+        1.7976931348623157e308,
+        5e-324,
+    ];
+    for &value in values.iter() {
+        for digits in [0, 1, 2, 5, 17, 20, 40, 100] {
+            let mut expected = String::new();
+            write!(expected, "{:.*}", digits, value).unwrap();
+            let actual = write_exact(value, &fixed(digits));
+            assert_eq!(actual, expected);
+        }
+    }
+}
@@ -10,5 +10,5 @@ fn issue_224_test() {
     let mut buffer = [b'\x00'; BUFFER_SIZE];
     let bytes = f.to_lexical_with_options::<RADIX>(&mut buffer, &options::JAVASCRIPT_LITERAL);
     let actual = unsafe { std::str::from_utf8_unchecked(bytes) };
-    assert_eq!(actual, "1.0020200012020012100112000100111212e212221");
+    assert_eq!(actual, "1.0020200012020012100112000100111021e212221");
 }
@@ -71,18 +71,24 @@ fn builder_test() {
     builder = builder.positive_exponent_break(num::NonZeroI32::new(9));
     builder = builder.negative_exponent_break(num::NonZeroI32::new(-9));
     builder = builder.round_mode(options::RoundMode::Truncate);
+    builder = builder.exact_digits(true);
+    builder = builder.fraction_digits(Some(2));
     builder = builder.trim_floats(true);
     builder = builder.exponent(b'^');
     builder = builder.decimal_point(b',');
     builder = builder.nan_string(Some(b"nan"));
     builder = builder.inf_string(Some(b"Infinity"));
+    builder = builder.lowercase_digits(true);
 
     assert_eq!(builder.get_max_significant_digits().unwrap().get(), 10);
     assert_eq!(builder.get_min_significant_digits().unwrap().get(), 5);
     assert_eq!(builder.get_positive_exponent_break().unwrap().get(), 9);
     assert_eq!(builder.get_negative_exponent_break().unwrap().get(), -9);
     assert_eq!(builder.get_round_mode(), options::RoundMode::Truncate);
+    assert!(builder.get_exact_digits());
+    assert_eq!(builder.get_fraction_digits(), Some(2));
     assert_eq!(builder.get_trim_floats(), true);
+    assert!(builder.get_lowercase_digits());
     assert_eq!(builder.get_exponent(), b'^');
     assert_eq!(builder.get_decimal_point(), b',');
     assert_eq!(builder.get_nan_string(), Some("nan".as_bytes()));
@@ -123,3 +129,39 @@ fn options_test() {
     assert_eq!(Options::builder(), OptionsBuilder::new());
     assert_eq!(opts.rebuild().build(), Ok(opts));
 }
+
+#[test]
+fn group_separator_test() {
+    use core::num::NonZeroU8;
+
+    let builder = Options::builder().group_separator(NonZeroU8::new(b','));
+    assert!(builder.is_valid());
+    assert!(builder.clone().group_separator(NonZeroU8::new(b'\'')).is_valid());
+    assert!(!builder.clone().group_separator(NonZeroU8::new(b'.')).is_valid());
+    assert!(!builder.clone().group_separator(NonZeroU8::new(b'e')).is_valid());
+    assert!(!builder.clone().group_separator(NonZeroU8::new(b'5')).is_valid());
+    assert_eq!(
+        builder.group_separator(NonZeroU8::new(b'-')).build(),
+        Err(lexical_write_float::Error::InvalidGroupSeparator)
+    );
+}
+
+#[test]
+fn fraction_digits_test() {
+    use lexical_write_float::format::STANDARD;
+    use lexical_write_float::options::MAX_FRACTION_DIGITS;
+
+    let builder = Options::builder().fraction_digits(Some(MAX_FRACTION_DIGITS));
+    assert!(builder.is_valid());
+    let options = builder.build().unwrap();
+    assert!(options.buffer_size_const::<f64, STANDARD>() > MAX_FRACTION_DIGITS);
+
+    let builder = Options::builder().fraction_digits(Some(MAX_FRACTION_DIGITS + 1));
+    assert!(!builder.is_valid());
+    assert_eq!(builder.build(), Err(lexical_write_float::Error::InvalidFractionDigits));
+
+    // Unchecked options never overflow the buffer size.
+    let options = Options::builder().fraction_digits(Some(usize::MAX)).build_unchecked();
+    assert!(!options.is_valid());
+    assert_eq!(options.buffer_size_const::<f64, STANDARD>(), usize::MAX);
+}
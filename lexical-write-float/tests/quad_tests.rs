@@ -0,0 +1,169 @@
+#![cfg(feature = "f128")]
+
+use core::num;
+
+use lexical_util::format::STANDARD;
+use lexical_write_float::{f128, quad, FormattedSize, Options, ToLexical, ToLexicalWithOptions};
+
+fn shortest_digits(bits: u128, radix: u32) -> (String, i32) {
+    let mut buffer = [0u8; quad::DIGIT_BUFFER_SIZE];
+    let (count, sci_exp) = quad::shortest_digits(f128::from_bits(bits), radix, &mut buffer);
+    let digits = buffer[..count].iter().map(|&x| char::from_digit(x as u32, radix).unwrap());
+    (digits.collect(), sci_exp)
+}
+
+fn write(bits: u128) -> String {
+    let mut buffer = [b'\x00'; f128::FORMATTED_SIZE_DECIMAL];
+    let digits = f128::from_bits(bits).to_lexical(&mut buffer);
+    std::str::from_utf8(digits).unwrap().to_string()
+}
+
+fn write_with_options(bits: u128, options: &Options) -> String {
+    let size = options.buffer_size_const::<f128, STANDARD>();
+    let mut buffer = vec![b'\x00'; size];
+    let float = f128::from_bits(bits);
+    let digits = float.to_lexical_with_options::<STANDARD>(&mut buffer, options);
+    std::str::from_utf8(digits).unwrap().to_string()
+}
+
+#[test]
+fn shortest_digits_test() {
+    assert_eq!(shortest_digits(0, 10), ("0".to_string(), 0));
+    assert_eq!(shortest_digits(0x3FFF0000000000000000000000000000, 10), ("1".to_string(), 0));
+    assert_eq!(shortest_digits(0x3FFB999999999999999999999999999A, 10), ("1".to_string(), -1));
+    assert_eq!(
+        shortest_digits(0x3FFD5555555555555555555555555555, 10),
+        ("3333333333333333333333333333333333".to_string(), -1)
+    );
+    assert_eq!(
+        shortest_digits(0x4000921FB54442D18469898CC51701B8, 10),
+        ("31415926535897932384626433832795028".to_string(), 0)
+    );
+    assert_eq!(shortest_digits(0x43E37E43C8800759BA59C08E14C7CD7B, 10), ("1".to_string(), 300));
+
+    // Limits, including the smallest normal and denormal floats.
+    assert_eq!(
+        shortest_digits(0x7FFEFFFFFFFFFFFFFFFFFFFFFFFFFFFF, 10),
+        ("1189731495357231765085759326628007".to_string(), 4932)
+    );
+    assert_eq!(
+        shortest_digits(0x00010000000000000000000000000000, 10),
+        ("33621031431120935062626778173217526".to_string(), -4932)
+    );
+    assert_eq!(shortest_digits(1, 10), ("6".to_string(), -4966));
+    assert_eq!(shortest_digits(3, 10), ("2".to_string(), -4965));
+
+    // Other radices.
+    assert_eq!(shortest_digits(0x3FFB999999999999999999999999999A, 2).1, -4);
+    assert_eq!(shortest_digits(1, 3), ("2".to_string(), -10407));
+    assert_eq!(shortest_digits(1, 36), ("9".to_string(), -3191));
+    assert_eq!(
+        shortest_digits(0x3FFB999999999999999999999999999A, 36),
+        ("3lllllllllllllllllllllp".to_string(), -1)
+    );
+    assert_eq!(
+        shortest_digits(0x7FFEFFFFFFFFFFFFFFFFFFFFFFFFFFFF, 36),
+        ("1f6lae5cv1t4ejmcmj6v0fo".to_string(), 3169)
+    );
+}
+
+#[test]
+fn write_test() {
+    assert_eq!(write(0), "0.0");
+    assert_eq!(write(1 << 127), "-0.0");
+    assert_eq!(write(0x3FFF0000000000000000000000000000), "1.0");
+    assert_eq!(write(0x3FFB999999999999999999999999999A), "0.1");
+    assert_eq!(write(0xBFFE5555555555555555555555555555), "-0.6666666666666666666666666666666666");
+    assert_eq!(write(0x4000921FB54442D18469898CC51701B8), "3.1415926535897932384626433832795028");
+    assert_eq!(write(0xBC1A56E1FC2F8F358D94DB7AC6149156), "-1.0e-300");
+    assert_eq!(
+        write(0x7FFEFFFFFFFFFFFFFFFFFFFFFFFFFFFF),
+        "1.189731495357231765085759326628007e4932"
+    );
+    assert_eq!(write(1), "6.0e-4966");
+    assert_eq!(write(0x7FFF8000000000000000000000000000), "NaN");
+    assert_eq!(write(0xFFFF0000000000000000000000000000), "-inf");
+}
+
+#[test]
+fn write_options_test() {
+    let options =
+        Options::builder().max_significant_digits(num::NonZeroUsize::new(5)).build_strict();
+    assert_eq!(write_with_options(0x4000921FB54442D18469898CC51701B8, &options), "3.1416");
+    assert_eq!(write_with_options(0x7FFEFFFFFFFFFFFFFFFFFFFFFFFFFFFF, &options), "1.1897e4932");
+
+    let options = Options::builder()
+        .max_significant_digits(num::NonZeroUsize::new(2))
+        .min_significant_digits(num::NonZeroUsize::new(2))
+        .positive_exponent_break(num::NonZeroI32::new(5000))
+        .negative_exponent_break(num::NonZeroI32::new(-5000))
+        .build_strict();
+    let value = write_with_options(0x7FFEFFFFFFFFFFFFFFFFFFFFFFFFFFFF, &options);
+    assert_eq!(value.len(), 4935);
+    assert!(value.starts_with("12000") && value.ends_with(".0"));
+    let value = write_with_options(1, &options);
+    assert_eq!(value.len(), 4969);
+    assert!(value.ends_with("00060"));
+}
+
+#[test]
+fn write_exact_digits_test() {
+    let options = Options::builder().exact_digits(true).build_strict();
+    assert_eq!(write_with_options(0x3FFF0000000000000000000000000000, &options), "1.0");
+    assert_eq!(
+        write_with_options(0x3FFB999999999999999999999999999A, &options),
+        "0.1000000000000000000000000000000000048148248609680896326399448564623182963452541205384704880998469889163970947265625"
+    );
+
+    // The smallest denormal float has the most significant digits.
+    let value = write_with_options(1, &options);
+    assert_eq!(value.len(), 11536);
+    assert!(value.starts_with("6.4751751194380251109244389582276465524995"));
+    assert!(value.ends_with("5625e-4966"));
+
+    let options = Options::builder()
+        .exact_digits(true)
+        .max_significant_digits(num::NonZeroUsize::new(40))
+        .build_strict();
+    assert_eq!(
+        write_with_options(0x4000921FB54442D18469898CC51701B8, &options),
+        "3.141592653589793238462643383279502797479"
+    );
+}
+
+#[test]
+fn write_fraction_digits_test() {
+    let options = Options::builder().fraction_digits(Some(2)).build_strict();
+    assert_eq!(write_with_options(0x4000921FB54442D18469898CC51701B8, &options), "3.14");
+    assert_eq!(write_with_options(0xBFFE5555555555555555555555555555, &options), "-0.67");
+    assert_eq!(write_with_options(1, &options), "0.00");
+
+    // The largest float has every integer digit.
+    let value = write_with_options(0x7FFEFFFFFFFFFFFFFFFFFFFFFFFFFFFF, &options);
+    assert_eq!(value.len(), 4936);
+    assert!(value.starts_with("1189731495357231765085759326628007") && value.ends_with(".00"));
+
+    let options = Options::builder().fraction_digits(Some(40)).build_strict();
+    assert_eq!(
+        write_with_options(0x3FFB999999999999999999999999999A, &options),
+        "0.1000000000000000000000000000000000048148"
+    );
+}
+
+#[test]
+#[cfg(feature = "radix")]
+fn write_radix_test() {
+    use lexical_util::format::NumberFormatBuilder;
+
+    const BASE3: u128 = NumberFormatBuilder::from_radix(3);
+    let options = Options::new();
+    let mut buffer = [b'\x00'; 1024];
+    let mut write3 = |bits: u128| {
+        let float = f128::from_bits(bits);
+        let digits = float.to_lexical_with_options::<BASE3>(&mut buffer, &options);
+        std::str::from_utf8(digits).unwrap().to_string()
+    };
+    assert_eq!(write3(0x3FFD5555555555555555555555555555), "0.1");
+    assert_eq!(write3(0x40022000000000000000000000000000), "100.0");
+    assert_eq!(write3(1), "2.0e-112021110");
+}
@@ -0,0 +1,61 @@
+#![cfg(feature = "radix")]
+
+use lexical_util::format::NumberFormatBuilder;
+use lexical_util::num::Float;
+use lexical_write_float::bigint::BIGINT_LIMBS;
+use lexical_write_float::{radix, shortest, Options, ToLexicalWithOptions};
+
+const BASE3: u128 = NumberFormatBuilder::from_radix(3);
+const BASE36: u128 = NumberFormatBuilder::from_radix(36);
+
+fn shortest_digits<F: Float>(float: F, radix: u32) -> (String, i32) {
+    let mut buffer = [0u8; radix::DIGIT_BUFFER_SIZE];
+    let (count, sci_exp) = shortest::shortest_digits::<_, BIGINT_LIMBS>(float, radix, &mut buffer);
+    let digits = buffer[..count].iter().map(|&x| char::from_digit(x as u32, radix).unwrap());
+    (digits.collect(), sci_exp)
+}
+
+fn write<const FORMAT: u128>(float: f64, options: &Options) -> String {
+    let mut buffer = [b'\x00'; 1024];
+    let digits = float.to_lexical_with_options::<FORMAT>(&mut buffer, options);
+    std::str::from_utf8(digits).unwrap().to_string()
+}
+
+#[test]
+fn shortest_digits_test() {
+    assert_eq!(shortest_digits(0.0f64, 3), ("0".to_string(), 0));
+    assert_eq!(shortest_digits(1.0f64 / 3.0, 3), ("1".to_string(), -1));
+    assert_eq!(shortest_digits(1.5f64, 6), ("13".to_string(), 0));
+    assert_eq!(shortest_digits(0.1f64, 3), ("22002200220022002200220022002201".to_string(), -3));
+    assert_eq!(shortest_digits(0.1f64, 36), ("3lllllllllm".to_string(), -1));
+    assert_eq!(shortest_digits(1e100f64, 36), ("2hqbczu2ow5".to_string(), 64));
+    assert_eq!(shortest_digits(123456.789f64, 12), ("5b5409574854619".to_string(), 4));
+
+    // Limits, including the smallest normal and denormal floats.
+    assert_eq!(
+        shortest_digits(f64::MAX, 3),
+        ("10020200012020012100112000100111021".to_string(), 646)
+    );
+    assert_eq!(shortest_digits(f64::MAX, 36), ("1a1e4vngail".to_string(), 198));
+    assert_eq!(shortest_digits(f64::MIN_POSITIVE, 36), ("34lmua2oeuw".to_string(), -198));
+    assert_eq!(shortest_digits(5e-324f64, 3), ("2".to_string(), -678));
+    assert_eq!(shortest_digits(5e-324f64, 7), ("2".to_string(), -383));
+    assert_eq!(shortest_digits(5e-324f64, 36), ("3".to_string(), -208));
+
+    // Single-precision floats.
+    assert_eq!(shortest_digits(0.1f32, 3), ("2200220022002201".to_string(), -3));
+    assert_eq!(shortest_digits(1e-45f32, 36), ("2".to_string(), -29));
+    assert_eq!(shortest_digits(f32::MAX, 36), ("f5lxw".to_string(), 24));
+}
+
+#[test]
+fn write_float_test() {
+    let options = Options::new();
+    assert_eq!(write::<BASE3>(0.1, &options), "0.0022002200220022002200220022002201");
+    assert_eq!(write::<BASE3>(1.0 / 3.0, &options), "0.1");
+    assert_eq!(write::<BASE3>(0.5, &options), "0.1111111111111111111111111111111112");
+    assert_eq!(write::<BASE3>(5e-324, &options), "2.0e-221010");
+    assert_eq!(write::<BASE36>(0.1, &options), "0.3LLLLLLLLLM");
+    assert_eq!(write::<BASE36>(1e100, &options), "2.HQBCZU2OW5e1S");
+    assert_eq!(write::<BASE36>(5e-324, &options), "3.0e-5S");
+}
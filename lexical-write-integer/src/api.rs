@@ -3,12 +3,16 @@
 #![doc(hidden)]
 
 use lexical_util::format::{NumberFormat, STANDARD};
+use lexical_util::grouping::{group_sizes, insert_separators};
 use lexical_util::num::SignedInteger;
 use lexical_util::{to_lexical, to_lexical_with_options};
 
 use crate::options::Options;
 use crate::write::WriteInteger;
 
+/// Default options for writing without any grouping.
+const DEFAULT_OPTIONS: Options = Options::new();
+
 // UNSIGNED
 
 /// Callback for unsigned integer formatter.
@@ -18,7 +22,7 @@ use crate::write::WriteInteger;
 /// Safe as long as the buffer can hold `FORMATTED_SIZE` elements
 /// (or `FORMATTED_SIZE_DECIMAL` for decimal).
 #[cfg_attr(not(feature = "compact"), inline(always))]
-fn unsigned<T, const FORMAT: u128>(value: T, buffer: &mut [u8]) -> usize
+fn unsigned<T, const FORMAT: u128>(value: T, buffer: &mut [u8], options: &Options) -> usize
 where
     T: WriteInteger,
 {
@@ -26,9 +30,9 @@ where
     if cfg!(feature = "format") && format.required_mantissa_sign() {
         buffer[0] = b'+';
         let buffer = &mut buffer[1..];
-        value.write_mantissa::<FORMAT>(buffer) + 1
+        write_digits::<FORMAT, _>(buffer, options, |b| value.write_mantissa::<FORMAT>(b)) + 1
     } else {
-        value.write_mantissa::<FORMAT>(buffer)
+        write_digits::<FORMAT, _>(buffer, options, |b| value.write_mantissa::<FORMAT>(b))
     }
 }
 
@@ -41,7 +45,11 @@ where
 /// Safe as long as the buffer can hold `FORMATTED_SIZE` elements
 /// (or `FORMATTED_SIZE_DECIMAL` for decimal).
 #[cfg_attr(not(feature = "compact"), inline(always))]
-fn signed<Signed, Unsigned, const FORMAT: u128>(value: Signed, buffer: &mut [u8]) -> usize
+fn signed<Signed, Unsigned, const FORMAT: u128>(
+    value: Signed,
+    buffer: &mut [u8],
+    options: &Options,
+) -> usize
 where
     Signed: SignedInteger,
     Unsigned: WriteInteger,
@@ -55,15 +63,69 @@ where
         let unsigned = Unsigned::as_cast(value.wrapping_neg());
         buffer[0] = b'-';
         let buffer = &mut buffer[1..];
-        unsigned.write_mantissa_signed::<FORMAT>(buffer) + 1
+        write_digits::<FORMAT, _>(buffer, options, |b| unsigned.write_mantissa_signed::<FORMAT>(b))
+            + 1
     } else if cfg!(feature = "format") && format.required_mantissa_sign() {
         let unsigned = Unsigned::as_cast(value);
         buffer[0] = b'+';
         let buffer = &mut buffer[1..];
-        unsigned.write_mantissa_signed::<FORMAT>(buffer) + 1
+        write_digits::<FORMAT, _>(buffer, options, |b| unsigned.write_mantissa_signed::<FORMAT>(b))
+            + 1
     } else {
         let unsigned = Unsigned::as_cast(value);
-        unsigned.write_mantissa_signed::<FORMAT>(buffer)
+        write_digits::<FORMAT, _>(buffer, options, |b| unsigned.write_mantissa_signed::<FORMAT>(b))
+    }
+}
+
+// DIGITS
+
+/// Write the digits after any sign, with the base prefix and suffix.
+///
+/// The base prefix is a `0` followed by the prefix character, so the
+/// digits are written as `0x1f` and not `x1f`, which is what the parsers
+/// expect for the same format.
+#[cfg_attr(not(feature = "compact"), inline(always))]
+pub(crate) fn write_digits<const FORMAT: u128, Cb>(
+    bytes: &mut [u8],
+    options: &Options,
+    cb: Cb,
+) -> usize
+where
+    Cb: FnOnce(&mut [u8]) -> usize,
+{
+    let format = NumberFormat::<FORMAT> {};
+    let prefix = if cfg!(feature = "format") && format.has_base_prefix() {
+        bytes[0] = b'0';
+        bytes[1] = format.base_prefix();
+        2
+    } else {
+        0
+    };
+    let bytes = &mut bytes[prefix..];
+    let length = cb(bytes);
+    if options.lowercase_digits() {
+        bytes[..length].make_ascii_lowercase();
+    }
+    let length = group_digits(bytes, length, options);
+    if cfg!(feature = "format") && format.has_base_suffix() {
+        bytes[length] = format.base_suffix();
+        prefix + length + 1
+    } else {
+        prefix + length
+    }
+}
+
+// GROUPING
+
+/// Insert separators between groups of the written digits.
+#[cfg_attr(not(feature = "compact"), inline(always))]
+fn group_digits(bytes: &mut [u8], length: usize, options: &Options) -> usize {
+    if let Some(separator) = options.group_separator() {
+        let (primary, secondary) =
+            group_sizes(options.group_size(), options.secondary_group_size());
+        insert_separators(bytes, length, length, separator.get(), primary, secondary)
+    } else {
+        length
     }
 }
 
@@ -77,7 +139,7 @@ macro_rules! unsigned_to_lexical {
             fn to_lexical(self, bytes: &mut [u8])
                 -> &mut [u8]
             {
-                let len = unsigned::<$t, { STANDARD }>(self, bytes);
+                let len = unsigned::<$t, { STANDARD }>(self, bytes, &DEFAULT_OPTIONS);
                 &mut bytes[..len]
             }
         }
@@ -92,9 +154,8 @@ macro_rules! unsigned_to_lexical {
                 options: &Self::Options,
             ) -> &'a mut [u8]
             {
-                _ = options;
                 assert!(NumberFormat::<{ FORMAT }> {}.is_valid());
-                let len = unsigned::<$t, FORMAT>(self, bytes);
+                let len = unsigned::<$t, FORMAT>(self, bytes, options);
                 &mut bytes[..len]
             }
         }
@@ -113,7 +174,7 @@ macro_rules! signed_to_lexical {
             fn to_lexical(self, bytes: &mut [u8])
                 -> &mut [u8]
             {
-                let len = signed::<$signed, $unsigned, { STANDARD }>(self, bytes);
+                let len = signed::<$signed, $unsigned, { STANDARD }>(self, bytes, &DEFAULT_OPTIONS);
                 &mut bytes[..len]
             }
         }
@@ -127,9 +188,8 @@ macro_rules! signed_to_lexical {
                 options: &Self::Options,
             ) -> &'a mut [u8]
             {
-                _ = options;
                 assert!(NumberFormat::<{ FORMAT }> {}.is_valid());
-                let len = signed::<$signed, $unsigned, FORMAT>(self, bytes);
+                let len = signed::<$signed, $unsigned, FORMAT>(self, bytes, options);
                 &mut bytes[..len]
             }
         }
@@ -0,0 +1,164 @@
+//! Write arbitrary-precision integers.
+//!
+//! This converts the limbs to digits with a subquadratic algorithm, and
+//! then writes the digits with the same sign, base prefix and suffix, and
+//! digit grouping as the integer writers.
+
+#![cfg(feature = "bigint")]
+
+use lexical_util::bigint::{BigInt, BigUint};
+use lexical_util::digit::digit_to_char;
+use lexical_util::format::NumberFormat;
+use lexical_util::grouping::{group_sizes, separator_count};
+
+use crate::api::write_digits;
+use crate::options::Options;
+
+/// Get an upper bound on the number of digits to write the value.
+#[inline(always)]
+fn digit_count<const FORMAT: u128>(value: &BigUint) -> usize {
+    // Each digit stores at least `floor(log2(radix))` bits.
+    let radix = NumberFormat::<{ FORMAT }>::MANTISSA_RADIX;
+    let bits_per_digit = (31 - radix.leading_zeros()) as usize;
+    let bits = value.bit_length();
+    ((bits + bits_per_digit - 1) / bits_per_digit).max(1)
+}
+
+/// Get an upper bound on the required buffer size to write an unsigned
+/// big integer.
+///
+/// This includes room for the sign, any group separators, and the base
+/// prefix and suffix.
+///
+/// # Examples
+///
+/// ```rust
+/// use lexical_write_integer::{biguint_buffer_size, format, BigUint, Options};
+///
+/// const FORMAT: u128 = format::STANDARD;
+/// const OPTIONS: Options = Options::new();
+/// let value = BigUint::from(u128::MAX);
+/// assert!(biguint_buffer_size::<FORMAT>(&value, &OPTIONS) >= 39);
+/// ```
+#[inline]
+pub fn biguint_buffer_size<const FORMAT: u128>(value: &BigUint, options: &Options) -> usize {
+    let format = NumberFormat::<{ FORMAT }> {};
+    let digits = digit_count::<FORMAT>(value);
+    // The prefix is a `0` and the prefix character.
+    let base_size = 2 * format.has_base_prefix() as usize + format.has_base_suffix() as usize;
+    let separators = if options.group_separator().is_some() {
+        let (primary, secondary) =
+            group_sizes(options.group_size(), options.secondary_group_size());
+        separator_count(digits, primary, secondary)
+    } else {
+        0
+    };
+    1 + digits + base_size + separators
+}
+
+/// Get an upper bound on the required buffer size to write a signed big
+/// integer.
+///
+/// See [`biguint_buffer_size`] for more details.
+#[inline]
+pub fn bigint_buffer_size<const FORMAT: u128>(value: &BigInt, options: &Options) -> usize {
+    biguint_buffer_size::<FORMAT>(value.magnitude(), options)
+}
+
+/// Write the sign and the digits of a big integer.
+fn write_big<const FORMAT: u128>(
+    is_negative: bool,
+    value: &BigUint,
+    bytes: &mut [u8],
+    options: &Options,
+) -> usize {
+    let format = NumberFormat::<{ FORMAT }> {};
+    assert!(format.is_valid());
+    let sign = if is_negative {
+        Some(b'-')
+    } else if cfg!(feature = "format") && format.required_mantissa_sign() {
+        Some(b'+')
+    } else {
+        None
+    };
+    let (bytes, count) = match sign {
+        Some(sign) => {
+            bytes[0] = sign;
+            (&mut bytes[1..], 1)
+        },
+        None => (bytes, 0),
+    };
+
+    let digits = value.to_digits(format.mantissa_radix());
+    count
+        + write_digits::<FORMAT, _>(bytes, options, |buffer| {
+            for (byte, &digit) in buffer[..digits.len()].iter_mut().zip(digits.iter()) {
+                *byte = digit_to_char(digit as u32);
+            }
+            digits.len()
+        })
+}
+
+/// Write an unsigned big integer to the buffer.
+///
+/// This writes the integer exactly like
+/// [`to_lexical_with_options`][crate::ToLexicalWithOptions::to_lexical_with_options],
+/// and returns the subslice of the buffer containing the written bytes.
+///
+/// # Panics
+///
+/// Panics if the number format is invalid, or if the buffer is smaller
+/// than [`biguint_buffer_size`].
+///
+/// # Examples
+///
+/// ```rust
+/// use lexical_write_integer::{biguint_buffer_size, format, write_biguint, BigUint, Options};
+///
+/// const FORMAT: u128 = format::STANDARD;
+/// const OPTIONS: Options = Options::new();
+/// let value = BigUint::from(u128::MAX);
+/// let mut buffer = vec![0u8; biguint_buffer_size::<FORMAT>(&value, &OPTIONS)];
+/// let digits = write_biguint::<FORMAT>(&value, &mut buffer, &OPTIONS);
+/// assert_eq!(digits, b"340282366920938463463374607431768211455");
+/// ```
+#[inline]
+pub fn write_biguint<'a, const FORMAT: u128>(
+    value: &BigUint,
+    bytes: &'a mut [u8],
+    options: &Options,
+) -> &'a mut [u8] {
+    let count = write_big::<FORMAT>(false, value, bytes, options);
+    &mut bytes[..count]
+}
+
+/// Write a signed big integer to the buffer.
+///
+/// See [`write_biguint`] for more details.
+///
+/// # Panics
+///
+/// Panics if the number format is invalid, or if the buffer is smaller
+/// than [`bigint_buffer_size`].
+///
+/// # Examples
+///
+/// ```rust
+/// use lexical_write_integer::{bigint_buffer_size, format, write_bigint, BigInt, Options};
+///
+/// const FORMAT: u128 = format::STANDARD;
+/// const OPTIONS: Options = Options::new();
+/// let value = BigInt::from(i128::MIN);
+/// let mut buffer = vec![0u8; bigint_buffer_size::<FORMAT>(&value, &OPTIONS)];
+/// let digits = write_bigint::<FORMAT>(&value, &mut buffer, &OPTIONS);
+/// assert_eq!(digits, b"-170141183460469231731687303715884105728");
+/// ```
+#[inline]
+pub fn write_bigint<'a, const FORMAT: u128>(
+    value: &BigInt,
+    bytes: &'a mut [u8],
+    options: &Options,
+) -> &'a mut [u8] {
+    let count = write_big::<FORMAT>(value.is_negative(), value.magnitude(), bytes, options);
+    &mut bytes[..count]
+}
@@ -28,11 +28,12 @@
 //!
 //! # Features
 //!
-//! * `format` - Add support for custom integer formatting (currently
-//!   unsupported).
+//! * `format` - Add support for custom integer formatting.
 //! * `power-of-two` - Add support for writing power-of-two integer strings.
 //! * `radix` - Add support for strings of any radix.
 //! * `compact` - Reduce code size at the cost of performance.
+//! * `bigint` - Add support for writing arbitrary-precision integers. This
+//!   requires the `std` feature.
 //! * `std` (Default) - Disable to allow use in a [`no_std`] environment.
 //!
 //! [`no_std`]: https://docs.rust-embedded.org/book/intro/no-std.html
@@ -41,10 +42,12 @@
 //!
 //! #### format
 //!
-//! Add support for custom integer formatting. Currently no custom styles are
-//! supported but this could include digit [`separator`] support in the future.
+//! Add support for custom integer formatting, such as a required sign or a
+//! [`base prefix`] and [`base suffix`], like `0x1F`. The sign is always
+//! written before the base prefix.
 //!
-//! [`separator`]: NumberFormatBuilder::digit_separator
+//! [`base prefix`]: NumberFormatBuilder::base_prefix
+//! [`base suffix`]: NumberFormatBuilder::base_suffix
 //!
 //! <!--
 //! For a list of all supported fields, see [Write Integer
@@ -347,6 +350,7 @@
 )]
 
 pub mod algorithm;
+pub mod bigint;
 pub mod compact;
 pub mod decimal;
 pub mod digit_count;
@@ -362,6 +366,8 @@ mod table_decimal;
 mod table_radix;
 
 // Re-exports
+#[cfg(feature = "bigint")]
+pub use lexical_util::bigint::{BigInt, BigUint};
 pub use lexical_util::constants::{FormattedSize, BUFFER_SIZE};
 pub use lexical_util::error::Error;
 pub use lexical_util::format::{self, NumberFormat, NumberFormatBuilder};
@@ -369,5 +375,8 @@ pub use lexical_util::options::WriteOptions;
 pub use lexical_util::result::Result;
 
 pub use self::api::{ToLexical, ToLexicalWithOptions};
+#[cfg(feature = "bigint")]
+#[doc(inline)]
+pub use self::bigint::{bigint_buffer_size, biguint_buffer_size, write_bigint, write_biguint};
 #[doc(inline)]
 pub use self::options::{Options, OptionsBuilder};
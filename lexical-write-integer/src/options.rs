@@ -1,27 +1,30 @@
 //! Configuration options for writing integers.
 //!
-//! This currently has no functionality, since we do not
-//! support any features for writing integers at this time.
+//! This supports grouping the digits with a separator, such as
+//! `1,234,567`, and writing lowercase digits for radixes above 10.
 //!
 //! # Examples
 //!
 //! ```rust
-//! # use core::str;
+//! # use core::{num, str};
 //! use lexical_write_integer::{Options, ToLexicalWithOptions};
 //! use lexical_write_integer::format::STANDARD;
 //!
 //! const OPTIONS: Options = Options::builder()
+//!     .group_separator(num::NonZeroU8::new(b','))
 //!     .build_strict();
 //!
 //! const BUFFER_SIZE: usize = OPTIONS.buffer_size_const::<u64, STANDARD>();
 //! let mut buffer = [0u8; BUFFER_SIZE];
-//! let value = 1234u64;
+//! let value = 1234567u64;
 //! let digits = value.to_lexical_with_options::<STANDARD>(&mut buffer, &OPTIONS);
-//! assert_eq!(str::from_utf8(digits), Ok("1234"));
+//! assert_eq!(str::from_utf8(digits), Ok("1,234,567"));
 //! ```
 
 use lexical_util::constants::FormattedSize;
-use lexical_util::format::NumberFormat;
+use lexical_util::error::Error;
+use lexical_util::format::{NumberFormat, OptionU8};
+use lexical_util::grouping::{group_sizes, is_valid_group_separator, separator_count};
 use lexical_util::options::WriteOptions;
 use lexical_util::result::Result;
 
@@ -45,13 +48,189 @@ use lexical_util::result::Result;
 /// assert_eq!(str::from_utf8(digits), Ok("1234"));
 /// ```
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
-pub struct OptionsBuilder {}
+pub struct OptionsBuilder {
+    /// Character to separate groups of digits.
+    group_separator: OptionU8,
+
+    /// Number of digits in the least significant group.
+    group_size: OptionU8,
+
+    /// Number of digits in the remaining groups.
+    secondary_group_size: OptionU8,
+
+    /// Write lowercase digits for radixes above 10.
+    lowercase_digits: bool,
+}
 
 impl OptionsBuilder {
     /// Create new options builder with default options.
     #[inline(always)]
     pub const fn new() -> Self {
-        Self {}
+        Self {
+            group_separator: None,
+            group_size: None,
+            secondary_group_size: None,
+            lowercase_digits: false,
+        }
+    }
+
+    // GETTERS
+
+    /// Get the character to separate groups of digits.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lexical_write_integer::Options;
+    ///
+    /// let builder = Options::builder();
+    /// assert_eq!(builder.get_group_separator(), None);
+    /// ```
+    #[inline(always)]
+    pub const fn get_group_separator(&self) -> OptionU8 {
+        self.group_separator
+    }
+
+    /// Get the number of digits in the least significant group.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lexical_write_integer::Options;
+    ///
+    /// let builder = Options::builder();
+    /// assert_eq!(builder.get_group_size(), None);
+    /// ```
+    #[inline(always)]
+    pub const fn get_group_size(&self) -> OptionU8 {
+        self.group_size
+    }
+
+    /// Get the number of digits in the remaining groups.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lexical_write_integer::Options;
+    ///
+    /// let builder = Options::builder();
+    /// assert_eq!(builder.get_secondary_group_size(), None);
+    /// ```
+    #[inline(always)]
+    pub const fn get_secondary_group_size(&self) -> OptionU8 {
+        self.secondary_group_size
+    }
+
+    /// Get if lowercase digits are written for radixes above 10.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lexical_write_integer::Options;
+    ///
+    /// let builder = Options::builder();
+    /// assert_eq!(builder.get_lowercase_digits(), false);
+    /// ```
+    #[inline(always)]
+    pub const fn get_lowercase_digits(&self) -> bool {
+        self.lowercase_digits
+    }
+
+    // SETTERS
+
+    /// Set the character to separate groups of digits.
+    ///
+    /// If set, the digits are grouped from the least significant digit,
+    /// such as `1,234,567`. The separator must be a valid ASCII character
+    /// that is not a digit or a sign. Defaults to `None`, which does not
+    /// group digits.
+    ///
+    /// To parse the written integers, use a number format with the same
+    /// digit separator and internal integer digit separators.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use core::num::NonZeroU8;
+    ///
+    /// use lexical_write_integer::Options;
+    ///
+    /// let builder = Options::builder()
+    ///     .group_separator(NonZeroU8::new(b','));
+    /// assert_eq!(builder.get_group_separator(), NonZeroU8::new(b','));
+    /// ```
+    #[inline(always)]
+    pub const fn group_separator(mut self, group_separator: OptionU8) -> Self {
+        self.group_separator = group_separator;
+        self
+    }
+
+    /// Set the number of digits in the least significant group.
+    ///
+    /// Defaults to `None`, which uses groups of 3 digits.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use core::num::NonZeroU8;
+    ///
+    /// use lexical_write_integer::Options;
+    ///
+    /// let builder = Options::builder()
+    ///     .group_size(NonZeroU8::new(4));
+    /// assert_eq!(builder.get_group_size(), NonZeroU8::new(4));
+    /// ```
+    #[inline(always)]
+    pub const fn group_size(mut self, group_size: OptionU8) -> Self {
+        self.group_size = group_size;
+        self
+    }
+
+    /// Set the number of digits in the remaining groups.
+    ///
+    /// For example, Indian grouping uses a group of 3 digits followed by
+    /// groups of 2 digits, such as `12,34,567`. Defaults to `None`, which
+    /// uses the [`group_size`] for all groups.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use core::num::NonZeroU8;
+    ///
+    /// use lexical_write_integer::Options;
+    ///
+    /// let builder = Options::builder()
+    ///     .secondary_group_size(NonZeroU8::new(2));
+    /// assert_eq!(builder.get_secondary_group_size(), NonZeroU8::new(2));
+    /// ```
+    ///
+    /// [`group_size`]: Self::group_size
+    #[inline(always)]
+    pub const fn secondary_group_size(mut self, secondary_group_size: OptionU8) -> Self {
+        self.secondary_group_size = secondary_group_size;
+        self
+    }
+
+    /// Set if lowercase digits are written for radixes above 10.
+    ///
+    /// Digits with a value of 10 or more are written as `A-Z` by default,
+    /// such as `FF` for 255 in hexadecimal. If set, they are written as
+    /// `a-z` instead, such as `ff`. This does not affect the base prefix
+    /// or suffix. Defaults to `false`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lexical_write_integer::Options;
+    ///
+    /// let builder = Options::builder()
+    ///     .lowercase_digits(true);
+    /// assert_eq!(builder.get_lowercase_digits(), true);
+    /// ```
+    #[inline(always)]
+    pub const fn lowercase_digits(mut self, lowercase_digits: bool) -> Self {
+        self.lowercase_digits = lowercase_digits;
+        self
     }
 
     // BUILDERS
@@ -59,7 +238,7 @@ impl OptionsBuilder {
     /// Check if the builder state is valid.
     #[inline(always)]
     pub const fn is_valid(&self) -> bool {
-        true
+        is_valid_group_separator(self.group_separator)
     }
 
     /// Build the [`Options`] struct without validation.
@@ -75,18 +254,20 @@ impl OptionsBuilder {
     /// [`is_valid`]: Self::is_valid
     #[inline(always)]
     pub const fn build_unchecked(&self) -> Options {
-        Options {}
+        Options {
+            group_separator: self.group_separator,
+            group_size: self.group_size,
+            secondary_group_size: self.secondary_group_size,
+            lowercase_digits: self.lowercase_digits,
+        }
     }
 
-    /// Build the [`Options`] struct.
-    ///
-    /// This can never panic.
+    /// Build the [`Options`] struct, panicking if the builder is invalid.
     ///
-    /// <!-- # Panics
+    /// # Panics
     ///
     /// If the built options are not valid. This should always
     /// be used within a const context to avoid panics at runtime.
-    /// -->
     #[inline(always)]
     pub const fn build_strict(&self) -> Options {
         match self.build() {
@@ -96,9 +277,16 @@ impl OptionsBuilder {
     }
 
     /// Build the [`Options`] struct.
+    ///
+    /// If the format is not valid, than an error is returned,
+    /// otherwise, the successful value is returned.
     #[inline(always)]
     pub const fn build(&self) -> Result<Options> {
-        Ok(self.build_unchecked())
+        if !is_valid_group_separator(self.group_separator) {
+            Err(Error::InvalidGroupSeparator)
+        } else {
+            Ok(self.build_unchecked())
+        }
     }
 }
 
@@ -128,16 +316,26 @@ impl Default for OptionsBuilder {
 /// let digits = value.to_lexical_with_options::<STANDARD>(&mut buffer, &OPTIONS);
 /// assert_eq!(str::from_utf8(digits), Ok("1234"));
 /// ```
-// FIXME: Add phantom data for private fields.
-//  This is a BREAKING change so requires a major API release.
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
-pub struct Options {}
+pub struct Options {
+    /// Character to separate groups of digits.
+    group_separator: OptionU8,
+
+    /// Number of digits in the least significant group.
+    group_size: OptionU8,
+
+    /// Number of digits in the remaining groups.
+    secondary_group_size: OptionU8,
+
+    /// Write lowercase digits for radixes above 10.
+    lowercase_digits: bool,
+}
 
 impl Options {
     /// Create options with default values.
     #[inline(always)]
     pub const fn new() -> Self {
-        Self {}
+        Self::builder().build_unchecked()
     }
 
     /// Create the default options for a given radix.
@@ -150,23 +348,109 @@ impl Options {
     /// Check if the options state is valid.
     #[inline(always)]
     pub const fn is_valid(&self) -> bool {
-        true
+        self.rebuild().is_valid()
     }
 
     /// Get an upper bound on the required buffer size.
     ///
-    /// This is always [`FORMATTED_SIZE`][FormattedSize::FORMATTED_SIZE]
+    /// This is [`FORMATTED_SIZE`][FormattedSize::FORMATTED_SIZE]
     /// or [`FORMATTED_SIZE_DECIMAL`][FormattedSize::FORMATTED_SIZE_DECIMAL],
-    /// depending on the radix.
+    /// depending on the radix, plus room for any group separators and
+    /// the base prefix and suffix.
     #[inline(always)]
     pub const fn buffer_size_const<T: FormattedSize, const FORMAT: u128>(&self) -> usize {
-        if (NumberFormat::<FORMAT> {}.radix()) == 10 {
+        let format = NumberFormat::<FORMAT> {};
+        let formatted_size = if format.radix() == 10 {
             T::FORMATTED_SIZE_DECIMAL
         } else {
             T::FORMATTED_SIZE
+        };
+        // The prefix is a `0` and the prefix character.
+        let base_size = 2 * format.has_base_prefix() as usize + format.has_base_suffix() as usize;
+        if self.group_separator.is_some() {
+            let (primary, secondary) = group_sizes(self.group_size, self.secondary_group_size);
+            formatted_size + base_size + separator_count(formatted_size, primary, secondary)
+        } else {
+            formatted_size + base_size
         }
     }
 
+    // GETTERS
+
+    /// Get the character to separate groups of digits.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use core::num::NonZeroU8;
+    ///
+    /// use lexical_write_integer::Options;
+    ///
+    /// const OPTIONS: Options = Options::builder()
+    ///     .group_separator(NonZeroU8::new(b'_'))
+    ///     .build_strict();
+    /// assert_eq!(OPTIONS.group_separator(), NonZeroU8::new(b'_'));
+    /// ```
+    #[inline(always)]
+    pub const fn group_separator(&self) -> OptionU8 {
+        self.group_separator
+    }
+
+    /// Get the number of digits in the least significant group.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use core::num::NonZeroU8;
+    ///
+    /// use lexical_write_integer::Options;
+    ///
+    /// const OPTIONS: Options = Options::builder()
+    ///     .group_size(NonZeroU8::new(4))
+    ///     .build_strict();
+    /// assert_eq!(OPTIONS.group_size(), NonZeroU8::new(4));
+    /// ```
+    #[inline(always)]
+    pub const fn group_size(&self) -> OptionU8 {
+        self.group_size
+    }
+
+    /// Get the number of digits in the remaining groups.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use core::num::NonZeroU8;
+    ///
+    /// use lexical_write_integer::Options;
+    ///
+    /// const OPTIONS: Options = Options::builder()
+    ///     .secondary_group_size(NonZeroU8::new(2))
+    ///     .build_strict();
+    /// assert_eq!(OPTIONS.secondary_group_size(), NonZeroU8::new(2));
+    /// ```
+    #[inline(always)]
+    pub const fn secondary_group_size(&self) -> OptionU8 {
+        self.secondary_group_size
+    }
+
+    /// Get if lowercase digits are written for radixes above 10.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lexical_write_integer::Options;
+    ///
+    /// const OPTIONS: Options = Options::builder()
+    ///     .lowercase_digits(true)
+    ///     .build_strict();
+    /// assert_eq!(OPTIONS.lowercase_digits(), true);
+    /// ```
+    #[inline(always)]
+    pub const fn lowercase_digits(&self) -> bool {
+        self.lowercase_digits
+    }
+
     // BUILDERS
 
     /// Get [`OptionsBuilder`] as a static function.
@@ -178,7 +462,12 @@ impl Options {
     /// Create [`OptionsBuilder`] using existing values.
     #[inline(always)]
     pub const fn rebuild(&self) -> OptionsBuilder {
-        OptionsBuilder {}
+        OptionsBuilder {
+            group_separator: self.group_separator,
+            group_size: self.group_size,
+            secondary_group_size: self.secondary_group_size,
+            lowercase_digits: self.lowercase_digits,
+        }
     }
 }
 
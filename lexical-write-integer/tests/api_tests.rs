@@ -5,7 +5,7 @@ use core::str::{from_utf8_unchecked, FromStr};
 
 #[cfg(feature = "radix")]
 use lexical_util::constants::BUFFER_SIZE;
-#[cfg(feature = "format")]
+#[cfg(any(feature = "format", feature = "power-of-two"))]
 use lexical_util::format::NumberFormatBuilder;
 use lexical_util::format::STANDARD;
 use lexical_write_integer::{Options, ToLexical, ToLexicalWithOptions};
@@ -40,6 +40,44 @@ fn mandatory_sign_test() {
     assert_eq!(b"+1", 1i8.to_lexical_with_options::<{ FORMAT }>(&mut buffer, &OPTIONS));
 }
 
+#[test]
+#[cfg(all(feature = "format", feature = "power-of-two"))]
+fn base_prefix_suffix_test() {
+    use core::num::NonZeroU8;
+
+    let mut buffer = [b'\x00'; 128];
+    const OPTIONS: Options = Options::new();
+    const PREFIX: u128 = NumberFormatBuilder::new()
+        .mantissa_radix(16)
+        .base_prefix(NonZeroU8::new(b'x'))
+        .build_strict();
+    assert_eq!(b"0x0", 0u32.to_lexical_with_options::<PREFIX>(&mut buffer, &OPTIONS));
+    assert_eq!(b"0xFF", 255u8.to_lexical_with_options::<PREFIX>(&mut buffer, &OPTIONS));
+    assert_eq!(b"-0x80", i8::MIN.to_lexical_with_options::<PREFIX>(&mut buffer, &OPTIONS));
+
+    const SUFFIX: u128 = NumberFormatBuilder::new()
+        .mantissa_radix(16)
+        .base_suffix(NonZeroU8::new(b'h'))
+        .build_strict();
+    assert_eq!(b"FFh", 255u8.to_lexical_with_options::<SUFFIX>(&mut buffer, &OPTIONS));
+    assert_eq!(b"-1h", (-1i64).to_lexical_with_options::<SUFFIX>(&mut buffer, &OPTIONS));
+
+    const SIGNED: u128 = NumberFormatBuilder::new()
+        .mantissa_radix(2)
+        .base_prefix(NonZeroU8::new(b'b'))
+        .base_suffix(NonZeroU8::new(b'y'))
+        .required_mantissa_sign(true)
+        .build_strict();
+    assert_eq!(b"+0b101y", 5u8.to_lexical_with_options::<SIGNED>(&mut buffer, &OPTIONS));
+    assert_eq!(b"-0b101y", (-5i8).to_lexical_with_options::<SIGNED>(&mut buffer, &OPTIONS));
+
+    // The buffer size includes the prefix and suffix.
+    const SIZE: usize = OPTIONS.buffer_size_const::<i8, SIGNED>();
+    let mut buffer = [b'\x00'; SIZE];
+    let digits = i8::MIN.to_lexical_with_options::<SIGNED>(&mut buffer, &OPTIONS);
+    assert_eq!(digits, b"-0b10000000y");
+}
+
 #[test]
 fn u8_test() {
     let mut buffer = [b'\x00'; 16];
@@ -1216,3 +1254,113 @@ fn usize_buffer_test() {
     let mut buffer = [b'\x00'; 5];
     123456usize.to_lexical(&mut buffer);
 }
+
+fn write_grouped<T>(value: T, options: &Options) -> String
+where
+    T: ToLexicalWithOptions<Options = Options> + lexical_util::constants::FormattedSize,
+{
+    let mut buffer = [b'\x00'; 128];
+    let size = options.buffer_size_const::<T, STANDARD>();
+    let digits = value.to_lexical_with_options::<STANDARD>(&mut buffer[..size], options);
+    String::from_utf8(digits.to_vec()).unwrap()
+}
+
+#[test]
+fn group_separator_test() {
+    use core::num::NonZeroU8;
+
+    let options = Options::builder().group_separator(NonZeroU8::new(b',')).build_strict();
+    assert_eq!(write_grouped(0u32, &options), "0");
+    assert_eq!(write_grouped(999u32, &options), "999");
+    assert_eq!(write_grouped(1000u32, &options), "1,000");
+    assert_eq!(write_grouped(-123456i32, &options), "-123,456");
+    assert_eq!(write_grouped(u64::MAX, &options), "18,446,744,073,709,551,615");
+    assert_eq!(
+        write_grouped(i128::MIN, &options),
+        "-170,141,183,460,469,231,731,687,303,715,884,105,728"
+    );
+
+    // Indian grouping uses groups of 2 after the first group of 3.
+    let options = options.rebuild().secondary_group_size(NonZeroU8::new(2)).build_strict();
+    assert_eq!(write_grouped(1234567u32, &options), "12,34,567");
+    assert_eq!(write_grouped(-12345678i64, &options), "-1,23,45,678");
+
+    let options = Options::builder()
+        .group_separator(NonZeroU8::new(b'_'))
+        .group_size(NonZeroU8::new(1))
+        .build_strict();
+    assert_eq!(write_grouped(i8::MIN, &options), "-1_2_8");
+    assert_eq!(write_grouped(u128::MAX, &options).len(), 77);
+}
+
+#[test]
+#[cfg(feature = "format")]
+fn group_separator_format_test() {
+    use core::num::NonZeroU8;
+
+    const FORMAT: u128 = NumberFormatBuilder::new().required_mantissa_sign(true).build_strict();
+    let options = Options::builder().group_separator(NonZeroU8::new(b' ')).build_strict();
+    let mut buffer = [b'\x00'; 128];
+    let digits = 1234567u32.to_lexical_with_options::<FORMAT>(&mut buffer, &options);
+    assert_eq!(digits, b"+1 234 567");
+}
+
+#[test]
+#[cfg(all(feature = "format", feature = "power-of-two"))]
+fn group_separator_base_prefix_test() {
+    use core::num::NonZeroU8;
+
+    const FORMAT: u128 = NumberFormatBuilder::new()
+        .mantissa_radix(16)
+        .base_prefix(NonZeroU8::new(b'x'))
+        .base_suffix(NonZeroU8::new(b'h'))
+        .build_strict();
+    let options = Options::builder()
+        .group_separator(NonZeroU8::new(b'_'))
+        .group_size(NonZeroU8::new(4))
+        .build_strict();
+    let mut buffer = [b'\x00'; 128];
+    let digits = (-0x12345678i32).to_lexical_with_options::<FORMAT>(&mut buffer, &options);
+    assert_eq!(digits, b"-0x1234_5678h");
+}
+
+#[test]
+#[cfg(feature = "power-of-two")]
+fn lowercase_digits_test() {
+    const HEX: u128 = NumberFormatBuilder::hexadecimal();
+    const UPPER: Options = Options::new();
+    const LOWER: Options = Options::builder().lowercase_digits(true).build_strict();
+    let mut buffer = [b'\x00'; 128];
+    assert_eq!(b"DEADBEEF", 0xDEADBEEFu32.to_lexical_with_options::<HEX>(&mut buffer, &UPPER));
+    assert_eq!(b"deadbeef", 0xDEADBEEFu32.to_lexical_with_options::<HEX>(&mut buffer, &LOWER));
+    assert_eq!(b"-7f", (-0x7Fi8).to_lexical_with_options::<HEX>(&mut buffer, &LOWER));
+    assert_eq!(b"255", 255u8.to_lexical_with_options::<STANDARD>(&mut buffer, &LOWER));
+}
+
+#[test]
+#[cfg(feature = "radix")]
+fn lowercase_digits_radix_test() {
+    const BASE36: u128 = NumberFormatBuilder::from_radix(36);
+    const LOWER: Options = Options::builder().lowercase_digits(true).build_strict();
+    let mut buffer = [b'\x00'; 128];
+    assert_eq!(b"zz", 1295u16.to_lexical_with_options::<BASE36>(&mut buffer, &LOWER));
+    assert_eq!(
+        b"-lexical",
+        (-46_615_238_589_i64).to_lexical_with_options::<BASE36>(&mut buffer, &LOWER)
+    );
+}
+
+#[test]
+#[cfg(all(feature = "format", feature = "power-of-two"))]
+fn lowercase_digits_base_prefix_test() {
+    use core::num::NonZeroU8;
+
+    const FORMAT: u128 = NumberFormatBuilder::new()
+        .mantissa_radix(16)
+        .base_prefix(NonZeroU8::new(b'X'))
+        .base_suffix(NonZeroU8::new(b'H'))
+        .build_strict();
+    const LOWER: Options = Options::builder().lowercase_digits(true).build_strict();
+    let mut buffer = [b'\x00'; 128];
+    assert_eq!(b"0Xabc1H", 0xABC1u16.to_lexical_with_options::<FORMAT>(&mut buffer, &LOWER));
+}
@@ -0,0 +1,154 @@
+#![cfg(feature = "bigint")]
+
+mod util;
+
+use core::num::NonZeroU8;
+
+use lexical_util::bigint::Limb;
+#[cfg(feature = "format")]
+use lexical_util::format::NumberFormatBuilder;
+use lexical_util::format::STANDARD;
+use lexical_write_integer::{
+    bigint_buffer_size,
+    biguint_buffer_size,
+    write_bigint,
+    write_biguint,
+    BigInt,
+    BigUint,
+    Options,
+    ToLexicalWithOptions,
+};
+#[cfg(feature = "radix")]
+use util::from_radix;
+
+fn check_unsigned<const FORMAT: u128>(value: u128, options: &Options) {
+    let mut expected = [b'\x00'; 512];
+    let expected = value.to_lexical_with_options::<FORMAT>(&mut expected, options);
+    let big = BigUint::from(value);
+    let mut buffer = vec![b'\x00'; biguint_buffer_size::<FORMAT>(&big, options)];
+    let actual = write_biguint::<FORMAT>(&big, &mut buffer, options);
+    assert_eq!(actual, expected);
+}
+
+fn check_signed<const FORMAT: u128>(value: i128, options: &Options) {
+    let mut expected = [b'\x00'; 512];
+    let expected = value.to_lexical_with_options::<FORMAT>(&mut expected, options);
+    let big = BigInt::from(value);
+    let mut buffer = vec![b'\x00'; bigint_buffer_size::<FORMAT>(&big, options)];
+    let actual = write_bigint::<FORMAT>(&big, &mut buffer, options);
+    assert_eq!(actual, expected);
+}
+
+fn check_native<const FORMAT: u128>(options: &Options) {
+    let unsigned = [0, 1, 9, 10, 999, 1000, u64::MAX as u128, u64::MAX as u128 + 1, u128::MAX];
+    for &value in unsigned.iter() {
+        check_unsigned::<FORMAT>(value, options);
+    }
+    let signed = [0, 1, -1, -10, 1234567, i64::MIN as i128, i128::MIN, i128::MAX];
+    for &value in signed.iter() {
+        check_signed::<FORMAT>(value, options);
+    }
+}
+
+#[test]
+fn write_biguint_test() {
+    const OPTIONS: Options = Options::new();
+    let mut buffer = [b'\x00'; 128];
+    let value = BigUint::new();
+    assert_eq!(write_biguint::<STANDARD>(&value, &mut buffer, &OPTIONS), b"0");
+
+    let value = BigUint::from(u128::MAX);
+    let expected = b"340282366920938463463374607431768211455";
+    assert_eq!(write_biguint::<STANDARD>(&value, &mut buffer, &OPTIONS), expected);
+}
+
+#[test]
+fn write_bigint_test() {
+    const OPTIONS: Options = Options::new();
+    let mut buffer = [b'\x00'; 128];
+    let value = BigInt::from_parts(true, BigUint::from(u128::MAX));
+    let expected = b"-340282366920938463463374607431768211455";
+    assert_eq!(write_bigint::<STANDARD>(&value, &mut buffer, &OPTIONS), expected);
+
+    // Negative zero is always written as zero.
+    let value = BigInt::from_parts(true, BigUint::new());
+    assert_eq!(write_bigint::<STANDARD>(&value, &mut buffer, &OPTIONS), b"0");
+}
+
+#[test]
+fn write_large_test() {
+    const OPTIONS: Options = Options::new();
+    // 10^2000 - 1 is written as 2000 nines.
+    let digits = vec![9u8; 2000];
+    let value = BigUint::from_digits(&digits, 10);
+    let mut buffer = vec![b'\x00'; biguint_buffer_size::<STANDARD>(&value, &OPTIONS)];
+    let actual = write_biguint::<STANDARD>(&value, &mut buffer, &OPTIONS);
+    assert_eq!(actual.len(), 2000);
+    assert!(actual.iter().all(|&c| c == b'9'));
+
+    // 2^8192 has 2467 decimal digits.
+    let mut limbs = vec![0; 8192 / Limb::BITS as usize];
+    limbs.push(1);
+    let value = BigUint::from_limbs(limbs);
+    let mut buffer = vec![b'\x00'; biguint_buffer_size::<STANDARD>(&value, &OPTIONS)];
+    let actual = write_biguint::<STANDARD>(&value, &mut buffer, &OPTIONS);
+    assert_eq!(actual.len(), 2467);
+    assert_eq!(&actual[..6], b"109074");
+}
+
+#[test]
+fn native_test() {
+    check_native::<STANDARD>(&Options::new());
+}
+
+#[test]
+#[cfg(feature = "radix")]
+fn native_radix_test() {
+    let options = Options::new();
+    check_native::<{ from_radix(2) }>(&options);
+    check_native::<{ from_radix(3) }>(&options);
+    check_native::<{ from_radix(7) }>(&options);
+    check_native::<{ from_radix(16) }>(&options);
+    check_native::<{ from_radix(20) }>(&options);
+    check_native::<{ from_radix(36) }>(&options);
+}
+
+#[test]
+fn native_group_separator_test() {
+    let options = Options::builder().group_separator(NonZeroU8::new(b',')).build_strict();
+    check_native::<STANDARD>(&options);
+
+    let options = options.rebuild().secondary_group_size(NonZeroU8::new(2)).build_strict();
+    check_native::<STANDARD>(&options);
+
+    let options = Options::builder()
+        .group_separator(NonZeroU8::new(b'_'))
+        .group_size(NonZeroU8::new(1))
+        .build_strict();
+    check_native::<STANDARD>(&options);
+}
+
+#[test]
+#[cfg(feature = "format")]
+fn native_format_test() {
+    const FORMAT: u128 = NumberFormatBuilder::new().required_mantissa_sign(true).build_strict();
+    let options = Options::builder().group_separator(NonZeroU8::new(b' ')).build_strict();
+    check_native::<FORMAT>(&options);
+}
+
+#[test]
+#[cfg(all(feature = "format", feature = "power-of-two"))]
+fn native_base_prefix_suffix_test() {
+    const FORMAT: u128 = NumberFormatBuilder::new()
+        .mantissa_radix(16)
+        .base_prefix(NonZeroU8::new(b'x'))
+        .base_suffix(NonZeroU8::new(b'h'))
+        .build_strict();
+    check_native::<FORMAT>(&Options::new());
+
+    let options = Options::builder()
+        .group_separator(NonZeroU8::new(b'_'))
+        .group_size(NonZeroU8::new(4))
+        .build_strict();
+    check_native::<FORMAT>(&options);
+}
@@ -9,4 +9,19 @@ fn options_tests() {
     assert!(OptionsBuilder::default().build().is_ok());
     assert!(OptionsBuilder::default().is_valid());
     assert_eq!(X.rebuild(), Options::builder());
+    assert!(!X.lowercase_digits());
+    assert!(Options::builder().lowercase_digits(true).build_strict().lowercase_digits());
+}
+
+#[test]
+fn group_separator_tests() {
+    use core::num::NonZeroU8;
+
+    let builder = Options::builder().group_separator(NonZeroU8::new(b','));
+    assert!(builder.is_valid());
+    assert!(builder.clone().group_separator(NonZeroU8::new(b' ')).is_valid());
+    assert!(!builder.clone().group_separator(NonZeroU8::new(b'1')).is_valid());
+    assert!(!builder.clone().group_separator(NonZeroU8::new(b'-')).is_valid());
+    assert!(!builder.clone().group_separator(NonZeroU8::new(0x80)).is_valid());
+    assert!(builder.group_separator(NonZeroU8::new(b'+')).build().is_err());
 }
@@ -0,0 +1,320 @@
+//! Write numbers to [`fmt::Write`] and [`io::Write`][`std-io-write`] sinks.
+//!
+//! [`display`] returns a [`Display`] adapter, so numbers written with custom
+//! options can be used directly in [`write!`] and [`format_args!`], and
+//! [`write_to_fmt`] and [`write_to_io`] write a number directly to a sink.
+//! The numbers are written to a stack buffer of `SIZE` bytes, so no memory
+//! is allocated. The default of [`BUFFER_SIZE`] bytes fits any number
+//! written with the default options: if the options require a larger buffer,
+//! such as for a large number of significant digits, use
+//! [`buffer_size_const`][`write-float-buffer_size_const`] as the size.
+//!
+//! The adapter honors the width, fill, alignment, `+` sign, and `0` flags
+//! of the formatter, like the integer formatters of the standard library.
+//! NaN and infinity are padded like strings, so they are never zero-padded.
+//! The precision is ignored: use the options to control the digits written.
+//!
+//! ```rust
+//! # #[cfg(feature = "write-floats")] {
+//! use lexical::WriteFloatOptions;
+//!
+//! const OPTIONS: WriteFloatOptions = WriteFloatOptions::builder()
+//!     .trim_floats(true)
+//!     .build_strict();
+//!
+//! let value = lexical::fmt::display(12.0, &OPTIONS);
+//! assert_eq!(format!("[{}]", value), "[12]");
+//! assert_eq!(format!("[{:>6}]", value), "[    12]");
+//! assert_eq!(format!("[{:*<+6}]", value), "[+12***]");
+//! assert_eq!(format!("[{:06}]", lexical::fmt::display(-1.5, &OPTIONS)), "[-001.5]");
+//! # }
+//! ```
+//!
+//! ```rust
+//! # #[cfg(feature = "write-floats")] {
+//! use core::num::NonZeroUsize;
+//!
+//! use lexical::fmt::Display;
+//! use lexical::format::STANDARD;
+//! use lexical::WriteFloatOptions;
+//!
+//! const OPTIONS: WriteFloatOptions = WriteFloatOptions::builder()
+//!     .min_significant_digits(NonZeroUsize::new(400))
+//!     .build_strict();
+//! const SIZE: usize = OPTIONS.buffer_size_const::<f64, STANDARD>();
+//!
+//! let value = Display::<_, STANDARD, SIZE>::new(1.0, &OPTIONS);
+//! assert_eq!(format!("{}", value).len(), 401);
+//! # }
+//! ```
+//!
+//! [`BUFFER_SIZE`]: crate::BUFFER_SIZE
+//! [`std-io-write`]: https://doc.rust-lang.org/std/io/trait.Write.html
+#![cfg_attr(feature = "std", doc = "[`write_to_io`]: crate::fmt::write_to_io")]
+#![cfg_attr(
+    not(feature = "std"),
+    doc = "[`write_to_io`]: https://docs.rs/lexical/latest/lexical/fmt/fn.write_to_io.html"
+)]
+#![cfg_attr(
+    feature = "write-floats",
+    doc = "[`write-float-buffer_size_const`]: crate::WriteFloatOptions::buffer_size_const"
+)]
+#![cfg_attr(
+    not(feature = "write-floats"),
+    doc = "[`write-float-buffer_size_const`]: https://docs.rs/lexical/latest/lexical/struct.WriteFloatOptions.html#method.buffer_size_const"
+)]
+#![cfg(any(feature = "write-floats", feature = "write-integers"))]
+
+use core::fmt;
+#[cfg(feature = "std")]
+use std::io;
+
+use crate::format::STANDARD;
+use crate::{ToLexicalWithOptions, BUFFER_SIZE};
+
+/// Adapter to display a number with custom options.
+///
+/// * `N`       - The number type to write.
+/// * `FORMAT`  - Packed struct containing the number format.
+/// * `SIZE`    - The size of the stack buffer to write the number to.
+///
+/// # Panics
+///
+/// Formatting panics if `SIZE` is smaller than the `buffer_size_const` of
+/// the options.
+pub struct Display<'a, N, const FORMAT: u128 = STANDARD, const SIZE: usize = BUFFER_SIZE>
+where
+    N: ToLexicalWithOptions,
+{
+    /// The number to write.
+    value: N,
+    /// The options to write the number.
+    options: &'a N::Options,
+}
+
+impl<'a, N, const FORMAT: u128, const SIZE: usize> Display<'a, N, FORMAT, SIZE>
+where
+    N: ToLexicalWithOptions,
+{
+    /// Create an adapter to display a number with a custom number format.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # #[cfg(all(feature = "write-floats", feature = "format"))] {
+    /// use lexical::fmt::Display;
+    /// use lexical::{NumberFormatBuilder, WriteFloatOptions};
+    ///
+    /// const FORMAT: u128 = NumberFormatBuilder::new()
+    ///     .required_exponent_sign(true)
+    ///     .build_strict();
+    /// let options = WriteFloatOptions::new();
+    /// let value = Display::<_, FORMAT>::new(1.5e300, &options);
+    /// assert_eq!(format!("[{:>10}]", value), "[  1.5e+300]");
+    /// # }
+    /// ```
+    #[inline(always)]
+    pub fn new(value: N, options: &'a N::Options) -> Self {
+        Self {
+            value,
+            options,
+        }
+    }
+
+    /// Get the number to write.
+    #[inline(always)]
+    pub fn value(&self) -> N {
+        self.value
+    }
+}
+
+impl<N, const FORMAT: u128, const SIZE: usize> Clone for Display<'_, N, FORMAT, SIZE>
+where
+    N: ToLexicalWithOptions,
+{
+    #[inline(always)]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<N, const FORMAT: u128, const SIZE: usize> Copy for Display<'_, N, FORMAT, SIZE> where
+    N: ToLexicalWithOptions
+{
+}
+
+impl<N, const FORMAT: u128, const SIZE: usize> fmt::Debug for Display<'_, N, FORMAT, SIZE>
+where
+    N: ToLexicalWithOptions,
+{
+    #[inline]
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self, formatter)
+    }
+}
+
+impl<N, const FORMAT: u128, const SIZE: usize> fmt::Display for Display<'_, N, FORMAT, SIZE>
+where
+    N: ToLexicalWithOptions,
+{
+    #[inline]
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        with_bytes::<N, FORMAT, SIZE, _>(self.value, self.options, |bytes| {
+            // `pad_integral` writes the sign, so it must be stripped from the
+            // digits. A positive sign required by the format must still be
+            // written before any zero padding.
+            let string = core::str::from_utf8(bytes).map_err(|_| fmt::Error)?;
+            if is_special(self.value) {
+                // NaN and infinity are not digits, so they are never
+                // zero-padded.
+                formatter.pad(string)
+            } else if let Some(digits) = string.strip_prefix('-') {
+                formatter.pad_integral(false, "", digits)
+            } else if let Some(digits) = string.strip_prefix('+') {
+                if formatter.sign_plus() {
+                    formatter.pad_integral(true, "", digits)
+                } else if formatter.sign_aware_zero_pad() {
+                    formatter.write_str("+")?;
+                    let count = string.chars().count();
+                    for _ in count..formatter.width().unwrap_or(0) {
+                        formatter.write_str("0")?;
+                    }
+                    formatter.write_str(digits)
+                } else {
+                    formatter.pad_integral(true, "", string)
+                }
+            } else {
+                formatter.pad_integral(true, "", string)
+            }
+        })
+    }
+}
+
+/// Create an adapter to display a number with custom options.
+///
+/// This uses the standard number format and a buffer of [`BUFFER_SIZE`]
+/// bytes: to use a custom number format or a larger buffer, use
+/// [`Display::new`].
+///
+/// * `value`   - Number to display.
+/// * `options` - Options to specify number writing.
+///
+/// # Examples
+///
+/// ```rust
+/// # #[cfg(feature = "write-integers")] {
+/// use lexical::WriteIntegerOptions;
+///
+/// let options = WriteIntegerOptions::new();
+/// assert_eq!(format!("{:>5}|{:<5}|", lexical::fmt::display(12, &options), 34), "   12|34   |");
+/// # }
+/// ```
+#[inline(always)]
+pub fn display<N: ToLexicalWithOptions>(value: N, options: &N::Options) -> Display<'_, N> {
+    Display::new(value, options)
+}
+
+/// Write a number with custom options to a [`fmt::Write`] sink.
+///
+/// This writes the number without any padding.
+///
+/// * `FORMAT`  - Packed struct containing the number format.
+/// * `SIZE`    - The size of the stack buffer to write the number to.
+/// * `value`   - Number to write.
+/// * `writer`  - The sink to write the number to.
+/// * `options` - Options to specify number writing.
+///
+/// # Panics
+///
+/// Panics if `SIZE` is smaller than the `buffer_size_const` of the options.
+///
+/// # Examples
+///
+/// ```rust
+/// # #[cfg(feature = "write-floats")] {
+/// use lexical::format::STANDARD;
+/// use lexical::{WriteFloatOptions, BUFFER_SIZE};
+///
+/// let options = WriteFloatOptions::new();
+/// let mut string = String::from("x = ");
+/// lexical::fmt::write_to_fmt::<_, _, STANDARD, BUFFER_SIZE>(1.5, &mut string, &options).unwrap();
+/// assert_eq!(string, "x = 1.5");
+/// # }
+/// ```
+#[inline]
+pub fn write_to_fmt<N, W, const FORMAT: u128, const SIZE: usize>(
+    value: N,
+    writer: &mut W,
+    options: &N::Options,
+) -> fmt::Result
+where
+    N: ToLexicalWithOptions,
+    W: fmt::Write + ?Sized,
+{
+    with_bytes::<N, FORMAT, SIZE, _>(value, options, |bytes| {
+        writer.write_str(core::str::from_utf8(bytes).map_err(|_| fmt::Error)?)
+    })
+}
+
+/// Write a number with custom options to an [`io::Write`] sink.
+///
+/// This writes the number without any padding.
+///
+/// * `FORMAT`  - Packed struct containing the number format.
+/// * `SIZE`    - The size of the stack buffer to write the number to.
+/// * `value`   - Number to write.
+/// * `writer`  - The sink to write the number to.
+/// * `options` - Options to specify number writing.
+///
+/// # Panics
+///
+/// Panics if `SIZE` is smaller than the `buffer_size_const` of the options.
+///
+/// # Examples
+///
+/// ```rust
+/// # #[cfg(feature = "write-integers")] {
+/// use lexical::format::STANDARD;
+/// use lexical::{WriteIntegerOptions, BUFFER_SIZE};
+///
+/// let options = WriteIntegerOptions::new();
+/// let mut bytes: Vec<u8> = Vec::new();
+/// lexical::fmt::write_to_io::<_, _, STANDARD, BUFFER_SIZE>(-15i32, &mut bytes, &options).unwrap();
+/// assert_eq!(bytes, b"-15");
+/// # }
+/// ```
+#[inline]
+#[cfg(feature = "std")]
+pub fn write_to_io<N, W, const FORMAT: u128, const SIZE: usize>(
+    value: N,
+    writer: &mut W,
+    options: &N::Options,
+) -> io::Result<()>
+where
+    N: ToLexicalWithOptions,
+    W: io::Write + ?Sized,
+{
+    with_bytes::<N, FORMAT, SIZE, _>(value, options, |bytes| writer.write_all(bytes))
+}
+
+/// Check if a number is NaN or infinite.
+#[inline(always)]
+#[allow(clippy::eq_op)] // reason = "`x - x` is only zero for finite numbers"
+fn is_special<N: ToLexicalWithOptions>(value: N) -> bool {
+    let zero = value - value;
+    !N::IS_INTEGER && zero.partial_cmp(&zero).is_none()
+}
+
+/// Write a number to a stack buffer, and call a function with the bytes.
+#[inline]
+fn with_bytes<N, const FORMAT: u128, const SIZE: usize, R>(
+    value: N,
+    options: &N::Options,
+    callback: impl FnOnce(&[u8]) -> R,
+) -> R
+where
+    N: ToLexicalWithOptions,
+{
+    let mut buffer = [0u8; SIZE];
+    callback(lexical_core::write_with_options::<_, FORMAT>(value, &mut buffer, options))
+}
@@ -0,0 +1,306 @@
+//! Parse numbers from buffered readers.
+//!
+//! [`NumberReader`] parses whitespace-delimited numbers from any type
+//! implementing [`BufRead`], such as a file wrapped in a
+//! [`BufReader`][std::io::BufReader], without reading the entire stream
+//! into memory. Numbers are parsed directly from the buffer of the reader,
+//! and only numbers straddling a refill of the buffer are copied.
+//!
+//! Each number is parsed with [`parse_partial_with_options`], so the
+//! number format and options are validated exactly like contiguous input.
+//! The index of a parse error is the absolute offset of the invalid byte
+//! in the stream, rather than the offset within the number. Numbers longer
+//! than [`MAX_NUMBER_LENGTH`] bytes are skipped with an error, so a stream
+//! without whitespace cannot exhaust memory.
+//!
+//! ```rust
+//! # #[cfg(feature = "parse-floats")] {
+//! use lexical::io::NumberReader;
+//!
+//! let input = b"1.5 -2e3\n3\n" as &[u8];
+//! let values: Result<Vec<f64>, _> = NumberReader::<_, f64>::new(input).collect();
+//! assert_eq!(values.unwrap(), [1.5, -2000.0, 3.0]);
+//!
+//! let input = b"1.5 2x 3" as &[u8];
+//! let mut reader = NumberReader::<_, f64>::new(input);
+//! assert_eq!(reader.next().unwrap().unwrap(), 1.5);
+//! let error = reader.next().unwrap().unwrap_err();
+//! assert_eq!(error.to_string(), "lexical parse error: 'invalid digit found' at index 5");
+//! assert_eq!(reader.next().unwrap().unwrap(), 3.0);
+//! # }
+//! ```
+//!
+//! [`parse_partial_with_options`]: crate::parse_partial_with_options
+
+#![cfg(all(feature = "std", any(feature = "parse-floats", feature = "parse-integers")))]
+
+use std::io::{self, BufRead};
+use std::{error, fmt};
+
+use crate::format::STANDARD;
+use crate::{Error, FromLexicalWithOptions};
+
+/// Maximum length in bytes of a number read from a stream.
+pub const MAX_NUMBER_LENGTH: usize = 0x10000;
+
+/// Error reading a number from a stream.
+#[derive(Debug)]
+pub enum ReadError {
+    /// Reading from the stream failed.
+    Io(io::Error),
+    /// Parsing the number failed. The index is the absolute offset in the
+    /// stream.
+    Parse(Error),
+    /// The number is longer than [`MAX_NUMBER_LENGTH`]. The index is the
+    /// absolute offset of the number in the stream.
+    TooLong(usize),
+}
+
+impl fmt::Display for ReadError {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io(error) => fmt::Display::fmt(error, formatter),
+            Self::Parse(error) => fmt::Display::fmt(error, formatter),
+            Self::TooLong(index) => write!(formatter, "number too long at index {}", index),
+        }
+    }
+}
+
+impl error::Error for ReadError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            Self::Io(error) => Some(error),
+            Self::Parse(error) => Some(error),
+            Self::TooLong(_) => None,
+        }
+    }
+}
+
+impl From<io::Error> for ReadError {
+    #[inline(always)]
+    fn from(error: io::Error) -> Self {
+        Self::Io(error)
+    }
+}
+
+impl From<Error> for ReadError {
+    #[inline(always)]
+    fn from(error: Error) -> Self {
+        Self::Parse(error)
+    }
+}
+
+/// Iterator over whitespace-delimited numbers parsed from a reader.
+///
+/// Numbers are separated by any amount of ASCII whitespace. After a parse
+/// error or a number longer than [`MAX_NUMBER_LENGTH`], the invalid number
+/// is skipped and the next call parses the following number. After an I/O
+/// error, the iterator is exhausted.
+///
+/// * `R`       - The buffered reader.
+/// * `T`       - The number type to parse.
+/// * `FORMAT`  - Packed struct containing the number format.
+pub struct NumberReader<R, T, const FORMAT: u128 = STANDARD>
+where
+    T: FromLexicalWithOptions,
+{
+    /// The buffered reader.
+    reader: R,
+    /// The options to parse numbers.
+    options: T::Options,
+    /// The bytes of a number straddling a refill of the buffer.
+    pending: Vec<u8>,
+    /// The absolute offset of the next unread byte.
+    offset: usize,
+    /// If an I/O error occurred.
+    is_done: bool,
+}
+
+impl<R, T, const FORMAT: u128> NumberReader<R, T, FORMAT>
+where
+    R: BufRead,
+    T: FromLexicalWithOptions,
+{
+    /// Create a number reader with the default options.
+    #[inline(always)]
+    pub fn new(reader: R) -> Self {
+        Self::with_options(reader, T::Options::default())
+    }
+
+    /// Create a number reader with custom options.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # #[cfg(all(feature = "parse-floats", feature = "format"))] {
+    /// use lexical::io::NumberReader;
+    /// use lexical::ParseFloatOptions;
+    ///
+    /// const FORMAT: u128 = lexical::format::STANDARD;
+    /// let options = ParseFloatOptions::builder().decimal_point(b',').build_strict();
+    /// let reader = NumberReader::<_, f32, FORMAT>::with_options(b"1,5\n-0,25" as &[u8], options);
+    /// let values: Result<Vec<f32>, _> = reader.collect();
+    /// assert_eq!(values.unwrap(), [1.5, -0.25]);
+    /// # }
+    /// ```
+    #[inline(always)]
+    pub fn with_options(reader: R, options: T::Options) -> Self {
+        Self {
+            reader,
+            options,
+            pending: Vec::new(),
+            offset: 0,
+            is_done: false,
+        }
+    }
+
+    /// Get the absolute offset of the next unread byte in the stream.
+    #[inline(always)]
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+
+    /// Get a reference to the underlying reader.
+    #[inline(always)]
+    pub fn get_ref(&self) -> &R {
+        &self.reader
+    }
+
+    /// Unwrap the number reader, returning the underlying reader.
+    #[inline(always)]
+    pub fn into_inner(self) -> R {
+        self.reader
+    }
+
+    /// Consume bytes from the buffer of the reader.
+    #[inline(always)]
+    fn consume(&mut self, count: usize) {
+        self.reader.consume(count);
+        self.offset += count;
+    }
+
+    /// Skip whitespace before the next number.
+    ///
+    /// Returns if there is another number in the stream.
+    fn skip_whitespace(&mut self) -> io::Result<bool> {
+        loop {
+            let buffer = fill_buf(&mut self.reader)?;
+            if buffer.is_empty() {
+                return Ok(false);
+            }
+            let length = buffer.len();
+            let count = buffer.iter().take_while(|c| c.is_ascii_whitespace()).count();
+            self.consume(count);
+            if count < length {
+                return Ok(true);
+            }
+        }
+    }
+
+    /// Skip the rest of the current number.
+    fn skip_number(&mut self) -> io::Result<()> {
+        loop {
+            let buffer = fill_buf(&mut self.reader)?;
+            let length = buffer.len();
+            let count = buffer.iter().position(|c| c.is_ascii_whitespace()).unwrap_or(length);
+            self.consume(count);
+            if count < length || length == 0 {
+                return Ok(());
+            }
+        }
+    }
+
+    /// Read and parse the next number, which starts at the current offset.
+    fn read_number(&mut self) -> Result<T, ReadError> {
+        let start = self.offset;
+        loop {
+            let buffer = fill_buf(&mut self.reader)?;
+            let length = buffer.len();
+            let count = buffer.iter().position(|c| c.is_ascii_whitespace()).unwrap_or(length);
+            if self.pending.len() + count > MAX_NUMBER_LENGTH {
+                self.consume(count);
+                self.pending.clear();
+                self.skip_number()?;
+                return Err(ReadError::TooLong(start));
+            }
+            if count < length && self.pending.is_empty() {
+                // The number is complete within the buffer, so parse it in place.
+                let result = parse_number::<T, FORMAT>(&buffer[..count], start, &self.options);
+                self.consume(count);
+                return result;
+            }
+
+            // The number may straddle a refill, or the stream ended.
+            self.pending.extend_from_slice(&buffer[..count]);
+            self.consume(count);
+            if count < length || length == 0 {
+                let result = parse_number::<T, FORMAT>(&self.pending, start, &self.options);
+                self.pending.clear();
+                return result;
+            }
+        }
+    }
+}
+
+impl<R, T, const FORMAT: u128> Iterator for NumberReader<R, T, FORMAT>
+where
+    R: BufRead,
+    T: FromLexicalWithOptions,
+{
+    type Item = Result<T, ReadError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.is_done {
+            return None;
+        }
+        let result = match self.skip_whitespace() {
+            Ok(true) => self.read_number(),
+            Ok(false) => return None,
+            Err(error) => Err(error.into()),
+        };
+        if let Err(ReadError::Io(_)) = result {
+            self.is_done = true;
+            self.pending.clear();
+        }
+        Some(result)
+    }
+}
+
+/// Fill the buffer of the reader, retrying if interrupted.
+#[inline]
+fn fill_buf<R: BufRead>(reader: &mut R) -> io::Result<&[u8]> {
+    loop {
+        match reader.fill_buf() {
+            Ok(_) => break,
+            Err(error) if error.kind() == io::ErrorKind::Interrupted => continue,
+            Err(error) => return Err(error),
+        }
+    }
+    // NOTE: We call `fill_buf` again to return the buffer, since the borrow
+    // checker rejects returning it from the loop. This is cheap, since the
+    // buffer is already filled.
+    reader.fill_buf()
+}
+
+/// Parse a complete number, translating error indexes to the stream.
+///
+/// * `start` - The absolute offset of the number in the stream.
+#[inline]
+fn parse_number<T, const FORMAT: u128>(
+    bytes: &[u8],
+    start: usize,
+    options: &T::Options,
+) -> Result<T, ReadError>
+where
+    T: FromLexicalWithOptions,
+{
+    let mut error = match crate::parse_partial_with_options::<T, _, FORMAT>(bytes, options) {
+        Ok((value, count)) if count == bytes.len() => return Ok(value),
+        Ok((_, count)) => Error::InvalidDigit(count),
+        Err(error) => error,
+    };
+    if let Some(index) = error.index_mut() {
+        *index += start;
+    }
+    Err(ReadError::Parse(error))
+}
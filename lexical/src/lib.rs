@@ -56,6 +56,9 @@
 //! # }
 //! ```
 //!
+//! To parse whitespace-delimited numbers from a [`BufRead`][`std-bufread`]
+//! stream, such as a large file, use [`NumberReader`].
+//!
 //! #### Write API
 //!
 //! The main parsing API is [`to_string`]. For example, to write a number to
@@ -68,6 +71,9 @@
 //! # }
 //! ```
 //!
+//! To write numbers within [`write!`] or to an [`io::Write`][`std-io-write`]
+//! sink without allocating, use the [`fmt`][`fmt-mod`] module.
+//!
 //! # Conversion API
 //!
 //! This writes and parses numbers to and from a format identical to
@@ -188,10 +194,18 @@
 //! * `format` - Add support for custom number formatting.
 //! * `f16` - Enable support for half-precision [`f16`][`ieee-f16`] and
 //!   [`bf16`][`brain-float`] floats.
+//! * `f128` - Enable support for quadruple-precision [`f128`][`ieee-f128`]
+//!   floats.
+//! * `unicode` - Enable support for parsing and writing Unicode decimal
+//!   digits, such as fullwidth or Arabic-Indic digits.
+//! * `diagnostic` - Enable diagnostic parse errors with the span, component,
+//!   and format rule of the error.
+//! * `serde` - Add helpers to (de)serialize numbers with custom formats.
 //! * `std` (Default) - Disable to allow use in a [`no_std`] environment.
 //!
 //! [`ieee-f16`]: https://en.wikipedia.org/wiki/Half-precision_floating-point_format
 //! [`brain-float`]: https://en.wikipedia.org/wiki/Bfloat16_floating-point_format
+//! [`ieee-f128`]: https://en.wikipedia.org/wiki/Quadruple-precision_floating-point_format
 //!
 //! A complete description of supported features includes:
 //!
@@ -350,6 +364,14 @@
 //! the number of static tables, inlining, and generics used, drastically
 //! reducing the size of the generated binaries.
 //!
+//! #### serde
+//!
+//! Add helpers in the [`serde`][`serde-mod`] module to serialize and
+//! deserialize numbers with a custom number format and options, such as
+//! for fields of JSON or TOML documents, using the `serialize_with` and
+//! `deserialize_with` attributes or the [`Lexical`][`serde-lexical`]
+//! newtype.
+//!
 //! #### std
 //!
 //! Enable use of the standard library. Currently, the standard library
@@ -436,6 +458,7 @@
 //! - The exponent [`break-point`][`write-float-positive_exponent_break`] for scientific notation.
 //! - The [`maximum`][`write-float-max_significant_digits`] and [`minimum`][`write-float-min_significant_digits`] number of significant digits to write.
 //! - The rounding [`mode`][`write-float-round_mode`] when truncating significant digits while writing.
+//! - The [`separator`][`write-float-group_separator`] to group the integer digits while writing, such as `1,234,567.89`.
 //!
 //! <!-- Spacer for Rustfmt -->
 #![cfg_attr(
@@ -449,6 +472,7 @@
 [`write-float-max_significant_digits`]: WriteFloatOptionsBuilder::max_significant_digits
 [`write-float-min_significant_digits`]: WriteFloatOptionsBuilder::min_significant_digits
 [`write-float-round_mode`]: WriteFloatOptionsBuilder::round_mode
+[`write-float-group_separator`]: WriteFloatOptionsBuilder::group_separator
 "
 )]
 #![cfg_attr(
@@ -462,6 +486,7 @@
 [`write-float-max_significant_digits`]: https://docs.rs/lexical/latest/lexical/struct.WriteFloatOptionsBuilder.html#method.max_significant_digits
 [`write-float-min_significant_digits`]: https://docs.rs/lexical/latest/lexical/struct.WriteFloatOptionsBuilder.html#method.min_significant_digits
 [`write-float-round_mode`]: https://docs.rs/lexical/latest/lexical/struct.WriteFloatOptionsBuilder.html#method.round_mode
+[`write-float-group_separator`]: https://docs.rs/lexical/latest/lexical/struct.WriteFloatOptionsBuilder.html#method.group_separator
 "
 )]
 //!
@@ -589,6 +614,36 @@
 [`parse_partial_with_options`]: https://docs.rs/lexical/latest/lexical/fn.parse_partial_with_options.html
 "
 )]
+#![cfg_attr(
+    feature = "serde",
+    doc = "
+[`serde-mod`]: mod@crate::serde
+[`serde-lexical`]: crate::serde::Lexical
+"
+)]
+#![cfg_attr(
+    not(feature = "serde"),
+    doc = "
+[`serde-mod`]: https://docs.rs/lexical/latest/lexical/serde/index.html
+[`serde-lexical`]: https://docs.rs/lexical/latest/lexical/serde/struct.Lexical.html
+"
+)]
+#![cfg_attr(
+    all(feature = "std", any(feature = "parse-floats", feature = "parse-integers")),
+    doc = "[`NumberReader`]: crate::io::NumberReader"
+)]
+#![cfg_attr(
+    not(all(feature = "std", any(feature = "parse-floats", feature = "parse-integers"))),
+    doc = "[`NumberReader`]: https://docs.rs/lexical/latest/lexical/io/struct.NumberReader.html"
+)]
+#![cfg_attr(
+    any(feature = "write-floats", feature = "write-integers"),
+    doc = "[`fmt-mod`]: mod@crate::fmt"
+)]
+#![cfg_attr(
+    not(any(feature = "write-floats", feature = "write-integers")),
+    doc = "[`fmt-mod`]: https://docs.rs/lexical/latest/lexical/fmt/index.html"
+)]
 //!
 //! <!-- Space for Rustfmt -->
 #![cfg_attr(feature = "parse-floats", doc = "[`ParseFloatOptions`]: crate::ParseFloatOptions")]
@@ -597,6 +652,8 @@
 #![cfg_attr(feature = "write-integers", doc = "[`WriteIntegerOptions`]: crate::WriteIntegerOptions")]
 //!
 //! [`NumberFormatBuilder`]: crate::NumberFormatBuilder
+//! [`std-bufread`]: https://doc.rust-lang.org/std/io/trait.BufRead.html
+//! [`std-io-write`]: https://doc.rust-lang.org/std/io/trait.Write.html
 //! [benchmarks]: https://github.com/Alexhuszagh/lexical-benchmarks
 //! [`rust-1.63.0`]: https://blog.rust-lang.org/2022/08/11/Rust-1.63.0.html
 
@@ -656,6 +713,15 @@ pub use lexical_core::format::{
 #[cfg(feature = "f16")]
 pub use lexical_core::{bf16, f16};
 
+#[cfg(feature = "f128")]
+pub use lexical_core::f128;
+
+#[cfg(feature = "unicode")]
+pub use lexical_core::DigitBlock;
+
+#[cfg(feature = "diagnostic")]
+pub use lexical_core::{Component, Diagnostic, Expected, Rule};
+
 // PARSE
 
 #[cfg(any(feature = "parse-floats", feature = "parse-integers"))]
@@ -664,6 +730,9 @@ pub use lexical_core::ParseOptions;
 #[cfg(any(feature = "parse-floats", feature = "parse-integers"))]
 pub use lexical_core::{FromLexical, FromLexicalWithOptions};
 
+#[cfg(all(feature = "std", any(feature = "parse-floats", feature = "parse-integers")))]
+pub use lexical_core::CodeUnit;
+
 #[cfg(feature = "parse-floats")]
 pub use lexical_core::{parse_float_options, ParseFloatOptions, ParseFloatOptionsBuilder};
 
@@ -687,6 +756,18 @@ pub use lexical_core::{write_float_options, WriteFloatOptions, WriteFloatOptions
 #[cfg(feature = "write-integers")]
 pub use lexical_core::{write_integer_options, WriteIntegerOptions, WriteIntegerOptionsBuilder};
 
+// FMT
+
+pub mod fmt;
+
+// IO
+
+pub mod io;
+
+// SERDE
+
+pub mod serde;
+
 // NOTE: We cannot just use an uninitialized vector with excess capacity and
 // then use read-assign rather than `ptr::write` or `MaybeUninit.write` to
 // modify the values. When LLVM was the primary code generator, this was
@@ -773,6 +854,81 @@ pub fn to_string_with_options<N: ToLexicalWithOptions, const FORMAT: u128>(
     }
 }
 
+/// High-level conversion of a number to a string with Unicode decimal
+/// digits.
+///
+/// This is identical to [`to_string`], except the digits `0-9` are written
+/// as the digits of the block.
+///
+/// * `n`       - Number to convert to string.
+/// * `block`   - The block of digits to write.
+///
+/// # Examples
+///
+/// ```rust
+/// use lexical::DigitBlock;
+///
+/// assert_eq!(lexical::to_string_unicode(1234, DigitBlock::DEVANAGARI), "१२३४");
+/// assert_eq!(lexical::to_string_unicode(-0.5, DigitBlock::FULLWIDTH), "-０.５");
+/// ```
+#[inline]
+#[cfg(all(feature = "unicode", any(feature = "write-floats", feature = "write-integers")))]
+pub fn to_string_unicode<N: ToLexical>(n: N, block: DigitBlock) -> String {
+    let mut buf = vec![0u8; block.buffer_size(N::FORMATTED_SIZE_DECIMAL)];
+    let len = lexical_core::write_unicode(n, block, buf.as_mut_slice()).len();
+
+    // SAFETY: safe since the buffer is of sufficient size, `len()` must be <= the
+    // vec size, and the digits are valid UTF-8.
+    unsafe {
+        buf.set_len(len);
+        String::from_utf8_unchecked(buf)
+    }
+}
+
+/// High-level conversion of a number to a string with Unicode decimal
+/// digits and custom writing options.
+///
+/// This is identical to [`to_string_with_options`], except the digits
+/// `0-9` are written as the digits of the block.
+///
+/// * `FORMAT`  - Packed struct containing the number format.
+/// * `n`       - Number to convert to string.
+/// * `block`   - The block of digits to write.
+/// * `options` - Options to specify number writing.
+///
+/// # Examples
+///
+/// ```rust
+/// use lexical::DigitBlock;
+///
+/// const FORMAT: u128 = lexical::format::STANDARD;
+/// const OPTIONS: lexical::WriteFloatOptions = lexical::WriteFloatOptions::builder()
+///     .trim_floats(true)
+///     .build_strict();
+/// let string = lexical::to_string_unicode_with_options::<_, FORMAT>(10.0, DigitBlock::THAI, &OPTIONS);
+/// assert_eq!(string, "๑๐");
+/// ```
+#[inline]
+#[cfg(all(feature = "unicode", any(feature = "write-floats", feature = "write-integers")))]
+#[allow(deprecated)] // reason = "allow the user of `buffer_size`"
+pub fn to_string_unicode_with_options<N: ToLexicalWithOptions, const FORMAT: u128>(
+    n: N,
+    block: DigitBlock,
+    options: &N::Options,
+) -> String {
+    let size = block.buffer_size(N::Options::buffer_size::<N, FORMAT>(options));
+    let mut buf = vec![0u8; size];
+    let slc = buf.as_mut_slice();
+    let len = lexical_core::write_unicode_with_options::<_, FORMAT>(n, block, slc, options).len();
+
+    // SAFETY: safe since the buffer is of sufficient size, `len()` must be <= the
+    // vec size, and the digits are valid UTF-8.
+    unsafe {
+        buf.set_len(len);
+        String::from_utf8_unchecked(buf)
+    }
+}
+
 /// High-level conversion of decimal-encoded bytes to a number.
 ///
 /// This function only returns a value if the entire string is
@@ -925,3 +1081,317 @@ pub fn parse_partial_with_options<
 ) -> Result<(N, usize)> {
     N::from_lexical_partial_with_options::<FORMAT>(bytes.as_ref(), options)
 }
+
+/// High-level conversion of UTF-16 or UTF-32 code units to a number.
+///
+/// This is identical to [`parse`], except any error indexes are in
+/// code units.
+///
+/// * `units`   - Slice of code units to convert to number.
+///
+/// # Examples
+///
+/// ```rust
+/// let units: Vec<u16> = "1.5".encode_utf16().collect();
+/// assert_eq!(lexical::parse_units::<f32, _>(&units), Ok(1.5));
+///
+/// let units: Vec<u32> = "1\u{3c0}".chars().map(u32::from).collect();
+/// assert!(lexical::parse_units::<i32, _>(&units).err().unwrap().is_invalid_digit());
+/// ```
+#[inline]
+#[cfg(all(feature = "std", any(feature = "parse-floats", feature = "parse-integers")))]
+pub fn parse_units<N: FromLexical, C: CodeUnit>(units: &[C]) -> Result<N> {
+    N::from_lexical_units(units)
+}
+
+/// High-level, partial conversion of UTF-16 or UTF-32 code units to a
+/// number.
+///
+/// This is identical to [`parse_partial`], except the number of processed
+/// elements and any error indexes are in code units.
+///
+/// * `units`   - Slice of code units to convert to number.
+///
+/// # Examples
+///
+/// ```rust
+/// let units: Vec<u16> = "12\u{3c0}".encode_utf16().collect();
+/// assert_eq!(lexical::parse_partial_units::<i32, _>(&units), Ok((12, 2)));
+/// ```
+#[inline]
+#[cfg(all(feature = "std", any(feature = "parse-floats", feature = "parse-integers")))]
+pub fn parse_partial_units<N: FromLexical, C: CodeUnit>(units: &[C]) -> Result<(N, usize)> {
+    N::from_lexical_partial_units(units)
+}
+
+/// High-level conversion of UTF-16 or UTF-32 code units to a number with
+/// custom parsing options.
+///
+/// This is identical to [`parse_with_options`], except any error indexes
+/// are in code units.
+///
+/// * `FORMAT`  - Packed struct containing the number format.
+/// * `units`   - Slice of code units to convert to number.
+/// * `options` - Options to specify number parsing.
+///
+/// # Panics
+///
+/// If the provided `FORMAT` is not valid, the function may panic. Please
+/// ensure `is_valid()` is called prior to using the format, or checking
+/// its validity using a static assertion.
+///
+/// # Examples
+///
+/// ```rust
+/// const FORMAT: u128 = lexical::format::STANDARD;
+/// const OPTIONS: lexical::ParseFloatOptions = lexical::ParseFloatOptions::builder()
+///     .decimal_point(b',')
+///     .build_strict();
+/// let units: Vec<u16> = "1,2345".encode_utf16().collect();
+/// assert_eq!(lexical::parse_units_with_options::<f32, _, FORMAT>(&units, &OPTIONS), Ok(1.2345));
+/// ```
+#[inline]
+#[cfg(all(feature = "std", any(feature = "parse-floats", feature = "parse-integers")))]
+pub fn parse_units_with_options<N: FromLexicalWithOptions, C: CodeUnit, const FORMAT: u128>(
+    units: &[C],
+    options: &N::Options,
+) -> Result<N> {
+    N::from_lexical_units_with_options::<FORMAT, C>(units, options)
+}
+
+/// High-level, partial conversion of UTF-16 or UTF-32 code units to a
+/// number with custom parsing options.
+///
+/// This is identical to [`parse_partial_with_options`], except the number
+/// of processed elements and any error indexes are in code units.
+///
+/// * `FORMAT`  - Packed struct containing the number format.
+/// * `units`   - Slice of code units to convert to number.
+/// * `options` - Options to specify number parsing.
+///
+/// # Panics
+///
+/// If the provided `FORMAT` is not valid, the function may panic. Please
+/// ensure `is_valid()` is called prior to using the format, or checking
+/// its validity using a static assertion.
+///
+/// # Examples
+///
+/// ```rust
+/// const FORMAT: u128 = lexical::format::STANDARD;
+/// const OPTIONS: lexical::ParseFloatOptions = lexical::ParseFloatOptions::builder()
+///     .decimal_point(b',')
+///     .build_strict();
+/// let units: Vec<u16> = "1,2345 m".encode_utf16().collect();
+/// assert_eq!(
+///     lexical::parse_partial_units_with_options::<f32, _, FORMAT>(&units, &OPTIONS),
+///     Ok((1.2345, 6))
+/// );
+/// ```
+#[inline]
+#[cfg(all(feature = "std", any(feature = "parse-floats", feature = "parse-integers")))]
+pub fn parse_partial_units_with_options<
+    N: FromLexicalWithOptions,
+    C: CodeUnit,
+    const FORMAT: u128,
+>(
+    units: &[C],
+    options: &N::Options,
+) -> Result<(N, usize)> {
+    N::from_lexical_partial_units_with_options::<FORMAT, C>(units, options)
+}
+
+/// High-level conversion of bytes with Unicode decimal digits to a number.
+///
+/// This is identical to [`parse`], except the digits may be UTF-8 encoded
+/// digits of any single block of Unicode decimal digits, such as fullwidth
+/// or Arabic-Indic digits. Any error indexes are in bytes of the input.
+///
+/// * `bytes`   - Byte slice to convert to number.
+///
+/// # Examples
+///
+/// ```rust
+/// assert_eq!(lexical::parse_unicode::<i32, _>("１２３"), Ok(123));
+/// assert_eq!(lexical::parse_unicode::<f64, _>("-٣.٥"), Ok(-3.5));
+/// assert_eq!(lexical::parse_unicode::<i32, _>("42"), Ok(42));
+/// ```
+#[inline]
+#[cfg(all(feature = "unicode", any(feature = "parse-floats", feature = "parse-integers")))]
+pub fn parse_unicode<N: FromLexical, Bytes: AsRef<[u8]>>(bytes: Bytes) -> Result<N> {
+    lexical_core::parse_unicode(bytes.as_ref())
+}
+
+/// High-level, partial conversion of bytes with Unicode decimal digits to
+/// a number.
+///
+/// This is identical to [`parse_partial`], except the digits may be UTF-8
+/// encoded digits of any single block of Unicode decimal digits. The
+/// number of processed bytes and any error indexes are in bytes of the
+/// input.
+///
+/// * `bytes`   - Byte slice to convert to number.
+///
+/// # Examples
+///
+/// ```rust
+/// assert_eq!(lexical::parse_partial_unicode::<i32, _>("१२ km"), Ok((12, 6)));
+/// ```
+#[inline]
+#[cfg(all(feature = "unicode", any(feature = "parse-floats", feature = "parse-integers")))]
+pub fn parse_partial_unicode<N: FromLexical, Bytes: AsRef<[u8]>>(
+    bytes: Bytes,
+) -> Result<(N, usize)> {
+    lexical_core::parse_partial_unicode(bytes.as_ref())
+}
+
+/// High-level conversion of bytes with Unicode decimal digits to a number
+/// with custom parsing options.
+///
+/// This is identical to [`parse_with_options`], except the digits may be
+/// UTF-8 encoded digits of any single block of Unicode decimal digits. The
+/// digit separators, signs, and other punctuation must be ASCII.
+///
+/// * `FORMAT`  - Packed struct containing the number format.
+/// * `bytes`   - Byte slice to convert to number.
+/// * `options` - Options to specify number parsing.
+///
+/// # Panics
+///
+/// If the provided `FORMAT` is not valid, the function may panic. Please
+/// ensure `is_valid()` is called prior to using the format, or checking
+/// its validity using a static assertion.
+///
+/// # Examples
+///
+/// ```rust
+/// const FORMAT: u128 = lexical::format::STANDARD;
+/// const OPTIONS: lexical::ParseFloatOptions = lexical::ParseFloatOptions::builder()
+///     .decimal_point(b',')
+///     .build_strict();
+/// assert_eq!(lexical::parse_unicode_with_options::<f32, _, FORMAT>("１,５", &OPTIONS), Ok(1.5));
+/// ```
+#[inline]
+#[cfg(all(feature = "unicode", any(feature = "parse-floats", feature = "parse-integers")))]
+pub fn parse_unicode_with_options<
+    N: FromLexicalWithOptions,
+    Bytes: AsRef<[u8]>,
+    const FORMAT: u128,
+>(
+    bytes: Bytes,
+    options: &N::Options,
+) -> Result<N> {
+    lexical_core::parse_unicode_with_options::<N, FORMAT>(bytes.as_ref(), options)
+}
+
+/// High-level, partial conversion of bytes with Unicode decimal digits to
+/// a number with custom parsing options.
+///
+/// This is identical to [`parse_partial_with_options`], except the digits
+/// may be UTF-8 encoded digits of any single block of Unicode decimal
+/// digits. The number of processed bytes and any error indexes are in
+/// bytes of the input.
+///
+/// * `FORMAT`  - Packed struct containing the number format.
+/// * `bytes`   - Byte slice to convert to number.
+/// * `options` - Options to specify number parsing.
+///
+/// # Panics
+///
+/// If the provided `FORMAT` is not valid, the function may panic. Please
+/// ensure `is_valid()` is called prior to using the format, or checking
+/// its validity using a static assertion.
+///
+/// # Examples
+///
+/// ```rust
+/// const FORMAT: u128 = lexical::format::STANDARD;
+/// const OPTIONS: lexical::ParseFloatOptions = lexical::ParseFloatOptions::builder()
+///     .decimal_point(b',')
+///     .build_strict();
+/// assert_eq!(
+///     lexical::parse_partial_unicode_with_options::<f32, _, FORMAT>("١,٥ m", &OPTIONS),
+///     Ok((1.5, 5))
+/// );
+/// ```
+#[inline]
+#[cfg(all(feature = "unicode", any(feature = "parse-floats", feature = "parse-integers")))]
+pub fn parse_partial_unicode_with_options<
+    N: FromLexicalWithOptions,
+    Bytes: AsRef<[u8]>,
+    const FORMAT: u128,
+>(
+    bytes: Bytes,
+    options: &N::Options,
+) -> Result<(N, usize)> {
+    lexical_core::parse_partial_unicode_with_options::<N, FORMAT>(bytes.as_ref(), options)
+}
+
+/// High-level conversion of bytes to a number, with a diagnostic on error.
+///
+/// This is identical to [`parse`], except errors are converted to a
+/// [`Diagnostic`], which displays the input with a caret under the error.
+///
+/// * `bytes`   - Byte slice to convert to number.
+///
+/// # Examples
+///
+/// ```rust
+/// # #[cfg(feature = "parse-integers")] {
+/// let error = lexical::parse_diagnostic::<i32, _>("12a4").unwrap_err();
+/// assert_eq!(error.span(), Some(2..3));
+/// assert_eq!(
+///     error.to_string(),
+///     "lexical parse error: invalid digit found at 2..3
+///  |
+///  | 12a4
+///  |   ^ expected a digit in the integer, found `a`"
+/// );
+/// # }
+/// ```
+#[inline]
+#[cfg(all(feature = "diagnostic", any(feature = "parse-floats", feature = "parse-integers")))]
+pub fn parse_diagnostic<N: FromLexical, Bytes: AsRef<[u8]> + ?Sized>(
+    bytes: &Bytes,
+) -> core::result::Result<N, Diagnostic<'_>> {
+    lexical_core::parse_diagnostic(bytes.as_ref())
+}
+
+/// High-level conversion of bytes to a number with custom parsing options,
+/// with a diagnostic on error.
+///
+/// This is identical to [`parse_with_options`], except errors are
+/// converted to a [`Diagnostic`] with the number format rule that rejected
+/// the input.
+///
+/// * `FORMAT`  - Packed struct containing the number format.
+/// * `bytes`   - Byte slice to convert to number.
+/// * `options` - Options to customize number parsing.
+///
+/// # Examples
+///
+/// ```rust
+/// # #[cfg(all(feature = "parse-floats", feature = "format"))] {
+/// use lexical::Rule;
+///
+/// const FORMAT: u128 = lexical::NumberFormatBuilder::new()
+///     .required_exponent_sign(true)
+///     .build_strict();
+/// const OPTIONS: lexical::ParseFloatOptions = lexical::ParseFloatOptions::new();
+/// let result = lexical::parse_diagnostic_with_options::<f64, _, FORMAT>("1.1e15", &OPTIONS);
+/// assert_eq!(result.unwrap_err().rule(), Some(Rule::RequiredExponentSign));
+/// # }
+/// ```
+#[inline]
+#[cfg(all(feature = "diagnostic", any(feature = "parse-floats", feature = "parse-integers")))]
+pub fn parse_diagnostic_with_options<
+    'a,
+    N: FromLexicalWithOptions,
+    Bytes: AsRef<[u8]> + ?Sized,
+    const FORMAT: u128,
+>(
+    bytes: &'a Bytes,
+    options: &N::Options,
+) -> core::result::Result<N, Diagnostic<'a>> {
+    lexical_core::parse_diagnostic_with_options::<N, FORMAT>(bytes.as_ref(), options)
+}
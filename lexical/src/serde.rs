@@ -0,0 +1,664 @@
+//! Helpers to (de)serialize numbers with custom formats using [`serde`].
+//!
+//! The serde data model has no representation for a number with a custom
+//! syntax, so these serialize numbers written with a custom syntax as
+//! strings, and deserialize numbers from strings or bytes parsed with the
+//! number format and options. Numbers the format and options write like
+//! the standard format, such as most numbers in [`JSON`] or [`TOML`], are
+//! serialized as native numbers, except 128-bit integers that do not fit in
+//! 64 bits, and native numbers are also accepted when deserializing, so the
+//! helpers require a self-describing data format.
+//! The number format is a const generic, like the rest of the API, and the
+//! options are provided by a type implementing [`Options`].
+//!
+//! Fields may use the helpers with the `serialize_with` and
+//! `deserialize_with` attributes, or be wrapped in the [`Lexical`]
+//! newtype. Deserialization errors include the lexical [`Error`], and
+//! therefore the index of the invalid byte within the number.
+//!
+//! ```rust
+//! # #[cfg(all(feature = "parse-floats", feature = "write-floats", feature = "format"))] {
+//! use lexical::format::JSON;
+//! use lexical::serde::Lexical;
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Serialize, Deserialize)]
+//! struct Point {
+//!     #[serde(
+//!         serialize_with = "lexical::serde::serialize::<_, _, JSON>",
+//!         deserialize_with = "lexical::serde::deserialize::<_, _, JSON>"
+//!     )]
+//!     x: f64,
+//!     y: Lexical<f64, JSON>,
+//! }
+//!
+//! let point: Point = serde_json::from_str(r#"{"x":"1.5","y":-2e3}"#).unwrap();
+//! assert_eq!(point.x, 1.5);
+//! assert_eq!(point.y.value, -2000.0);
+//! assert_eq!(serde_json::to_string(&point).unwrap(), r#"{"x":1.5,"y":-2000.0}"#);
+//!
+//! let error = serde_json::from_str::<Point>(r#"{"x":"+1.5","y":"1"}"#).err().unwrap();
+//! assert!(error.to_string().contains("at index 0"));
+//! # }
+//! ```
+//!
+//! [`serde`]: https://docs.rs/serde
+//! [`Error`]: crate::Error
+//! [`JSON`]: crate::format::JSON
+//! [`TOML`]: crate::format::TOML
+
+#![cfg(feature = "serde")]
+
+use core::cmp::Ordering;
+use core::marker::PhantomData;
+use core::{fmt, str};
+
+#[cfg(any(feature = "parse-floats", feature = "parse-integers"))]
+use ::serde::de::{self, Deserialize, Deserializer, Visitor};
+#[cfg(any(feature = "write-floats", feature = "write-integers"))]
+use ::serde::ser::{self, Serialize, Serializer};
+
+use crate::format::STANDARD;
+#[cfg(all(feature = "parse-floats", feature = "write-floats"))]
+use crate::FormattedSize;
+#[cfg(any(feature = "parse-floats", feature = "parse-integers"))]
+use crate::FromLexicalWithOptions;
+#[cfg(feature = "parse-floats")]
+use crate::ParseFloatOptions;
+#[cfg(feature = "parse-integers")]
+use crate::ParseIntegerOptions;
+#[cfg(any(feature = "write-floats", feature = "write-integers"))]
+use crate::ToLexicalWithOptions;
+#[cfg(feature = "write-floats")]
+use crate::WriteFloatOptions;
+#[cfg(feature = "write-integers")]
+use crate::WriteIntegerOptions;
+
+/// Options to parse and write numbers with serde.
+///
+/// Each option defaults to the default options, so only the options
+/// which differ must be provided.
+///
+/// ```rust
+/// # #[cfg(feature = "write-floats")] {
+/// use lexical::WriteFloatOptions;
+///
+/// struct TrimFloats;
+///
+/// impl lexical::serde::Options for TrimFloats {
+///     const WRITE_FLOAT: WriteFloatOptions =
+///         WriteFloatOptions::builder().trim_floats(true).build_strict();
+/// }
+/// # }
+/// ```
+pub trait Options {
+    /// Options to parse floats.
+    #[cfg(feature = "parse-floats")]
+    const PARSE_FLOAT: ParseFloatOptions = ParseFloatOptions::new();
+
+    /// Options to parse integers.
+    #[cfg(feature = "parse-integers")]
+    const PARSE_INTEGER: ParseIntegerOptions = ParseIntegerOptions::new();
+
+    /// Options to write floats.
+    #[cfg(feature = "write-floats")]
+    const WRITE_FLOAT: WriteFloatOptions = WriteFloatOptions::new();
+
+    /// Options to write integers.
+    #[cfg(feature = "write-integers")]
+    const WRITE_INTEGER: WriteIntegerOptions = WriteIntegerOptions::new();
+}
+
+/// The default options to parse and write numbers.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct DefaultOptions;
+
+impl Options for DefaultOptions {
+}
+
+/// Number that can be parsed with serde.
+#[cfg(any(feature = "parse-floats", feature = "parse-integers"))]
+pub trait ParseNumber: FromLexicalWithOptions {
+    /// Get the options to parse the number.
+    fn parse_options<O: Options>() -> Self::Options;
+
+    /// Convert a native signed integer, if it can be represented.
+    fn from_native_i128(value: i128) -> Option<Self>;
+
+    /// Convert a native unsigned integer, if it can be represented.
+    fn from_native_u128(value: u128) -> Option<Self>;
+
+    /// Convert a native float, if it can be represented.
+    fn from_native_f64(value: f64) -> Option<Self>;
+}
+
+/// Number that can be written with serde.
+#[cfg(any(feature = "write-floats", feature = "write-integers"))]
+pub trait WriteNumber: ToLexicalWithOptions {
+    /// Get the options to write the number.
+    fn write_options<O: Options>() -> Self::Options;
+
+    /// Get the buffer size required to write the number with the options.
+    fn buffer_size<O: Options, const FORMAT: u128>() -> usize;
+
+    /// Get if the options are the default options.
+    fn is_default<O: Options>() -> bool;
+
+    /// Serialize the number as a native number.
+    ///
+    /// Numbers without a native representation, such as special floats,
+    /// are serialized as the `string` written with the standard format.
+    /// 128-bit integers that do not fit in 64 bits are also serialized as
+    /// strings, since self-describing formats may deserialize them as
+    /// floats.
+    fn serialize_native<S: Serializer>(
+        self,
+        string: &str,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error>;
+}
+
+/// Implement `serialize_native` for an integer.
+#[cfg(feature = "write-integers")]
+macro_rules! serialize_integer {
+    ($serialize:ident $(as $native:ty)?) => {
+        #[inline(always)]
+        fn serialize_native<S: Serializer>(self, _: &str, serializer: S) -> Result<S::Ok, S::Error> {
+            serializer.$serialize(self $(as $native)?)
+        }
+    };
+
+    ($serialize:ident try $native:ty) => {
+        #[inline(always)]
+        fn serialize_native<S: Serializer>(self, string: &str, serializer: S) -> Result<S::Ok, S::Error> {
+            match <$native>::try_from(self) {
+                Ok(value) => serializer.$serialize(value),
+                Err(_) => serializer.serialize_str(string),
+            }
+        }
+    };
+}
+
+macro_rules! integer_impl {
+    ($($t:ty => $serialize:ident $($kind:ident $native:ty)?),* $(,)?) => ($(
+        #[cfg(feature = "parse-integers")]
+        impl ParseNumber for $t {
+            #[inline(always)]
+            fn parse_options<O: Options>() -> Self::Options {
+                O::PARSE_INTEGER
+            }
+
+            #[inline(always)]
+            fn from_native_i128(value: i128) -> Option<Self> {
+                Self::try_from(value).ok()
+            }
+
+            #[inline(always)]
+            fn from_native_u128(value: u128) -> Option<Self> {
+                Self::try_from(value).ok()
+            }
+
+            #[inline(always)]
+            fn from_native_f64(_: f64) -> Option<Self> {
+                None
+            }
+        }
+
+        #[cfg(feature = "write-integers")]
+        impl WriteNumber for $t {
+            #[inline(always)]
+            fn write_options<O: Options>() -> Self::Options {
+                O::WRITE_INTEGER
+            }
+
+            #[inline(always)]
+            fn buffer_size<O: Options, const FORMAT: u128>() -> usize {
+                O::WRITE_INTEGER.buffer_size_const::<Self, FORMAT>()
+            }
+
+            #[inline(always)]
+            fn is_default<O: Options>() -> bool {
+                O::WRITE_INTEGER == WriteIntegerOptions::new()
+            }
+
+            serialize_integer!($serialize $($kind $native)?);
+        }
+    )*);
+}
+
+integer_impl! {
+    u8 => serialize_u8,
+    u16 => serialize_u16,
+    u32 => serialize_u32,
+    u64 => serialize_u64,
+    u128 => serialize_u64 try u64,
+    usize => serialize_u64 as u64,
+    i8 => serialize_i8,
+    i16 => serialize_i16,
+    i32 => serialize_i32,
+    i64 => serialize_i64,
+    i128 => serialize_i64 try i64,
+    isize => serialize_i64 as i64,
+}
+
+macro_rules! float_impl {
+    ($($t:ident => $serialize:ident, $from_f64:path ;)*) => ($(
+        #[cfg(feature = "parse-floats")]
+        impl ParseNumber for $t {
+            #[inline(always)]
+            fn parse_options<O: Options>() -> Self::Options {
+                O::PARSE_FLOAT
+            }
+
+            #[inline(always)]
+            fn from_native_i128(value: i128) -> Option<Self> {
+                Some(value as $t)
+            }
+
+            #[inline(always)]
+            fn from_native_u128(value: u128) -> Option<Self> {
+                Some(value as $t)
+            }
+
+            #[inline(always)]
+            fn from_native_f64(value: f64) -> Option<Self> {
+                Some($from_f64(value))
+            }
+        }
+
+        #[cfg(feature = "write-floats")]
+        impl WriteNumber for $t {
+            #[inline(always)]
+            fn write_options<O: Options>() -> Self::Options {
+                O::WRITE_FLOAT
+            }
+
+            #[inline(always)]
+            fn buffer_size<O: Options, const FORMAT: u128>() -> usize {
+                O::WRITE_FLOAT.buffer_size_const::<Self, FORMAT>()
+            }
+
+            #[inline(always)]
+            fn is_default<O: Options>() -> bool {
+                O::WRITE_FLOAT == WriteFloatOptions::new()
+            }
+
+            #[inline(always)]
+            fn serialize_native<S: Serializer>(self, string: &str, serializer: S) -> Result<S::Ok, S::Error> {
+                if self.is_finite() {
+                    serializer.$serialize(self)
+                } else {
+                    serializer.serialize_str(string)
+                }
+            }
+        }
+    )*);
+}
+
+float_impl! {
+    f32 => serialize_f32, narrow_f64 ;
+    f64 => serialize_f64, core::convert::identity ;
+}
+
+/// Round a native float to an `f32`.
+///
+/// The deserializer already rounded the number to an `f64`, so casting it
+/// rounds twice, which differs from parsing the number if the `f64` is
+/// halfway between two `f32`. Parsing the shortest digits of the `f64`
+/// instead breaks the tie by those digits, which may still differ from
+/// the original digits if they were within half an `f64` ulp of the
+/// halfway point: serialize such numbers as strings to parse them exactly.
+#[inline]
+#[cfg(feature = "parse-floats")]
+fn narrow_f64(value: f64) -> f32 {
+    #[cfg(feature = "write-floats")]
+    if value.is_finite() {
+        let mut buffer = [0u8; f64::FORMATTED_SIZE_DECIMAL];
+        let digits = lexical_core::write(value, &mut buffer);
+        if let Ok(value) = lexical_core::parse::<f32>(digits) {
+            return value;
+        }
+    }
+    value as f32
+}
+
+#[cfg(all(feature = "f128", feature = "parse-floats"))]
+impl ParseNumber for crate::f128 {
+    #[inline(always)]
+    fn parse_options<O: Options>() -> Self::Options {
+        O::PARSE_FLOAT
+    }
+
+    #[inline(always)]
+    fn from_native_i128(value: i128) -> Option<Self> {
+        Some(Self::from_i128(value))
+    }
+
+    #[inline(always)]
+    fn from_native_u128(value: u128) -> Option<Self> {
+        Some(Self::from_u128(value))
+    }
+
+    #[inline(always)]
+    fn from_native_f64(value: f64) -> Option<Self> {
+        Some(Self::from_f64(value))
+    }
+}
+
+#[cfg(all(feature = "f128", feature = "write-floats"))]
+impl WriteNumber for crate::f128 {
+    #[inline(always)]
+    fn write_options<O: Options>() -> Self::Options {
+        O::WRITE_FLOAT
+    }
+
+    #[inline(always)]
+    fn buffer_size<O: Options, const FORMAT: u128>() -> usize {
+        O::WRITE_FLOAT.buffer_size_const::<Self, FORMAT>()
+    }
+
+    #[inline(always)]
+    fn is_default<O: Options>() -> bool {
+        O::WRITE_FLOAT == WriteFloatOptions::new()
+    }
+
+    /// Serde has no quadruple-precision floats, so this is always a string.
+    #[inline(always)]
+    fn serialize_native<S: Serializer>(
+        self,
+        string: &str,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(string)
+    }
+}
+
+/// Size of the stack buffers to write numbers to.
+///
+/// This fits any number written with the default options in any radix.
+#[cfg(any(feature = "write-floats", feature = "write-integers"))]
+const STACK_SIZE: usize = 512;
+
+/// Serialize a number with the default options.
+///
+/// This may be used with the `serialize_with` attribute. See
+/// [`serialize_with_options`] for when the number is serialized as a
+/// string.
+///
+/// * `FORMAT`      - Packed struct containing the number format.
+/// * `value`       - Number to serialize.
+/// * `serializer`  - Serializer for the string.
+#[inline]
+#[cfg(any(feature = "write-floats", feature = "write-integers"))]
+pub fn serialize<N, S, const FORMAT: u128>(value: &N, serializer: S) -> Result<S::Ok, S::Error>
+where
+    N: WriteNumber,
+    S: Serializer,
+{
+    serialize_with_options::<N, DefaultOptions, S, FORMAT>(value, serializer)
+}
+
+/// Serialize a number with custom writing options.
+///
+/// This may be used with the `serialize_with` attribute. If the number
+/// is written identically with the standard format and default options,
+/// it is serialized as a native number, so the serializer writes the same
+/// value. Otherwise, it is serialized as a string.
+///
+/// * `FORMAT`      - Packed struct containing the number format.
+/// * `value`       - Number to serialize.
+/// * `serializer`  - Serializer for the string.
+#[inline]
+#[cfg(any(feature = "write-floats", feature = "write-integers"))]
+pub fn serialize_with_options<N, O, S, const FORMAT: u128>(
+    value: &N,
+    serializer: S,
+) -> Result<S::Ok, S::Error>
+where
+    N: WriteNumber,
+    O: Options,
+    S: Serializer,
+{
+    // The buffer is on the stack unless the options require more than
+    // `STACK_SIZE` bytes, such as for a large number of significant digits.
+    let options = N::write_options::<O>();
+    let size = N::buffer_size::<O, FORMAT>();
+    let mut stack = [0u8; STACK_SIZE];
+    let mut heap;
+    let buffer = if size <= STACK_SIZE {
+        &mut stack[..size]
+    } else {
+        heap = vec![0u8; size];
+        heap.as_mut_slice()
+    };
+    let digits = lexical_core::write_with_options::<N, FORMAT>(*value, buffer, &options);
+    let string = str::from_utf8(digits).map_err(ser::Error::custom)?;
+
+    // Only write the number again if the format or options differ.
+    let is_native = (FORMAT == STANDARD && N::is_default::<O>()) || {
+        let mut buffer = [0u8; STACK_SIZE];
+        let options = N::write_options::<DefaultOptions>();
+        digits == lexical_core::write_with_options::<N, STANDARD>(*value, &mut buffer, &options)
+    };
+    if is_native {
+        value.serialize_native(string, serializer)
+    } else {
+        serializer.serialize_str(string)
+    }
+}
+
+/// Deserialize a number from a string, bytes, or a native number with the
+/// default options.
+///
+/// This may be used with the `deserialize_with` attribute. The error
+/// contains the lexical error, including the index of the invalid byte.
+///
+/// * `FORMAT`          - Packed struct containing the number format.
+/// * `deserializer`    - Deserializer for the string or bytes.
+#[inline]
+#[cfg(any(feature = "parse-floats", feature = "parse-integers"))]
+pub fn deserialize<'de, N, D, const FORMAT: u128>(deserializer: D) -> Result<N, D::Error>
+where
+    N: ParseNumber,
+    D: Deserializer<'de>,
+{
+    deserialize_with_options::<N, DefaultOptions, D, FORMAT>(deserializer)
+}
+
+/// Deserialize a number from a string, bytes, or a native number with
+/// custom parsing options.
+///
+/// This may be used with the `deserialize_with` attribute. Strings and
+/// bytes are parsed with the number format and options, and the error
+/// contains the lexical error, including the index of the invalid byte.
+/// Native numbers were already parsed by the deserializer, so they are
+/// converted directly, and must be representable by the number type.
+///
+/// * `FORMAT`          - Packed struct containing the number format.
+/// * `deserializer`    - Deserializer for the string or bytes.
+#[inline]
+#[cfg(any(feature = "parse-floats", feature = "parse-integers"))]
+pub fn deserialize_with_options<'de, N, O, D, const FORMAT: u128>(
+    deserializer: D,
+) -> Result<N, D::Error>
+where
+    N: ParseNumber,
+    O: Options,
+    D: Deserializer<'de>,
+{
+    deserializer.deserialize_any(NumberVisitor::<N, O, FORMAT>(PhantomData))
+}
+
+/// Visitor to parse a number from a string, bytes, or a native number.
+#[cfg(any(feature = "parse-floats", feature = "parse-integers"))]
+struct NumberVisitor<N, O, const FORMAT: u128>(PhantomData<(N, O)>);
+
+#[cfg(any(feature = "parse-floats", feature = "parse-integers"))]
+impl<N, O, const FORMAT: u128> NumberVisitor<N, O, FORMAT>
+where
+    N: ParseNumber,
+    O: Options,
+{
+    /// Parse the number, converting the lexical error to a serde error.
+    #[inline]
+    fn parse<E: de::Error>(bytes: &[u8]) -> Result<N, E> {
+        let options = N::parse_options::<O>();
+        N::from_lexical_with_options::<FORMAT>(bytes, &options).map_err(E::custom)
+    }
+}
+
+#[cfg(any(feature = "parse-floats", feature = "parse-integers"))]
+impl<'de, N, O, const FORMAT: u128> Visitor<'de> for NumberVisitor<N, O, FORMAT>
+where
+    N: ParseNumber,
+    O: Options,
+{
+    type Value = N;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("a number or a number as a string")
+    }
+
+    #[inline]
+    fn visit_i64<E: de::Error>(self, value: i64) -> Result<N, E> {
+        N::from_native_i128(value.into())
+            .ok_or_else(|| E::invalid_value(de::Unexpected::Signed(value), &self))
+    }
+
+    #[inline]
+    fn visit_u64<E: de::Error>(self, value: u64) -> Result<N, E> {
+        N::from_native_u128(value.into())
+            .ok_or_else(|| E::invalid_value(de::Unexpected::Unsigned(value), &self))
+    }
+
+    #[inline]
+    fn visit_i128<E: de::Error>(self, value: i128) -> Result<N, E> {
+        N::from_native_i128(value)
+            .ok_or_else(|| E::invalid_value(de::Unexpected::Other("128-bit integer"), &self))
+    }
+
+    #[inline]
+    fn visit_u128<E: de::Error>(self, value: u128) -> Result<N, E> {
+        N::from_native_u128(value)
+            .ok_or_else(|| E::invalid_value(de::Unexpected::Other("128-bit integer"), &self))
+    }
+
+    #[inline]
+    fn visit_f64<E: de::Error>(self, value: f64) -> Result<N, E> {
+        N::from_native_f64(value)
+            .ok_or_else(|| E::invalid_type(de::Unexpected::Float(value), &self))
+    }
+
+    #[inline]
+    fn visit_str<E: de::Error>(self, value: &str) -> Result<N, E> {
+        Self::parse(value.as_bytes())
+    }
+
+    #[inline]
+    fn visit_bytes<E: de::Error>(self, value: &[u8]) -> Result<N, E> {
+        Self::parse(value)
+    }
+}
+
+/// Newtype to (de)serialize a number with a custom format.
+///
+/// This serializes the number as a string if it has a custom syntax, and
+/// deserializes it from a string, bytes, or a native number, like
+/// [`serialize_with_options`] and [`deserialize_with_options`].
+///
+/// * `FORMAT`  - Packed struct containing the number format.
+/// * `O`       - The options to parse and write the number.
+pub struct Lexical<N, const FORMAT: u128 = STANDARD, O = DefaultOptions> {
+    /// The wrapped number.
+    pub value: N,
+    /// Marker for the options.
+    options: PhantomData<O>,
+}
+
+impl<N, const FORMAT: u128, O> Lexical<N, FORMAT, O> {
+    /// Wrap a number to (de)serialize it with a custom format.
+    #[inline(always)]
+    pub const fn new(value: N) -> Self {
+        Self {
+            value,
+            options: PhantomData,
+        }
+    }
+
+    /// Get the wrapped number.
+    #[inline(always)]
+    pub fn into_inner(self) -> N {
+        self.value
+    }
+}
+
+impl<N, const FORMAT: u128, O> From<N> for Lexical<N, FORMAT, O> {
+    #[inline(always)]
+    fn from(value: N) -> Self {
+        Self::new(value)
+    }
+}
+
+// Implement these manually, so they don't require the options to
+// implement the traits.
+
+impl<N: Clone, const FORMAT: u128, O> Clone for Lexical<N, FORMAT, O> {
+    #[inline(always)]
+    fn clone(&self) -> Self {
+        Self::new(self.value.clone())
+    }
+}
+
+impl<N: Copy, const FORMAT: u128, O> Copy for Lexical<N, FORMAT, O> {
+}
+
+impl<N: Default, const FORMAT: u128, O> Default for Lexical<N, FORMAT, O> {
+    #[inline(always)]
+    fn default() -> Self {
+        Self::new(N::default())
+    }
+}
+
+impl<N: fmt::Debug, const FORMAT: u128, O> fmt::Debug for Lexical<N, FORMAT, O> {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.debug_tuple("Lexical").field(&self.value).finish()
+    }
+}
+
+impl<N: PartialEq, const FORMAT: u128, O> PartialEq for Lexical<N, FORMAT, O> {
+    #[inline(always)]
+    fn eq(&self, other: &Self) -> bool {
+        self.value == other.value
+    }
+}
+
+impl<N: PartialOrd, const FORMAT: u128, O> PartialOrd for Lexical<N, FORMAT, O> {
+    #[inline(always)]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.value.partial_cmp(&other.value)
+    }
+}
+
+#[cfg(any(feature = "write-floats", feature = "write-integers"))]
+impl<N, const FORMAT: u128, O> Serialize for Lexical<N, FORMAT, O>
+where
+    N: WriteNumber,
+    O: Options,
+{
+    #[inline]
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize_with_options::<N, O, S, FORMAT>(&self.value, serializer)
+    }
+}
+
+#[cfg(any(feature = "parse-floats", feature = "parse-integers"))]
+impl<'de, N, const FORMAT: u128, O> Deserialize<'de> for Lexical<N, FORMAT, O>
+where
+    N: ParseNumber,
+    O: Options,
+{
+    #[inline]
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserialize_with_options::<N, O, D, FORMAT>(deserializer).map(Self::new)
+    }
+}
@@ -0,0 +1,184 @@
+#![cfg(any(feature = "write-floats", feature = "write-integers"))]
+
+use core::fmt::{self, Write};
+
+use lexical::fmt::{display, write_to_fmt};
+
+/// Write the format arguments to a string.
+fn render(args: fmt::Arguments<'_>) -> String {
+    let mut string = String::new();
+    string.write_fmt(args).unwrap();
+    string
+}
+
+#[test]
+#[cfg(feature = "write-integers")]
+fn display_integer_test() {
+    use lexical::WriteIntegerOptions;
+
+    let options = WriteIntegerOptions::new();
+    assert_eq!(display(0u8, &options).to_string(), "0");
+    assert_eq!(display(-128i8, &options).to_string(), "-128");
+    assert_eq!(display(u128::MAX, &options).to_string(), u128::MAX.to_string());
+
+    let value = display(-42i32, &options);
+    assert_eq!(render(format_args!("[{:>6}]", value)), "[   -42]");
+    assert_eq!(render(format_args!("[{:<6}]", value)), "[-42   ]");
+    assert_eq!(render(format_args!("[{:_^7}]", value)), "[__-42__]");
+    assert_eq!(render(format_args!("[{:06}]", value)), "[-00042]");
+    assert_eq!(render(format_args!("[{:+}]", display(42i32, &options))), "[+42]");
+    assert_eq!(render(format_args!("[{:2}]", value)), "[-42]");
+    assert_eq!(render(format_args!("[{:?}]", value)), "[-42]");
+
+    // The padding matches the standard library.
+    for value in [i64::MIN, -1, 0, 7, i64::MAX] {
+        let expected = render(format_args!("{:>+025}|{:*<22}", value, value));
+        let actual = render(format_args!(
+            "{:>+025}|{:*<22}",
+            display(value, &options),
+            display(value, &options)
+        ));
+        assert_eq!(actual, expected);
+    }
+}
+
+#[test]
+#[cfg(feature = "write-floats")]
+fn display_float_test() {
+    use lexical::WriteFloatOptions;
+
+    let options = WriteFloatOptions::builder().trim_floats(true).build_strict();
+    assert_eq!(display(1.5f64, &options).to_string(), "1.5");
+    assert_eq!(display(12.0f32, &options).to_string(), "12");
+    assert_eq!(display(f64::NAN, &options).to_string(), "NaN");
+    assert_eq!(render(format_args!("[{:>8}]", display(-2.5e-7f64, &options))), "[ -2.5e-7]");
+    assert_eq!(render(format_args!("[{:08}]", display(-1.25f64, &options))), "[-0001.25]");
+    assert_eq!(render(format_args!("[{:^9}]", display(f64::INFINITY, &options))), "[   inf   ]");
+
+    // The precision is ignored.
+    assert_eq!(render(format_args!("[{:.1}]", display(1.25f64, &options))), "[1.25]");
+}
+
+#[test]
+#[cfg(feature = "write-floats")]
+fn display_buffer_size_test() {
+    use core::num::NonZeroUsize;
+
+    use lexical::fmt::Display;
+    use lexical::format::STANDARD;
+    use lexical::WriteFloatOptions;
+
+    // The options require more than `BUFFER_SIZE` bytes.
+    const OPTIONS: WriteFloatOptions =
+        WriteFloatOptions::builder().min_significant_digits(NonZeroUsize::new(400)).build_strict();
+    const SIZE: usize = OPTIONS.buffer_size_const::<f64, STANDARD>();
+    let string = Display::<_, STANDARD, SIZE>::new(1.0f64, &OPTIONS).to_string();
+    assert_eq!(string.len(), 401);
+    assert!(string.starts_with("1.000"));
+}
+
+#[test]
+#[should_panic]
+#[cfg(feature = "write-floats")]
+fn display_buffer_too_small_test() {
+    use core::num::NonZeroUsize;
+
+    use lexical::WriteFloatOptions;
+
+    let options =
+        WriteFloatOptions::builder().min_significant_digits(NonZeroUsize::new(400)).build_strict();
+    let _ = display(1.0f64, &options).to_string();
+}
+
+#[test]
+#[cfg(all(feature = "write-floats", feature = "format"))]
+fn display_format_test() {
+    use lexical::fmt::Display;
+    use lexical::{NumberFormatBuilder, WriteFloatOptions};
+
+    const FORMAT: u128 = NumberFormatBuilder::new().required_exponent_sign(true).build_strict();
+
+    let options = WriteFloatOptions::new();
+    let value = Display::<_, FORMAT>::new(1.5e300f64, &options);
+    assert_eq!(value.to_string(), "1.5e+300");
+    assert_eq!(render(format_args!("[{:>10}]", value)), "[  1.5e+300]");
+    assert_eq!(value.value(), 1.5e300);
+}
+
+#[test]
+#[cfg(all(feature = "write-floats", feature = "format"))]
+fn display_positive_sign_test() {
+    use lexical::fmt::Display;
+    use lexical::{NumberFormatBuilder, WriteFloatOptions, BUFFER_SIZE};
+
+    const FORMAT: u128 = NumberFormatBuilder::new().required_mantissa_sign(true).build_strict();
+
+    // The sign required by the format is written before the zero padding.
+    let options = WriteFloatOptions::new();
+    let value = Display::<_, FORMAT, BUFFER_SIZE>::new(1.5f64, &options);
+    assert_eq!(value.to_string(), "+1.5");
+    assert_eq!(render(format_args!("[{:06}]", value)), "[+001.5]");
+    assert_eq!(render(format_args!("[{:+06}]", value)), "[+001.5]");
+    assert_eq!(render(format_args!("[{:>6}]", value)), "[  +1.5]");
+    assert_eq!(render(format_args!("[{:+}]", value)), "[+1.5]");
+    assert_eq!(render(format_args!("[{:03}]", value)), "[+1.5]");
+    let value = Display::<_, FORMAT, BUFFER_SIZE>::new(-1.5f64, &options);
+    assert_eq!(render(format_args!("[{:06}]", value)), "[-001.5]");
+}
+
+#[test]
+#[cfg(feature = "write-floats")]
+fn display_special_test() {
+    use lexical::WriteFloatOptions;
+
+    // Special values are padded like strings, without zeros.
+    let options = WriteFloatOptions::new();
+    let nan = lexical::fmt::display(f64::NAN, &options);
+    assert_eq!(render(format_args!("[{:05}]", nan)), "[NaN  ]");
+    assert_eq!(render(format_args!("[{:>5}]", nan)), "[  NaN]");
+    let inf = lexical::fmt::display(f64::NEG_INFINITY, &options);
+    assert_eq!(render(format_args!("[{:06}]", inf)), "[-inf  ]");
+    assert_eq!(render(format_args!("[{:*^6}]", inf)), "[*-inf*]");
+}
+
+#[test]
+#[cfg(feature = "write-integers")]
+fn write_to_fmt_test() {
+    use lexical::format::STANDARD;
+    use lexical::{WriteIntegerOptions, BUFFER_SIZE};
+
+    let options = WriteIntegerOptions::new();
+    let mut string = String::new();
+    for value in [1u32, 23, 456] {
+        write_to_fmt::<_, _, STANDARD, BUFFER_SIZE>(value, &mut string, &options).unwrap();
+        string.push(',');
+    }
+    assert_eq!(string, "1,23,456,");
+
+    let writer: &mut dyn Write = &mut string;
+    write_to_fmt::<_, _, STANDARD, BUFFER_SIZE>(-7i64, writer, &options).unwrap();
+    assert_eq!(string, "1,23,456,-7");
+}
+
+#[test]
+#[cfg(all(feature = "std", feature = "write-floats"))]
+fn write_to_io_test() {
+    use std::io;
+
+    use lexical::fmt::write_to_io;
+    use lexical::format::STANDARD;
+    use lexical::{WriteFloatOptions, BUFFER_SIZE};
+
+    let options = WriteFloatOptions::new();
+    let mut bytes: Vec<u8> = Vec::new();
+    write_to_io::<_, _, STANDARD, BUFFER_SIZE>(-0.5f64, &mut bytes, &options).unwrap();
+    write_to_io::<_, _, STANDARD, BUFFER_SIZE>(1e300f64, &mut bytes, &options).unwrap();
+    assert_eq!(bytes, b"-0.51.0e300");
+
+    // Errors from the sink are returned.
+    let mut buffer = [0u8; 4];
+    let mut writer = &mut buffer[..];
+    let error =
+        write_to_io::<_, _, STANDARD, BUFFER_SIZE>(123.456f64, &mut writer, &options).unwrap_err();
+    assert_eq!(error.kind(), io::ErrorKind::WriteZero);
+}
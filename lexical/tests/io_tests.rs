@@ -0,0 +1,172 @@
+#![cfg(feature = "std")]
+
+use std::io::{self, BufRead, Read};
+
+use lexical::io::{NumberReader, ReadError, MAX_NUMBER_LENGTH};
+use lexical::Error;
+
+/// Buffered reader that returns at most `capacity` bytes per refill.
+///
+/// The first refill is interrupted, and once the input is read, the
+/// reader either returns an error once or the end of the input.
+struct ChunkedReader<'a> {
+    input: &'a [u8],
+    capacity: usize,
+    is_interrupted: bool,
+    is_failing: bool,
+}
+
+impl<'a> ChunkedReader<'a> {
+    fn new(input: &'a [u8], capacity: usize) -> Self {
+        Self {
+            input,
+            capacity,
+            is_interrupted: true,
+            is_failing: false,
+        }
+    }
+
+    fn failing(input: &'a [u8], capacity: usize) -> Self {
+        Self {
+            is_failing: true,
+            ..Self::new(input, capacity)
+        }
+    }
+}
+
+impl Read for ChunkedReader<'_> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let count = self.fill_buf()?.read(buf)?;
+        self.consume(count);
+        Ok(count)
+    }
+}
+
+impl BufRead for ChunkedReader<'_> {
+    fn fill_buf(&mut self) -> io::Result<&[u8]> {
+        if self.is_interrupted {
+            self.is_interrupted = false;
+            return Err(io::Error::new(io::ErrorKind::Interrupted, "interrupted"));
+        }
+        if self.input.is_empty() && self.is_failing {
+            self.is_failing = false;
+            return Err(io::Error::new(io::ErrorKind::Other, "disconnected"));
+        }
+        Ok(&self.input[..self.capacity.min(self.input.len())])
+    }
+
+    fn consume(&mut self, amt: usize) {
+        self.input = &self.input[amt..];
+    }
+}
+
+fn parse_error<T>(result: Option<Result<T, ReadError>>) -> Error {
+    match result {
+        Some(Err(ReadError::Parse(error))) => error,
+        _ => panic!("expected a parse error"),
+    }
+}
+
+#[test]
+#[cfg(feature = "parse-floats")]
+fn float_test() {
+    let input = b"  1.5 -2.25e2\n\n3\t4.0e-1\r\n" as &[u8];
+    let values: Result<Vec<f64>, _> = NumberReader::<_, f64>::new(input).collect();
+    assert_eq!(values.unwrap(), [1.5, -225.0, 3.0, 0.4]);
+
+    let values: Result<Vec<f64>, _> = NumberReader::<_, f64>::new(b"" as &[u8]).collect();
+    assert_eq!(values.unwrap(), []);
+    let values: Result<Vec<f64>, _> = NumberReader::<_, f64>::new(b" \n " as &[u8]).collect();
+    assert_eq!(values.unwrap(), []);
+}
+
+#[test]
+#[cfg(feature = "parse-floats")]
+fn straddle_test() {
+    // The numbers straddle refills of small buffers.
+    let input = b"1.2345 -6.789e-3 12345678901234567890 NaN 0.5";
+    for capacity in 1..8 {
+        let reader = ChunkedReader::new(input, capacity);
+        let values: Vec<f64> = NumberReader::<_, f64>::new(reader).map(Result::unwrap).collect();
+        assert_eq!(values.len(), 5);
+        assert_eq!(values[0], 1.2345);
+        assert_eq!(values[1], -6.789e-3);
+        assert_eq!(values[2], 12345678901234567890.0);
+        assert!(values[3].is_nan());
+        assert_eq!(values[4], 0.5);
+    }
+}
+
+#[test]
+#[cfg(feature = "parse-integers")]
+fn error_test() {
+    // Error indexes are absolute offsets in the stream.
+    let input = b"12 3a4\n256 -7\n9x" as &[u8];
+    let mut reader = NumberReader::<_, u8>::new(ChunkedReader::new(input, 3));
+    assert_eq!(reader.next().unwrap().unwrap(), 12);
+    assert_eq!(parse_error(reader.next()), Error::InvalidDigit(4));
+    assert_eq!(parse_error(reader.next()), Error::Overflow(9));
+    assert_eq!(parse_error(reader.next()), Error::InvalidDigit(11));
+    assert_eq!(parse_error(reader.next()), Error::InvalidDigit(15));
+    assert!(reader.next().is_none());
+    assert_eq!(reader.offset(), input.len());
+}
+
+#[test]
+#[cfg(feature = "parse-integers")]
+fn io_error_test() {
+    let reader = ChunkedReader::failing(b"1 23 45", 2);
+    let mut reader = NumberReader::<_, i32>::new(reader);
+    assert_eq!(reader.next().unwrap().unwrap(), 1);
+    assert_eq!(reader.next().unwrap().unwrap(), 23);
+    match reader.next() {
+        Some(Err(ReadError::Io(error))) => assert_eq!(error.to_string(), "disconnected"),
+        _ => panic!("expected an I/O error"),
+    }
+    assert!(reader.next().is_none());
+}
+
+#[test]
+#[cfg(all(feature = "parse-floats", feature = "format"))]
+fn format_test() {
+    use lexical::format::JSON;
+    use lexical::ParseFloatOptions;
+
+    let input = b"1.5 +2 .5 3e1" as &[u8];
+    let reader = ChunkedReader::new(input, 4);
+    let mut reader = NumberReader::<_, f64, JSON>::new(reader);
+    assert_eq!(reader.next().unwrap().unwrap(), 1.5);
+    assert_eq!(parse_error(reader.next()), Error::InvalidPositiveSign(4));
+    assert_eq!(parse_error(reader.next()), Error::EmptyInteger(7));
+    assert_eq!(reader.next().unwrap().unwrap(), 30.0);
+    assert!(reader.next().is_none());
+
+    let options = ParseFloatOptions::builder().decimal_point(b',').build_strict();
+    let input = b"1,5\n2,25" as &[u8];
+    let reader = NumberReader::<_, f32, JSON>::with_options(input, options);
+    let values: Result<Vec<f32>, _> = reader.collect();
+    assert_eq!(values.unwrap(), [1.5, 2.25]);
+}
+
+#[test]
+#[cfg(feature = "parse-integers")]
+fn too_long_test() {
+    // Numbers longer than the maximum are skipped without buffering them.
+    let mut input = b"1 ".to_vec();
+    input.resize(MAX_NUMBER_LENGTH + 3, b'0');
+    input.extend_from_slice(b" 2 ");
+    input.resize(input.len() + MAX_NUMBER_LENGTH - 1, b'0');
+    input.extend_from_slice(b"3 4");
+    for capacity in [7, input.len()] {
+        let mut reader = NumberReader::<_, u64>::new(ChunkedReader::new(&input, capacity));
+        assert_eq!(reader.next().unwrap().unwrap(), 1);
+        match reader.next() {
+            Some(Err(ReadError::TooLong(index))) => assert_eq!(index, 2),
+            _ => panic!("expected a too long error"),
+        }
+        assert_eq!(reader.next().unwrap().unwrap(), 2);
+        assert_eq!(reader.next().unwrap().unwrap(), 3);
+        assert_eq!(reader.next().unwrap().unwrap(), 4);
+        assert!(reader.next().is_none());
+    }
+}
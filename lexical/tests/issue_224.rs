@@ -8,5 +8,5 @@ fn issue_224() {
         value,
         &lexical::write_float_options::JAVASCRIPT_LITERAL,
     );
-    assert_eq!(actual, "1.0020200012020012100112000100111212e212221");
+    assert_eq!(actual, "1.0020200012020012100112000100111021e212221");
 }
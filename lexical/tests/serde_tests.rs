@@ -0,0 +1,164 @@
+#![cfg(feature = "serde")]
+
+use lexical::serde::Lexical;
+use serde::{Deserialize, Serialize};
+
+#[test]
+#[cfg(all(feature = "parse-integers", feature = "write-integers"))]
+fn integer_test() {
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Row {
+        #[serde(
+            serialize_with = "lexical::serde::serialize::<_, _, { lexical::format::STANDARD }>",
+            deserialize_with = "lexical::serde::deserialize::<_, _, { lexical::format::STANDARD }>"
+        )]
+        id: u64,
+        count: Lexical<i32>,
+    }
+
+    let row: Row = serde_json::from_str(r#"{"id":"12345","count":"-12"}"#).unwrap();
+    assert_eq!(row, Row {
+        id: 12345,
+        count: Lexical::new(-12),
+    });
+    assert_eq!(serde_json::to_string(&row).unwrap(), r#"{"id":12345,"count":-12}"#);
+    let native: Row = serde_json::from_str(r#"{"id":12345,"count":-12}"#).unwrap();
+    assert_eq!(native, row);
+
+    let error = serde_json::from_str::<Row>(r#"{"id":"123a5","count":"0"}"#).unwrap_err();
+    assert!(error.to_string().starts_with("lexical parse error: 'invalid digit found' at index 3"));
+    let error = serde_json::from_str::<Row>(r#"{"id":"1","count":"2147483648"}"#).unwrap_err();
+    assert!(error.to_string().contains("'numeric overflow occurred' at index 9"));
+    let error = serde_json::from_str::<Row>(r#"{"id":1,"count":2147483648}"#).unwrap_err();
+    assert!(error.to_string().starts_with("invalid value: integer `2147483648`"));
+    let error = serde_json::from_str::<Row>(r#"{"id":-1,"count":0}"#).unwrap_err();
+    assert!(error.to_string().starts_with("invalid value: integer `-1`"));
+    let error = serde_json::from_str::<Row>(r#"{"id":1.5,"count":0}"#).unwrap_err();
+    assert!(error.to_string().starts_with("invalid type: floating point `1.5`"));
+    let error = serde_json::from_str::<Row>(r#"{"id":true,"count":0}"#).unwrap_err();
+    assert!(error.to_string().contains("expected a number or a number as a string"));
+}
+
+#[test]
+#[cfg(all(feature = "parse-floats", feature = "write-floats", feature = "format"))]
+fn float_format_test() {
+    use lexical::format::{JSON, TOML};
+    use lexical::serde::Options;
+    use lexical::{ParseFloatOptions, WriteFloatOptions};
+
+    struct Comma;
+
+    impl Options for Comma {
+        const PARSE_FLOAT: ParseFloatOptions =
+            ParseFloatOptions::builder().decimal_point(b',').build_strict();
+        const WRITE_FLOAT: WriteFloatOptions =
+            WriteFloatOptions::builder().decimal_point(b',').trim_floats(true).build_strict();
+    }
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Values {
+        json: Lexical<f64, JSON>,
+        toml: Lexical<f32, TOML>,
+        comma: Lexical<f64, { lexical::format::STANDARD }, Comma>,
+    }
+
+    let values: Values =
+        serde_json::from_str(r#"{"json":"1.5e3","toml":"1_000.5","comma":"2,5"}"#).unwrap();
+    assert_eq!(values.json.into_inner(), 1500.0);
+    assert_eq!(values.toml.value, 1000.5);
+    assert_eq!(values.comma.value, 2.5);
+    let string = serde_json::to_string(&values).unwrap();
+    assert_eq!(string, r#"{"json":1500.0,"toml":1000.5,"comma":"2,5"}"#);
+    let native: Values =
+        serde_json::from_str(r#"{"json":15e2,"toml":1000.5,"comma":"2,5"}"#).unwrap();
+    assert_eq!(native, values);
+    let native: Values = serde_json::from_str(r#"{"json":-3,"toml":7,"comma":"0"}"#).unwrap();
+    assert_eq!((native.json.value, native.toml.value), (-3.0, 7.0));
+
+    let error = serde_json::from_str::<Values>(r#"{"json":"1.","toml":"0","comma":"0"}"#);
+    assert!(error.unwrap_err().to_string().contains("at index 2"));
+    let error = serde_json::from_str::<Values>(r#"{"json":"0","toml":"1__0","comma":"0"}"#);
+    assert!(error.unwrap_err().to_string().contains("at index 1"));
+
+    // Numbers with a custom syntax and special values are strings.
+    let values = Values {
+        json: Lexical::new(1e300),
+        toml: Lexical::new(f32::NAN),
+        comma: Lexical::new(-0.5),
+    };
+    let string = serde_json::to_string(&values).unwrap();
+    assert_eq!(string, r#"{"json":1e+300,"toml":"NaN","comma":"-0,5"}"#);
+
+    const STANDARD: u128 = lexical::format::STANDARD;
+    let mut deserializer = serde_json::Deserializer::from_slice(br#""-0,25""#);
+    let value =
+        lexical::serde::deserialize_with_options::<f64, Comma, _, STANDARD>(&mut deserializer);
+    assert_eq!(value.unwrap(), -0.25);
+}
+
+#[test]
+#[cfg(all(feature = "parse-floats", feature = "write-floats"))]
+fn float_native_rounding_test() {
+    // The native number is rounded to `f64` first, and halfway between two
+    // `f32`, so casting it would round to even.
+    let input = "1.00000005960464477550";
+    let expected = lexical::parse::<f32, _>(input).unwrap();
+    assert_eq!(expected, 1.0000001);
+    let value: Lexical<f32> = serde_json::from_str(input).unwrap();
+    assert_eq!(value.value, expected);
+    let value: Lexical<f32> = serde_json::from_str("16777217").unwrap();
+    assert_eq!(value.value, 16777216.0);
+    let value: Lexical<f32> = serde_json::from_str("1e300").unwrap();
+    assert_eq!(value.value, f32::INFINITY);
+}
+
+#[test]
+#[cfg(all(feature = "parse-integers", feature = "write-integers"))]
+fn wide_integer_test() {
+    use serde::de::value::{Error, I128Deserializer, U128Deserializer};
+    use serde::de::IntoDeserializer;
+
+    // Wide integers which do not fit in 64 bits are strings, since
+    // self-describing formats may deserialize them as floats.
+    let values = [Lexical::<u128>::new(u128::MAX), Lexical::new(12)];
+    let string = serde_json::to_string(&values).unwrap();
+    assert_eq!(string, r#"["340282366920938463463374607431768211455",12]"#);
+    assert_eq!(serde_json::from_str::<[Lexical<u128>; 2]>(&string).unwrap(), values);
+    let values = [Lexical::<i128>::new(i128::MIN), Lexical::new(-12)];
+    let string = serde_json::to_string(&values).unwrap();
+    assert_eq!(string, r#"["-170141183460469231731687303715884105728",-12]"#);
+    assert_eq!(serde_json::from_str::<[Lexical<i128>; 2]>(&string).unwrap(), values);
+
+    // Native wide integers are accepted.
+    let deserializer: U128Deserializer<Error> = u128::MAX.into_deserializer();
+    assert_eq!(Lexical::<u128>::deserialize(deserializer).unwrap().value, u128::MAX);
+    let deserializer: I128Deserializer<Error> = i128::MIN.into_deserializer();
+    assert_eq!(Lexical::<i128>::deserialize(deserializer).unwrap().value, i128::MIN);
+    let deserializer: U128Deserializer<Error> = u128::MAX.into_deserializer();
+    let error = Lexical::<u64>::deserialize(deserializer).unwrap_err();
+    assert!(error.to_string().starts_with("invalid value: 128-bit integer"));
+}
+
+#[test]
+#[cfg(all(feature = "parse-floats", feature = "write-floats"))]
+fn float_large_options_test() {
+    use core::num::NonZeroUsize;
+
+    use lexical::serde::Options;
+    use lexical::WriteFloatOptions;
+
+    struct Digits;
+
+    impl Options for Digits {
+        const WRITE_FLOAT: WriteFloatOptions = WriteFloatOptions::builder()
+            .min_significant_digits(NonZeroUsize::new(600))
+            .build_strict();
+    }
+
+    // The options require a larger buffer than the stack buffer.
+    let value = Lexical::<f64, { lexical::format::STANDARD }, Digits>::new(1.5);
+    let string = serde_json::to_string(&value).unwrap();
+    assert_eq!(string.len(), 603);
+    assert!(string.starts_with(r#""1.50000"#));
+    assert_eq!(serde_json::from_str::<Lexical<f64>>(&string).unwrap().value, 1.5);
+}